@@ -0,0 +1,257 @@
+//! `wasm-bin dump`: a section-by-section summary of a module and, with `-d`,
+//! a disassembly of its code with the offset of each instruction.
+
+use crate::{Args, Error};
+use std::fmt::Write;
+use wasm_bin::{
+    decode::{decode, Reader, Result},
+    instructions::{Blocktype, Instr, Memarg},
+    modules::Locals,
+    types::{Numtype, Reftype, Valtype, Vectype},
+    values::Name,
+};
+
+pub fn run(args: &[String]) -> std::result::Result<(), Error> {
+    let args = Args::parse(args, &["-d"], &[])?;
+    let bytes = args.read()?;
+    // Decoding first reports a malformed module before anything is printed.
+    let m = decode(&bytes).map_err(|e| Error::Failed(format!("{}: {e}", args.file)))?;
+    let mut out = String::new();
+    sections(&bytes, &mut out)?;
+    if args.flag("-d") {
+        code(&bytes, m.imported_funcs(), &mut out)?;
+    }
+    print!("{out}");
+    Ok(())
+}
+
+const NAMES: [&str; 13] = [
+    "custom",
+    "type",
+    "import",
+    "function",
+    "table",
+    "memory",
+    "global",
+    "export",
+    "start",
+    "elem",
+    "code",
+    "data",
+    "datacount",
+];
+
+/// Calls `f` with the id, the offset of the id, the size and the contents of
+/// each section.
+fn each_section<'a>(
+    bytes: &'a [u8],
+    mut f: impl FnMut(u8, usize, usize, Reader<'a>) -> Result<()>,
+) -> Result<()> {
+    let mut r = Reader::new(bytes);
+    r.bytes(8)?;
+    while !r.is_empty() {
+        let offset = r.offset();
+        let id = r.byte()?;
+        let n = r.u32()? as usize;
+        f(id, offset, n, r.sub(n)?)?;
+    }
+    Ok(())
+}
+
+fn sections(bytes: &[u8], out: &mut String) -> Result<()> {
+    writeln!(out, "Sections:").unwrap();
+    each_section(bytes, |id, offset, size, mut s| {
+        let detail = match id {
+            0 => format!("name {:?}", s.read::<Name>()?.0),
+            8 => format!("func {}", s.u32()?),
+            // The data count section holds just the count, the others start
+            // with the length of their vector.
+            _ => format!("count {}", s.u32()?),
+        };
+        let name = NAMES[id as usize];
+        writeln!(
+            out,
+            "  {offset:#010x} {id:>2} {name:<9} size {size:<8} {detail}"
+        )
+        .unwrap();
+        Ok(())
+    })
+}
+
+fn code(bytes: &[u8], imported: u32, out: &mut String) -> Result<()> {
+    writeln!(out, "\nCode:").unwrap();
+    each_section(bytes, |id, _, _, mut s| {
+        if id != 10 {
+            return Ok(());
+        }
+        for i in 0..s.u32()? {
+            let offset = s.offset();
+            let size = s.u32()? as usize;
+            writeln!(out, "func[{}] at {offset:#x}, size {size}:", imported + i).unwrap();
+            let mut body = s.sub(size)?;
+            for _ in 0..body.u32()? {
+                let offset = body.offset();
+                let l: Locals = body.read()?;
+                writeln!(out, "  {offset:#010x}: local {} x {}", l.n, valtype(l.t)).unwrap();
+            }
+            instrs(&mut body, out)?;
+        }
+        Ok(())
+    })
+}
+
+/// Prints the instructions of a function body, indenting those in blocks.
+fn instrs(r: &mut Reader, out: &mut String) -> Result<()> {
+    let mut depth = 1;
+    while depth > 0 {
+        let offset = r.offset();
+        let (indent, text) = match r.peek()? {
+            op @ (0x02..=0x04) => {
+                r.byte()?;
+                let name = ["block", "loop", "if"][op as usize - 2];
+                depth += 1;
+                (depth - 2, format!("{name}{}", blocktype(&r.read()?)))
+            }
+            0x05 => {
+                r.byte()?;
+                (depth - 2, "else".into())
+            }
+            0x0b => {
+                r.byte()?;
+                depth -= 1;
+                (depth.max(1) - 1, "end".into())
+            }
+            _ => (depth - 1, instr(&r.read()?)),
+        };
+        writeln!(
+            out,
+            "  {offset:#010x}: {:indent$}{text}",
+            "",
+            indent = indent * 2
+        )
+        .unwrap();
+    }
+    Ok(())
+}
+
+fn blocktype(bt: &Blocktype) -> String {
+    match bt {
+        Blocktype::Empty => String::new(),
+        Blocktype::ValueType(t) => format!(" (result {})", valtype(*t)),
+        Blocktype::TypeIndex(x) => format!(" (type {})", x.0),
+    }
+}
+
+fn valtype(t: Valtype) -> &'static str {
+    match t {
+        Valtype::Numtype(Numtype::I32) => "i32",
+        Valtype::Numtype(Numtype::I64) => "i64",
+        Valtype::Numtype(Numtype::F32) => "f32",
+        Valtype::Numtype(Numtype::F64) => "f64",
+        Valtype::Vectype(Vectype::V128) => "v128",
+        Valtype::Reftype(r) => reftype(r),
+    }
+}
+
+fn reftype(r: Reftype) -> &'static str {
+    match r {
+        Reftype::Funcref => "funcref",
+        Reftype::Externref => "externref",
+    }
+}
+
+fn memarg(m: &Memarg) -> String {
+    let align = 1u64.checked_shl(m.align).unwrap_or(0);
+    format!("offset={} align={align}", m.offset)
+}
+
+/// A NaN in the text format: its sign, and its payload unless canonical.
+fn nan(negative: bool, payload: u64, canonical: u64) -> String {
+    let sign = if negative { "-" } else { "" };
+    if payload == canonical {
+        format!("{sign}nan")
+    } else {
+        format!("{sign}nan:{payload:#x}")
+    }
+}
+
+/// Formats an instruction that doesn't open a block in the text format.
+fn instr(i: &Instr) -> String {
+    match i {
+        Instr::Opcode(op) => op.name().into(),
+        Instr::Block(..) | Instr::Loop(..) | Instr::If(..) | Instr::IfElse(..) => unreachable!(),
+        Instr::Br(l) => format!("br {}", l.0),
+        Instr::BrIf(l) => format!("br_if {}", l.0),
+        Instr::BrTable(ls, default) => {
+            let mut s = "br_table".to_string();
+            for l in ls.0.iter().chain([default]) {
+                write!(s, " {}", l.0).unwrap();
+            }
+            s
+        }
+        Instr::Call(x) => format!("call {}", x.0),
+        Instr::CallIndirect(t, table) => format!("call_indirect {} (type {})", table.0, t.0),
+        Instr::RefNull(Reftype::Funcref) => "ref.null func".into(),
+        Instr::RefNull(Reftype::Externref) => "ref.null extern".into(),
+        Instr::RefFunc(x) => format!("ref.func {}", x.0),
+        Instr::Select(None) => "select".into(),
+        Instr::Select(Some(ts)) => {
+            let ts: Vec<_> = ts.0.iter().map(|&t| valtype(t)).collect();
+            format!("select (result {})", ts.join(" "))
+        }
+        Instr::LocalGet(x) => format!("local.get {}", x.0),
+        Instr::LocalSet(x) => format!("local.set {}", x.0),
+        Instr::LocalTee(x) => format!("local.tee {}", x.0),
+        Instr::GlobalGet(x) => format!("global.get {}", x.0),
+        Instr::GlobalSet(x) => format!("global.set {}", x.0),
+        Instr::TableGet(x) => format!("table.get {}", x.0),
+        Instr::TableSet(x) => format!("table.set {}", x.0),
+        Instr::TableInit(y, x) => format!("table.init {} {}", x.0, y.0),
+        Instr::ElemDrop(y) => format!("elem.drop {}", y.0),
+        Instr::TableCopy(x, y) => format!("table.copy {} {}", x.0, y.0),
+        Instr::TableGrow(x) => format!("table.grow {}", x.0),
+        Instr::TableSize(x) => format!("table.size {}", x.0),
+        Instr::TableFill(x) => format!("table.fill {}", x.0),
+        Instr::MemoryMemarg(op, m) => format!("{} {}", op.name(), memarg(m)),
+        Instr::MemorySize => "memory.size".into(),
+        Instr::MemoryGrow => "memory.grow".into(),
+        Instr::MemoryInit(x) => format!("memory.init {}", x.0),
+        Instr::DataDrop(x) => format!("data.drop {}", x.0),
+        Instr::MemoryCopy => "memory.copy".into(),
+        Instr::MemoryFill => "memory.fill".into(),
+        Instr::I32Const(n) => format!("i32.const {n}"),
+        Instr::I64Const(n) => format!("i64.const {n}"),
+        Instr::F32Const(z) if z.get().is_nan() => {
+            let payload = (z.0 & 0x7f_ffff).into();
+            format!("f32.const {}", nan(z.0 >> 31 != 0, payload, 0x40_0000))
+        }
+        Instr::F32Const(z) => format!("f32.const {:?}", z.get()),
+        Instr::F64Const(z) if z.get().is_nan() => {
+            let payload = z.0 & 0xf_ffff_ffff_ffff;
+            format!(
+                "f64.const {}",
+                nan(z.0 >> 63 != 0, payload, 0x8_0000_0000_0000)
+            )
+        }
+        Instr::F64Const(z) => format!("f64.const {:?}", z.get()),
+        Instr::TruncSat(op) => op.name().into(),
+        Instr::V128Const(b) => {
+            let mut s = "v128.const i8x16".to_string();
+            for b in b {
+                write!(s, " {b}").unwrap();
+            }
+            s
+        }
+        Instr::I8x16Shuffle(lanes) => {
+            let mut s = "i8x16.shuffle".to_string();
+            for l in lanes {
+                write!(s, " {}", l.0).unwrap();
+            }
+            s
+        }
+        Instr::VectorMemarg(op, m) => format!("{} {}", op.name(), memarg(m)),
+        Instr::VectorMemargLaneidx(op, m, l) => format!("{} {} {}", op.name(), memarg(m), l.0),
+        Instr::VectorLaneidx(op, l) => format!("{} {}", op.name(), l.0),
+        Instr::VectorNoImmediate(op) => op.name().into(),
+    }
+}
@@ -0,0 +1,101 @@
+//! Command-line tools for WebAssembly binaries.
+
+mod dump;
+mod strip;
+
+use std::{env, process::ExitCode};
+
+const USAGE: &str = "\
+usage: wasm-bin <command> [options] <file>
+
+commands:
+  dump [-d] <file>    summarize each section; -d also disassembles the code
+  strip [--debug] [--name <glob>]... [-o <out>] <file>
+                      remove custom sections: the debug ones (name, .debug_*,
+                      sourceMappingURL), those matching a glob, or by default
+                      all of them; the file is rewritten unless -o is given";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("dump") => dump::run(&args[1..]),
+        Some("strip") => strip::run(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        _ => Err(Error::Usage),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Error::Usage) => {
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(Error::Failed(message)) => {
+            eprintln!("wasm-bin: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+enum Error {
+    /// The arguments don't make sense; the usage is printed.
+    Usage,
+    Failed(String),
+}
+
+impl From<wasm_bin::decode::Error> for Error {
+    fn from(e: wasm_bin::decode::Error) -> Self {
+        Error::Failed(e.to_string())
+    }
+}
+
+/// A command's arguments: exactly one file, plus flags and options that take
+/// a value, in any order.
+struct Args {
+    file: String,
+    flags: Vec<String>,
+    options: Vec<(String, String)>,
+}
+
+impl Args {
+    fn parse(args: &[String], flags: &[&str], options: &[&str]) -> Result<Self, Error> {
+        let mut parsed = Args {
+            file: String::new(),
+            flags: vec![],
+            options: vec![],
+        };
+        let mut file = None;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if flags.contains(&arg.as_str()) {
+                parsed.flags.push(arg.clone());
+            } else if options.contains(&arg.as_str()) {
+                let value = args.next().ok_or(Error::Usage)?;
+                parsed.options.push((arg.clone(), value.clone()));
+            } else if arg.starts_with('-') || file.is_some() {
+                return Err(Error::Usage);
+            } else {
+                file = Some(arg.clone());
+            }
+        }
+        parsed.file = file.ok_or(Error::Usage)?;
+        Ok(parsed)
+    }
+
+    fn flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+
+    fn values<'a>(&'a self, option: &'a str) -> impl Iterator<Item = &'a str> {
+        self.options
+            .iter()
+            .filter(move |(o, _)| o == option)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn read(&self) -> Result<Vec<u8>, Error> {
+        std::fs::read(&self.file).map_err(|e| Error::Failed(format!("{}: {e}", self.file)))
+    }
+}
@@ -0,0 +1,63 @@
+//! `wasm-bin strip`: removes custom sections, either all of them, the ones
+//! holding debug information, or those whose names match a glob.
+
+use crate::{Args, Error};
+use wasm_bin::{decode::decode, names::Names, Grammar};
+
+pub fn run(args: &[String]) -> Result<(), Error> {
+    let args = Args::parse(args, &["--debug"], &["--name", "-o"])?;
+    let globs: Vec<_> = args.values("--name").collect();
+    let debug = args.flag("--debug");
+    let out = args.values("-o").last().unwrap_or(&args.file).to_string();
+
+    let mut m = decode(&args.read()?).map_err(|e| Error::Failed(format!("{}: {e}", args.file)))?;
+    m.retain_customs(|c| {
+        let name = c.name.0.as_str();
+        let strip = if debug || !globs.is_empty() {
+            debug && is_debug(name) || globs.iter().any(|g| matches(g, name))
+        } else {
+            true
+        };
+        !strip
+    });
+
+    let mut bytes = vec![];
+    m.write(&mut bytes)
+        .and_then(|()| std::fs::write(&out, bytes))
+        .map_err(|e| Error::Failed(format!("{out}: {e}")))
+}
+
+fn is_debug(name: &str) -> bool {
+    name == Names::NAME || name == "sourceMappingURL" || name.starts_with(".debug_")
+}
+
+/// Matches a name against a glob where `*` stands for any run of characters
+/// and `?` for any one character.
+fn matches(glob: &str, name: &str) -> bool {
+    let (glob, name): (Vec<_>, Vec<_>) = (glob.chars().collect(), name.chars().collect());
+    // On a mismatch, the last `*` takes one more character and matching
+    // resumes after it.
+    let (mut g, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match glob.get(g) {
+            Some('*') => {
+                star = Some((g, n));
+                g += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                g += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((sg, sn)) => {
+                    star = Some((sg, sn + 1));
+                    g = sg + 1;
+                    n = sn + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    glob[g..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,106 @@
+use crate::{
+    instructions::Instr,
+    modules::{Custom, Customsec, Funcidx, Module, Section},
+    offsets::{self, FuncOffsets},
+    values::Name,
+    write_all, Grammar,
+};
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BranchHint {
+    Unlikely = 0x00,
+    Likely = 0x01,
+}
+
+impl Grammar for BranchHint {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_all!(w, 1u32, *self as u8)
+    }
+}
+
+/// Hints for `if` and `br_if` instructions, keyed by function and by the
+/// instruction's position in the body: its index in encoding order, with the
+/// contents of `block`, `loop` and `if` following the instruction that opens
+/// them, as in [`FuncOffsets::instrs`].
+///
+/// Positions don't depend on the encoding, so hints carry over to another
+/// encoding of the same bodies. After a pass edits a body, hints for it have
+/// to be moved along with its instructions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BranchHints(pub BTreeMap<(Funcidx, u32), BranchHint>);
+
+impl BranchHints {
+    pub const NAME: &'static str = "metadata.code.branch_hint";
+
+    /// Builds the `metadata.code.branch_hint` section from the offsets of the
+    /// module's code section. Fails if a hint targets a function without a
+    /// body or a position that doesn't hold a conditional branch.
+    pub fn custom(&self, imported_funcs: u32, funcs: &[FuncOffsets]) -> io::Result<Custom> {
+        let mut entries: Vec<(Funcidx, Vec<(u32, BranchHint)>)> = vec![];
+        for (&(x, position), &hint) in self.0.iter() {
+            let func =
+                x.0.checked_sub(imported_funcs)
+                    .and_then(|code| funcs.get(code as usize))
+                    .ok_or_else(|| invalid(format!("function {} has no body", x.0)))?;
+            let instr = func
+                .instrs
+                .get(position as usize)
+                .filter(|i| matches!(i.instr, Instr::If(..) | Instr::IfElse(..) | Instr::BrIf(_)))
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "instruction {position} of function {} is not a conditional branch",
+                        x.0
+                    ))
+                })?;
+            // Offsets in the section start at the local declarations, and
+            // increase with the position.
+            let offset = (instr.offset - func.locals) as u32;
+            match entries.last_mut() {
+                Some((last, hints)) if *last == x => hints.push((offset, hint)),
+                _ => entries.push((x, vec![(offset, hint)])),
+            }
+        }
+
+        let mut contents = vec![];
+        (entries.len() as u32).write(&mut contents)?;
+        for (x, hints) in entries {
+            write_all!(&mut contents, x, hints.len() as u32)?;
+            for (offset, hint) in hints {
+                write_all!(&mut contents, offset, hint)?;
+            }
+        }
+        Ok(Custom {
+            name: Name(Self::NAME.to_string()),
+            contents: contents.into_boxed_slice(),
+        })
+    }
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+impl Module {
+    /// Encodes the module with a branch hint section placed immediately
+    /// before the code section.
+    pub fn write_with_branch_hints<W: Write>(
+        &self,
+        w: &mut W,
+        hints: &BranchHints,
+    ) -> io::Result<()> {
+        let imported_funcs = self.imported_funcs();
+        self.write_with(w, |c, w| {
+            let mut code = vec![];
+            let funcs = offsets::write_codesec(c, &mut code)?;
+            if !hints.0.is_empty() {
+                Customsec(Section(hints.custom(imported_funcs, &funcs)?)).write(w)?;
+            }
+            w.write_all(&code)
+        })
+    }
+}
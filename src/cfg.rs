@@ -0,0 +1,409 @@
+use crate::{
+    instructions::{Instr, Opcode},
+    modules::{Func, Labelidx},
+};
+use std::{collections::BTreeSet, fmt};
+
+mod structurize;
+
+/// A branch targets a label that isn't in scope, which can't happen in a
+/// body that validates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Error(pub Labelidx);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "label {} is not in scope", self.0 .0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// How control leaves a basic block. Targets are indices into
+/// [`Cfg::blocks`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Terminator {
+    Jump(usize),
+    /// Pops an `i32` and continues at `then` if it is nonzero and at
+    /// `otherwise` if it is zero.
+    Branch {
+        then: usize,
+        otherwise: usize,
+    },
+    /// Pops an `i32` and continues at the target it indexes, or at `default`
+    /// if it is out of range.
+    Table {
+        targets: Box<[usize]>,
+        default: usize,
+    },
+    Return,
+    Unreachable,
+}
+
+impl Terminator {
+    pub fn successors(&self) -> Vec<usize> {
+        match self {
+            Terminator::Jump(b) => vec![*b],
+            Terminator::Branch { then, otherwise } => vec![*then, *otherwise],
+            Terminator::Table { targets, default } => {
+                let mut out: Vec<_> = targets.to_vec();
+                out.push(*default);
+                out.sort_unstable();
+                out.dedup();
+                out
+            }
+            Terminator::Return | Terminator::Unreachable => vec![],
+        }
+    }
+}
+
+/// A run of instructions without control flow. Structured control
+/// instructions and branches never appear in `instrs`; they are expressed by
+/// the terminators instead.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BasicBlock {
+    pub instrs: Vec<Instr>,
+    pub terminator: Terminator,
+}
+
+/// The control-flow graph of a function body, with the entry at block 0.
+///
+/// Operands stay on the stack across edges, just as in the structured form.
+/// Values a branch discards when it leaves a block with fewer results than
+/// the stack holds are not represented.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cfg {
+    pub blocks: Vec<BasicBlock>,
+}
+
+impl Cfg {
+    /// Lowers a function body. The end of each `block` and `if` and the start
+    /// of each `loop` begin a new basic block. Code following an
+    /// unconditional branch lands in blocks without predecessors.
+    pub fn from_func(f: &Func) -> Result<Self, Error> {
+        let mut builder = Builder {
+            blocks: vec![],
+            current: 0,
+            labels: vec![],
+        };
+        let entry = builder.block();
+        let exit = builder.block();
+        builder.blocks[exit].terminator = Terminator::Return;
+        builder.current = entry;
+        builder.labels.push(exit);
+        builder.seq(&f.e.0)?;
+        builder.terminate(Terminator::Jump(exit));
+        Ok(Self {
+            blocks: builder.blocks,
+        })
+    }
+
+    pub fn successors(&self, b: usize) -> Vec<usize> {
+        self.blocks[b].terminator.successors()
+    }
+
+    /// The predecessors of each block.
+    pub fn predecessors(&self) -> Vec<Vec<usize>> {
+        let mut preds = vec![vec![]; self.blocks.len()];
+        for b in 0..self.blocks.len() {
+            for s in self.successors(b) {
+                preds[s].push(b);
+            }
+        }
+        preds
+    }
+
+    /// The blocks reachable from the entry in reverse postorder.
+    pub fn reverse_postorder(&self) -> Vec<usize> {
+        let mut visited = vec![false; self.blocks.len()];
+        let mut order = vec![];
+        let mut stack = vec![(0, self.successors(0))];
+        visited[0] = true;
+        while let Some((b, succs)) = stack.last_mut() {
+            match succs.pop() {
+                Some(s) if !visited[s] => {
+                    visited[s] = true;
+                    let succs = self.successors(s);
+                    stack.push((s, succs));
+                }
+                Some(_) => {}
+                None => {
+                    order.push(*b);
+                    stack.pop();
+                }
+            }
+        }
+        order.reverse();
+        order
+    }
+
+    /// Computes the dominator tree with the Cooper-Harvey-Kennedy algorithm.
+    pub fn dominators(&self) -> Dominators {
+        let rpo = self.reverse_postorder();
+        let mut number = vec![usize::MAX; self.blocks.len()];
+        for (i, &b) in rpo.iter().enumerate() {
+            number[b] = i;
+        }
+        let preds = self.predecessors();
+        let mut idom = vec![None; self.blocks.len()];
+        idom[0] = Some(0);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &b in rpo.iter().skip(1) {
+                let mut new: Option<usize> = None;
+                for &p in &preds[b] {
+                    if idom[p].is_none() {
+                        continue;
+                    }
+                    new = Some(match new {
+                        None => p,
+                        Some(mut a) => {
+                            let mut p = p;
+                            while a != p {
+                                while number[a] > number[p] {
+                                    a = idom[a].unwrap_or(0);
+                                }
+                                while number[p] > number[a] {
+                                    p = idom[p].unwrap_or(0);
+                                }
+                            }
+                            a
+                        }
+                    });
+                }
+                if new.is_some() && idom[b] != new {
+                    idom[b] = new;
+                    changed = true;
+                }
+            }
+        }
+        idom[0] = None;
+        Dominators {
+            idom,
+            reachable: number.iter().map(|&n| n != usize::MAX).collect(),
+        }
+    }
+
+    /// Finds the natural loops, one per header, merging back edges that share
+    /// a header. A back edge is an edge to a block that dominates its source,
+    /// so cycles entered at more than one block are not reported.
+    pub fn loops(&self, dom: &Dominators) -> Loops {
+        let preds = self.predecessors();
+        let mut loops: Vec<Loop> = vec![];
+        for &header in &self.reverse_postorder() {
+            let latches: Vec<_> = preds[header]
+                .iter()
+                .copied()
+                .filter(|&p| dom.dominates(header, p))
+                .collect();
+            if latches.is_empty() {
+                continue;
+            }
+            let mut blocks = BTreeSet::from([header]);
+            let mut stack = latches;
+            while let Some(b) = stack.pop() {
+                if blocks.insert(b) {
+                    stack.extend(preds[b].iter().filter(|&&p| dom.is_reachable(p)));
+                }
+            }
+            loops.push(Loop {
+                header,
+                blocks,
+                parent: None,
+            });
+        }
+        for i in 0..loops.len() {
+            loops[i].parent = (0..loops.len())
+                .filter(|&j| {
+                    j != i
+                        && loops[j].blocks.len() > loops[i].blocks.len()
+                        && loops[j].blocks.contains(&loops[i].header)
+                })
+                .min_by_key(|&j| loops[j].blocks.len());
+        }
+        Loops(loops)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dominators {
+    idom: Vec<Option<usize>>,
+    reachable: Vec<bool>,
+}
+
+impl Dominators {
+    /// The immediate dominator of a block, or `None` for the entry and for
+    /// unreachable blocks.
+    pub fn idom(&self, b: usize) -> Option<usize> {
+        self.idom[b]
+    }
+
+    pub fn is_reachable(&self, b: usize) -> bool {
+        self.reachable[b]
+    }
+
+    /// Whether every path from the entry to `b` passes through `a`. A block
+    /// dominates itself.
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        if !self.reachable[a] || !self.reachable[b] {
+            return false;
+        }
+        let mut b = b;
+        loop {
+            if a == b {
+                return true;
+            }
+            match self.idom[b] {
+                Some(d) => b = d,
+                None => return false,
+            }
+        }
+    }
+
+    /// The blocks immediately dominated by each block.
+    pub fn children(&self) -> Vec<Vec<usize>> {
+        let mut children = vec![vec![]; self.idom.len()];
+        for (b, d) in self.idom.iter().enumerate() {
+            if let Some(d) = d {
+                children[*d].push(b);
+            }
+        }
+        children
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Loop {
+    pub header: usize,
+    /// Every block in the loop, including the header and those of nested
+    /// loops.
+    pub blocks: BTreeSet<usize>,
+    /// The index of the innermost enclosing loop.
+    pub parent: Option<usize>,
+}
+
+/// The loop-nesting forest, with outer loops before inner ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Loops(pub Vec<Loop>);
+
+impl Loops {
+    /// The index of the innermost loop containing a block.
+    pub fn innermost(&self, b: usize) -> Option<usize> {
+        (0..self.0.len())
+            .filter(|&i| self.0[i].blocks.contains(&b))
+            .min_by_key(|&i| self.0[i].blocks.len())
+    }
+
+    /// How many loops contain a block.
+    pub fn depth(&self, b: usize) -> usize {
+        self.0.iter().filter(|l| l.blocks.contains(&b)).count()
+    }
+}
+
+struct Builder {
+    blocks: Vec<BasicBlock>,
+    current: usize,
+    /// The block each label branches to, innermost last.
+    labels: Vec<usize>,
+}
+
+impl Builder {
+    fn block(&mut self) -> usize {
+        self.blocks.push(BasicBlock {
+            instrs: vec![],
+            terminator: Terminator::Unreachable,
+        });
+        self.blocks.len() - 1
+    }
+
+    fn terminate(&mut self, t: Terminator) {
+        self.blocks[self.current].terminator = t;
+    }
+
+    /// Ends the current block and continues in a new one.
+    fn split(&mut self, t: Terminator) {
+        self.terminate(t);
+        self.current = self.block();
+    }
+
+    fn label(&self, l: Labelidx) -> Result<usize, Error> {
+        let i = (self.labels.len() - 1)
+            .checked_sub(l.0 as usize)
+            .ok_or(Error(l))?;
+        Ok(self.labels[i])
+    }
+
+    fn nested(&mut self, label: usize, instrs: &[Instr]) -> Result<(), Error> {
+        self.labels.push(label);
+        self.seq(instrs)?;
+        self.labels.pop();
+        Ok(())
+    }
+
+    fn seq(&mut self, instrs: &[Instr]) -> Result<(), Error> {
+        for i in instrs {
+            match i {
+                Instr::Block(_, r#in) => {
+                    let after = self.block();
+                    self.nested(after, r#in)?;
+                    self.terminate(Terminator::Jump(after));
+                    self.current = after;
+                }
+                Instr::Loop(_, r#in) => {
+                    let header = self.block();
+                    self.terminate(Terminator::Jump(header));
+                    self.current = header;
+                    self.nested(header, r#in)?;
+                    let after = self.block();
+                    self.terminate(Terminator::Jump(after));
+                    self.current = after;
+                }
+                Instr::If(_, r#in) => {
+                    let then = self.block();
+                    let after = self.block();
+                    self.terminate(Terminator::Branch {
+                        then,
+                        otherwise: after,
+                    });
+                    self.current = then;
+                    self.nested(after, r#in)?;
+                    self.terminate(Terminator::Jump(after));
+                    self.current = after;
+                }
+                Instr::IfElse(_, in1, in2) => {
+                    let then = self.block();
+                    let otherwise = self.block();
+                    let after = self.block();
+                    self.terminate(Terminator::Branch { then, otherwise });
+                    self.current = then;
+                    self.nested(after, in1)?;
+                    self.terminate(Terminator::Jump(after));
+                    self.current = otherwise;
+                    self.nested(after, in2)?;
+                    self.terminate(Terminator::Jump(after));
+                    self.current = after;
+                }
+                Instr::Br(l) => self.split(Terminator::Jump(self.label(*l)?)),
+                Instr::BrIf(l) => {
+                    let then = self.label(*l)?;
+                    let otherwise = self.block();
+                    self.terminate(Terminator::Branch { then, otherwise });
+                    self.current = otherwise;
+                }
+                Instr::BrTable(ls, l) => self.split(Terminator::Table {
+                    targets: ls
+                        .0
+                        .iter()
+                        .map(|l| self.label(*l))
+                        .collect::<Result<_, _>>()?,
+                    default: self.label(*l)?,
+                }),
+                Instr::Opcode(Opcode::Return) => self.split(Terminator::Return),
+                Instr::Opcode(Opcode::Unreachable) => self.split(Terminator::Unreachable),
+                _ => self.blocks[self.current].instrs.push(i.clone()),
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+use super::{Cfg, Terminator};
+use crate::{
+    instructions::{Blocktype, Instr, Opcode},
+    modules::{Labelidx, Localidx},
+    Vector,
+};
+
+impl Cfg {
+    /// Rebuilds structured control flow, the inverse of
+    /// [`from_func`](Cfg::from_func). Blocks unreachable from the entry are
+    /// left out.
+    ///
+    /// Reducible graphs are translated following Ramsey's "Beyond Relooper":
+    /// each merge node gets a `block` placed right after the code of its
+    /// immediate dominator, each loop header a `loop`, and each edge becomes
+    /// a branch to one of those or the code of its target inline. Irreducible
+    /// graphs fall back to a loop around a `br_table` that dispatches on
+    /// `label`, an `i32` local the function can spare; it is untouched
+    /// otherwise.
+    ///
+    /// The operand stack must be empty along every edge, apart from the
+    /// condition a terminator pops. All blocks are typed `[] -> []`.
+    pub fn structurize(&self, label: Localidx) -> Box<[Instr]> {
+        let rpo = self.reverse_postorder();
+        let mut number = vec![usize::MAX; self.blocks.len()];
+        for (i, &b) in rpo.iter().enumerate() {
+            number[b] = i;
+        }
+        let dom = self.dominators();
+
+        let mut forward = vec![0; self.blocks.len()];
+        let mut header = vec![false; self.blocks.len()];
+        let mut reducible = true;
+        for &x in &rpo {
+            for y in self.successors_with_duplicates(x) {
+                if number[y] > number[x] {
+                    forward[y] += 1;
+                } else {
+                    header[y] = true;
+                    reducible &= dom.dominates(y, x);
+                }
+            }
+        }
+
+        let mut s = Structurizer {
+            cfg: self,
+            number,
+            children: dom.children(),
+            merge: forward.iter().map(|&n| n > 1).collect(),
+            header,
+            frames: vec![],
+            dispatch: None,
+        };
+        let mut out = if reducible {
+            s.tree(0)
+        } else {
+            s.dispatch(label, &rpo)
+        };
+        if !matches!(
+            out.last(),
+            Some(
+                Instr::Br(_)
+                    | Instr::BrTable(..)
+                    | Instr::Opcode(Opcode::Return | Opcode::Unreachable)
+            )
+        ) {
+            out.push(Instr::Opcode(Opcode::Unreachable));
+        }
+        out.into_boxed_slice()
+    }
+
+    fn successors_with_duplicates(&self, b: usize) -> Vec<usize> {
+        match &self.blocks[b].terminator {
+            Terminator::Branch { then, otherwise } => vec![*then, *otherwise],
+            t => t.successors(),
+        }
+    }
+}
+
+/// The constructs enclosing the code being generated, innermost last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frame {
+    /// A `loop` whose label continues at this block.
+    Loop(usize),
+    /// A `block` whose end is followed by the code for this block.
+    Block(usize),
+    /// The loop that dispatches to every block in the irreducible fallback.
+    Dispatch,
+    /// Any other label.
+    Other,
+}
+
+struct Structurizer<'a> {
+    cfg: &'a Cfg,
+    /// Reverse postorder numbers.
+    number: Vec<usize>,
+    /// Dominator tree children.
+    children: Vec<Vec<usize>>,
+    /// Whether a block has more than one forward in-edge.
+    merge: Vec<bool>,
+    /// Whether a block is the target of a back edge.
+    header: Vec<bool>,
+    frames: Vec<Frame>,
+    /// The label local and the dispatch index of each block in the
+    /// irreducible fallback.
+    dispatch: Option<(Localidx, Vec<usize>)>,
+}
+
+impl Structurizer<'_> {
+    fn tree(&mut self, x: usize) -> Vec<Instr> {
+        let mut merges: Vec<_> = self.children[x]
+            .iter()
+            .copied()
+            .filter(|&c| self.merge[c])
+            .collect();
+        merges.sort_by_key(|&c| self.number[c]);
+        if self.header[x] {
+            self.frames.push(Frame::Loop(x));
+            let body = self.within(x, &merges);
+            self.frames.pop();
+            vec![Instr::Loop(Blocktype::Empty, body.into_boxed_slice())]
+        } else {
+            self.within(x, &merges)
+        }
+    }
+
+    /// The code for `x` followed by its merge children, the one latest in
+    /// reverse postorder outermost.
+    fn within(&mut self, x: usize, merges: &[usize]) -> Vec<Instr> {
+        match merges.split_last() {
+            None => self.node(x),
+            Some((&y, rest)) => {
+                self.frames.push(Frame::Block(y));
+                let inner = self.within(x, rest);
+                self.frames.pop();
+                let mut out = vec![Instr::Block(Blocktype::Empty, inner.into_boxed_slice())];
+                out.extend(self.tree(y));
+                out
+            }
+        }
+    }
+
+    fn dispatch(&mut self, label: Localidx, rpo: &[usize]) -> Vec<Instr> {
+        let mut index = vec![0; self.cfg.blocks.len()];
+        for (i, &b) in rpo.iter().enumerate() {
+            index[b] = i;
+        }
+        self.dispatch = Some((label, index));
+
+        self.frames.push(Frame::Dispatch);
+        self.frames.extend(rpo.iter().map(|_| Frame::Other));
+        let targets = (0..rpo.len() as u32 - 1).map(Labelidx).collect();
+        let mut code = vec![
+            Instr::LocalGet(label),
+            Instr::BrTable(Vector(targets), Labelidx(rpo.len() as u32 - 1)),
+        ];
+        for &b in rpo {
+            self.frames.pop();
+            code = vec![Instr::Block(Blocktype::Empty, code.into_boxed_slice())];
+            code.extend(self.node(b));
+        }
+        self.frames.pop();
+        vec![
+            Instr::I32Const(0),
+            Instr::LocalSet(label),
+            Instr::Loop(Blocktype::Empty, code.into_boxed_slice()),
+        ]
+    }
+
+    /// The instructions of a block followed by its terminator.
+    fn node(&mut self, x: usize) -> Vec<Instr> {
+        let mut out = self.cfg.blocks[x].instrs.clone();
+        match &self.cfg.blocks[x].terminator {
+            Terminator::Jump(y) => out.extend(self.branch(x, *y)),
+            Terminator::Branch { then, otherwise } => {
+                self.frames.push(Frame::Other);
+                let then = self.branch(x, *then);
+                let otherwise = self.branch(x, *otherwise);
+                self.frames.pop();
+                out.push(Instr::IfElse(
+                    Blocktype::Empty,
+                    then.into_boxed_slice(),
+                    otherwise.into_boxed_slice(),
+                ));
+            }
+            Terminator::Table { targets, default } => {
+                let mut distinct: Vec<usize> = vec![];
+                for &t in targets.iter().chain([default]) {
+                    if !distinct.contains(&t) {
+                        distinct.push(t);
+                    }
+                }
+                let position = |t: &usize| {
+                    let i = distinct.iter().position(|d| d == t);
+                    Labelidx(i.expect("every target is among the distinct ones") as u32)
+                };
+                // The index is computed inside the innermost block since the
+                // blocks can't take parameters.
+                out.push(Instr::BrTable(
+                    Vector(targets.iter().map(position).collect()),
+                    position(default),
+                ));
+                self.frames.extend(distinct.iter().map(|_| Frame::Other));
+                for &t in &distinct {
+                    self.frames.pop();
+                    out = vec![Instr::Block(Blocktype::Empty, out.into_boxed_slice())];
+                    out.extend(self.branch(x, t));
+                }
+            }
+            Terminator::Return => out.push(Instr::Opcode(Opcode::Return)),
+            Terminator::Unreachable => out.push(Instr::Opcode(Opcode::Unreachable)),
+        }
+        out
+    }
+
+    fn branch(&mut self, x: usize, y: usize) -> Vec<Instr> {
+        if let Some((label, index)) = &self.dispatch {
+            return vec![
+                Instr::I32Const(index[y] as i32),
+                Instr::LocalSet(*label),
+                Instr::Br(self.depth(Frame::Dispatch)),
+            ];
+        }
+        if self.number[y] <= self.number[x] {
+            vec![Instr::Br(self.depth(Frame::Loop(y)))]
+        } else if self.merge[y] {
+            vec![Instr::Br(self.depth(Frame::Block(y)))]
+        } else {
+            self.tree(y)
+        }
+    }
+
+    /// The label of an enclosing frame. Back edges go to a loop header that
+    /// dominates the source and forward edges to a merge node are reached
+    /// from within the block placed for it, so the frame is always there.
+    fn depth(&self, frame: Frame) -> Labelidx {
+        let depth = self.frames.iter().rev().position(|&f| f == frame);
+        Labelidx(depth.unwrap_or_else(|| panic!("no enclosing {frame:?}")) as u32)
+    }
+}
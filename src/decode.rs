@@ -0,0 +1,875 @@
+use crate::{
+    instructions::{
+        Blocktype, Expr, Instr, Laneidx, Memarg, MemoryMemarg, Opcode, TruncSat, VectorLaneidx,
+        VectorMemarg, VectorMemargLaneidx, VectorNoImmediate, S33,
+    },
+    modules::{
+        Code, Codesec, Custom, Customsec, Data, Datacountsec, Dataidx, Datasec, Elem, Elemidx,
+        Elemkind, Elemsec, Export, Exportdesc, Exportsec, Func, Funcidx, Funcsec, Global,
+        Globalidx, Globalsec, Import, Importdesc, Importsec, KnownSection, Labelidx, Localidx,
+        Locals, Mem, Memidx, Memsec, Module, Placement, Section, SectionId, Start, Startsec, Table,
+        Tableidx, Tablesec, Typeidx, Typesec,
+    },
+    types::{
+        Functype, Globaltype, Limits, Memtype, Mut, Numtype, Reftype, Resulttype, Tabletype,
+        Valtype, Vectype,
+    },
+    values::{Name, F32, F64},
+    Vector,
+};
+use std::{fmt, sync::OnceLock};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Error {
+    /// Where in the input decoding failed.
+    pub offset: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {:#x}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    UnexpectedEnd,
+    /// A LEB128 integer with more bytes than its type allows.
+    IntegerTooLong,
+    /// A LEB128 integer whose unused bits aren't zero or sign extension.
+    IntegerTooLarge,
+    MalformedUtf8,
+    MagicHeader,
+    UnknownVersion,
+    UnknownSection(u8),
+    /// A known section after one that must follow it, or twice.
+    SectionOrder(u8),
+    /// A section or function body whose contents don't fill its size.
+    SizeMismatch,
+    /// A byte that doesn't encode any of the named construct.
+    Unknown(&'static str),
+    ZeroByteExpected,
+    /// The function and code sections have different lengths.
+    FunctionCount,
+    /// The data count section doesn't match the data section, or is missing
+    /// though the code uses data indices.
+    DataCount,
+    TooManyLocals,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnexpectedEnd => write!(f, "unexpected end"),
+            ErrorKind::IntegerTooLong => write!(f, "integer representation too long"),
+            ErrorKind::IntegerTooLarge => write!(f, "integer too large"),
+            ErrorKind::MalformedUtf8 => write!(f, "malformed UTF-8 encoding"),
+            ErrorKind::MagicHeader => write!(f, "magic header not detected"),
+            ErrorKind::UnknownVersion => write!(f, "unknown binary version"),
+            ErrorKind::UnknownSection(id) => write!(f, "malformed section id {id}"),
+            ErrorKind::SectionOrder(id) => write!(f, "unexpected section {id}"),
+            ErrorKind::SizeMismatch => write!(f, "section size mismatch"),
+            ErrorKind::Unknown(what) => write!(f, "malformed {what}"),
+            ErrorKind::ZeroByteExpected => write!(f, "zero byte expected"),
+            ErrorKind::FunctionCount => {
+                write!(f, "function and code section have inconsistent lengths")
+            }
+            ErrorKind::DataCount => {
+                write!(f, "data count and data section have inconsistent lengths")
+            }
+            ErrorKind::TooManyLocals => write!(f, "too many locals"),
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A cursor over the bytes being decoded. Offsets are relative to the start
+/// of the outermost reader.
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    base: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            base: 0,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.base + self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    pub fn error(&self, kind: ErrorKind) -> Error {
+        Error {
+            offset: self.offset(),
+            kind,
+        }
+    }
+
+    pub fn peek(&self) -> Result<u8> {
+        self.bytes
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| self.error(ErrorKind::UnexpectedEnd))
+    }
+
+    pub fn byte(&mut self) -> Result<u8> {
+        let b = self.peek()?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    pub fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| self.error(ErrorKind::UnexpectedEnd))?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Splits off the next `n` bytes as their own reader.
+    pub fn sub(&mut self, n: usize) -> Result<Reader<'a>> {
+        let base = self.offset();
+        Ok(Reader {
+            bytes: self.bytes(n)?,
+            pos: 0,
+            base,
+        })
+    }
+
+    pub fn read<T: Decode>(&mut self) -> Result<T> {
+        T::decode(self)
+    }
+
+    pub fn unsigned(&mut self, bits: u32) -> Result<u64> {
+        let mut result = 0;
+        let mut shift = 0;
+        loop {
+            let b = self.byte()?;
+            result |= ((b & 0x7f) as u64) << shift;
+            if shift + 7 >= bits {
+                if b & 0x80 != 0 {
+                    return Err(self.error(ErrorKind::IntegerTooLong));
+                }
+                if (b & 0x7f) >> (bits - shift) != 0 {
+                    return Err(self.error(ErrorKind::IntegerTooLarge));
+                }
+                return Ok(result);
+            }
+            if b & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    pub fn signed(&mut self, bits: u32) -> Result<i64> {
+        let mut result = 0i64;
+        let mut shift = 0;
+        loop {
+            let b = self.byte()?;
+            result |= ((b & 0x7f) as i64) << shift;
+            if shift + 7 >= bits {
+                if b & 0x80 != 0 {
+                    return Err(self.error(ErrorKind::IntegerTooLong));
+                }
+                // The bits past the width must all copy the sign bit.
+                let used = bits - shift;
+                let rest = (b & 0x7f) >> (used - 1);
+                if rest != 0 && rest != 0x7f >> (used - 1) {
+                    return Err(self.error(ErrorKind::IntegerTooLarge));
+                }
+                return Ok(result << (64 - bits) >> (64 - bits));
+            }
+            shift += 7;
+            if b & 0x80 == 0 {
+                return Ok(result << (64 - shift) >> (64 - shift));
+            }
+        }
+    }
+
+    pub fn u32(&mut self) -> Result<u32> {
+        Ok(self.unsigned(32)? as u32)
+    }
+
+    pub fn i32(&mut self) -> Result<i32> {
+        Ok(self.signed(32)? as i32)
+    }
+
+    pub fn i64(&mut self) -> Result<i64> {
+        self.signed(64)
+    }
+
+    fn zero(&mut self) -> Result<()> {
+        match self.byte()? {
+            0 => Ok(()),
+            _ => Err(self.error(ErrorKind::ZeroByteExpected)),
+        }
+    }
+
+    fn unknown<T>(&self, what: &'static str) -> Result<T> {
+        Err(Error {
+            offset: self.offset() - 1,
+            kind: ErrorKind::Unknown(what),
+        })
+    }
+}
+
+/// The inverse of [`Grammar`](crate::Grammar).
+pub trait Decode: Sized {
+    fn decode(r: &mut Reader) -> Result<Self>;
+}
+
+/// Decodes a binary module.
+pub fn decode(bytes: &[u8]) -> Result<Module> {
+    let mut r = Reader::new(bytes);
+    let m = r.read()?;
+    if !r.is_empty() {
+        return Err(r.error(ErrorKind::SizeMismatch));
+    }
+    Ok(m)
+}
+
+impl Decode for u8 {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        r.byte()
+    }
+}
+
+impl Decode for u32 {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        r.u32()
+    }
+}
+
+impl Decode for i32 {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        r.i32()
+    }
+}
+
+impl Decode for i64 {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        r.i64()
+    }
+}
+
+impl Decode for F32 {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let mut b = [0; 4];
+        b.copy_from_slice(r.bytes(4)?);
+        Ok(F32(u32::from_le_bytes(b)))
+    }
+}
+
+impl Decode for F64 {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let mut b = [0; 8];
+        b.copy_from_slice(r.bytes(8)?);
+        Ok(F64(u64::from_le_bytes(b)))
+    }
+}
+
+impl Decode for Name {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let n = r.u32()? as usize;
+        let start = r.offset();
+        let s = std::str::from_utf8(r.bytes(n)?).map_err(|_| Error {
+            offset: start,
+            kind: ErrorKind::MalformedUtf8,
+        })?;
+        Ok(Name(s.to_string()))
+    }
+}
+
+impl<T: Decode> Decode for Vector<T> {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let n = r.u32()? as usize;
+        // Every element takes at least a byte, so a bad length can't make us
+        // allocate more than the input.
+        let mut items = Vec::with_capacity(n.min(r.bytes.len() - r.pos));
+        for _ in 0..n {
+            items.push(r.read()?);
+        }
+        Ok(Vector(items.into_boxed_slice()))
+    }
+}
+
+macro_rules! idx {
+    ($($t:ident),*) => {
+        $(
+            impl Decode for $t {
+                fn decode(r: &mut Reader) -> Result<Self> {
+                    Ok($t(r.u32()?))
+                }
+            }
+        )*
+    };
+}
+
+idx!(Typeidx, Funcidx, Tableidx, Memidx, Globalidx, Elemidx, Dataidx, Localidx, Labelidx);
+
+fn valtype(b: u8) -> Option<Valtype> {
+    Some(match b {
+        0x7f => Valtype::Numtype(Numtype::I32),
+        0x7e => Valtype::Numtype(Numtype::I64),
+        0x7d => Valtype::Numtype(Numtype::F32),
+        0x7c => Valtype::Numtype(Numtype::F64),
+        0x7b => Valtype::Vectype(Vectype::V128),
+        0x70 => Valtype::Reftype(Reftype::Funcref),
+        0x6f => Valtype::Reftype(Reftype::Externref),
+        _ => return None,
+    })
+}
+
+impl Decode for Valtype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        match valtype(r.byte()?) {
+            Some(t) => Ok(t),
+            None => r.unknown("value type"),
+        }
+    }
+}
+
+impl Decode for Reftype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        match r.byte()? {
+            0x70 => Ok(Reftype::Funcref),
+            0x6f => Ok(Reftype::Externref),
+            _ => r.unknown("reference type"),
+        }
+    }
+}
+
+impl Decode for Resulttype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Resulttype(r.read()?))
+    }
+}
+
+impl Decode for Functype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        if r.byte()? != 0x60 {
+            return r.unknown("function type");
+        }
+        Ok(Functype {
+            parameters: r.read()?,
+            results: r.read()?,
+        })
+    }
+}
+
+impl Decode for Limits {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        match r.byte()? {
+            0x00 => Ok(Limits::Min(r.u32()?)),
+            0x01 => Ok(Limits::MinMax(r.u32()?, r.u32()?)),
+            _ => r.unknown("limits"),
+        }
+    }
+}
+
+impl Decode for Memtype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Memtype(r.read()?))
+    }
+}
+
+impl Decode for Tabletype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Tabletype {
+            element_type: r.read()?,
+            limits: r.read()?,
+        })
+    }
+}
+
+impl Decode for Mut {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        match r.byte()? {
+            0x00 => Ok(Mut::Const),
+            0x01 => Ok(Mut::Var),
+            _ => r.unknown("mutability"),
+        }
+    }
+}
+
+impl Decode for Globaltype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Globaltype {
+            ty: r.read()?,
+            mutability: r.read()?,
+        })
+    }
+}
+
+impl Decode for Blocktype {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let b = r.peek()?;
+        if b == 0x40 {
+            r.byte()?;
+            return Ok(Blocktype::Empty);
+        }
+        if let Some(t) = valtype(b) {
+            r.byte()?;
+            return Ok(Blocktype::ValueType(t));
+        }
+        match r.signed(33)? {
+            x if x >= 0 => Ok(Blocktype::TypeIndex(S33(x))),
+            _ => r.unknown("block type"),
+        }
+    }
+}
+
+impl Decode for Memarg {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Memarg {
+            align: r.u32()?,
+            offset: r.u32()?,
+        })
+    }
+}
+
+impl Decode for Laneidx {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Laneidx(r.byte()?))
+    }
+}
+
+/// Decodes instructions up to an `end` or `else`, consuming and returning it.
+fn instrs(r: &mut Reader) -> Result<(Box<[Instr]>, u8)> {
+    let mut out = vec![];
+    loop {
+        match r.peek()? {
+            b @ (0x0b | 0x05) => {
+                r.byte()?;
+                return Ok((out.into_boxed_slice(), b));
+            }
+            _ => out.push(r.read()?),
+        }
+    }
+}
+
+/// Decodes instructions up to an `end`.
+fn body(r: &mut Reader) -> Result<Box<[Instr]>> {
+    match instrs(r)? {
+        (body, 0x0b) => Ok(body),
+        _ => r.unknown("instruction"),
+    }
+}
+
+impl Decode for Expr {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Expr(body(r)?))
+    }
+}
+
+fn lookup<T: Copy, K: PartialEq>(all: &[T], key: impl Fn(T) -> K, k: K) -> Option<T> {
+    all.iter().copied().find(|&op| key(op) == k)
+}
+
+fn opcode(b: u8) -> Option<Opcode> {
+    static TABLE: OnceLock<[Option<Opcode>; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [None; 256];
+        for &op in Opcode::ALL {
+            table[op as usize] = Some(op);
+        }
+        table
+    })[b as usize]
+}
+
+fn vector_no_immediate(n: u32) -> Option<VectorNoImmediate> {
+    static TABLE: OnceLock<[Option<VectorNoImmediate>; 256]> = OnceLock::new();
+    let table = TABLE.get_or_init(|| {
+        let mut table = [None; 256];
+        for &op in VectorNoImmediate::ALL {
+            table[op as usize] = Some(op);
+        }
+        table
+    });
+    *table.get(n as usize)?
+}
+
+impl Decode for Instr {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(match r.byte()? {
+            0x02 => Instr::Block(r.read()?, body(r)?),
+            0x03 => Instr::Loop(r.read()?, body(r)?),
+            0x04 => {
+                let bt = r.read()?;
+                match instrs(r)? {
+                    (then, 0x05) => Instr::IfElse(bt, then, body(r)?),
+                    (then, _) => Instr::If(bt, then),
+                }
+            }
+            0x0c => Instr::Br(r.read()?),
+            0x0d => Instr::BrIf(r.read()?),
+            0x0e => Instr::BrTable(r.read()?, r.read()?),
+            0x10 => Instr::Call(r.read()?),
+            0x11 => Instr::CallIndirect(r.read()?, r.read()?),
+
+            0xd0 => Instr::RefNull(r.read()?),
+            0xd2 => Instr::RefFunc(r.read()?),
+
+            0x1b => Instr::Select(None),
+            0x1c => Instr::Select(Some(r.read()?)),
+
+            0x20 => Instr::LocalGet(r.read()?),
+            0x21 => Instr::LocalSet(r.read()?),
+            0x22 => Instr::LocalTee(r.read()?),
+            0x23 => Instr::GlobalGet(r.read()?),
+            0x24 => Instr::GlobalSet(r.read()?),
+
+            0x25 => Instr::TableGet(r.read()?),
+            0x26 => Instr::TableSet(r.read()?),
+
+            b @ 0x28..=0x3e => match lookup(MemoryMemarg::ALL, |op| op as u8, b) {
+                Some(op) => Instr::MemoryMemarg(op, r.read()?),
+                None => return r.unknown("opcode"),
+            },
+            0x3f => {
+                r.zero()?;
+                Instr::MemorySize
+            }
+            0x40 => {
+                r.zero()?;
+                Instr::MemoryGrow
+            }
+
+            0x41 => Instr::I32Const(r.i32()?),
+            0x42 => Instr::I64Const(r.i64()?),
+            0x43 => Instr::F32Const(r.read()?),
+            0x44 => Instr::F64Const(r.read()?),
+
+            0xfc => match r.u32()? {
+                n @ 0..=7 => match lookup(TruncSat::ALL, |op| op as u32, n) {
+                    Some(op) => Instr::TruncSat(op),
+                    None => return r.unknown("opcode"),
+                },
+                8 => {
+                    let x = r.read()?;
+                    r.zero()?;
+                    Instr::MemoryInit(x)
+                }
+                9 => Instr::DataDrop(r.read()?),
+                10 => {
+                    r.zero()?;
+                    r.zero()?;
+                    Instr::MemoryCopy
+                }
+                11 => {
+                    r.zero()?;
+                    Instr::MemoryFill
+                }
+                12 => Instr::TableInit(r.read()?, r.read()?),
+                13 => Instr::ElemDrop(r.read()?),
+                14 => Instr::TableCopy(r.read()?, r.read()?),
+                15 => Instr::TableGrow(r.read()?),
+                16 => Instr::TableSize(r.read()?),
+                17 => Instr::TableFill(r.read()?),
+                _ => return r.unknown("opcode"),
+            },
+
+            0xfd => {
+                let n = r.u32()?;
+                if n == 12 {
+                    let mut b = [0; 16];
+                    b.copy_from_slice(r.bytes(16)?);
+                    Instr::V128Const(b)
+                } else if n == 13 {
+                    let mut lanes = [Laneidx(0); 16];
+                    for l in &mut lanes {
+                        *l = r.read()?;
+                    }
+                    Instr::I8x16Shuffle(lanes)
+                } else if let Some(op) = lookup(VectorMemarg::ALL, |op| op as u32, n) {
+                    Instr::VectorMemarg(op, r.read()?)
+                } else if let Some(op) = lookup(VectorMemargLaneidx::ALL, |op| op as u32, n) {
+                    Instr::VectorMemargLaneidx(op, r.read()?, r.read()?)
+                } else if let Some(op) = lookup(VectorLaneidx::ALL, |op| op as u32, n) {
+                    Instr::VectorLaneidx(op, r.read()?)
+                } else if let Some(op) = vector_no_immediate(n) {
+                    Instr::VectorNoImmediate(op)
+                } else {
+                    return r.unknown("opcode");
+                }
+            }
+
+            b => match opcode(b) {
+                Some(op) => Instr::Opcode(op),
+                None => return r.unknown("opcode"),
+            },
+        })
+    }
+}
+
+impl Decode for Importdesc {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(match r.byte()? {
+            0x00 => Importdesc::Func(r.read()?),
+            0x01 => Importdesc::Table(r.read()?),
+            0x02 => Importdesc::Mem(r.read()?),
+            0x03 => Importdesc::Global(r.read()?),
+            _ => return r.unknown("import kind"),
+        })
+    }
+}
+
+impl Decode for Import {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Import {
+            r#mod: r.read()?,
+            nm: r.read()?,
+            d: r.read()?,
+        })
+    }
+}
+
+impl Decode for Table {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Table(r.read()?))
+    }
+}
+
+impl Decode for Mem {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Mem(r.read()?))
+    }
+}
+
+impl Decode for Global {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Global {
+            gt: r.read()?,
+            e: r.read()?,
+        })
+    }
+}
+
+impl Decode for Exportdesc {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(match r.byte()? {
+            0x00 => Exportdesc::Func(r.read()?),
+            0x01 => Exportdesc::Table(r.read()?),
+            0x02 => Exportdesc::Mem(r.read()?),
+            0x03 => Exportdesc::Global(r.read()?),
+            _ => return r.unknown("export kind"),
+        })
+    }
+}
+
+impl Decode for Export {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Export {
+            nm: r.read()?,
+            d: r.read()?,
+        })
+    }
+}
+
+impl Decode for Start {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Start(r.read()?))
+    }
+}
+
+impl Decode for Elemkind {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        match r.byte()? {
+            0x00 => Ok(Elemkind),
+            _ => r.unknown("elements segment kind"),
+        }
+    }
+}
+
+impl Decode for Elem {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(match r.u32()? {
+            0 => Elem::FuncrefFuncActive(r.read()?, r.read()?),
+            1 => Elem::ElemkindFuncPassive(r.read()?, r.read()?),
+            2 => Elem::ElemkindFuncActive(r.read()?, r.read()?, r.read()?, r.read()?),
+            3 => Elem::ElemkindFuncDeclarative(r.read()?, r.read()?),
+            4 => Elem::FuncrefExprActive(r.read()?, r.read()?),
+            5 => Elem::ReftypeExprPassive(r.read()?, r.read()?),
+            6 => Elem::ReftypeExprActive(r.read()?, r.read()?, r.read()?, r.read()?),
+            7 => Elem::ReftypeExprDeclarative(r.read()?, r.read()?),
+            _ => return r.unknown("elements segment kind"),
+        })
+    }
+}
+
+impl Decode for Locals {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(Locals {
+            n: r.read()?,
+            t: r.read()?,
+        })
+    }
+}
+
+impl Decode for Func {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let t: Vector<Locals> = r.read()?;
+        if t.0.iter().map(|l| l.n as u64).sum::<u64>() > u32::MAX as u64 {
+            return Err(r.error(ErrorKind::TooManyLocals));
+        }
+        Ok(Func { t, e: r.read()? })
+    }
+}
+
+impl Decode for Code {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let n = r.u32()? as usize;
+        let mut body = r.sub(n)?;
+        let f = body.read()?;
+        if !body.is_empty() {
+            return Err(body.error(ErrorKind::SizeMismatch));
+        }
+        Ok(Code(f))
+    }
+}
+
+impl Decode for Data {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(match r.u32()? {
+            0 => Data::ActiveAtZero(r.read()?, r.read()?),
+            1 => Data::Passive(r.read()?),
+            2 => Data::ActiveAtIndex(r.read()?, r.read()?, r.read()?),
+            _ => return r.unknown("data segment kind"),
+        })
+    }
+}
+
+impl Decode for Custom {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        let name = r.read()?;
+        let rest = r.bytes.len() - r.pos;
+        Ok(Custom {
+            name,
+            contents: r.bytes(rest)?.into(),
+        })
+    }
+}
+
+fn section<const N: u8, T: Decode>(r: &mut Reader) -> Result<Section<N, T>> {
+    Ok(Section(r.read()?))
+}
+
+impl Decode for Module {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        if r.bytes(4).ok() != Some(&[0x00, 0x61, 0x73, 0x6d][..]) {
+            return Err(Error {
+                offset: 0,
+                kind: ErrorKind::MagicHeader,
+            });
+        }
+        if r.bytes(4).ok() != Some(&[0x01, 0x00, 0x00, 0x00][..]) {
+            return Err(Error {
+                offset: 4,
+                kind: ErrorKind::UnknownVersion,
+            });
+        }
+
+        let mut m = Module::default();
+        // The position in `KnownSection::ALL` of the last known section.
+        let mut last = None;
+        while !r.is_empty() {
+            let id = r.byte()?;
+            let n = r.u32()? as usize;
+            let mut s = r.sub(n)?;
+            if id == 0 {
+                let custom = Customsec(section(&mut s)?);
+                let placement = match last.map_or(0, |i| i + 1) {
+                    i if i < KnownSection::ALL.len() => Placement::Before(KnownSection::ALL[i]),
+                    _ => Placement::Last,
+                };
+                m.insert_custom(placement, custom.0 .0);
+                continue;
+            }
+
+            let Some(known) = section_id(id) else {
+                return Err(Error {
+                    offset: s.offset() - 1 - leb_len(n),
+                    kind: ErrorKind::UnknownSection(id),
+                });
+            };
+            let position = KnownSection::ALL.iter().position(|s| s.id() == known);
+            if position <= last {
+                return Err(r.error(ErrorKind::SectionOrder(id)));
+            }
+            last = position;
+            match known {
+                SectionId::Custom => unreachable!(),
+                SectionId::Type => m.typesec = Some(Typesec(section(&mut s)?)),
+                SectionId::Import => m.importsec = Some(Importsec(section(&mut s)?)),
+                SectionId::Func => m.funcsec = Some(Funcsec(section(&mut s)?)),
+                SectionId::Table => m.tablesec = Some(Tablesec(section(&mut s)?)),
+                SectionId::Mem => m.memsec = Some(Memsec(section(&mut s)?)),
+                SectionId::Global => m.globalsec = Some(Globalsec(section(&mut s)?)),
+                SectionId::Export => m.exportsec = Some(Exportsec(section(&mut s)?)),
+                SectionId::Start => m.startsec = Some(Startsec(section(&mut s)?)),
+                SectionId::Elem => m.elemsec = Some(Elemsec(section(&mut s)?)),
+                SectionId::Code => m.codesec = Some(Codesec(section(&mut s)?)),
+                SectionId::Data => m.datasec = Some(Datasec(section(&mut s)?)),
+                SectionId::Datacount => m.datacountsec = Some(Datacountsec(section(&mut s)?)),
+            }
+            if !s.is_empty() {
+                return Err(s.error(ErrorKind::SizeMismatch));
+            }
+        }
+
+        let funcs = m.funcsec.as_ref().map_or(0, |f| f.0 .0 .0.len());
+        let codes = m.codesec.as_ref().map_or(0, |c| c.0 .0 .0.len());
+        if funcs != codes {
+            return Err(r.error(ErrorKind::FunctionCount));
+        }
+        let datas = m.datasec.as_ref().map_or(0, |d| d.0 .0 .0.len());
+        match &m.datacountsec {
+            Some(n) if n.0 .0 as usize != datas => return Err(r.error(ErrorKind::DataCount)),
+            None if m.uses_data_count() => return Err(r.error(ErrorKind::DataCount)),
+            _ => {}
+        }
+        Ok(m)
+    }
+}
+
+fn section_id(id: u8) -> Option<SectionId> {
+    Some(match id {
+        0 => SectionId::Custom,
+        1 => SectionId::Type,
+        2 => SectionId::Import,
+        3 => SectionId::Func,
+        4 => SectionId::Table,
+        5 => SectionId::Mem,
+        6 => SectionId::Global,
+        7 => SectionId::Export,
+        8 => SectionId::Start,
+        9 => SectionId::Elem,
+        10 => SectionId::Code,
+        11 => SectionId::Data,
+        12 => SectionId::Datacount,
+        _ => return None,
+    })
+}
+
+fn leb_len(n: usize) -> usize {
+    (usize::BITS - n.leading_zeros()).max(1).div_ceil(7) as usize
+}
@@ -0,0 +1,262 @@
+use crate::{
+    modules::{Custom, Import, Importdesc, Module, Placement},
+    names::{malformed, read_name, read_u32, read_u8},
+    types::{Globaltype, Mut, Numtype, Valtype},
+    values::Name,
+    write_all, Grammar, Vector,
+};
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MemInfo {
+    pub memory_size: u32,
+    /// Log2 of the required alignment.
+    pub memory_alignment: u32,
+    pub table_size: u32,
+    /// Log2 of the required alignment.
+    pub table_alignment: u32,
+}
+
+impl Grammar for MemInfo {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_all!(
+            w,
+            self.memory_size,
+            self.memory_alignment,
+            self.table_size,
+            self.table_alignment
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symflags(pub u32);
+
+impl Symflags {
+    pub const BINDING_WEAK: Self = Self(0x01);
+    pub const BINDING_LOCAL: Self = Self(0x02);
+    pub const VISIBILITY_HIDDEN: Self = Self(0x04);
+    pub const UNDEFINED: Self = Self(0x10);
+    pub const EXPORTED: Self = Self(0x20);
+    pub const EXPLICIT_NAME: Self = Self(0x40);
+    pub const NO_STRIP: Self = Self(0x80);
+    pub const TLS: Self = Self(0x100);
+}
+
+impl std::ops::BitOr for Symflags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Grammar for Symflags {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.0.write(w)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExportInfo {
+    pub nm: Name,
+    pub flags: Symflags,
+}
+
+impl Grammar for ExportInfo {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_all!(w, self.nm, self.flags)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ImportInfo {
+    pub r#mod: Name,
+    pub nm: Name,
+    pub flags: Symflags,
+}
+
+impl Grammar for ImportInfo {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_all!(w, self.r#mod, self.nm, self.flags)
+    }
+}
+
+/// The contents of the `dylink.0` custom section, which must be the first
+/// section of a side module; [`insert`](Dylink::insert) puts it there.
+/// Subsections this crate doesn't understand are kept as raw bytes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dylink {
+    pub mem_info: Option<MemInfo>,
+    pub needed: Vector<Name>,
+    pub export_info: Vector<ExportInfo>,
+    pub import_info: Vector<ImportInfo>,
+    pub other: Box<[(u8, Box<[u8]>)]>,
+}
+
+impl Default for Dylink {
+    fn default() -> Self {
+        Self {
+            mem_info: None,
+            needed: Vector(Box::new([])),
+            export_info: Vector(Box::new([])),
+            import_info: Vector(Box::new([])),
+            other: Box::new([]),
+        }
+    }
+}
+
+impl Dylink {
+    pub const NAME: &'static str = "dylink.0";
+
+    /// Parses the module's `dylink.0` section, if it has one.
+    pub fn from_module(m: &Module) -> io::Result<Option<Self>> {
+        m.custom(Self::NAME)
+            .map(|c| Self::parse(&c.contents))
+            .transpose()
+    }
+
+    pub fn parse(mut bytes: &[u8]) -> io::Result<Self> {
+        let mut dylink = Self::default();
+        let mut other = vec![];
+        while !bytes.is_empty() {
+            let id = read_u8(&mut bytes)?;
+            let len = read_u32(&mut bytes)? as usize;
+            if len > bytes.len() {
+                return Err(malformed("subsection extends past the section"));
+            }
+            let (mut sub, rest) = bytes.split_at(len);
+            bytes = rest;
+            match id {
+                1 => {
+                    dylink.mem_info = Some(MemInfo {
+                        memory_size: read_u32(&mut sub)?,
+                        memory_alignment: read_u32(&mut sub)?,
+                        table_size: read_u32(&mut sub)?,
+                        table_alignment: read_u32(&mut sub)?,
+                    })
+                }
+                2 => dylink.needed = read_vec(&mut sub, read_name)?,
+                3 => {
+                    dylink.export_info = read_vec(&mut sub, |b| {
+                        Ok(ExportInfo {
+                            nm: read_name(b)?,
+                            flags: Symflags(read_u32(b)?),
+                        })
+                    })?
+                }
+                4 => {
+                    dylink.import_info = read_vec(&mut sub, |b| {
+                        Ok(ImportInfo {
+                            r#mod: read_name(b)?,
+                            nm: read_name(b)?,
+                            flags: Symflags(read_u32(b)?),
+                        })
+                    })?
+                }
+                _ => {
+                    other.push((id, sub.into()));
+                    continue;
+                }
+            }
+            if !sub.is_empty() {
+                return Err(malformed("trailing bytes in subsection"));
+            }
+        }
+        dylink.other = other.into_boxed_slice();
+        Ok(dylink)
+    }
+
+    pub fn custom(&self) -> io::Result<Custom> {
+        let mut contents = vec![];
+        self.write(&mut contents)?;
+        Ok(Custom {
+            name: Name(Self::NAME.to_string()),
+            contents: contents.into_boxed_slice(),
+        })
+    }
+
+    /// Makes this the module's `dylink.0` section, replacing any it has, at
+    /// the start of the module.
+    pub fn insert(&self, m: &mut Module) -> io::Result<()> {
+        let custom = self.custom()?;
+        m.retain_customs(|c| c.name.0 != Self::NAME);
+        m.insert_custom(Placement::First, custom);
+        Ok(())
+    }
+}
+
+impl Grammar for Dylink {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut subsections = vec![];
+        if let Some(mem_info) = &self.mem_info {
+            subsection(&mut subsections, 1, mem_info)?;
+        }
+        if !self.needed.0.is_empty() {
+            subsection(&mut subsections, 2, &self.needed)?;
+        }
+        if !self.export_info.0.is_empty() {
+            subsection(&mut subsections, 3, &self.export_info)?;
+        }
+        if !self.import_info.0.is_empty() {
+            subsection(&mut subsections, 4, &self.import_info)?;
+        }
+        for (id, payload) in self.other.iter() {
+            subsections.push((*id, payload.to_vec()));
+        }
+        subsections.sort_by_key(|(id, _)| *id);
+        for (id, payload) in subsections {
+            write_all!(w, id, payload.len() as u32, payload.as_slice())?;
+        }
+        Ok(())
+    }
+}
+
+fn subsection(out: &mut Vec<(u8, Vec<u8>)>, id: u8, payload: &impl Grammar) -> io::Result<()> {
+    let mut buf = vec![];
+    payload.write(&mut buf)?;
+    out.push((id, buf));
+    Ok(())
+}
+
+fn read_vec<T>(
+    bytes: &mut &[u8],
+    read: impl Fn(&mut &[u8]) -> io::Result<T>,
+) -> io::Result<Vector<T>> {
+    let n = read_u32(bytes)?;
+    let items = (0..n).map(|_| read(bytes)).collect::<io::Result<_>>()?;
+    Ok(Vector(items))
+}
+
+impl Import {
+    /// `env.__memory_base`, the offset of the side module's static data.
+    pub fn memory_base() -> Self {
+        Self::i32_global("env", "__memory_base", Mut::Const)
+    }
+
+    /// `env.__table_base`, the offset of the side module's table entries.
+    pub fn table_base() -> Self {
+        Self::i32_global("env", "__table_base", Mut::Const)
+    }
+
+    /// A `GOT.mem` entry holding the address of the data symbol `symbol`.
+    pub fn got_mem(symbol: &str) -> Self {
+        Self::i32_global("GOT.mem", symbol, Mut::Var)
+    }
+
+    /// A `GOT.func` entry holding the table index of the function `symbol`.
+    pub fn got_func(symbol: &str) -> Self {
+        Self::i32_global("GOT.func", symbol, Mut::Var)
+    }
+
+    fn i32_global(r#mod: &str, nm: &str, mutability: Mut) -> Self {
+        Self {
+            r#mod: Name(r#mod.to_string()),
+            nm: Name(nm.to_string()),
+            d: Importdesc::Global(Globaltype {
+                ty: Valtype::Numtype(Numtype::I32),
+                mutability,
+            }),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! [`Arbitrary`] implementations for fuzzing and property tests.
+//!
+//! Most of the AST derives `Arbitrary`. [`Module`] is implemented by hand so
+//! that what it generates decodes back to itself: the function and code
+//! sections agree, a data count section is there when the code needs one,
+//! and custom sections sit in the slot the decoder would put them in.
+//! [`ValidModule`] goes further and only generates modules that validate,
+//! using the [`generate`](crate::generate) module.
+
+use crate::{
+    generate::{self, GenerateConfig},
+    instructions::S33,
+    modules::{
+        Codesec, Custom, Datacountsec, Func, KnownSection, Locals, Module, Placement, Section,
+        SectionId,
+    },
+    Vector,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for S33 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(S33(u.arbitrary::<u32>()?.into()))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Func {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // A function can't have more than 2^32 - 1 locals in total.
+        let mut t: Vec<Locals> = u.arbitrary()?;
+        let mut total = 0u64;
+        t.retain(|l| {
+            total += l.n as u64;
+            total <= u32::MAX as u64
+        });
+        Ok(Func {
+            t: Vector(t.into()),
+            e: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Module {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut m = Module {
+            typesec: u.arbitrary()?,
+            importsec: u.arbitrary()?,
+            funcsec: u.arbitrary()?,
+            tablesec: u.arbitrary()?,
+            memsec: u.arbitrary()?,
+            globalsec: u.arbitrary()?,
+            exportsec: u.arbitrary()?,
+            startsec: u.arbitrary()?,
+            elemsec: u.arbitrary()?,
+            datasec: u.arbitrary()?,
+            ..Default::default()
+        };
+        let funcs = m.funcsec.as_ref().map_or(0, |f| f.0 .0 .0.len());
+        if funcs > 0 || u.arbitrary()? {
+            let codes = (0..funcs).map(|_| u.arbitrary()).collect::<Result<_>>()?;
+            m.codesec = Some(Codesec(Section(Vector(codes))));
+        }
+        data_count(u, &mut m)?;
+        customs(u, &mut m)?;
+        Ok(m)
+    }
+}
+
+/// Adds a data count section when the code needs one and, some of the time,
+/// when it doesn't.
+fn data_count(u: &mut Unstructured, m: &mut Module) -> Result<()> {
+    if m.uses_data_count() || u.arbitrary()? {
+        let n = m.datasec.as_ref().map_or(0, |d| d.0 .0 .0.len());
+        m.datacountsec = Some(Datacountsec(Section(n as u32)));
+    }
+    Ok(())
+}
+
+/// Adds custom sections at arbitrary spots, each one after any already in
+/// the slot that decoding would put it in: the one following the last known
+/// section before the spot.
+fn customs(u: &mut Unstructured, m: &mut Module) -> Result<()> {
+    for _ in 0..u.arbitrary_len::<Custom>()? {
+        let spot = u.int_in_range(0..=KnownSection::ALL.len())?;
+        let slot = KnownSection::ALL[..spot]
+            .iter()
+            .rposition(|s| has_section(m, s.id()))
+            .map_or(0, |i| i + 1);
+        let placement = match KnownSection::ALL.get(slot) {
+            Some(&id) => Placement::Before(id),
+            None => Placement::Last,
+        };
+        m.insert_custom(placement, u.arbitrary()?);
+    }
+    Ok(())
+}
+
+fn has_section(m: &Module, id: SectionId) -> bool {
+    match id {
+        SectionId::Custom => false,
+        SectionId::Type => m.typesec.is_some(),
+        SectionId::Import => m.importsec.is_some(),
+        SectionId::Func => m.funcsec.is_some(),
+        SectionId::Table => m.tablesec.is_some(),
+        SectionId::Mem => m.memsec.is_some(),
+        SectionId::Global => m.globalsec.is_some(),
+        SectionId::Export => m.exportsec.is_some(),
+        SectionId::Start => m.startsec.is_some(),
+        SectionId::Elem => m.elemsec.is_some(),
+        SectionId::Code => m.codesec.is_some(),
+        SectionId::Data => m.datasec.is_some(),
+        SectionId::Datacount => m.datacountsec.is_some(),
+    }
+}
+
+/// A module that passes [`validate`](crate::validate::validate), from
+/// [`generate::module`] with the default configuration and arbitrary custom
+/// sections added.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidModule(pub Module);
+
+impl<'a> Arbitrary<'a> for ValidModule {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut m = generate::module(u, &GenerateConfig::default())?;
+        customs(u, &mut m)?;
+        Ok(ValidModule(m))
+    }
+}
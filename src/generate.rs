@@ -0,0 +1,1120 @@
+//! Random valid modules for fuzzing engines.
+//!
+//! Like wasm-smith, the generator builds a module section by section,
+//! choosing each index, type and instruction among those that are valid at
+//! that point and allowed by the [`GenerateConfig`], so it never produces an
+//! invalid module. Function bodies are type checked as they are built, using
+//! the stack effects in [`stack`](crate::stack).
+//!
+//! [`generate`] draws each choice straight from a seeded generator, so no
+//! randomness is produced ahead of time. [`module`] and
+//! [`ValidModule`](crate::fuzz::ValidModule) draw from fuzzer input in an
+//! [`Unstructured`] instead.
+
+use crate::{
+    instructions::{
+        Blocktype, Expr, Instr, Laneidx, Memarg, MemoryMemarg, Opcode, TruncSat, VectorLaneidx,
+        VectorMemarg, VectorMemargLaneidx, VectorNoImmediate, S33,
+    },
+    modules::{
+        Code, Codesec, Data, Datacountsec, Dataidx, Datasec, Elem, Elemidx, Elemkind, Elemsec,
+        Export, Exportdesc, Exportsec, Func, Funcidx, Funcsec, Global, Globalidx, Globalsec,
+        Import, Importdesc, Importsec, Labelidx, Localidx, Locals, Mem, Memidx, Memsec, Module,
+        Section, Start, Startsec, Table, Tableidx, Tablesec, Typeidx, Typesec,
+    },
+    stack::{Context, FuncContext},
+    types::{
+        Functype, Globaltype, Limits, Memtype, Mut, Numtype, Reftype, Resulttype, Tabletype,
+        Valtype, Vectype,
+    },
+    validate::{elem_type, lane_width, lanes, memory_width, vector_width},
+    values::Name,
+    Vector,
+};
+use arbitrary::{unstructured::Int, Arbitrary, Result, Unstructured};
+use std::{collections::HashSet, sync::OnceLock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GenerateConfig {
+    /// `v128` and the vector instructions.
+    pub simd: bool,
+    /// `externref`, reference values on the stack, multiple tables and the
+    /// table instructions.
+    pub reference_types: bool,
+    /// Passive segments, the data count section and the `memory.*`,
+    /// `table.*`, `data.drop` and `elem.drop` instructions that use them.
+    pub bulk_memory: bool,
+    /// More than one result, and block types given by a type index.
+    pub multi_value: bool,
+    /// The `extend8_s` family of instructions.
+    pub sign_extension: bool,
+    /// The `trunc_sat` instructions.
+    pub saturating_float_to_int: bool,
+    /// Defined functions, not counting imported ones.
+    pub max_funcs: u32,
+    /// Instructions per function body, counting those in nested blocks and
+    /// those that leave the right values on the stack at the end of each
+    /// block.
+    pub max_instrs: u32,
+    pub imports: bool,
+}
+
+impl Default for GenerateConfig {
+    fn default() -> Self {
+        Self {
+            simd: true,
+            reference_types: true,
+            bulk_memory: true,
+            multi_value: true,
+            sign_extension: true,
+            saturating_float_to_int: true,
+            max_funcs: 8,
+            max_instrs: 64,
+            imports: true,
+        }
+    }
+}
+
+/// Generates a valid module from a seed. The same seed and configuration
+/// always give the same module.
+pub fn generate(seed: u64, config: &GenerateConfig) -> Module {
+    build(Source::Seed(SplitMix64(seed)), config)
+        .expect("a seeded generator never runs out of data")
+}
+
+/// Generates a valid module, drawing every choice from `u`.
+pub fn module(u: &mut Unstructured, config: &GenerateConfig) -> Result<Module> {
+    build(Source::Data(u), config)
+}
+
+fn build(u: Source, config: &GenerateConfig) -> Result<Module> {
+    let valtypes = VALTYPES
+        .into_iter()
+        .filter(|t| match t {
+            Valtype::Numtype(_) => true,
+            Valtype::Vectype(_) => config.simd,
+            Valtype::Reftype(_) => config.reference_types,
+        })
+        .collect();
+    let mut b = Builder {
+        u,
+        config,
+        valtypes,
+        m: Module::default(),
+    };
+    b.module()?;
+    Ok(b.m)
+}
+
+/// SplitMix64, which is enough to turn a seed into a stream of bytes.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Where the choices come from: fuzzer input, which runs out, or a seeded
+/// generator, which fills a small buffer afresh for each choice.
+enum Source<'u, 'a> {
+    Data(&'u mut Unstructured<'a>),
+    Seed(SplitMix64),
+}
+
+/// Enough bytes for any single choice the generator makes.
+const CHUNK: usize = 32;
+
+impl Source<'_, '_> {
+    fn with<T>(&mut self, f: impl FnOnce(&mut Unstructured) -> Result<T>) -> Result<T> {
+        match self {
+            Source::Data(u) => f(u),
+            Source::Seed(rng) => {
+                let mut bytes = [0; CHUNK];
+                for chunk in bytes.chunks_mut(8) {
+                    chunk.copy_from_slice(&rng.next().to_le_bytes());
+                }
+                f(&mut Unstructured::new(&bytes))
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Source::Data(u) => u.is_empty(),
+            Source::Seed(_) => false,
+        }
+    }
+
+    fn arbitrary<T: for<'b> Arbitrary<'b>>(&mut self) -> Result<T> {
+        self.with(|u| u.arbitrary())
+    }
+
+    fn int_in_range<T: Int>(&mut self, range: std::ops::RangeInclusive<T>) -> Result<T> {
+        self.with(|u| u.int_in_range(range))
+    }
+
+    fn choose<'b, T>(&mut self, choices: &'b [T]) -> Result<&'b T> {
+        self.with(|u| u.choose(choices))
+    }
+
+    fn ratio<T: Int>(&mut self, numerator: T, denominator: T) -> Result<bool> {
+        self.with(|u| u.ratio(numerator, denominator))
+    }
+}
+
+const MAX_TYPES: u32 = 8;
+const MAX_PARAMS: u32 = 4;
+const MAX_IMPORTS: u32 = 6;
+const MAX_TABLES: u32 = 3;
+const MAX_GLOBALS: u32 = 6;
+const MAX_EXPORTS: u32 = 8;
+const MAX_ELEMS: u32 = 4;
+const MAX_DATAS: u32 = 4;
+const MAX_LOCALS: u32 = 4;
+const MAX_SEGMENT: u32 = 4;
+const MAX_NAME: u32 = 8;
+const MAX_DEPTH: usize = 6;
+
+const VALTYPES: [Valtype; 7] = [
+    Valtype::Numtype(Numtype::I32),
+    Valtype::Numtype(Numtype::I64),
+    Valtype::Numtype(Numtype::F32),
+    Valtype::Numtype(Numtype::F64),
+    Valtype::Vectype(Vectype::V128),
+    Valtype::Reftype(Reftype::Funcref),
+    Valtype::Reftype(Reftype::Externref),
+];
+const I32: Valtype = Valtype::Numtype(Numtype::I32);
+
+struct Builder<'u, 'a, 'c> {
+    u: Source<'u, 'a>,
+    config: &'c GenerateConfig,
+    /// The value types the configuration allows.
+    valtypes: Vec<Valtype>,
+    m: Module,
+}
+
+impl Builder<'_, '_, '_> {
+    fn module(&mut self) -> Result<()> {
+        let types = self.many(MAX_TYPES, Self::functype)?;
+        self.m.typesec = vector(types).map(|v| Typesec(Section(v)));
+        let (mut mem, mut table) = (false, false);
+        let max_imports = if self.config.imports { MAX_IMPORTS } else { 0 };
+        let imports = self.many(max_imports, |b| {
+            let i = b.import(!mem, !table || b.config.reference_types)?;
+            mem |= matches!(i.d, Importdesc::Mem(_));
+            table |= matches!(i.d, Importdesc::Table(_));
+            Ok(i)
+        })?;
+        self.m.importsec = vector(imports).map(|v| Importsec(Section(v)));
+        // A body without instructions can't produce results.
+        let types = self.m.typesec.as_ref().map_or(&[][..], |t| &t.0 .0 .0);
+        let func_types: Vec<_> = (0..types.len() as u32)
+            .map(Typeidx)
+            .filter(|y| self.config.max_instrs > 0 || types[y.0 as usize].results.0 .0.is_empty())
+            .collect();
+        let funcs = match func_types.len() {
+            0 => vec![],
+            _ => self.many(self.config.max_funcs, |b| b.u.choose(&func_types).copied())?,
+        };
+        self.m.funcsec = vector(funcs).map(|v| Funcsec(Section(v)));
+        let max_tables = match self.config.reference_types {
+            true => MAX_TABLES,
+            false => 1u32.saturating_sub(self.tables()),
+        };
+        let tables = self.many(max_tables, |b| Ok(Table(b.tabletype()?)))?;
+        self.m.tablesec = vector(tables).map(|v| Tablesec(Section(v)));
+        if self.m.imported_mems() == 0 && self.u.arbitrary()? {
+            let mem = Mem(Memtype(self.limits(65536)?));
+            self.m.memsec = Some(Memsec(Section(Vector(Box::new([mem])))));
+        }
+        let globals = self.many(MAX_GLOBALS, |b| {
+            let gt = b.globaltype()?;
+            Ok(Global {
+                gt,
+                e: b.const_expr(gt.ty)?,
+            })
+        })?;
+        self.m.globalsec = vector(globals).map(|v| Globalsec(Section(v)));
+
+        let mut names = HashSet::new();
+        let exports = self.many(MAX_EXPORTS, |b| {
+            let nm = b.name()?.0;
+            let d = b.exportdesc()?;
+            Ok(d.filter(|_| names.insert(nm.clone()))
+                .map(|d| Export { nm: Name(nm), d }))
+        })?;
+        self.m.exportsec =
+            vector(exports.into_iter().flatten().collect()).map(|v| Exportsec(Section(v)));
+
+        let starts: Vec<_> = (0..self.funcs())
+            .map(Funcidx)
+            .filter(|&x| {
+                self.m
+                    .func_type(x)
+                    .is_some_and(|t| t.parameters.0 .0.is_empty() && t.results.0 .0.is_empty())
+            })
+            .collect();
+        if !starts.is_empty() && self.u.arbitrary()? {
+            let x = *self.u.choose(&starts)?;
+            self.m.startsec = Some(Startsec(Section(Start(x))));
+        }
+
+        let elems = self.many(MAX_ELEMS, Self::elem)?;
+        self.m.elemsec = vector(elems.into_iter().flatten().collect()).map(|v| Elemsec(Section(v)));
+        let datas = self.many(MAX_DATAS, Self::data)?;
+        self.m.datasec = vector(datas.into_iter().flatten().collect()).map(|v| Datasec(Section(v)));
+        if self.config.bulk_memory && self.u.arbitrary()? {
+            let n = self.m.datasec.as_ref().map_or(0, |d| d.0 .0 .0.len());
+            self.m.datacountsec = Some(Datacountsec(Section(n as u32)));
+        }
+
+        let refs = refs(&self.m);
+        let mut codes = vec![];
+        for x in (self.m.imported_funcs()..self.funcs()).map(Funcidx) {
+            let ty = self.m.func_type(x).unwrap();
+            let locals = (0..self.u.int_in_range(0..=MAX_LOCALS)?)
+                .map(|_| {
+                    Ok(Locals {
+                        n: self.u.int_in_range(1..=3)?,
+                        t: *self.u.choose(&self.valtypes)?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let f = Func {
+                t: Vector(locals.into()),
+                e: Expr(Box::new([])),
+            };
+            let min = !ty.results.0 .0.is_empty() as u32;
+            let mut c = CodeBuilder {
+                fuel: self.u.int_in_range(min..=self.config.max_instrs)?,
+                reserve: 0,
+                u: &mut self.u,
+                config: self.config,
+                valtypes: &self.valtypes,
+                ctx: FuncContext::new(&self.m, ty, &f),
+                refs: &refs,
+                vals: vec![],
+            };
+            let body = c.instrs(&ty.results.0 .0)?;
+            codes.push(Code(Func {
+                t: f.t,
+                e: Expr(body),
+            }));
+        }
+        if !codes.is_empty() {
+            self.m.codesec = Some(Codesec(Section(Vector(codes.into()))));
+        }
+        Ok(())
+    }
+
+    fn many<T>(&mut self, max: u32, mut f: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        (0..self.u.int_in_range(0..=max)?)
+            .map(|_| f(self))
+            .collect()
+    }
+
+    fn types(&self) -> u32 {
+        self.m
+            .typesec
+            .as_ref()
+            .map_or(0, |t| t.0 .0 .0.len() as u32)
+    }
+
+    fn funcs(&self) -> u32 {
+        self.m.imported_funcs()
+            + self
+                .m
+                .funcsec
+                .as_ref()
+                .map_or(0, |f| f.0 .0 .0.len() as u32)
+    }
+
+    fn tables(&self) -> u32 {
+        self.m.imported_tables()
+            + self
+                .m
+                .tablesec
+                .as_ref()
+                .map_or(0, |t| t.0 .0 .0.len() as u32)
+    }
+
+    fn mems(&self) -> u32 {
+        self.m.imported_mems() + self.m.memsec.as_ref().map_or(0, |m| m.0 .0 .0.len() as u32)
+    }
+
+    fn globals(&self) -> u32 {
+        self.m.imported_globals()
+            + self
+                .m
+                .globalsec
+                .as_ref()
+                .map_or(0, |g| g.0 .0 .0.len() as u32)
+    }
+
+    fn functype(&mut self) -> Result<Functype> {
+        let max_results = if self.config.multi_value {
+            MAX_PARAMS
+        } else {
+            1
+        };
+        Ok(Functype {
+            parameters: self.resulttype(MAX_PARAMS)?,
+            results: self.resulttype(max_results)?,
+        })
+    }
+
+    fn resulttype(&mut self, max: u32) -> Result<Resulttype> {
+        let n = self.u.int_in_range(0..=max)?;
+        let types = (0..n)
+            .map(|_| self.u.choose(&self.valtypes).copied())
+            .collect::<Result<_>>()?;
+        Ok(Resulttype(Vector(types)))
+    }
+
+    fn reftype(&mut self) -> Result<Reftype> {
+        Ok(match self.config.reference_types && self.u.arbitrary()? {
+            true => Reftype::Externref,
+            false => Reftype::Funcref,
+        })
+    }
+
+    fn globaltype(&mut self) -> Result<Globaltype> {
+        Ok(Globaltype {
+            ty: *self.u.choose(&self.valtypes)?,
+            mutability: self.u.arbitrary()?,
+        })
+    }
+
+    fn name(&mut self) -> Result<Name> {
+        let n = self.u.int_in_range(0..=MAX_NAME)?;
+        (0..n)
+            .map(|_| self.u.arbitrary::<char>())
+            .collect::<Result<_>>()
+            .map(Name)
+    }
+
+    fn bytes(&mut self) -> Result<Vector<u8>> {
+        let n = self.u.int_in_range(0..=MAX_SEGMENT * 4)?;
+        (0..n)
+            .map(|_| self.u.arbitrary())
+            .collect::<Result<_>>()
+            .map(Vector)
+    }
+
+    fn limits(&mut self, max: u32) -> Result<Limits> {
+        let min = self.u.int_in_range(0..=16.min(max))?;
+        Ok(match self.u.arbitrary()? {
+            true => Limits::MinMax(
+                min,
+                self.u.int_in_range(min..=min.saturating_add(16).min(max))?,
+            ),
+            false => Limits::Min(min),
+        })
+    }
+
+    fn tabletype(&mut self) -> Result<Tabletype> {
+        Ok(Tabletype {
+            element_type: self.reftype()?,
+            limits: self.limits(u32::MAX)?,
+        })
+    }
+
+    /// An import, of a memory or table only if `mem` or `table` allow one.
+    fn import(&mut self, mem: bool, table: bool) -> Result<Import> {
+        let mut kinds = vec![3];
+        if self.types() > 0 {
+            kinds.push(0);
+        }
+        if table {
+            kinds.push(1);
+        }
+        if mem {
+            kinds.push(2);
+        }
+        let d = match self.u.choose(&kinds)? {
+            0 => Importdesc::Func(Typeidx(self.u.int_in_range(0..=self.types() - 1)?)),
+            1 => Importdesc::Table(self.tabletype()?),
+            2 => Importdesc::Mem(Memtype(self.limits(65536)?)),
+            _ => Importdesc::Global(self.globaltype()?),
+        };
+        Ok(Import {
+            r#mod: self.name()?,
+            nm: self.name()?,
+            d,
+        })
+    }
+
+    fn exportdesc(&mut self) -> Result<Option<Exportdesc>> {
+        let mut kinds = vec![];
+        for (kind, n) in [self.funcs(), self.tables(), self.mems(), self.globals()]
+            .into_iter()
+            .enumerate()
+        {
+            if n > 0 {
+                kinds.push((kind, n));
+            }
+        }
+        if kinds.is_empty() {
+            return Ok(None);
+        }
+        let (kind, n) = *self.u.choose(&kinds)?;
+        let x = self.u.int_in_range(0..=n - 1)?;
+        Ok(Some(match kind {
+            0 => Exportdesc::Func(Funcidx(x)),
+            1 => Exportdesc::Table(Tableidx(x)),
+            2 => Exportdesc::Mem(Memidx(x)),
+            _ => Exportdesc::Global(Globalidx(x)),
+        }))
+    }
+
+    /// A constant expression producing a `t`: a constant, `ref.func` or one
+    /// of the imported immutable globals.
+    fn const_expr(&mut self, t: Valtype) -> Result<Expr> {
+        let mut options = vec![constant(&mut self.u, t)?];
+        if t == Valtype::Reftype(Reftype::Funcref) && self.funcs() > 0 {
+            options.push(Instr::RefFunc(Funcidx(
+                self.u.int_in_range(0..=self.funcs() - 1)?,
+            )));
+        }
+        for x in (0..self.m.imported_globals()).map(Globalidx) {
+            if self.m.global_type(x)
+                == Some(Globaltype {
+                    ty: t,
+                    mutability: Mut::Const,
+                })
+            {
+                options.push(Instr::GlobalGet(x));
+            }
+        }
+        Ok(Expr(Box::new([self.u.choose(&options)?.clone()])))
+    }
+
+    fn func_list(&mut self) -> Result<Vector<Funcidx>> {
+        let n = self.funcs();
+        let funcs = match n {
+            0 => vec![],
+            n => (0..self.u.int_in_range(0..=MAX_SEGMENT)?)
+                .map(|_| Ok(Funcidx(self.u.int_in_range(0..=n - 1)?)))
+                .collect::<Result<_>>()?,
+        };
+        Ok(Vector(funcs.into()))
+    }
+
+    fn expr_list(&mut self, t: Reftype) -> Result<Vector<Expr>> {
+        let n = self.u.int_in_range(0..=MAX_SEGMENT)?;
+        let exprs = (0..n)
+            .map(|_| self.const_expr(Valtype::Reftype(t)))
+            .collect::<Result<_>>()?;
+        Ok(Vector(exprs))
+    }
+
+    /// An element segment of a kind the configuration allows, if there is
+    /// one.
+    fn elem(&mut self) -> Result<Option<Elem>> {
+        let tables: Vec<_> = (0..self.tables())
+            .map(Tableidx)
+            .filter_map(|x| Some((x, self.m.table_type(x)?.element_type)))
+            .collect();
+        let funcref_at_zero = tables.first().is_some_and(|&(_, t)| t == Reftype::Funcref);
+        let (bulk, reference) = (self.config.bulk_memory, self.config.reference_types);
+        let mut kinds = vec![];
+        if funcref_at_zero {
+            kinds.push(0);
+        }
+        if bulk {
+            kinds.extend([1, 5]);
+            if funcref_at_zero {
+                kinds.push(4);
+            }
+        }
+        if bulk && reference {
+            kinds.extend([3, 7]);
+            if tables.iter().any(|&(_, t)| t == Reftype::Funcref) {
+                kinds.push(2);
+            }
+            if !tables.is_empty() {
+                kinds.push(6);
+            }
+        }
+        if kinds.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(match *self.u.choose(&kinds)? {
+            0 => Elem::FuncrefFuncActive(self.const_expr(I32)?, self.func_list()?),
+            1 => Elem::ElemkindFuncPassive(Elemkind, self.func_list()?),
+            2 => {
+                let funcref: Vec<_> = tables
+                    .iter()
+                    .filter(|&&(_, t)| t == Reftype::Funcref)
+                    .map(|&(x, _)| x)
+                    .collect();
+                let x = *self.u.choose(&funcref)?;
+                Elem::ElemkindFuncActive(x, self.const_expr(I32)?, Elemkind, self.func_list()?)
+            }
+            3 => Elem::ElemkindFuncDeclarative(Elemkind, self.func_list()?),
+            4 => Elem::FuncrefExprActive(self.const_expr(I32)?, self.expr_list(Reftype::Funcref)?),
+            5 => {
+                let t = self.reftype()?;
+                Elem::ReftypeExprPassive(t, self.expr_list(t)?)
+            }
+            6 => {
+                let (x, t) = *self.u.choose(&tables)?;
+                Elem::ReftypeExprActive(x, self.const_expr(I32)?, t, self.expr_list(t)?)
+            }
+            _ => {
+                let t = self.reftype()?;
+                Elem::ReftypeExprDeclarative(t, self.expr_list(t)?)
+            }
+        }))
+    }
+
+    /// A data segment of a kind the configuration allows, if there is one.
+    fn data(&mut self) -> Result<Option<Data>> {
+        let active = self.mems() > 0;
+        if !active && !self.config.bulk_memory {
+            return Ok(None);
+        }
+        let bytes = self.bytes()?;
+        Ok(Some(
+            match active && (!self.config.bulk_memory || self.u.arbitrary()?) {
+                true if self.u.arbitrary()? => Data::ActiveAtZero(self.const_expr(I32)?, bytes),
+                true => Data::ActiveAtIndex(Memidx(0), self.const_expr(I32)?, bytes),
+                false => Data::Passive(bytes),
+            },
+        ))
+    }
+}
+
+fn vector<T>(items: Vec<T>) -> Option<Vector<T>> {
+    (!items.is_empty()).then(|| Vector(items.into()))
+}
+
+fn constant(u: &mut Source, t: Valtype) -> Result<Instr> {
+    Ok(match t {
+        Valtype::Numtype(Numtype::I32) => Instr::I32Const(u.arbitrary()?),
+        Valtype::Numtype(Numtype::I64) => Instr::I64Const(u.arbitrary()?),
+        Valtype::Numtype(Numtype::F32) => Instr::F32Const(u.arbitrary()?),
+        Valtype::Numtype(Numtype::F64) => Instr::F64Const(u.arbitrary()?),
+        Valtype::Vectype(Vectype::V128) => Instr::V128Const(u.arbitrary()?),
+        Valtype::Reftype(t) => Instr::RefNull(t),
+    })
+}
+
+/// The functions `ref.func` may name in function bodies.
+fn refs(m: &Module) -> Vec<Funcidx> {
+    let mut refs = HashSet::new();
+    let mut exprs = |e: &Expr| {
+        for i in e.0.iter() {
+            if let Instr::RefFunc(x) = i {
+                refs.insert(*x);
+            }
+        }
+    };
+    for g in m.globalsec.iter().flat_map(|g| g.0 .0 .0.iter()) {
+        exprs(&g.e);
+    }
+    let mut funcs = vec![];
+    for e in m.elemsec.iter().flat_map(|e| e.0 .0 .0.iter()) {
+        match e {
+            Elem::FuncrefFuncActive(_, y)
+            | Elem::ElemkindFuncPassive(_, y)
+            | Elem::ElemkindFuncActive(_, _, _, y)
+            | Elem::ElemkindFuncDeclarative(_, y) => funcs.extend(y.0.iter().copied()),
+            Elem::FuncrefExprActive(_, el)
+            | Elem::ReftypeExprPassive(_, el)
+            | Elem::ReftypeExprActive(_, _, _, el)
+            | Elem::ReftypeExprDeclarative(_, el) => el.0.iter().for_each(&mut exprs),
+        }
+    }
+    for e in m.exportsec.iter().flat_map(|e| e.0 .0 .0.iter()) {
+        if let Exportdesc::Func(x) = e.d {
+            funcs.push(x);
+        }
+    }
+    refs.extend(funcs);
+    let mut refs: Vec<_> = refs.into_iter().collect();
+    refs.sort();
+    refs
+}
+
+/// Generates a function body, tracking the types on the operand stack of
+/// the innermost block. Code after a branch is unreachable; it starts over
+/// from an empty stack, which is always a valid assumption.
+struct CodeBuilder<'u, 's, 'a, 'm> {
+    u: &'u mut Source<'s, 'a>,
+    config: &'m GenerateConfig,
+    valtypes: &'m [Valtype],
+    ctx: FuncContext<'m>,
+    refs: &'m [Funcidx],
+    vals: Vec<Valtype>,
+    /// Instructions left for the body, always enough to end each open block
+    /// with the right values on the stack.
+    fuel: u32,
+    /// The fuel the enclosing blocks need to end once this one has.
+    reserve: u32,
+}
+
+impl CodeBuilder<'_, '_, '_, '_> {
+    /// Instructions for the current block, ending with exactly `results` on
+    /// the stack.
+    fn instrs(&mut self, results: &[Valtype]) -> Result<Box<[Instr]>> {
+        let mut body = vec![];
+        // Nested blocks end early some of the time, while the function body
+        // goes on until it runs out of fuel. An instruction that leaves too
+        // little to clear up the stack afterwards is taken back.
+        while self.fuel > self.reserve + self.end_cost(results)
+            && !self.u.is_empty()
+            && (self.ctx.labels.len() == 1 || self.u.ratio(7, 8)?)
+        {
+            let (vals, fuel) = (self.vals.clone(), self.fuel);
+            self.fuel -= 1;
+            let i = self.instr()?;
+            self.apply(&i);
+            if self.fuel - self.reserve < self.end_cost(results) {
+                (self.vals, self.fuel) = (vals, fuel);
+                break;
+            }
+            body.push(i);
+        }
+        // Drops and zeros, unless the block's parameters already cost more
+        // than its fuel. Then an `unreachable`, which fits any stack.
+        if self.vals != results {
+            let n = self.end_cost(results);
+            if self.fuel - self.reserve >= n {
+                let kept = self.kept(results);
+                body.extend(
+                    self.vals[kept..]
+                        .iter()
+                        .map(|_| Instr::Opcode(Opcode::Drop)),
+                );
+                body.extend(results[kept..].iter().map(|t| t.zero_instr()));
+                self.fuel -= n;
+            } else {
+                body.push(Instr::Opcode(Opcode::Unreachable));
+                self.fuel -= 1;
+            }
+        }
+        self.vals.clear();
+        Ok(body.into())
+    }
+
+    /// How many of the values on the stack can stay as the first of
+    /// `results`.
+    fn kept(&self, results: &[Valtype]) -> usize {
+        self.vals
+            .iter()
+            .zip(results)
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// The instructions it takes to turn the stack into `results`.
+    fn end_cost(&self, results: &[Valtype]) -> u32 {
+        let kept = self.kept(results);
+        (self.vals.len() - kept + results.len() - kept) as u32
+    }
+
+    fn instr(&mut self) -> Result<Instr> {
+        let mut groups = self.candidates();
+        groups.retain(|g| !g.is_empty());
+        let group = self.u.choose(&groups)?;
+        let i = self.u.choose(group)?.clone();
+        self.fill(i)
+    }
+
+    /// The instructions that are in scope and fit the stack, grouped so that
+    /// no kind of instruction crowds out the others. Immediates that don't
+    /// affect the stack are filled in once one is chosen.
+    fn candidates(&self) -> Vec<Vec<Instr>> {
+        let m = self.ctx.module;
+        let count = |n: Option<usize>| n.unwrap_or(0) as u32;
+        let types = count(m.typesec.as_ref().map(|t| t.0 .0 .0.len()));
+        let funcs = m.imported_funcs() + count(m.funcsec.as_ref().map(|f| f.0 .0 .0.len()));
+        let tables = m.imported_tables() + count(m.tablesec.as_ref().map(|t| t.0 .0 .0.len()));
+        let mems = m.imported_mems() + count(m.memsec.as_ref().map(|m| m.0 .0 .0.len()));
+        let globals = m.imported_globals() + count(m.globalsec.as_ref().map(|g| g.0 .0 .0.len()));
+        let elems = m.elemsec.as_ref().map_or(&[][..], |e| &e.0 .0 .0);
+        let datas = m.datacountsec.as_ref().map_or(0, |d| d.0 .0);
+        let table_type = |x: u32| m.table_type(Tableidx(x)).map(|t| t.element_type);
+
+        let [numeric, vector, memarg] = operators().each_ref().map(|ops| {
+            ops.iter()
+                .filter(|(i, ty)| {
+                    self.vals.ends_with(&ty.parameters.0 .0) && enabled(self.config, i)
+                })
+                .map(|(i, _)| i.clone())
+                .collect::<Vec<_>>()
+        });
+
+        let constants = self.valtypes.iter().map(|t| t.zero_instr()).collect();
+
+        let locals = self.ctx.locals.len() as u32;
+        let mut variables = vec![];
+        for x in (0..locals).map(Localidx) {
+            variables.extend([Instr::LocalGet(x), Instr::LocalSet(x), Instr::LocalTee(x)]);
+        }
+        for x in (0..globals).map(Globalidx) {
+            variables.push(Instr::GlobalGet(x));
+            if m.global_type(x).is_some_and(|g| g.mutability == Mut::Var) {
+                variables.push(Instr::GlobalSet(x));
+            }
+        }
+
+        let labels = self.ctx.labels.len() as u32;
+        let mut control = vec![
+            Instr::Opcode(Opcode::Nop),
+            Instr::Opcode(Opcode::Unreachable),
+            Instr::Opcode(Opcode::Return),
+            Instr::Opcode(Opcode::Drop),
+            Instr::Select(None),
+        ];
+        if let [.., t, _] = self.vals[..] {
+            control.push(Instr::Select(Some(Vector(Box::new([t])))));
+        }
+        for l in (0..labels).map(Labelidx) {
+            control.extend([
+                Instr::Br(l),
+                Instr::BrIf(l),
+                Instr::BrTable(Vector(Box::new([])), l),
+            ]);
+        }
+        control.extend((0..funcs).map(|x| Instr::Call(Funcidx(x))));
+        for x in (0..tables).filter(|&x| table_type(x) == Some(Reftype::Funcref)) {
+            control.extend((0..types).map(|y| Instr::CallIndirect(Typeidx(y), Tableidx(x))));
+        }
+
+        // A block takes one instruction to end itself and, for `if` with
+        // `else`, one for the second arm.
+        let mut blocks = vec![];
+        if (labels as usize) <= MAX_DEPTH && self.fuel >= self.reserve + 3 {
+            let blocktypes = [Blocktype::Empty]
+                .into_iter()
+                .chain(self.valtypes.iter().map(|&t| Blocktype::ValueType(t)))
+                .chain((0..types).map(|x| Blocktype::TypeIndex(S33(x.into()))));
+            for bt in blocktypes {
+                blocks.extend([
+                    Instr::Block(bt, Box::new([])),
+                    Instr::Loop(bt, Box::new([])),
+                    Instr::IfElse(bt, Box::new([]), Box::new([])),
+                ]);
+                if bt
+                    .functype(&self.ctx)
+                    .is_some_and(|t| t.parameters == t.results)
+                {
+                    blocks.push(Instr::If(bt, Box::new([])));
+                }
+            }
+        }
+
+        let mut memory = vec![];
+        if mems > 0 {
+            memory.extend([
+                Instr::MemorySize,
+                Instr::MemoryGrow,
+                Instr::MemoryCopy,
+                Instr::MemoryFill,
+            ]);
+            memory.extend((0..datas).map(|x| Instr::MemoryInit(Dataidx(x))));
+        }
+        memory.extend((0..datas).map(|x| Instr::DataDrop(Dataidx(x))));
+
+        let mut reference = vec![
+            Instr::RefNull(Reftype::Funcref),
+            Instr::RefNull(Reftype::Externref),
+            Instr::Opcode(Opcode::RefIsNull),
+        ];
+        reference.extend(self.refs.iter().map(|&x| Instr::RefFunc(x)));
+        for x in (0..tables).map(Tableidx) {
+            reference.extend([
+                Instr::TableGet(x),
+                Instr::TableSet(x),
+                Instr::TableGrow(x),
+                Instr::TableSize(x),
+                Instr::TableFill(x),
+            ]);
+            for (y, e) in elems.iter().enumerate() {
+                if Some(elem_type(e)) == table_type(x.0) {
+                    reference.push(Instr::TableInit(Elemidx(y as u32), x));
+                }
+            }
+            for y in (0..tables).filter(|&y| table_type(y) == table_type(x.0)) {
+                reference.push(Instr::TableCopy(x, Tableidx(y)));
+            }
+        }
+        reference.extend((0..elems.len() as u32).map(|y| Instr::ElemDrop(Elemidx(y))));
+
+        let mut groups = vec![numeric, vector];
+        for group in [constants, variables, control, blocks, memory, reference] {
+            groups.push(
+                group
+                    .into_iter()
+                    .filter(|i| enabled(self.config, i) && self.fits(i))
+                    .collect(),
+            );
+        }
+        if mems > 0 {
+            groups[6].extend(memarg);
+        }
+        groups
+    }
+
+    /// Whether the operands the instruction takes are on top of the stack.
+    fn fits(&self, i: &Instr) -> bool {
+        let top = |n: usize| self.vals.len().checked_sub(n).map(|i| self.vals[i]);
+        match i {
+            Instr::Opcode(Opcode::Drop) => !self.vals.is_empty(),
+            Instr::Opcode(Opcode::RefIsNull) => matches!(top(1), Some(Valtype::Reftype(_))),
+            Instr::Select(None) => {
+                top(1) == Some(I32)
+                    && top(2) == top(3)
+                    && matches!(top(2), Some(Valtype::Numtype(_) | Valtype::Vectype(_)))
+            }
+            _ => match i.stack_effect(&self.ctx) {
+                Some(ty) => self.vals.ends_with(&ty.parameters.0 .0),
+                None => false,
+            },
+        }
+    }
+
+    fn apply(&mut self, i: &Instr) {
+        match i {
+            Instr::Opcode(Opcode::Drop) => {
+                self.vals.pop();
+            }
+            Instr::Opcode(Opcode::RefIsNull) => {
+                self.vals.pop();
+                self.vals.push(I32);
+            }
+            Instr::Select(None) => {
+                self.vals.truncate(self.vals.len() - 2);
+            }
+            Instr::Br(_)
+            | Instr::BrTable(..)
+            | Instr::Opcode(Opcode::Unreachable | Opcode::Return) => self.vals.clear(),
+            _ => {
+                let ty = i.stack_effect(&self.ctx).unwrap();
+                self.vals
+                    .truncate(self.vals.len() - ty.parameters.0 .0.len());
+                self.vals.extend(ty.results.0 .0.iter().copied());
+            }
+        }
+    }
+
+    fn fill(&mut self, i: Instr) -> Result<Instr> {
+        Ok(match i {
+            Instr::I32Const(_)
+            | Instr::I64Const(_)
+            | Instr::F32Const(_)
+            | Instr::F64Const(_)
+            | Instr::V128Const(_) => {
+                let t = i.stack_effect(&self.ctx).unwrap().results.0 .0[0];
+                constant(self.u, t)?
+            }
+            Instr::VectorLaneidx(op, _) => {
+                Instr::VectorLaneidx(op, Laneidx(self.u.int_in_range(0..=lanes(op) - 1)?))
+            }
+            Instr::I8x16Shuffle(_) => {
+                let mut lanes = [Laneidx(0); 16];
+                for l in &mut lanes {
+                    *l = Laneidx(self.u.int_in_range(0..=31)?);
+                }
+                Instr::I8x16Shuffle(lanes)
+            }
+            Instr::MemoryMemarg(op, _) => Instr::MemoryMemarg(op, self.memarg(memory_width(op))?),
+            Instr::VectorMemarg(op, _) => Instr::VectorMemarg(op, self.memarg(vector_width(op))?),
+            Instr::VectorMemargLaneidx(op, _, _) => {
+                let width = lane_width(op);
+                let l = Laneidx(self.u.int_in_range(0..=(16 >> width) - 1)?);
+                Instr::VectorMemargLaneidx(op, self.memarg(width)?, l)
+            }
+            Instr::BrTable(_, l) => {
+                let label = self.ctx.label(l);
+                let targets: Vec<_> = (0..self.ctx.labels.len() as u32)
+                    .map(Labelidx)
+                    .filter(|&t| self.ctx.label(t) == label)
+                    .collect();
+                let ls = (0..self.u.int_in_range(0..=MAX_SEGMENT)?)
+                    .map(|_| self.u.choose(&targets).copied())
+                    .collect::<Result<_>>()?;
+                Instr::BrTable(Vector(ls), l)
+            }
+            Instr::Block(bt, _) => {
+                let ty = bt.functype(&self.ctx).unwrap();
+                Instr::Block(bt, self.block(&ty, &ty.results.0 .0, 0)?)
+            }
+            Instr::Loop(bt, _) => {
+                let ty = bt.functype(&self.ctx).unwrap();
+                Instr::Loop(bt, self.block(&ty, &ty.parameters.0 .0, 0)?)
+            }
+            Instr::If(bt, _) => {
+                let ty = bt.functype(&self.ctx).unwrap();
+                Instr::If(bt, self.block(&ty, &ty.results.0 .0, 0)?)
+            }
+            Instr::IfElse(bt, _, _) => {
+                let ty = bt.functype(&self.ctx).unwrap();
+                let then = self.block(&ty, &ty.results.0 .0, 1)?;
+                Instr::IfElse(bt, then, self.block(&ty, &ty.results.0 .0, 0)?)
+            }
+            i => i,
+        })
+    }
+
+    /// A nested block's body, starting from its parameters on a fresh stack.
+    /// `after` is the number of blocks that follow it before the enclosing
+    /// one resumes. The block gets half the fuel to spare, leaving the rest
+    /// for the enclosing one.
+    fn block(&mut self, ty: &Functype, label: &[Valtype], after: u32) -> Result<Box<[Instr]>> {
+        let outer = std::mem::replace(&mut self.vals, ty.parameters.0 .0.to_vec());
+        let reserve = self.reserve;
+        let needed = reserve + 1 + after;
+        self.reserve = needed + (self.fuel - needed - 1) / 2;
+        self.ctx.labels.push(label.into());
+        let body = self.instrs(&ty.results.0 .0);
+        self.ctx.labels.pop();
+        self.reserve = reserve;
+        self.vals = outer;
+        body
+    }
+
+    fn memarg(&mut self, width: u32) -> Result<Memarg> {
+        Ok(Memarg {
+            align: self.u.int_in_range(0..=width)?,
+            offset: self.u.arbitrary()?,
+        })
+    }
+}
+
+/// Whether the instruction is in the MVP or a proposal the configuration
+/// allows.
+fn enabled(config: &GenerateConfig, i: &Instr) -> bool {
+    use Opcode::*;
+    match i {
+        Instr::V128Const(_)
+        | Instr::I8x16Shuffle(_)
+        | Instr::VectorMemarg(..)
+        | Instr::VectorMemargLaneidx(..)
+        | Instr::VectorLaneidx(..)
+        | Instr::VectorNoImmediate(_) => config.simd,
+        Instr::Opcode(I32Extend8S | I32Extend16S | I64Extend8S | I64Extend16S | I64Extend32S) => {
+            config.sign_extension
+        }
+        Instr::TruncSat(_) => config.saturating_float_to_int,
+        Instr::RefNull(_)
+        | Instr::RefFunc(_)
+        | Instr::Opcode(RefIsNull)
+        | Instr::Select(Some(_))
+        | Instr::TableGet(_)
+        | Instr::TableSet(_)
+        | Instr::TableGrow(_)
+        | Instr::TableSize(_)
+        | Instr::TableFill(_) => config.reference_types,
+        Instr::MemoryInit(_)
+        | Instr::DataDrop(_)
+        | Instr::MemoryCopy
+        | Instr::MemoryFill
+        | Instr::TableInit(..)
+        | Instr::ElemDrop(_)
+        | Instr::TableCopy(..) => config.bulk_memory,
+        Instr::Block(Blocktype::TypeIndex(_), _)
+        | Instr::Loop(Blocktype::TypeIndex(_), _)
+        | Instr::If(Blocktype::TypeIndex(_), _)
+        | Instr::IfElse(Blocktype::TypeIndex(_), _, _) => config.multi_value,
+        _ => true,
+    }
+}
+
+/// The numeric, vector and memory instructions whose stack effect doesn't
+/// depend on the context, with their stack effects.
+fn operators() -> &'static [Vec<(Instr, Functype)>; 3] {
+    static OPERATORS: OnceLock<[Vec<(Instr, Functype)>; 3]> = OnceLock::new();
+    OPERATORS.get_or_init(|| {
+        let numeric = Opcode::ALL
+            .iter()
+            .filter(|op| {
+                !matches!(
+                    op,
+                    Opcode::Unreachable
+                        | Opcode::Nop
+                        | Opcode::Return
+                        | Opcode::Drop
+                        | Opcode::RefIsNull
+                )
+            })
+            .map(|&op| Instr::Opcode(op))
+            .chain(TruncSat::ALL.iter().map(|&op| Instr::TruncSat(op)));
+        let vector = VectorNoImmediate::ALL
+            .iter()
+            .map(|&op| Instr::VectorNoImmediate(op))
+            .chain(
+                VectorLaneidx::ALL
+                    .iter()
+                    .map(|&op| Instr::VectorLaneidx(op, Laneidx(0))),
+            )
+            .chain([Instr::I8x16Shuffle([Laneidx(0); 16])]);
+        let memarg = Memarg {
+            align: 0,
+            offset: 0,
+        };
+        let memory = MemoryMemarg::ALL
+            .iter()
+            .map(|&op| Instr::MemoryMemarg(op, memarg))
+            .chain(
+                VectorMemarg::ALL
+                    .iter()
+                    .map(|&op| Instr::VectorMemarg(op, memarg)),
+            )
+            .chain(
+                VectorMemargLaneidx::ALL
+                    .iter()
+                    .map(|&op| Instr::VectorMemargLaneidx(op, memarg, Laneidx(0))),
+            );
+        let with_effect = |i: Instr| {
+            let ty = i.stack_effect(&NoContext).unwrap();
+            (i, ty)
+        };
+        [
+            numeric.map(with_effect).collect(),
+            vector.map(with_effect).collect(),
+            memory.map(with_effect).collect(),
+        ]
+    })
+}
+
+/// Resolves nothing, for instructions without indices.
+struct NoContext;
+
+impl Context for NoContext {
+    fn ty(&self, _: Typeidx) -> Option<Functype> {
+        None
+    }
+
+    fn func(&self, _: Funcidx) -> Option<Functype> {
+        None
+    }
+
+    fn local(&self, _: Localidx) -> Option<Valtype> {
+        None
+    }
+
+    fn global(&self, _: Globalidx) -> Option<Valtype> {
+        None
+    }
+
+    fn table(&self, _: Tableidx) -> Option<Reftype> {
+        None
+    }
+
+    fn label(&self, _: Labelidx) -> Option<Box<[Valtype]>> {
+        None
+    }
+
+    fn results(&self) -> Option<Box<[Valtype]>> {
+        None
+    }
+}
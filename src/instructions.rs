@@ -3,10 +3,12 @@ use std::io::{self, Write};
 use crate::{
     modules::{Dataidx, Elemidx, Funcidx, Globalidx, Labelidx, Localidx, Tableidx, Typeidx},
     types::{Reftype, Valtype},
+    values::{F32, F64},
     write_all, Grammar, Vector,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Blocktype {
     Empty,
     ValueType(Valtype),
@@ -24,6 +26,7 @@ impl Grammar for Blocktype {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Memarg {
     pub align: u32,
     pub offset: u32,
@@ -37,6 +40,7 @@ impl Grammar for Memarg {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Laneidx(pub u8);
 
 impl Grammar for Laneidx {
@@ -45,15 +49,13 @@ impl Grammar for Laneidx {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Expr(pub Box<[Instr]>);
 
 impl Grammar for Expr {
     fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
-        self.0
-            .iter()
-            .map(|i| i.write(w))
-            .collect::<io::Result<()>>()?;
+        self.0.iter().try_for_each(|i| i.write(w))?;
         0x0bu8.write(w)
     }
 }
@@ -73,7 +75,8 @@ impl Grammar for S33 {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Instr {
     Opcode(Opcode),
     // Control
@@ -117,8 +120,8 @@ pub enum Instr {
     // Numeric
     I32Const(i32),
     I64Const(i64),
-    F32Const(f32),
-    F64Const(f64),
+    F32Const(F32),
+    F64Const(F64),
     TruncSat(TruncSat),
 
     // Vector
@@ -126,19 +129,38 @@ pub enum Instr {
     I8x16Shuffle([Laneidx; 16]),
     VectorMemarg(VectorMemarg, Memarg),
     VectorMemargLaneidx(VectorMemargLaneidx, Memarg, Laneidx),
-    VectorLaneidx(VectorMemarg, Laneidx),
+    VectorLaneidx(VectorLaneidx, Laneidx),
     VectorNoImmediate(VectorNoImmediate),
 }
 
 impl Grammar for Instr {
     fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.write_with(w, &mut |_: &W, _: &Instr| {})
+    }
+}
+
+impl Instr {
+    /// Writes the instruction, calling `f` with the writer just before the
+    /// opcode of this instruction and of each one nested in it.
+    pub(crate) fn write_with<'a, W: Write, F: FnMut(&W, &'a Instr)>(
+        &'a self,
+        w: &mut W,
+        f: &mut F,
+    ) -> io::Result<()> {
+        f(w, self);
         match self {
             Instr::Opcode(op) => op.write(w),
             // Control
-            Instr::Block(bt, r#in) => write_all!(w, 0x02u8, bt, r#in, 0x0bu8),
-            Instr::Loop(bt, r#in) => write_all!(w, 0x03u8, bt, r#in, 0x0bu8),
-            Instr::If(bt, r#in) => write_all!(w, 0x04u8, bt, r#in, 0x0bu8),
-            Instr::IfElse(bt, in1, in2) => write_all!(w, 0x04u8, bt, in1, 0x05u8, in2, 0x0bu8),
+            Instr::Block(bt, r#in) => write_block(w, f, 0x02, bt, r#in),
+            Instr::Loop(bt, r#in) => write_block(w, f, 0x03, bt, r#in),
+            Instr::If(bt, r#in) => write_block(w, f, 0x04, bt, r#in),
+            Instr::IfElse(bt, in1, in2) => {
+                write_all!(w, 0x04u8, bt)?;
+                in1.iter().try_for_each(|i| i.write_with(w, f))?;
+                0x05u8.write(w)?;
+                in2.iter().try_for_each(|i| i.write_with(w, f))?;
+                0x0bu8.write(w)
+            }
             Instr::Br(l) => write_all!(w, 0x0cu8, l),
             Instr::BrIf(l) => write_all!(w, 0x0du8, l),
             Instr::BrTable(l, default) => write_all!(w, 0x0eu8, l, default),
@@ -202,8 +224,21 @@ impl Grammar for Instr {
     }
 }
 
+fn write_block<'a, W: Write, F: FnMut(&W, &'a Instr)>(
+    w: &mut W,
+    f: &mut F,
+    opcode: u8,
+    bt: &Blocktype,
+    r#in: &'a [Instr],
+) -> io::Result<()> {
+    write_all!(w, opcode, bt)?;
+    r#in.iter().try_for_each(|i| i.write_with(w, f))?;
+    0x0bu8.write(w)
+}
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum MemoryMemarg {
     I32Load = 0x28,
     I64Load,
@@ -236,8 +271,67 @@ impl Grammar for MemoryMemarg {
     }
 }
 
+impl MemoryMemarg {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::I32Load,
+        Self::I64Load,
+        Self::F32Load,
+        Self::F64Load,
+        Self::I32Load8S,
+        Self::I32Load8U,
+        Self::I32Load16S,
+        Self::I32Load16U,
+        Self::I64Load8S,
+        Self::I64Load8U,
+        Self::I64Load16S,
+        Self::I64Load16U,
+        Self::I64Load32S,
+        Self::I64Load32U,
+        Self::I32Store,
+        Self::I64Store,
+        Self::F32Store,
+        Self::F64Store,
+        Self::I32Store8,
+        Self::I32Store16,
+        Self::I64Store8,
+        Self::I64Store16,
+        Self::I64Store32,
+    ];
+
+    /// The instruction's name in the text format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::I32Load => "i32.load",
+            Self::I64Load => "i64.load",
+            Self::F32Load => "f32.load",
+            Self::F64Load => "f64.load",
+            Self::I32Load8S => "i32.load8_s",
+            Self::I32Load8U => "i32.load8_u",
+            Self::I32Load16S => "i32.load16_s",
+            Self::I32Load16U => "i32.load16_u",
+            Self::I64Load8S => "i64.load8_s",
+            Self::I64Load8U => "i64.load8_u",
+            Self::I64Load16S => "i64.load16_s",
+            Self::I64Load16U => "i64.load16_u",
+            Self::I64Load32S => "i64.load32_s",
+            Self::I64Load32U => "i64.load32_u",
+            Self::I32Store => "i32.store",
+            Self::I64Store => "i64.store",
+            Self::F32Store => "f32.store",
+            Self::F64Store => "f64.store",
+            Self::I32Store8 => "i32.store8",
+            Self::I32Store16 => "i32.store16",
+            Self::I64Store8 => "i64.store8",
+            Self::I64Store16 => "i64.store16",
+            Self::I64Store32 => "i64.store32",
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Opcode {
     Unreachable = 0x00,
     Nop = 0x01u8,
@@ -380,8 +474,287 @@ impl Grammar for Opcode {
     }
 }
 
+impl Opcode {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::Unreachable,
+        Self::Nop,
+        Self::Return,
+        Self::RefIsNull,
+        Self::Drop,
+        Self::I32Eqz,
+        Self::I32Eq,
+        Self::I32Ne,
+        Self::I32LtS,
+        Self::I32LtU,
+        Self::I32GtS,
+        Self::I32GtU,
+        Self::I32LeS,
+        Self::I32LeU,
+        Self::I32GeS,
+        Self::I32GeU,
+        Self::I64Eqz,
+        Self::I64Eq,
+        Self::I64Ne,
+        Self::I64LtS,
+        Self::I64LtU,
+        Self::I64GtS,
+        Self::I64GtU,
+        Self::I64LeS,
+        Self::I64LeU,
+        Self::I64GeS,
+        Self::I64GeU,
+        Self::F32Eq,
+        Self::F32Ne,
+        Self::F32Lt,
+        Self::F32Gt,
+        Self::F32Le,
+        Self::F32Ge,
+        Self::F64Eq,
+        Self::F64Ne,
+        Self::F64Lt,
+        Self::F64Gt,
+        Self::F64Le,
+        Self::F64Ge,
+        Self::I32Clz,
+        Self::I32Ctz,
+        Self::I32Popcnt,
+        Self::I32Add,
+        Self::I32Sub,
+        Self::I32Mul,
+        Self::I32DivS,
+        Self::I32DivU,
+        Self::I32RemS,
+        Self::I32RemU,
+        Self::I32And,
+        Self::I32Or,
+        Self::I32Xor,
+        Self::I32Shl,
+        Self::I32ShrS,
+        Self::I32ShrU,
+        Self::I32Rotl,
+        Self::I32Rotr,
+        Self::I64Clz,
+        Self::I64Ctz,
+        Self::I64Popcnt,
+        Self::I64Add,
+        Self::I64Sub,
+        Self::I64Mul,
+        Self::I64DivS,
+        Self::I64DivU,
+        Self::I64RemS,
+        Self::I64RemU,
+        Self::I64And,
+        Self::I64Or,
+        Self::I64Xor,
+        Self::I64Shl,
+        Self::I64ShrS,
+        Self::I64ShrU,
+        Self::I64Rotl,
+        Self::I64Rotr,
+        Self::F32Abs,
+        Self::F32Neg,
+        Self::F32Ceil,
+        Self::F32Floor,
+        Self::F32Trunc,
+        Self::F32Nearest,
+        Self::F32Sqrt,
+        Self::F32Add,
+        Self::F32Sub,
+        Self::F32Mul,
+        Self::F32Div,
+        Self::F32Min,
+        Self::F32Max,
+        Self::F32Copysign,
+        Self::F64Abs,
+        Self::F64Neg,
+        Self::F64Ceil,
+        Self::F64Floor,
+        Self::F64Trunc,
+        Self::F64Nearest,
+        Self::F64Sqrt,
+        Self::F64Add,
+        Self::F64Sub,
+        Self::F64Mul,
+        Self::F64Div,
+        Self::F64Min,
+        Self::F64Max,
+        Self::F64Copysign,
+        Self::I32WrapI64,
+        Self::I32TruncF32S,
+        Self::I32TruncF32U,
+        Self::I32TruncF64S,
+        Self::I32TruncF64U,
+        Self::I64ExtendI32S,
+        Self::I64ExtendI32U,
+        Self::I64TruncF32S,
+        Self::I64TruncF32U,
+        Self::I64TruncF64S,
+        Self::I64TruncF64U,
+        Self::F32ConvertI32S,
+        Self::F32ConvertI32U,
+        Self::F32ConvertI64S,
+        Self::F32ConvertI64U,
+        Self::F32DemoteF64,
+        Self::F64ConvertI32S,
+        Self::F64ConvertI32U,
+        Self::F64ConvertI64S,
+        Self::F64ConvertI64U,
+        Self::F64PromoteF32,
+        Self::I32ReinterpretF32,
+        Self::I64ReinterpretF64,
+        Self::F32ReinterpretI32,
+        Self::F64ReinterpretI64,
+        Self::I32Extend8S,
+        Self::I32Extend16S,
+        Self::I64Extend8S,
+        Self::I64Extend16S,
+        Self::I64Extend32S,
+    ];
+
+    /// The instruction's name in the text format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Unreachable => "unreachable",
+            Self::Nop => "nop",
+            Self::Return => "return",
+            Self::RefIsNull => "ref.is_null",
+            Self::Drop => "drop",
+            Self::I32Eqz => "i32.eqz",
+            Self::I32Eq => "i32.eq",
+            Self::I32Ne => "i32.ne",
+            Self::I32LtS => "i32.lt_s",
+            Self::I32LtU => "i32.lt_u",
+            Self::I32GtS => "i32.gt_s",
+            Self::I32GtU => "i32.gt_u",
+            Self::I32LeS => "i32.le_s",
+            Self::I32LeU => "i32.le_u",
+            Self::I32GeS => "i32.ge_s",
+            Self::I32GeU => "i32.ge_u",
+            Self::I64Eqz => "i64.eqz",
+            Self::I64Eq => "i64.eq",
+            Self::I64Ne => "i64.ne",
+            Self::I64LtS => "i64.lt_s",
+            Self::I64LtU => "i64.lt_u",
+            Self::I64GtS => "i64.gt_s",
+            Self::I64GtU => "i64.gt_u",
+            Self::I64LeS => "i64.le_s",
+            Self::I64LeU => "i64.le_u",
+            Self::I64GeS => "i64.ge_s",
+            Self::I64GeU => "i64.ge_u",
+            Self::F32Eq => "f32.eq",
+            Self::F32Ne => "f32.ne",
+            Self::F32Lt => "f32.lt",
+            Self::F32Gt => "f32.gt",
+            Self::F32Le => "f32.le",
+            Self::F32Ge => "f32.ge",
+            Self::F64Eq => "f64.eq",
+            Self::F64Ne => "f64.ne",
+            Self::F64Lt => "f64.lt",
+            Self::F64Gt => "f64.gt",
+            Self::F64Le => "f64.le",
+            Self::F64Ge => "f64.ge",
+            Self::I32Clz => "i32.clz",
+            Self::I32Ctz => "i32.ctz",
+            Self::I32Popcnt => "i32.popcnt",
+            Self::I32Add => "i32.add",
+            Self::I32Sub => "i32.sub",
+            Self::I32Mul => "i32.mul",
+            Self::I32DivS => "i32.div_s",
+            Self::I32DivU => "i32.div_u",
+            Self::I32RemS => "i32.rem_s",
+            Self::I32RemU => "i32.rem_u",
+            Self::I32And => "i32.and",
+            Self::I32Or => "i32.or",
+            Self::I32Xor => "i32.xor",
+            Self::I32Shl => "i32.shl",
+            Self::I32ShrS => "i32.shr_s",
+            Self::I32ShrU => "i32.shr_u",
+            Self::I32Rotl => "i32.rotl",
+            Self::I32Rotr => "i32.rotr",
+            Self::I64Clz => "i64.clz",
+            Self::I64Ctz => "i64.ctz",
+            Self::I64Popcnt => "i64.popcnt",
+            Self::I64Add => "i64.add",
+            Self::I64Sub => "i64.sub",
+            Self::I64Mul => "i64.mul",
+            Self::I64DivS => "i64.div_s",
+            Self::I64DivU => "i64.div_u",
+            Self::I64RemS => "i64.rem_s",
+            Self::I64RemU => "i64.rem_u",
+            Self::I64And => "i64.and",
+            Self::I64Or => "i64.or",
+            Self::I64Xor => "i64.xor",
+            Self::I64Shl => "i64.shl",
+            Self::I64ShrS => "i64.shr_s",
+            Self::I64ShrU => "i64.shr_u",
+            Self::I64Rotl => "i64.rotl",
+            Self::I64Rotr => "i64.rotr",
+            Self::F32Abs => "f32.abs",
+            Self::F32Neg => "f32.neg",
+            Self::F32Ceil => "f32.ceil",
+            Self::F32Floor => "f32.floor",
+            Self::F32Trunc => "f32.trunc",
+            Self::F32Nearest => "f32.nearest",
+            Self::F32Sqrt => "f32.sqrt",
+            Self::F32Add => "f32.add",
+            Self::F32Sub => "f32.sub",
+            Self::F32Mul => "f32.mul",
+            Self::F32Div => "f32.div",
+            Self::F32Min => "f32.min",
+            Self::F32Max => "f32.max",
+            Self::F32Copysign => "f32.copysign",
+            Self::F64Abs => "f64.abs",
+            Self::F64Neg => "f64.neg",
+            Self::F64Ceil => "f64.ceil",
+            Self::F64Floor => "f64.floor",
+            Self::F64Trunc => "f64.trunc",
+            Self::F64Nearest => "f64.nearest",
+            Self::F64Sqrt => "f64.sqrt",
+            Self::F64Add => "f64.add",
+            Self::F64Sub => "f64.sub",
+            Self::F64Mul => "f64.mul",
+            Self::F64Div => "f64.div",
+            Self::F64Min => "f64.min",
+            Self::F64Max => "f64.max",
+            Self::F64Copysign => "f64.copysign",
+            Self::I32WrapI64 => "i32.wrap_i64",
+            Self::I32TruncF32S => "i32.trunc_f32_s",
+            Self::I32TruncF32U => "i32.trunc_f32_u",
+            Self::I32TruncF64S => "i32.trunc_f64_s",
+            Self::I32TruncF64U => "i32.trunc_f64_u",
+            Self::I64ExtendI32S => "i64.extend_i32_s",
+            Self::I64ExtendI32U => "i64.extend_i32_u",
+            Self::I64TruncF32S => "i64.trunc_f32_s",
+            Self::I64TruncF32U => "i64.trunc_f32_u",
+            Self::I64TruncF64S => "i64.trunc_f64_s",
+            Self::I64TruncF64U => "i64.trunc_f64_u",
+            Self::F32ConvertI32S => "f32.convert_i32_s",
+            Self::F32ConvertI32U => "f32.convert_i32_u",
+            Self::F32ConvertI64S => "f32.convert_i64_s",
+            Self::F32ConvertI64U => "f32.convert_i64_u",
+            Self::F32DemoteF64 => "f32.demote_f64",
+            Self::F64ConvertI32S => "f64.convert_i32_s",
+            Self::F64ConvertI32U => "f64.convert_i32_u",
+            Self::F64ConvertI64S => "f64.convert_i64_s",
+            Self::F64ConvertI64U => "f64.convert_i64_u",
+            Self::F64PromoteF32 => "f64.promote_f32",
+            Self::I32ReinterpretF32 => "i32.reinterpret_f32",
+            Self::I64ReinterpretF64 => "i64.reinterpret_f64",
+            Self::F32ReinterpretI32 => "f32.reinterpret_i32",
+            Self::F64ReinterpretI64 => "f64.reinterpret_i64",
+            Self::I32Extend8S => "i32.extend8_s",
+            Self::I32Extend16S => "i32.extend16_s",
+            Self::I64Extend8S => "i64.extend8_s",
+            Self::I64Extend16S => "i64.extend16_s",
+            Self::I64Extend32S => "i64.extend32_s",
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum TruncSat {
     I32TruncSatF32S = 0,
     I32TruncSatF32U,
@@ -399,8 +772,37 @@ impl Grammar for TruncSat {
     }
 }
 
+impl TruncSat {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::I32TruncSatF32S,
+        Self::I32TruncSatF32U,
+        Self::I32TruncSatF64S,
+        Self::I32TruncSatF64U,
+        Self::I64TruncSatF32S,
+        Self::I64TruncSatF32U,
+        Self::I64TruncSatF64S,
+        Self::I64TruncSatF64U,
+    ];
+
+    /// The instruction's name in the text format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::I32TruncSatF32S => "i32.trunc_sat_f32_s",
+            Self::I32TruncSatF32U => "i32.trunc_sat_f32_u",
+            Self::I32TruncSatF64S => "i32.trunc_sat_f64_s",
+            Self::I32TruncSatF64U => "i32.trunc_sat_f64_u",
+            Self::I64TruncSatF32S => "i64.trunc_sat_f32_s",
+            Self::I64TruncSatF32U => "i64.trunc_sat_f32_u",
+            Self::I64TruncSatF64S => "i64.trunc_sat_f64_s",
+            Self::I64TruncSatF64U => "i64.trunc_sat_f64_u",
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VectorMemarg {
     V128Load = 0,
     V128Load8x8S,
@@ -424,8 +826,49 @@ impl Grammar for VectorMemarg {
     }
 }
 
+impl VectorMemarg {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::V128Load,
+        Self::V128Load8x8S,
+        Self::V128Load8x8U,
+        Self::V128Load16x4S,
+        Self::V128Load16x4U,
+        Self::V128Load32x2S,
+        Self::V128Load32x2U,
+        Self::V128Load8Splat,
+        Self::V128Load16Splat,
+        Self::V128Load32Splat,
+        Self::V128Load64Splat,
+        Self::V128Load32Zero,
+        Self::V128Load64Zero,
+        Self::V128Store,
+    ];
+
+    /// The instruction's name in the text format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::V128Load => "v128.load",
+            Self::V128Load8x8S => "v128.load8x8_s",
+            Self::V128Load8x8U => "v128.load8x8_u",
+            Self::V128Load16x4S => "v128.load16x4_s",
+            Self::V128Load16x4U => "v128.load16x4_u",
+            Self::V128Load32x2S => "v128.load32x2_s",
+            Self::V128Load32x2U => "v128.load32x2_u",
+            Self::V128Load8Splat => "v128.load8_splat",
+            Self::V128Load16Splat => "v128.load16_splat",
+            Self::V128Load32Splat => "v128.load32_splat",
+            Self::V128Load64Splat => "v128.load64_splat",
+            Self::V128Load32Zero => "v128.load32_zero",
+            Self::V128Load64Zero => "v128.load64_zero",
+            Self::V128Store => "v128.store",
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VectorMemargLaneidx {
     V128Load8Lane = 84,
     V128Load16Lane,
@@ -443,8 +886,37 @@ impl Grammar for VectorMemargLaneidx {
     }
 }
 
+impl VectorMemargLaneidx {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::V128Load8Lane,
+        Self::V128Load16Lane,
+        Self::V128Load32Lane,
+        Self::V128Load64Lane,
+        Self::V128Store8Lane,
+        Self::V128Store16Lane,
+        Self::V128Store32Lane,
+        Self::V128Store64Lane,
+    ];
+
+    /// The instruction's name in the text format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::V128Load8Lane => "v128.load8_lane",
+            Self::V128Load16Lane => "v128.load16_lane",
+            Self::V128Load32Lane => "v128.load32_lane",
+            Self::V128Load64Lane => "v128.load64_lane",
+            Self::V128Store8Lane => "v128.store8_lane",
+            Self::V128Store16Lane => "v128.store16_lane",
+            Self::V128Store32Lane => "v128.store32_lane",
+            Self::V128Store64Lane => "v128.store64_lane",
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VectorLaneidx {
     I8x16ExtractLaneS = 21,
     I8x16ExtractLaneU,
@@ -468,8 +940,49 @@ impl Grammar for VectorLaneidx {
     }
 }
 
+impl VectorLaneidx {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::I8x16ExtractLaneS,
+        Self::I8x16ExtractLaneU,
+        Self::I8x16ReplaceLane,
+        Self::I16x8ExtractLaneS,
+        Self::I16x8ExtractLaneU,
+        Self::I16x8ReplaceLane,
+        Self::I32x4ExtractLane,
+        Self::I32x4ReplaceLane,
+        Self::I64x2ExtractLane,
+        Self::I64x2ReplaceLane,
+        Self::F32x4ExtractLane,
+        Self::F32x4ReplaceLane,
+        Self::F64x2ExtractLane,
+        Self::F64x2ReplaceLane,
+    ];
+
+    /// The instruction's name in the text format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::I8x16ExtractLaneS => "i8x16.extract_lane_s",
+            Self::I8x16ExtractLaneU => "i8x16.extract_lane_u",
+            Self::I8x16ReplaceLane => "i8x16.replace_lane",
+            Self::I16x8ExtractLaneS => "i16x8.extract_lane_s",
+            Self::I16x8ExtractLaneU => "i16x8.extract_lane_u",
+            Self::I16x8ReplaceLane => "i16x8.replace_lane",
+            Self::I32x4ExtractLane => "i32x4.extract_lane",
+            Self::I32x4ReplaceLane => "i32x4.replace_lane",
+            Self::I64x2ExtractLane => "i64x2.extract_lane",
+            Self::I64x2ReplaceLane => "i64x2.replace_lane",
+            Self::F32x4ExtractLane => "f32x4.extract_lane",
+            Self::F32x4ReplaceLane => "f32x4.replace_lane",
+            Self::F64x2ExtractLane => "f64x2.extract_lane",
+            Self::F64x2ReplaceLane => "f64x2.replace_lane",
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VectorNoImmediate {
     //
     I8x16Swizzle = 14,
@@ -522,17 +1035,17 @@ pub enum VectorNoImmediate {
     //
     F32x4Eq = 65,
     F32x4Ne,
-    F32x4LtS,
-    F32x4GtS,
-    F32x4LeS,
-    F32x4GeS,
+    F32x4Lt,
+    F32x4Gt,
+    F32x4Le,
+    F32x4Ge,
     //
     F64x2Eq = 71,
     F64x2Ne,
-    F64x2LtS,
-    F64x2GtS,
-    F64x2LeS,
-    F64x2GeS,
+    F64x2Lt,
+    F64x2Gt,
+    F64x2Le,
+    F64x2Ge,
     //
     V128Not = 77,
     V128And,
@@ -564,9 +1077,10 @@ pub enum VectorNoImmediate {
     I8x16MaxU,
     I8x16AvgrU = 123,
     //
-    I16x8ExtaddPairwise = 124,
-    I16x8Abs,
-    I16x8Neg = 128,
+    I16x8ExtaddPairwiseI8x16S = 124,
+    I16x8ExtaddPairwiseI8x16U,
+    I16x8Abs = 128,
+    I16x8Neg,
     I16x8Q15MulrSatS,
     I16x8AllTrue,
     I16x8Bitmask,
@@ -596,34 +1110,31 @@ pub enum VectorNoImmediate {
     I16x8ExtmulLowI8x16U,
     I16x8ExtmulHighI8x16U,
     //
-    I32x4ExtaddPairwiseS = 126,
-    I32x4ExtaddPairwiseU,
+    I32x4ExtaddPairwiseI16x8S = 126,
+    I32x4ExtaddPairwiseI16x8U,
     I32x4Abs = 160,
     I32x4Neg,
-    I32x4Q15MulrSatS,
     I32x4AllTrue = 163,
     I32x4Bitmask,
-    I32x4ExtendLowI8x16S = 167,
-    I32x4ExtendHighI8x16S,
-    I32x4ExtendLowI8x16U,
-    I32x4ExtendHighI8x16U,
+    I32x4ExtendLowI16x8S = 167,
+    I32x4ExtendHighI16x8S,
+    I32x4ExtendLowI16x8U,
+    I32x4ExtendHighI16x8U,
     I32x4Shl,
     I32x4ShrS,
     I32x4ShrU,
     I32x4Add,
-    I32x4AddSatS,
-    I32x4AddSatU,
     I32x4Sub = 177,
-    I32x4Mul,
+    I32x4Mul = 181,
     I32x4MinS,
     I32x4MinU,
     I32x4MaxS,
     I32x4MaxU,
-    I32x4AvgrU,
-    I32x4ExtmulLowI8x16S = 188,
-    I32x4ExtmulHighI8x16S,
-    I32x4ExtmulLowI8x16U,
-    I32x4ExtmulHighI8x16U,
+    I32x4DotI16x8S,
+    I32x4ExtmulLowI16x8S = 188,
+    I32x4ExtmulHighI16x8S,
+    I32x4ExtmulLowI16x8U,
+    I32x4ExtmulHighI16x8U,
     //
     I64x2Abs = 192,
     I64x2Neg,
@@ -639,10 +1150,10 @@ pub enum VectorNoImmediate {
     I64x2Add,
     I64x2Sub = 209,
     I64x2Mul = 213,
-    I64x2ExtlowLowI32x4S = 220,
-    I64x2ExtlowHighI32x4S,
-    I64x2ExtlowLowI32x4U,
-    I64x2ExtlowHighI32x4U,
+    I64x2ExtmulLowI32x4S = 220,
+    I64x2ExtmulHighI32x4S,
+    I64x2ExtmulLowI32x4U,
+    I64x2ExtmulHighI32x4U,
     //
     F32x4Ceil = 103,
     F32x4Floor,
@@ -650,7 +1161,7 @@ pub enum VectorNoImmediate {
     F32x4Nearest,
     F32x4Abs = 224,
     F32x4Neg,
-    F32x4Sqrt,
+    F32x4Sqrt = 227,
     F32x4Add,
     F32x4Sub,
     F32x4Mul,
@@ -693,3 +1204,411 @@ impl Grammar for VectorNoImmediate {
         (*self as u32).write(w)
     }
 }
+
+impl VectorNoImmediate {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::I8x16Swizzle,
+        Self::I8x16Splat,
+        Self::I16x8Splat,
+        Self::I32x4Splat,
+        Self::I64x2Splat,
+        Self::F32x4Splat,
+        Self::F64x2Splat,
+        Self::I8x16Eq,
+        Self::I8x16Ne,
+        Self::I8x16LtS,
+        Self::I8x16LtU,
+        Self::I8x16GtS,
+        Self::I8x16GtU,
+        Self::I8x16LeS,
+        Self::I8x16LeU,
+        Self::I8x16GeS,
+        Self::I8x16GeU,
+        Self::I16x8Eq,
+        Self::I16x8Ne,
+        Self::I16x8LtS,
+        Self::I16x8LtU,
+        Self::I16x8GtS,
+        Self::I16x8GtU,
+        Self::I16x8LeS,
+        Self::I16x8LeU,
+        Self::I16x8GeS,
+        Self::I16x8GeU,
+        Self::I32x4Eq,
+        Self::I32x4Ne,
+        Self::I32x4LtS,
+        Self::I32x4LtU,
+        Self::I32x4GtS,
+        Self::I32x4GtU,
+        Self::I32x4LeS,
+        Self::I32x4LeU,
+        Self::I32x4GeS,
+        Self::I32x4GeU,
+        Self::I64x2Eq,
+        Self::I64x2Ne,
+        Self::I64x2LtS,
+        Self::I64x2GtS,
+        Self::I64x2LeS,
+        Self::I64x2GeS,
+        Self::F32x4Eq,
+        Self::F32x4Ne,
+        Self::F32x4Lt,
+        Self::F32x4Gt,
+        Self::F32x4Le,
+        Self::F32x4Ge,
+        Self::F64x2Eq,
+        Self::F64x2Ne,
+        Self::F64x2Lt,
+        Self::F64x2Gt,
+        Self::F64x2Le,
+        Self::F64x2Ge,
+        Self::V128Not,
+        Self::V128And,
+        Self::V128AndNot,
+        Self::V128Or,
+        Self::V128Xor,
+        Self::V128Bitselect,
+        Self::V128AnyTrue,
+        Self::I8x16Abs,
+        Self::I8x16Neg,
+        Self::I8x16Popcnt,
+        Self::I8x16AllTrue,
+        Self::I8x16Bitmask,
+        Self::I8x16NarrowI16x8S,
+        Self::I8x16NarrowI16x8U,
+        Self::I8x16Shl,
+        Self::I8x16ShrS,
+        Self::I8x16ShrU,
+        Self::I8x16Add,
+        Self::I8x16AddSatS,
+        Self::I8x16AddSatU,
+        Self::I8x16Sub,
+        Self::I8x16SubSatS,
+        Self::I8x16SubSatU,
+        Self::I8x16MinS,
+        Self::I8x16MinU,
+        Self::I8x16MaxS,
+        Self::I8x16MaxU,
+        Self::I8x16AvgrU,
+        Self::I16x8ExtaddPairwiseI8x16S,
+        Self::I16x8ExtaddPairwiseI8x16U,
+        Self::I16x8Abs,
+        Self::I16x8Neg,
+        Self::I16x8Q15MulrSatS,
+        Self::I16x8AllTrue,
+        Self::I16x8Bitmask,
+        Self::I16x8NarrowI32x4S,
+        Self::I16x8NarrowI32x4U,
+        Self::I16x8ExtendLowI8x16S,
+        Self::I16x8ExtendHighI8x16S,
+        Self::I16x8ExtendLowI8x16U,
+        Self::I16x8ExtendHighI8x16U,
+        Self::I16x8Shl,
+        Self::I16x8ShrS,
+        Self::I16x8ShrU,
+        Self::I16x8Add,
+        Self::I16x8AddSatS,
+        Self::I16x8AddSatU,
+        Self::I16x8Sub,
+        Self::I16x8SubSatS,
+        Self::I16x8SubSatU,
+        Self::I16x8Mul,
+        Self::I16x8MinS,
+        Self::I16x8MinU,
+        Self::I16x8MaxS,
+        Self::I16x8MaxU,
+        Self::I16x8AvgrU,
+        Self::I16x8ExtmulLowI8x16S,
+        Self::I16x8ExtmulHighI8x16S,
+        Self::I16x8ExtmulLowI8x16U,
+        Self::I16x8ExtmulHighI8x16U,
+        Self::I32x4ExtaddPairwiseI16x8S,
+        Self::I32x4ExtaddPairwiseI16x8U,
+        Self::I32x4Abs,
+        Self::I32x4Neg,
+        Self::I32x4AllTrue,
+        Self::I32x4Bitmask,
+        Self::I32x4ExtendLowI16x8S,
+        Self::I32x4ExtendHighI16x8S,
+        Self::I32x4ExtendLowI16x8U,
+        Self::I32x4ExtendHighI16x8U,
+        Self::I32x4Shl,
+        Self::I32x4ShrS,
+        Self::I32x4ShrU,
+        Self::I32x4Add,
+        Self::I32x4Sub,
+        Self::I32x4Mul,
+        Self::I32x4MinS,
+        Self::I32x4MinU,
+        Self::I32x4MaxS,
+        Self::I32x4MaxU,
+        Self::I32x4DotI16x8S,
+        Self::I32x4ExtmulLowI16x8S,
+        Self::I32x4ExtmulHighI16x8S,
+        Self::I32x4ExtmulLowI16x8U,
+        Self::I32x4ExtmulHighI16x8U,
+        Self::I64x2Abs,
+        Self::I64x2Neg,
+        Self::I64x2AllTrue,
+        Self::I64x2Bitmask,
+        Self::I64x2ExtendLowI32x4S,
+        Self::I64x2ExtendHighI32x4S,
+        Self::I64x2ExtendLowI32x4U,
+        Self::I64x2ExtendHighI32x4U,
+        Self::I64x2Shl,
+        Self::I64x2ShrS,
+        Self::I64x2ShrU,
+        Self::I64x2Add,
+        Self::I64x2Sub,
+        Self::I64x2Mul,
+        Self::I64x2ExtmulLowI32x4S,
+        Self::I64x2ExtmulHighI32x4S,
+        Self::I64x2ExtmulLowI32x4U,
+        Self::I64x2ExtmulHighI32x4U,
+        Self::F32x4Ceil,
+        Self::F32x4Floor,
+        Self::F32x4Trunc,
+        Self::F32x4Nearest,
+        Self::F32x4Abs,
+        Self::F32x4Neg,
+        Self::F32x4Sqrt,
+        Self::F32x4Add,
+        Self::F32x4Sub,
+        Self::F32x4Mul,
+        Self::F32x4Div,
+        Self::F32x4Min,
+        Self::F32x4Max,
+        Self::F32x4Pmin,
+        Self::F32x4Pmax,
+        Self::F64x2Ceil,
+        Self::F64x2Floor,
+        Self::F64x2Trunc,
+        Self::F64x2Nearest,
+        Self::F64x2Abs,
+        Self::F64x2Neg,
+        Self::F64x2Sqrt,
+        Self::F64x2Add,
+        Self::F64x2Sub,
+        Self::F64x2Mul,
+        Self::F64x2Div,
+        Self::F64x2Min,
+        Self::F64x2Max,
+        Self::F64x2Pmin,
+        Self::F64x2Pmax,
+        Self::I32x4TruncSatF32x4S,
+        Self::I32x4TruncSatF32x4U,
+        Self::F32x4ConvertI32x4S,
+        Self::F32x4ConvertI32x4U,
+        Self::I32x4TruncSatF64x2SZero,
+        Self::I32x4TruncSatF64x2UZero,
+        Self::F64x2ConvertLowI32x4S,
+        Self::F64x2ConvertLowI32x4U,
+        Self::F32x4DemoteF64x2Zero,
+        Self::F64x2PromoteLowF32x4,
+    ];
+
+    /// The instruction's name in the text format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::I8x16Swizzle => "i8x16.swizzle",
+            Self::I8x16Splat => "i8x16.splat",
+            Self::I16x8Splat => "i16x8.splat",
+            Self::I32x4Splat => "i32x4.splat",
+            Self::I64x2Splat => "i64x2.splat",
+            Self::F32x4Splat => "f32x4.splat",
+            Self::F64x2Splat => "f64x2.splat",
+            Self::I8x16Eq => "i8x16.eq",
+            Self::I8x16Ne => "i8x16.ne",
+            Self::I8x16LtS => "i8x16.lt_s",
+            Self::I8x16LtU => "i8x16.lt_u",
+            Self::I8x16GtS => "i8x16.gt_s",
+            Self::I8x16GtU => "i8x16.gt_u",
+            Self::I8x16LeS => "i8x16.le_s",
+            Self::I8x16LeU => "i8x16.le_u",
+            Self::I8x16GeS => "i8x16.ge_s",
+            Self::I8x16GeU => "i8x16.ge_u",
+            Self::I16x8Eq => "i16x8.eq",
+            Self::I16x8Ne => "i16x8.ne",
+            Self::I16x8LtS => "i16x8.lt_s",
+            Self::I16x8LtU => "i16x8.lt_u",
+            Self::I16x8GtS => "i16x8.gt_s",
+            Self::I16x8GtU => "i16x8.gt_u",
+            Self::I16x8LeS => "i16x8.le_s",
+            Self::I16x8LeU => "i16x8.le_u",
+            Self::I16x8GeS => "i16x8.ge_s",
+            Self::I16x8GeU => "i16x8.ge_u",
+            Self::I32x4Eq => "i32x4.eq",
+            Self::I32x4Ne => "i32x4.ne",
+            Self::I32x4LtS => "i32x4.lt_s",
+            Self::I32x4LtU => "i32x4.lt_u",
+            Self::I32x4GtS => "i32x4.gt_s",
+            Self::I32x4GtU => "i32x4.gt_u",
+            Self::I32x4LeS => "i32x4.le_s",
+            Self::I32x4LeU => "i32x4.le_u",
+            Self::I32x4GeS => "i32x4.ge_s",
+            Self::I32x4GeU => "i32x4.ge_u",
+            Self::I64x2Eq => "i64x2.eq",
+            Self::I64x2Ne => "i64x2.ne",
+            Self::I64x2LtS => "i64x2.lt_s",
+            Self::I64x2GtS => "i64x2.gt_s",
+            Self::I64x2LeS => "i64x2.le_s",
+            Self::I64x2GeS => "i64x2.ge_s",
+            Self::F32x4Eq => "f32x4.eq",
+            Self::F32x4Ne => "f32x4.ne",
+            Self::F32x4Lt => "f32x4.lt",
+            Self::F32x4Gt => "f32x4.gt",
+            Self::F32x4Le => "f32x4.le",
+            Self::F32x4Ge => "f32x4.ge",
+            Self::F64x2Eq => "f64x2.eq",
+            Self::F64x2Ne => "f64x2.ne",
+            Self::F64x2Lt => "f64x2.lt",
+            Self::F64x2Gt => "f64x2.gt",
+            Self::F64x2Le => "f64x2.le",
+            Self::F64x2Ge => "f64x2.ge",
+            Self::V128Not => "v128.not",
+            Self::V128And => "v128.and",
+            Self::V128AndNot => "v128.andnot",
+            Self::V128Or => "v128.or",
+            Self::V128Xor => "v128.xor",
+            Self::V128Bitselect => "v128.bitselect",
+            Self::V128AnyTrue => "v128.any_true",
+            Self::I8x16Abs => "i8x16.abs",
+            Self::I8x16Neg => "i8x16.neg",
+            Self::I8x16Popcnt => "i8x16.popcnt",
+            Self::I8x16AllTrue => "i8x16.all_true",
+            Self::I8x16Bitmask => "i8x16.bitmask",
+            Self::I8x16NarrowI16x8S => "i8x16.narrow_i16x8_s",
+            Self::I8x16NarrowI16x8U => "i8x16.narrow_i16x8_u",
+            Self::I8x16Shl => "i8x16.shl",
+            Self::I8x16ShrS => "i8x16.shr_s",
+            Self::I8x16ShrU => "i8x16.shr_u",
+            Self::I8x16Add => "i8x16.add",
+            Self::I8x16AddSatS => "i8x16.add_sat_s",
+            Self::I8x16AddSatU => "i8x16.add_sat_u",
+            Self::I8x16Sub => "i8x16.sub",
+            Self::I8x16SubSatS => "i8x16.sub_sat_s",
+            Self::I8x16SubSatU => "i8x16.sub_sat_u",
+            Self::I8x16MinS => "i8x16.min_s",
+            Self::I8x16MinU => "i8x16.min_u",
+            Self::I8x16MaxS => "i8x16.max_s",
+            Self::I8x16MaxU => "i8x16.max_u",
+            Self::I8x16AvgrU => "i8x16.avgr_u",
+            Self::I16x8ExtaddPairwiseI8x16S => "i16x8.extadd_pairwise_i8x16_s",
+            Self::I16x8ExtaddPairwiseI8x16U => "i16x8.extadd_pairwise_i8x16_u",
+            Self::I16x8Abs => "i16x8.abs",
+            Self::I16x8Neg => "i16x8.neg",
+            Self::I16x8Q15MulrSatS => "i16x8.q15mulr_sat_s",
+            Self::I16x8AllTrue => "i16x8.all_true",
+            Self::I16x8Bitmask => "i16x8.bitmask",
+            Self::I16x8NarrowI32x4S => "i16x8.narrow_i32x4_s",
+            Self::I16x8NarrowI32x4U => "i16x8.narrow_i32x4_u",
+            Self::I16x8ExtendLowI8x16S => "i16x8.extend_low_i8x16_s",
+            Self::I16x8ExtendHighI8x16S => "i16x8.extend_high_i8x16_s",
+            Self::I16x8ExtendLowI8x16U => "i16x8.extend_low_i8x16_u",
+            Self::I16x8ExtendHighI8x16U => "i16x8.extend_high_i8x16_u",
+            Self::I16x8Shl => "i16x8.shl",
+            Self::I16x8ShrS => "i16x8.shr_s",
+            Self::I16x8ShrU => "i16x8.shr_u",
+            Self::I16x8Add => "i16x8.add",
+            Self::I16x8AddSatS => "i16x8.add_sat_s",
+            Self::I16x8AddSatU => "i16x8.add_sat_u",
+            Self::I16x8Sub => "i16x8.sub",
+            Self::I16x8SubSatS => "i16x8.sub_sat_s",
+            Self::I16x8SubSatU => "i16x8.sub_sat_u",
+            Self::I16x8Mul => "i16x8.mul",
+            Self::I16x8MinS => "i16x8.min_s",
+            Self::I16x8MinU => "i16x8.min_u",
+            Self::I16x8MaxS => "i16x8.max_s",
+            Self::I16x8MaxU => "i16x8.max_u",
+            Self::I16x8AvgrU => "i16x8.avgr_u",
+            Self::I16x8ExtmulLowI8x16S => "i16x8.extmul_low_i8x16_s",
+            Self::I16x8ExtmulHighI8x16S => "i16x8.extmul_high_i8x16_s",
+            Self::I16x8ExtmulLowI8x16U => "i16x8.extmul_low_i8x16_u",
+            Self::I16x8ExtmulHighI8x16U => "i16x8.extmul_high_i8x16_u",
+            Self::I32x4ExtaddPairwiseI16x8S => "i32x4.extadd_pairwise_i16x8_s",
+            Self::I32x4ExtaddPairwiseI16x8U => "i32x4.extadd_pairwise_i16x8_u",
+            Self::I32x4Abs => "i32x4.abs",
+            Self::I32x4Neg => "i32x4.neg",
+            Self::I32x4AllTrue => "i32x4.all_true",
+            Self::I32x4Bitmask => "i32x4.bitmask",
+            Self::I32x4ExtendLowI16x8S => "i32x4.extend_low_i16x8_s",
+            Self::I32x4ExtendHighI16x8S => "i32x4.extend_high_i16x8_s",
+            Self::I32x4ExtendLowI16x8U => "i32x4.extend_low_i16x8_u",
+            Self::I32x4ExtendHighI16x8U => "i32x4.extend_high_i16x8_u",
+            Self::I32x4Shl => "i32x4.shl",
+            Self::I32x4ShrS => "i32x4.shr_s",
+            Self::I32x4ShrU => "i32x4.shr_u",
+            Self::I32x4Add => "i32x4.add",
+            Self::I32x4Sub => "i32x4.sub",
+            Self::I32x4Mul => "i32x4.mul",
+            Self::I32x4MinS => "i32x4.min_s",
+            Self::I32x4MinU => "i32x4.min_u",
+            Self::I32x4MaxS => "i32x4.max_s",
+            Self::I32x4MaxU => "i32x4.max_u",
+            Self::I32x4DotI16x8S => "i32x4.dot_i16x8_s",
+            Self::I32x4ExtmulLowI16x8S => "i32x4.extmul_low_i16x8_s",
+            Self::I32x4ExtmulHighI16x8S => "i32x4.extmul_high_i16x8_s",
+            Self::I32x4ExtmulLowI16x8U => "i32x4.extmul_low_i16x8_u",
+            Self::I32x4ExtmulHighI16x8U => "i32x4.extmul_high_i16x8_u",
+            Self::I64x2Abs => "i64x2.abs",
+            Self::I64x2Neg => "i64x2.neg",
+            Self::I64x2AllTrue => "i64x2.all_true",
+            Self::I64x2Bitmask => "i64x2.bitmask",
+            Self::I64x2ExtendLowI32x4S => "i64x2.extend_low_i32x4_s",
+            Self::I64x2ExtendHighI32x4S => "i64x2.extend_high_i32x4_s",
+            Self::I64x2ExtendLowI32x4U => "i64x2.extend_low_i32x4_u",
+            Self::I64x2ExtendHighI32x4U => "i64x2.extend_high_i32x4_u",
+            Self::I64x2Shl => "i64x2.shl",
+            Self::I64x2ShrS => "i64x2.shr_s",
+            Self::I64x2ShrU => "i64x2.shr_u",
+            Self::I64x2Add => "i64x2.add",
+            Self::I64x2Sub => "i64x2.sub",
+            Self::I64x2Mul => "i64x2.mul",
+            Self::I64x2ExtmulLowI32x4S => "i64x2.extmul_low_i32x4_s",
+            Self::I64x2ExtmulHighI32x4S => "i64x2.extmul_high_i32x4_s",
+            Self::I64x2ExtmulLowI32x4U => "i64x2.extmul_low_i32x4_u",
+            Self::I64x2ExtmulHighI32x4U => "i64x2.extmul_high_i32x4_u",
+            Self::F32x4Ceil => "f32x4.ceil",
+            Self::F32x4Floor => "f32x4.floor",
+            Self::F32x4Trunc => "f32x4.trunc",
+            Self::F32x4Nearest => "f32x4.nearest",
+            Self::F32x4Abs => "f32x4.abs",
+            Self::F32x4Neg => "f32x4.neg",
+            Self::F32x4Sqrt => "f32x4.sqrt",
+            Self::F32x4Add => "f32x4.add",
+            Self::F32x4Sub => "f32x4.sub",
+            Self::F32x4Mul => "f32x4.mul",
+            Self::F32x4Div => "f32x4.div",
+            Self::F32x4Min => "f32x4.min",
+            Self::F32x4Max => "f32x4.max",
+            Self::F32x4Pmin => "f32x4.pmin",
+            Self::F32x4Pmax => "f32x4.pmax",
+            Self::F64x2Ceil => "f64x2.ceil",
+            Self::F64x2Floor => "f64x2.floor",
+            Self::F64x2Trunc => "f64x2.trunc",
+            Self::F64x2Nearest => "f64x2.nearest",
+            Self::F64x2Abs => "f64x2.abs",
+            Self::F64x2Neg => "f64x2.neg",
+            Self::F64x2Sqrt => "f64x2.sqrt",
+            Self::F64x2Add => "f64x2.add",
+            Self::F64x2Sub => "f64x2.sub",
+            Self::F64x2Mul => "f64x2.mul",
+            Self::F64x2Div => "f64x2.div",
+            Self::F64x2Min => "f64x2.min",
+            Self::F64x2Max => "f64x2.max",
+            Self::F64x2Pmin => "f64x2.pmin",
+            Self::F64x2Pmax => "f64x2.pmax",
+            Self::I32x4TruncSatF32x4S => "i32x4.trunc_sat_f32x4_s",
+            Self::I32x4TruncSatF32x4U => "i32x4.trunc_sat_f32x4_u",
+            Self::F32x4ConvertI32x4S => "f32x4.convert_i32x4_s",
+            Self::F32x4ConvertI32x4U => "f32x4.convert_i32x4_u",
+            Self::I32x4TruncSatF64x2SZero => "i32x4.trunc_sat_f64x2_s_zero",
+            Self::I32x4TruncSatF64x2UZero => "i32x4.trunc_sat_f64x2_u_zero",
+            Self::F64x2ConvertLowI32x4S => "f64x2.convert_low_i32x4_s",
+            Self::F64x2ConvertLowI32x4U => "f64x2.convert_low_i32x4_u",
+            Self::F32x4DemoteF64x2Zero => "f32x4.demote_f64x2_zero",
+            Self::F64x2PromoteLowF32x4 => "f64x2.promote_low_f32x4",
+        }
+    }
+}
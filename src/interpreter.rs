@@ -0,0 +1,1189 @@
+use crate::{
+    instructions::{
+        Blocktype, Expr, Instr, MemoryMemarg, Opcode, VectorMemarg, VectorMemargLaneidx, S33,
+    },
+    modules::{
+        Data, Elem, Exportdesc, Func, Funcidx, Globalidx, Importdesc, Module, Tableidx, Typeidx,
+    },
+    numeric::{self, Num},
+    stack::Context,
+    types::{
+        Functype, Globaltype, Limits, Memtype, Mut, Numtype, Reftype, Tabletype, Valtype, Vectype,
+    },
+    values::{F32, F64},
+};
+use std::{fmt, ops::Range, rc::Rc};
+
+mod simd;
+
+/// The size of a memory page in bytes.
+pub const PAGE: usize = 65536;
+
+/// A runtime value. References hold store addresses, with `None` for null.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Value {
+    I32(i32),
+    I64(i64),
+    F32(F32),
+    F64(F64),
+    V128(u128),
+    FuncRef(Option<usize>),
+    ExternRef(Option<u32>),
+}
+
+impl Value {
+    /// The zero or null value locals of the type start with.
+    pub fn default_for(t: Valtype) -> Self {
+        match t {
+            Valtype::Numtype(Numtype::I32) => Value::I32(0),
+            Valtype::Numtype(Numtype::I64) => Value::I64(0),
+            Valtype::Numtype(Numtype::F32) => Value::F32(F32(0)),
+            Valtype::Numtype(Numtype::F64) => Value::F64(F64(0)),
+            Valtype::Vectype(Vectype::V128) => Value::V128(0),
+            Valtype::Reftype(Reftype::Funcref) => Value::FuncRef(None),
+            Valtype::Reftype(Reftype::Externref) => Value::ExternRef(None),
+        }
+    }
+
+    pub fn ty(&self) -> Valtype {
+        match self {
+            Value::I32(_) => Valtype::Numtype(Numtype::I32),
+            Value::I64(_) => Valtype::Numtype(Numtype::I64),
+            Value::F32(_) => Valtype::Numtype(Numtype::F32),
+            Value::F64(_) => Valtype::Numtype(Numtype::F64),
+            Value::V128(_) => Valtype::Vectype(Vectype::V128),
+            Value::FuncRef(_) => Valtype::Reftype(Reftype::Funcref),
+            Value::ExternRef(_) => Valtype::Reftype(Reftype::Externref),
+        }
+    }
+
+    fn num(self) -> Option<Num> {
+        match self {
+            Value::I32(n) => Some(Num::I32(n)),
+            Value::I64(n) => Some(Num::I64(n)),
+            Value::F32(n) => Some(Num::F32(n.get())),
+            Value::F64(n) => Some(Num::F64(n.get())),
+            _ => None,
+        }
+    }
+}
+
+impl From<Num> for Value {
+    fn from(n: Num) -> Self {
+        match n {
+            Num::I32(n) => Value::I32(n),
+            Num::I64(n) => Value::I64(n),
+            Num::F32(n) => Value::F32(n.into()),
+            Num::F64(n) => Value::F64(n.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Trap {
+    Unreachable,
+    Numeric(numeric::Trap),
+    MemoryOutOfBounds,
+    TableOutOfBounds,
+    /// An indirect call through a null table element.
+    UninitializedElement,
+    IndirectCallTypeMismatch,
+    CallStackExhausted,
+    /// Raised by a host function.
+    Host(String),
+    /// The code doesn't validate, say by popping from an empty stack or
+    /// using an index that doesn't exist.
+    Invalid,
+}
+
+impl From<numeric::Trap> for Trap {
+    fn from(t: numeric::Trap) -> Self {
+        Trap::Numeric(t)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Error {
+    /// The imports don't match what the module declares.
+    Link(String),
+    /// Initializing the instance or running its start function trapped.
+    Trap(Trap),
+}
+
+impl From<Trap> for Error {
+    fn from(t: Trap) -> Self {
+        Error::Trap(t)
+    }
+}
+
+/// Something an instance can import or export, as an address in the store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Extern {
+    Func(usize),
+    Table(usize),
+    Mem(usize),
+    Global(usize),
+}
+
+pub type HostFunc = Rc<dyn Fn(&[Value]) -> Result<Vec<Value>, Trap>>;
+
+#[derive(Clone)]
+pub enum FuncInst {
+    Wasm {
+        ty: Functype,
+        instance: usize,
+        code: Rc<Func>,
+    },
+    Host {
+        ty: Functype,
+        f: HostFunc,
+    },
+}
+
+impl FuncInst {
+    pub fn ty(&self) -> &Functype {
+        match self {
+            FuncInst::Wasm { ty, .. } | FuncInst::Host { ty, .. } => ty,
+        }
+    }
+}
+
+impl fmt::Debug for FuncInst {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FuncInst::Wasm { ty, instance, .. } => f
+                .debug_struct("Wasm")
+                .field("ty", ty)
+                .field("instance", instance)
+                .finish_non_exhaustive(),
+            FuncInst::Host { ty, .. } => f
+                .debug_struct("Host")
+                .field("ty", ty)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableInst {
+    pub ty: Reftype,
+    pub elements: Vec<Value>,
+    pub max: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MemInst {
+    pub data: Vec<u8>,
+    pub max: Option<u32>,
+}
+
+impl MemInst {
+    pub fn pages(&self) -> usize {
+        self.data.len() / PAGE
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlobalInst {
+    pub ty: Globaltype,
+    pub value: Value,
+}
+
+/// The addresses of everything a module instance uses, indexed the same way
+/// as in the module.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Instance {
+    pub types: Vec<Functype>,
+    pub funcs: Vec<usize>,
+    pub tables: Vec<usize>,
+    pub mems: Vec<usize>,
+    pub globals: Vec<usize>,
+    pub elems: Vec<usize>,
+    pub datas: Vec<usize>,
+    pub exports: Vec<(String, Extern)>,
+}
+
+impl Instance {
+    pub fn export(&self, name: &str) -> Option<Extern> {
+        self.exports
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, e)| e)
+    }
+}
+
+/// Holds the runtime state of any number of instances, which may import
+/// from each other and from the host.
+#[derive(Debug, Clone)]
+pub struct Store {
+    pub funcs: Vec<FuncInst>,
+    pub tables: Vec<TableInst>,
+    pub mems: Vec<MemInst>,
+    pub globals: Vec<GlobalInst>,
+    /// Element segments, emptied when dropped.
+    pub elems: Vec<Vec<Value>>,
+    /// Data segments, emptied when dropped.
+    pub datas: Vec<Vec<u8>>,
+    pub instances: Vec<Instance>,
+    /// How deeply calls may nest before trapping with
+    /// [`CallStackExhausted`](Trap::CallStackExhausted). Every call recurses
+    /// on the native stack, so this should leave room on the thread's.
+    pub max_depth: usize,
+    /// How many elements `table.grow` may take a table to, whatever its own
+    /// maximum. Past it the instruction returns -1, as when the host is out
+    /// of memory, since elements are allocated eagerly.
+    pub max_table_elements: usize,
+    /// How many pages `memory.grow` may take a memory to, in the same way.
+    pub max_pages: usize,
+    depth: usize,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self {
+            funcs: vec![],
+            tables: vec![],
+            mems: vec![],
+            globals: vec![],
+            elems: vec![],
+            datas: vec![],
+            instances: vec![],
+            max_depth: 256,
+            max_table_elements: 1 << 20,
+            max_pages: 1 << 14,
+            depth: 0,
+        }
+    }
+}
+
+/// How a block was left.
+enum Flow {
+    /// Falling off the end.
+    Continue,
+    /// Branching to the label this many levels out.
+    Br(u32),
+    Return,
+}
+
+struct Frame {
+    instance: usize,
+    locals: Vec<Value>,
+}
+
+/// Resolves nothing, for the stack effects of numeric instructions.
+struct Bare;
+
+impl Context for Bare {
+    fn ty(&self, _: Typeidx) -> Option<Functype> {
+        None
+    }
+
+    fn func(&self, _: Funcidx) -> Option<Functype> {
+        None
+    }
+
+    fn local(&self, _: crate::modules::Localidx) -> Option<Valtype> {
+        None
+    }
+
+    fn global(&self, _: Globalidx) -> Option<Valtype> {
+        None
+    }
+
+    fn table(&self, _: Tableidx) -> Option<Reftype> {
+        None
+    }
+
+    fn label(&self, _: crate::modules::Labelidx) -> Option<Box<[Valtype]>> {
+        None
+    }
+
+    fn results(&self) -> Option<Box<[Valtype]>> {
+        None
+    }
+}
+
+enum Mode<'a> {
+    Passive,
+    Active(u32, &'a Expr),
+    Declarative,
+}
+
+fn pop(st: &mut Vec<Value>) -> Result<Value, Trap> {
+    st.pop().ok_or(Trap::Invalid)
+}
+
+fn pop_i32(st: &mut Vec<Value>) -> Result<i32, Trap> {
+    match pop(st)? {
+        Value::I32(n) => Ok(n),
+        _ => Err(Trap::Invalid),
+    }
+}
+
+fn pop_n(st: &mut Vec<Value>, n: usize) -> Result<Vec<Value>, Trap> {
+    let at = st.len().checked_sub(n).ok_or(Trap::Invalid)?;
+    Ok(st.split_off(at))
+}
+
+/// Drops the values between `height` and the top `arity`.
+fn unwind(st: &mut Vec<Value>, height: usize, arity: usize) -> Result<(), Trap> {
+    let top = st.len().checked_sub(arity).ok_or(Trap::Invalid)?;
+    if top < height {
+        return Err(Trap::Invalid);
+    }
+    st.drain(height..top);
+    Ok(())
+}
+
+/// Applies a numeric instruction, setting the quiet bit of NaN results as
+/// Wasm requires of all but the bitwise instructions.
+fn eval(op: Opcode, args: &[Num]) -> Option<Result<Num, numeric::Trap>> {
+    let n = numeric::eval(op, args)?;
+    if numeric::is_bitwise(op) {
+        return Some(n);
+    }
+    Some(n.map(|n| match n {
+        Num::F32(x) if x.is_nan() => Num::F32(f32::from_bits(x.to_bits() | 1 << 22)),
+        Num::F64(x) if x.is_nan() => Num::F64(f64::from_bits(x.to_bits() | 1 << 51)),
+        n => n,
+    }))
+}
+
+fn range(start: u64, n: u64, len: usize) -> Option<Range<usize>> {
+    let end = start.checked_add(n)?;
+    (end <= len as u64).then_some(start as usize..end as usize)
+}
+
+fn limits(l: Limits) -> (u32, Option<u32>) {
+    match l {
+        Limits::Min(min) => (min, None),
+        Limits::MinMax(min, max) => (min, Some(max)),
+    }
+}
+
+/// Whether something of the given size and maximum can be imported as the
+/// expected limits.
+fn limits_match(size: u64, max: Option<u32>, expected: Limits) -> bool {
+    let (min, expected_max) = limits(expected);
+    size >= min as u64
+        && match (max, expected_max) {
+            (_, None) => true,
+            (Some(max), Some(expected)) => max <= expected,
+            (None, Some(_)) => false,
+        }
+}
+
+impl Store {
+    pub fn alloc_host_func(
+        &mut self,
+        ty: Functype,
+        f: impl Fn(&[Value]) -> Result<Vec<Value>, Trap> + 'static,
+    ) -> Extern {
+        self.funcs.push(FuncInst::Host { ty, f: Rc::new(f) });
+        Extern::Func(self.funcs.len() - 1)
+    }
+
+    pub fn alloc_table(&mut self, ty: Tabletype, init: Value) -> Extern {
+        let (min, max) = limits(ty.limits);
+        self.tables.push(TableInst {
+            ty: ty.element_type,
+            elements: vec![init; min as usize],
+            max,
+        });
+        Extern::Table(self.tables.len() - 1)
+    }
+
+    pub fn alloc_mem(&mut self, ty: Memtype) -> Extern {
+        let (min, max) = limits(ty.0);
+        self.mems.push(MemInst {
+            data: vec![0; min as usize * PAGE],
+            max,
+        });
+        Extern::Mem(self.mems.len() - 1)
+    }
+
+    pub fn alloc_global(&mut self, ty: Globaltype, value: Value) -> Extern {
+        self.globals.push(GlobalInst { ty, value });
+        Extern::Global(self.globals.len() - 1)
+    }
+
+    /// Links the module against `imports`, given in the order of its import
+    /// section, then initializes the instance and runs its start function.
+    /// Returns the index of the instance.
+    ///
+    /// If linking or allocation fails, the store is left as it was. Once
+    /// initialization has begun the instance stays, as segments may already
+    /// have written references to its functions into imported tables.
+    pub fn instantiate(&mut self, m: &Module, imports: &[Extern]) -> Result<usize, Error> {
+        let sizes = [
+            self.funcs.len(),
+            self.tables.len(),
+            self.mems.len(),
+            self.globals.len(),
+            self.elems.len(),
+            self.datas.len(),
+            self.instances.len(),
+        ];
+        let (id, modes) = match self.allocate(m, imports) {
+            Ok(allocated) => allocated,
+            Err(e) => {
+                let [funcs, tables, mems, globals, elems, datas, instances] = sizes;
+                self.funcs.truncate(funcs);
+                self.tables.truncate(tables);
+                self.mems.truncate(mems);
+                self.globals.truncate(globals);
+                self.elems.truncate(elems);
+                self.datas.truncate(datas);
+                self.instances.truncate(instances);
+                return Err(e);
+            }
+        };
+
+        for (i, mode) in modes.iter().enumerate() {
+            match mode {
+                Mode::Passive => {}
+                Mode::Active(x, off) => {
+                    let Value::I32(d) = self.eval_const(id, off)? else {
+                        return Err(Trap::Invalid.into());
+                    };
+                    let n = self.elems[self.instances[id].elems[i]].len() as i32;
+                    let mut st = vec![Value::I32(d), Value::I32(0), Value::I32(n)];
+                    self.table_init(id, *x, i as u32, &mut st)?;
+                    self.elems[self.instances[id].elems[i]].clear();
+                }
+                Mode::Declarative => self.elems[self.instances[id].elems[i]].clear(),
+            }
+        }
+        let datas = m.datasec.iter().flat_map(|d| d.0 .0 .0.iter());
+        for (i, d) in datas.enumerate() {
+            let (x, off) = match d {
+                Data::ActiveAtZero(off, _) => (0, off),
+                Data::ActiveAtIndex(x, off, _) => (x.0, off),
+                Data::Passive(_) => continue,
+            };
+            let Value::I32(d) = self.eval_const(id, off)? else {
+                return Err(Trap::Invalid.into());
+            };
+            let n = self.datas[self.instances[id].datas[i]].len() as i32;
+            let mut st = vec![Value::I32(d), Value::I32(0), Value::I32(n)];
+            self.memory_init(id, x, i as u32, &mut st)?;
+            self.datas[self.instances[id].datas[i]].clear();
+        }
+
+        if let Some(start) = &m.startsec {
+            let f = self.instances[id].funcs.get(start.0 .0 .0 .0 as usize);
+            self.invoke(*f.ok_or(Trap::Invalid)?, &[])?;
+        }
+        Ok(id)
+    }
+
+    /// Links and allocates an instance, up to but not including running any
+    /// of its code apart from constant expressions.
+    fn allocate<'m>(
+        &mut self,
+        m: &'m Module,
+        imports: &[Extern],
+    ) -> Result<(usize, Vec<Mode<'m>>), Error> {
+        let id = self.instances.len();
+        let mut inst = Instance {
+            types: m
+                .typesec
+                .iter()
+                .flat_map(|t| t.0 .0 .0.iter().cloned())
+                .collect(),
+            ..Default::default()
+        };
+
+        let expected: Vec<_> = m.importsec.iter().flat_map(|i| i.0 .0 .0.iter()).collect();
+        if expected.len() != imports.len() {
+            return Err(Error::Link(format!(
+                "expected {} imports, got {}",
+                expected.len(),
+                imports.len()
+            )));
+        }
+        for (import, &e) in expected.iter().zip(imports) {
+            let matches = match (import.d, e) {
+                (Importdesc::Func(t), Extern::Func(a)) => {
+                    inst.funcs.push(a);
+                    let ty = inst.types.get(t.0 as usize);
+                    self.funcs.get(a).is_some_and(|f| Some(f.ty()) == ty)
+                }
+                (Importdesc::Table(t), Extern::Table(a)) => {
+                    inst.tables.push(a);
+                    self.tables.get(a).is_some_and(|table| {
+                        table.ty == t.element_type
+                            && limits_match(table.elements.len() as u64, table.max, t.limits)
+                    })
+                }
+                (Importdesc::Mem(t), Extern::Mem(a)) => {
+                    inst.mems.push(a);
+                    self.mems
+                        .get(a)
+                        .is_some_and(|mem| limits_match(mem.pages() as u64, mem.max, t.0))
+                }
+                (Importdesc::Global(t), Extern::Global(a)) => {
+                    inst.globals.push(a);
+                    self.globals.get(a).is_some_and(|g| g.ty == t)
+                }
+                _ => false,
+            };
+            if !matches {
+                return Err(Error::Link(format!(
+                    "incompatible import type for {}.{}",
+                    import.r#mod.0, import.nm.0
+                )));
+            }
+        }
+
+        let funcs = m.funcsec.iter().flat_map(|f| f.0 .0 .0.iter());
+        let codes = m.codesec.iter().flat_map(|c| c.0 .0 .0.iter());
+        for (t, code) in funcs.zip(codes) {
+            let ty = inst.types.get(t.0 as usize).ok_or(Trap::Invalid)?.clone();
+            inst.funcs.push(self.funcs.len());
+            self.funcs.push(FuncInst::Wasm {
+                ty,
+                instance: id,
+                code: Rc::new(code.0.clone()),
+            });
+        }
+        for t in m.tablesec.iter().flat_map(|t| t.0 .0 .0.iter()) {
+            let null = Value::default_for(Valtype::Reftype(t.0.element_type));
+            inst.tables.push(self.tables.len());
+            self.alloc_table(t.0, null);
+        }
+        for mem in m.memsec.iter().flat_map(|m| m.0 .0 .0.iter()) {
+            inst.mems.push(self.mems.len());
+            self.alloc_mem(mem.0);
+        }
+        self.instances.push(inst);
+
+        // Globals are initialized in order, so each can read those before it.
+        for g in m.globalsec.iter().flat_map(|g| g.0 .0 .0.iter()) {
+            let value = self.eval_const(id, &g.e)?;
+            self.instances[id].globals.push(self.globals.len());
+            self.alloc_global(g.gt, value);
+        }
+
+        let elems: Vec<_> = m.elemsec.iter().flat_map(|e| e.0 .0 .0.iter()).collect();
+        let mut modes = vec![];
+        for e in &elems {
+            let (mode, funcs, exprs) = match e {
+                Elem::FuncrefFuncActive(off, y) => (Mode::Active(0, off), Some(y), None),
+                Elem::ElemkindFuncPassive(_, y) => (Mode::Passive, Some(y), None),
+                Elem::ElemkindFuncActive(x, off, _, y) => (Mode::Active(x.0, off), Some(y), None),
+                Elem::ElemkindFuncDeclarative(_, y) => (Mode::Declarative, Some(y), None),
+                Elem::FuncrefExprActive(off, el) => (Mode::Active(0, off), None, Some(el)),
+                Elem::ReftypeExprPassive(_, el) => (Mode::Passive, None, Some(el)),
+                Elem::ReftypeExprActive(x, off, _, el) => (Mode::Active(x.0, off), None, Some(el)),
+                Elem::ReftypeExprDeclarative(_, el) => (Mode::Declarative, None, Some(el)),
+            };
+            let mut refs = vec![];
+            for y in funcs.iter().flat_map(|y| y.0.iter()) {
+                let a = self.instances[id].funcs.get(y.0 as usize);
+                refs.push(Value::FuncRef(Some(*a.ok_or(Trap::Invalid)?)));
+            }
+            for e in exprs.iter().flat_map(|el| el.0.iter()) {
+                refs.push(self.eval_const(id, e)?);
+            }
+            self.instances[id].elems.push(self.elems.len());
+            self.elems.push(refs);
+            modes.push(mode);
+        }
+
+        for d in m.datasec.iter().flat_map(|d| d.0 .0 .0.iter()) {
+            let (Data::ActiveAtZero(_, b) | Data::Passive(b) | Data::ActiveAtIndex(_, _, b)) = d;
+            self.instances[id].datas.push(self.datas.len());
+            self.datas.push(b.0.to_vec());
+        }
+
+        let inst = &self.instances[id];
+        let mut exports = vec![];
+        for e in m.exportsec.iter().flat_map(|e| e.0 .0 .0.iter()) {
+            let ext = match e.d {
+                Exportdesc::Func(x) => inst.funcs.get(x.0 as usize).map(|&a| Extern::Func(a)),
+                Exportdesc::Table(x) => inst.tables.get(x.0 as usize).map(|&a| Extern::Table(a)),
+                Exportdesc::Mem(x) => inst.mems.get(x.0 as usize).map(|&a| Extern::Mem(a)),
+                Exportdesc::Global(x) => inst.globals.get(x.0 as usize).map(|&a| Extern::Global(a)),
+            };
+            exports.push((e.nm.0.clone(), ext.ok_or(Trap::Invalid)?));
+        }
+        self.instances[id].exports = exports;
+        Ok((id, modes))
+    }
+
+    /// Finds an export of an instance.
+    pub fn export(&self, instance: usize, name: &str) -> Option<Extern> {
+        self.instances.get(instance)?.export(name)
+    }
+
+    /// Calls the function at an address with arguments of its parameter
+    /// types.
+    pub fn invoke(&mut self, func: usize, args: &[Value]) -> Result<Vec<Value>, Trap> {
+        let ty = self.funcs.get(func).ok_or(Trap::Invalid)?.ty();
+        if !args
+            .iter()
+            .map(Value::ty)
+            .eq(ty.parameters.0 .0.iter().copied())
+        {
+            return Err(Trap::Invalid);
+        }
+        let mut st = args.to_vec();
+        self.depth = 0;
+        self.call(func, &mut st)?;
+        Ok(st)
+    }
+
+    fn eval_const(&mut self, instance: usize, e: &Expr) -> Result<Value, Trap> {
+        let mut frame = Frame {
+            instance,
+            locals: vec![],
+        };
+        let mut st = vec![];
+        self.exec(&mut frame, &mut st, &e.0)?;
+        match st[..] {
+            [v] => Ok(v),
+            _ => Err(Trap::Invalid),
+        }
+    }
+
+    fn call(&mut self, func: usize, st: &mut Vec<Value>) -> Result<(), Trap> {
+        match self.funcs.get(func).ok_or(Trap::Invalid)?.clone() {
+            FuncInst::Host { ty, f } => {
+                let args = pop_n(st, ty.parameters.0 .0.len())?;
+                let results = f(&args)?;
+                if !results
+                    .iter()
+                    .map(Value::ty)
+                    .eq(ty.results.0 .0.iter().copied())
+                {
+                    return Err(Trap::Invalid);
+                }
+                st.extend(results);
+                Ok(())
+            }
+            FuncInst::Wasm { ty, instance, code } => {
+                if self.depth >= self.max_depth {
+                    return Err(Trap::CallStackExhausted);
+                }
+                let mut locals = pop_n(st, ty.parameters.0 .0.len())?;
+                for l in code.t.0.iter() {
+                    locals.extend(std::iter::repeat_n(Value::default_for(l.t), l.n as usize));
+                }
+                let mut frame = Frame { instance, locals };
+                let mut inner = vec![];
+                self.depth += 1;
+                let flow = self.exec(&mut frame, &mut inner, &code.e.0);
+                self.depth -= 1;
+                flow?;
+                let n = ty.results.0 .0.len();
+                st.extend(pop_n(&mut inner, n)?);
+                Ok(())
+            }
+        }
+    }
+
+    fn block(
+        &mut self,
+        frame: &mut Frame,
+        st: &mut Vec<Value>,
+        bt: &Blocktype,
+        body: &[Instr],
+        is_loop: bool,
+    ) -> Result<Flow, Trap> {
+        let (params, results) = match bt {
+            Blocktype::Empty => (0, 0),
+            Blocktype::ValueType(_) => (0, 1),
+            Blocktype::TypeIndex(S33(x)) => {
+                let types = &self.instances[frame.instance].types;
+                let t = types.get(*x as usize).ok_or(Trap::Invalid)?;
+                (t.parameters.0 .0.len(), t.results.0 .0.len())
+            }
+        };
+        let height = st.len().checked_sub(params).ok_or(Trap::Invalid)?;
+        loop {
+            match self.exec(frame, st, body)? {
+                Flow::Continue => return Ok(Flow::Continue),
+                Flow::Br(0) if is_loop => unwind(st, height, params)?,
+                Flow::Br(0) => {
+                    unwind(st, height, results)?;
+                    return Ok(Flow::Continue);
+                }
+                Flow::Br(n) => return Ok(Flow::Br(n - 1)),
+                Flow::Return => return Ok(Flow::Return),
+            }
+        }
+    }
+
+    fn instance(&self, frame: &Frame) -> &Instance {
+        &self.instances[frame.instance]
+    }
+
+    fn addr(addrs: &[usize], x: u32) -> Result<usize, Trap> {
+        addrs.get(x as usize).copied().ok_or(Trap::Invalid)
+    }
+
+    fn mem(&mut self, instance: usize, x: u32) -> Result<&mut MemInst, Trap> {
+        let a = Self::addr(&self.instances[instance].mems, x)?;
+        Ok(&mut self.mems[a])
+    }
+
+    fn table(&mut self, instance: usize, x: u32) -> Result<&mut TableInst, Trap> {
+        let a = Self::addr(&self.instances[instance].tables, x)?;
+        Ok(&mut self.tables[a])
+    }
+
+    fn load<const N: usize>(
+        &mut self,
+        frame: &Frame,
+        st: &mut Vec<Value>,
+        offset: u32,
+    ) -> Result<[u8; N], Trap> {
+        let addr = pop_i32(st)? as u32 as u64 + offset as u64;
+        let mem = self.mem(frame.instance, 0)?;
+        let r = range(addr, N as u64, mem.data.len()).ok_or(Trap::MemoryOutOfBounds)?;
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&mem.data[r]);
+        Ok(bytes)
+    }
+
+    fn store(
+        &mut self,
+        frame: &Frame,
+        st: &mut Vec<Value>,
+        offset: u32,
+        bytes: &[u8],
+    ) -> Result<(), Trap> {
+        let addr = pop_i32(st)? as u32 as u64 + offset as u64;
+        let mem = self.mem(frame.instance, 0)?;
+        let r = range(addr, bytes.len() as u64, mem.data.len()).ok_or(Trap::MemoryOutOfBounds)?;
+        mem.data[r].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Pops the destination, source and length operands of a bulk
+    /// instruction.
+    fn bulk(st: &mut Vec<Value>) -> Result<(u64, u64, u64), Trap> {
+        let n = pop_i32(st)? as u32 as u64;
+        let s = pop_i32(st)? as u32 as u64;
+        let d = pop_i32(st)? as u32 as u64;
+        Ok((d, s, n))
+    }
+
+    fn table_init(
+        &mut self,
+        instance: usize,
+        x: u32,
+        y: u32,
+        st: &mut Vec<Value>,
+    ) -> Result<(), Trap> {
+        let (d, s, n) = Self::bulk(st)?;
+        let elem = Self::addr(&self.instances[instance].elems, y)?;
+        let src = range(s, n, self.elems[elem].len()).ok_or(Trap::TableOutOfBounds)?;
+        let src = self.elems[elem][src].to_vec();
+        let table = self.table(instance, x)?;
+        let dst = range(d, n, table.elements.len()).ok_or(Trap::TableOutOfBounds)?;
+        table.elements[dst].copy_from_slice(&src);
+        Ok(())
+    }
+
+    fn memory_init(
+        &mut self,
+        instance: usize,
+        x: u32,
+        y: u32,
+        st: &mut Vec<Value>,
+    ) -> Result<(), Trap> {
+        let (d, s, n) = Self::bulk(st)?;
+        let data = Self::addr(&self.instances[instance].datas, y)?;
+        let src = range(s, n, self.datas[data].len()).ok_or(Trap::MemoryOutOfBounds)?;
+        let src = self.datas[data][src].to_vec();
+        let mem = self.mem(instance, x)?;
+        let dst = range(d, n, mem.data.len()).ok_or(Trap::MemoryOutOfBounds)?;
+        mem.data[dst].copy_from_slice(&src);
+        Ok(())
+    }
+
+    fn exec(
+        &mut self,
+        frame: &mut Frame,
+        st: &mut Vec<Value>,
+        instrs: &[Instr],
+    ) -> Result<Flow, Trap> {
+        for instr in instrs {
+            match instr {
+                Instr::Opcode(op) => match op {
+                    Opcode::Unreachable => return Err(Trap::Unreachable),
+                    Opcode::Nop => {}
+                    Opcode::Return => return Ok(Flow::Return),
+                    Opcode::Drop => {
+                        pop(st)?;
+                    }
+                    Opcode::RefIsNull => {
+                        let null =
+                            matches!(pop(st)?, Value::FuncRef(None) | Value::ExternRef(None));
+                        st.push(Value::I32(null as i32));
+                    }
+                    op => {
+                        let ty = op.stack_effect(&Bare).ok_or(Trap::Invalid)?;
+                        let args = pop_n(st, ty.parameters.0 .0.len())?
+                            .into_iter()
+                            .map(Value::num)
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or(Trap::Invalid)?;
+                        st.push(eval(*op, &args).ok_or(Trap::Invalid)??.into());
+                    }
+                },
+
+                Instr::Block(bt, body) => match self.block(frame, st, bt, body, false)? {
+                    Flow::Continue => {}
+                    flow => return Ok(flow),
+                },
+                Instr::Loop(bt, body) => match self.block(frame, st, bt, body, true)? {
+                    Flow::Continue => {}
+                    flow => return Ok(flow),
+                },
+                Instr::If(bt, body) => {
+                    if pop_i32(st)? != 0 {
+                        match self.block(frame, st, bt, body, false)? {
+                            Flow::Continue => {}
+                            flow => return Ok(flow),
+                        }
+                    }
+                }
+                Instr::IfElse(bt, then, otherwise) => {
+                    let body = if pop_i32(st)? != 0 { then } else { otherwise };
+                    match self.block(frame, st, bt, body, false)? {
+                        Flow::Continue => {}
+                        flow => return Ok(flow),
+                    }
+                }
+                Instr::Br(l) => return Ok(Flow::Br(l.0)),
+                Instr::BrIf(l) => {
+                    if pop_i32(st)? != 0 {
+                        return Ok(Flow::Br(l.0));
+                    }
+                }
+                Instr::BrTable(ls, default) => {
+                    let i = pop_i32(st)? as u32 as usize;
+                    return Ok(Flow::Br(ls.0.get(i).unwrap_or(default).0));
+                }
+                Instr::Call(x) => {
+                    let a = Self::addr(&self.instance(frame).funcs, x.0)?;
+                    self.call(a, st)?;
+                }
+                Instr::CallIndirect(y, x) => {
+                    let i = pop_i32(st)? as u32 as usize;
+                    let ty = self.instance(frame).types.get(y.0 as usize);
+                    let ty = ty.ok_or(Trap::Invalid)?.clone();
+                    let table = self.table(frame.instance, x.0)?;
+                    let a = match table.elements.get(i) {
+                        None => return Err(Trap::TableOutOfBounds),
+                        Some(Value::FuncRef(None)) => return Err(Trap::UninitializedElement),
+                        Some(Value::FuncRef(Some(a))) => *a,
+                        Some(_) => return Err(Trap::Invalid),
+                    };
+                    if *self.funcs[a].ty() != ty {
+                        return Err(Trap::IndirectCallTypeMismatch);
+                    }
+                    self.call(a, st)?;
+                }
+
+                Instr::RefNull(t) => st.push(Value::default_for(Valtype::Reftype(*t))),
+                Instr::RefFunc(x) => {
+                    let a = Self::addr(&self.instance(frame).funcs, x.0)?;
+                    st.push(Value::FuncRef(Some(a)));
+                }
+
+                Instr::Select(_) => {
+                    let c = pop_i32(st)?;
+                    let b = pop(st)?;
+                    let a = pop(st)?;
+                    st.push(if c != 0 { a } else { b });
+                }
+
+                Instr::LocalGet(x) => {
+                    let v = frame.locals.get(x.0 as usize).ok_or(Trap::Invalid)?;
+                    st.push(*v);
+                }
+                Instr::LocalSet(x) => {
+                    let v = pop(st)?;
+                    *frame.locals.get_mut(x.0 as usize).ok_or(Trap::Invalid)? = v;
+                }
+                Instr::LocalTee(x) => {
+                    let v = *st.last().ok_or(Trap::Invalid)?;
+                    *frame.locals.get_mut(x.0 as usize).ok_or(Trap::Invalid)? = v;
+                }
+                Instr::GlobalGet(x) => {
+                    let a = Self::addr(&self.instance(frame).globals, x.0)?;
+                    st.push(self.globals[a].value);
+                }
+                Instr::GlobalSet(x) => {
+                    let a = Self::addr(&self.instance(frame).globals, x.0)?;
+                    let v = pop(st)?;
+                    let g = &mut self.globals[a];
+                    if g.ty.mutability != Mut::Var {
+                        return Err(Trap::Invalid);
+                    }
+                    g.value = v;
+                }
+
+                Instr::TableGet(x) => {
+                    let i = pop_i32(st)? as u32 as usize;
+                    let table = self.table(frame.instance, x.0)?;
+                    st.push(*table.elements.get(i).ok_or(Trap::TableOutOfBounds)?);
+                }
+                Instr::TableSet(x) => {
+                    let v = pop(st)?;
+                    let i = pop_i32(st)? as u32 as usize;
+                    let table = self.table(frame.instance, x.0)?;
+                    *table.elements.get_mut(i).ok_or(Trap::TableOutOfBounds)? = v;
+                }
+                Instr::TableInit(y, x) => self.table_init(frame.instance, x.0, y.0, st)?,
+                Instr::ElemDrop(y) => {
+                    let a = Self::addr(&self.instance(frame).elems, y.0)?;
+                    self.elems[a].clear();
+                }
+                Instr::TableCopy(x, y) => {
+                    let (d, s, n) = Self::bulk(st)?;
+                    let src = self.table(frame.instance, y.0)?;
+                    let s = range(s, n, src.elements.len()).ok_or(Trap::TableOutOfBounds)?;
+                    let src = src.elements[s].to_vec();
+                    let dst = self.table(frame.instance, x.0)?;
+                    let d = range(d, n, dst.elements.len()).ok_or(Trap::TableOutOfBounds)?;
+                    dst.elements[d].copy_from_slice(&src);
+                }
+                Instr::TableGrow(x) => {
+                    let n = pop_i32(st)? as u32;
+                    let v = pop(st)?;
+                    let limit = self.max_table_elements;
+                    let table = self.table(frame.instance, x.0)?;
+                    let old = table.elements.len() as u32;
+                    match old.checked_add(n) {
+                        Some(new)
+                            if table.max.is_none_or(|max| new <= max) && new as usize <= limit =>
+                        {
+                            table.elements.resize(new as usize, v);
+                            st.push(Value::I32(old as i32));
+                        }
+                        _ => st.push(Value::I32(-1)),
+                    }
+                }
+                Instr::TableSize(x) => {
+                    let table = self.table(frame.instance, x.0)?;
+                    st.push(Value::I32(table.elements.len() as i32));
+                }
+                Instr::TableFill(x) => {
+                    let n = pop_i32(st)? as u32 as u64;
+                    let v = pop(st)?;
+                    let i = pop_i32(st)? as u32 as u64;
+                    let table = self.table(frame.instance, x.0)?;
+                    let r = range(i, n, table.elements.len()).ok_or(Trap::TableOutOfBounds)?;
+                    table.elements[r].fill(v);
+                }
+
+                Instr::MemoryMemarg(op, m) => self.memory_memarg(frame, st, *op, m.offset)?,
+                Instr::MemorySize => {
+                    let pages = self.mem(frame.instance, 0)?.pages();
+                    st.push(Value::I32(pages as i32));
+                }
+                Instr::MemoryGrow => {
+                    let n = pop_i32(st)? as u32 as usize;
+                    let limit = self.max_pages;
+                    let mem = self.mem(frame.instance, 0)?;
+                    let old = mem.pages();
+                    let max = mem.max.map_or(PAGE, |max| max as usize).min(limit);
+                    if old + n <= max {
+                        mem.data.resize((old + n) * PAGE, 0);
+                        st.push(Value::I32(old as i32));
+                    } else {
+                        st.push(Value::I32(-1));
+                    }
+                }
+                Instr::MemoryInit(y) => self.memory_init(frame.instance, 0, y.0, st)?,
+                Instr::DataDrop(y) => {
+                    let a = Self::addr(&self.instance(frame).datas, y.0)?;
+                    self.datas[a].clear();
+                }
+                Instr::MemoryCopy => {
+                    let (d, s, n) = Self::bulk(st)?;
+                    let mem = self.mem(frame.instance, 0)?;
+                    let len = mem.data.len();
+                    let s = range(s, n, len).ok_or(Trap::MemoryOutOfBounds)?;
+                    let d = range(d, n, len).ok_or(Trap::MemoryOutOfBounds)?;
+                    mem.data.copy_within(s, d.start);
+                }
+                Instr::MemoryFill => {
+                    let n = pop_i32(st)? as u32 as u64;
+                    let v = pop_i32(st)?;
+                    let d = pop_i32(st)? as u32 as u64;
+                    let mem = self.mem(frame.instance, 0)?;
+                    let r = range(d, n, mem.data.len()).ok_or(Trap::MemoryOutOfBounds)?;
+                    mem.data[r].fill(v as u8);
+                }
+
+                Instr::I32Const(n) => st.push(Value::I32(*n)),
+                Instr::I64Const(n) => st.push(Value::I64(*n)),
+                Instr::F32Const(n) => st.push(Value::F32(*n)),
+                Instr::F64Const(n) => st.push(Value::F64(*n)),
+                Instr::TruncSat(op) => {
+                    let n = pop(st)?.num().ok_or(Trap::Invalid)?;
+                    st.push(numeric::trunc_sat(*op, n).ok_or(Trap::Invalid)?.into());
+                }
+
+                Instr::V128Const(b) => st.push(Value::V128(u128::from_le_bytes(*b))),
+                Instr::I8x16Shuffle(lanes) => {
+                    let (Value::V128(b), Value::V128(a)) = (pop(st)?, pop(st)?) else {
+                        return Err(Trap::Invalid);
+                    };
+                    st.push(Value::V128(simd::shuffle(a, b, &lanes.map(|l| l.0))));
+                }
+                Instr::VectorMemarg(op, m) => self.vector_memarg(frame, st, *op, m.offset)?,
+                Instr::VectorMemargLaneidx(op, m, l) => {
+                    self.vector_memarg_laneidx(frame, st, *op, m.offset, l.0)?
+                }
+                Instr::VectorLaneidx(op, l) => {
+                    let args = pop_n(st, op.stack_effect().parameters.0 .0.len())?;
+                    st.push(simd::lane(*op, l.0, &args).ok_or(Trap::Invalid)?);
+                }
+                Instr::VectorNoImmediate(op) => {
+                    let args = pop_n(st, op.stack_effect().parameters.0 .0.len())?;
+                    st.push(simd::no_immediate(*op, &args).ok_or(Trap::Invalid)?);
+                }
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    fn memory_memarg(
+        &mut self,
+        frame: &Frame,
+        st: &mut Vec<Value>,
+        op: MemoryMemarg,
+        offset: u32,
+    ) -> Result<(), Trap> {
+        use MemoryMemarg::*;
+        let v = match op {
+            I32Load => Value::I32(i32::from_le_bytes(self.load(frame, st, offset)?)),
+            I64Load => Value::I64(i64::from_le_bytes(self.load(frame, st, offset)?)),
+            F32Load => Value::F32(F32(u32::from_le_bytes(self.load(frame, st, offset)?))),
+            F64Load => Value::F64(F64(u64::from_le_bytes(self.load(frame, st, offset)?))),
+            I32Load8S => Value::I32(i8::from_le_bytes(self.load(frame, st, offset)?) as i32),
+            I32Load8U => Value::I32(u8::from_le_bytes(self.load(frame, st, offset)?) as i32),
+            I32Load16S => Value::I32(i16::from_le_bytes(self.load(frame, st, offset)?) as i32),
+            I32Load16U => Value::I32(u16::from_le_bytes(self.load(frame, st, offset)?) as i32),
+            I64Load8S => Value::I64(i8::from_le_bytes(self.load(frame, st, offset)?) as i64),
+            I64Load8U => Value::I64(u8::from_le_bytes(self.load(frame, st, offset)?) as i64),
+            I64Load16S => Value::I64(i16::from_le_bytes(self.load(frame, st, offset)?) as i64),
+            I64Load16U => Value::I64(u16::from_le_bytes(self.load(frame, st, offset)?) as i64),
+            I64Load32S => Value::I64(i32::from_le_bytes(self.load(frame, st, offset)?) as i64),
+            I64Load32U => Value::I64(u32::from_le_bytes(self.load(frame, st, offset)?) as i64),
+            I32Store | I64Store | F32Store | F64Store | I32Store8 | I32Store16 | I64Store8
+            | I64Store16 | I64Store32 => {
+                let bytes = match (op, pop(st)?) {
+                    (I32Store, Value::I32(n)) => n.to_le_bytes().to_vec(),
+                    (I64Store, Value::I64(n)) => n.to_le_bytes().to_vec(),
+                    (F32Store, Value::F32(n)) => n.0.to_le_bytes().to_vec(),
+                    (F64Store, Value::F64(n)) => n.0.to_le_bytes().to_vec(),
+                    (I32Store8, Value::I32(n)) => vec![n as u8],
+                    (I32Store16, Value::I32(n)) => (n as u16).to_le_bytes().to_vec(),
+                    (I64Store8, Value::I64(n)) => vec![n as u8],
+                    (I64Store16, Value::I64(n)) => (n as u16).to_le_bytes().to_vec(),
+                    (I64Store32, Value::I64(n)) => (n as u32).to_le_bytes().to_vec(),
+                    _ => return Err(Trap::Invalid),
+                };
+                return self.store(frame, st, offset, &bytes);
+            }
+        };
+        st.push(v);
+        Ok(())
+    }
+
+    fn vector_memarg(
+        &mut self,
+        frame: &Frame,
+        st: &mut Vec<Value>,
+        op: VectorMemarg,
+        offset: u32,
+    ) -> Result<(), Trap> {
+        use VectorMemarg::*;
+        let v = match op {
+            V128Load => u128::from_le_bytes(self.load(frame, st, offset)?),
+            V128Load8x8S | V128Load8x8U | V128Load16x4S | V128Load16x4U | V128Load32x2S
+            | V128Load32x2U => simd::load_extend(self.load(frame, st, offset)?, op),
+            V128Load8Splat => {
+                u128::from_le_bytes([u8::from_le_bytes(self.load(frame, st, offset)?); 16])
+            }
+            V128Load16Splat => {
+                let b: [u8; 2] = self.load(frame, st, offset)?;
+                u128::from_le_bytes(std::array::from_fn(|i| b[i % 2]))
+            }
+            V128Load32Splat => {
+                let b: [u8; 4] = self.load(frame, st, offset)?;
+                u128::from_le_bytes(std::array::from_fn(|i| b[i % 4]))
+            }
+            V128Load64Splat => {
+                let b: [u8; 8] = self.load(frame, st, offset)?;
+                u128::from_le_bytes(std::array::from_fn(|i| b[i % 8]))
+            }
+            V128Load32Zero => u32::from_le_bytes(self.load(frame, st, offset)?) as u128,
+            V128Load64Zero => u64::from_le_bytes(self.load(frame, st, offset)?) as u128,
+            V128Store => {
+                let Value::V128(v) = pop(st)? else {
+                    return Err(Trap::Invalid);
+                };
+                return self.store(frame, st, offset, &v.to_le_bytes());
+            }
+        };
+        st.push(Value::V128(v));
+        Ok(())
+    }
+
+    fn vector_memarg_laneidx(
+        &mut self,
+        frame: &Frame,
+        st: &mut Vec<Value>,
+        op: VectorMemargLaneidx,
+        offset: u32,
+        l: u8,
+    ) -> Result<(), Trap> {
+        use VectorMemargLaneidx::*;
+        let Value::V128(v) = pop(st)? else {
+            return Err(Trap::Invalid);
+        };
+        let w = match op {
+            V128Load8Lane | V128Store8Lane => 1,
+            V128Load16Lane | V128Store16Lane => 2,
+            V128Load32Lane | V128Store32Lane => 4,
+            V128Load64Lane | V128Store64Lane => 8,
+        };
+        let lane = l as usize * w..(l as usize + 1) * w;
+        if lane.end > 16 {
+            return Err(Trap::Invalid);
+        }
+        let mut bytes = v.to_le_bytes();
+        match op {
+            V128Load8Lane | V128Load16Lane | V128Load32Lane | V128Load64Lane => {
+                let loaded: [u8; 8] = match w {
+                    1 => {
+                        let [b] = self.load(frame, st, offset)?;
+                        [b, 0, 0, 0, 0, 0, 0, 0]
+                    }
+                    2 => {
+                        let [a, b] = self.load(frame, st, offset)?;
+                        [a, b, 0, 0, 0, 0, 0, 0]
+                    }
+                    4 => {
+                        let [a, b, c, d] = self.load(frame, st, offset)?;
+                        [a, b, c, d, 0, 0, 0, 0]
+                    }
+                    _ => self.load(frame, st, offset)?,
+                };
+                bytes[lane].copy_from_slice(&loaded[..w]);
+                st.push(Value::V128(u128::from_le_bytes(bytes)));
+                Ok(())
+            }
+            _ => self.store(frame, st, offset, &bytes[lane]),
+        }
+    }
+}
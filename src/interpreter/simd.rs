@@ -0,0 +1,521 @@
+use super::Value;
+use crate::{
+    instructions::{Opcode, TruncSat, VectorLaneidx, VectorNoImmediate},
+    numeric::{self, Num},
+    values::{F32, F64},
+};
+use std::array;
+
+trait Lane: Copy {
+    fn read(b: &[u8]) -> Self;
+    fn write(self, b: &mut [u8]);
+}
+
+macro_rules! lane {
+    ($($t:ty),*) => {
+        $(
+            impl Lane for $t {
+                fn read(b: &[u8]) -> Self {
+                    let mut bytes = [0; std::mem::size_of::<$t>()];
+                    bytes.copy_from_slice(b);
+                    <$t>::from_le_bytes(bytes)
+                }
+
+                fn write(self, b: &mut [u8]) {
+                    b.copy_from_slice(&self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+lane!(i8, u8, i16, u16, i32, u32, i64, u64, f32, f64);
+
+fn get<T: Lane, const N: usize>(v: u128) -> [T; N] {
+    let b = v.to_le_bytes();
+    let w = 16 / N;
+    array::from_fn(|i| T::read(&b[i * w..(i + 1) * w]))
+}
+
+fn put<T: Lane, const N: usize>(lanes: [T; N]) -> u128 {
+    let mut b = [0; 16];
+    let w = 16 / N;
+    for (i, x) in lanes.into_iter().enumerate() {
+        x.write(&mut b[i * w..(i + 1) * w]);
+    }
+    u128::from_le_bytes(b)
+}
+
+fn map<T: Lane, U: Lane, const N: usize>(v: u128, f: impl Fn(T) -> U) -> u128 {
+    put::<U, N>(get::<T, N>(v).map(f))
+}
+
+fn zip<T: Lane, U: Lane, const N: usize>(a: u128, b: u128, f: impl Fn(T, T) -> U) -> u128 {
+    let (a, b) = (get::<T, N>(a), get::<T, N>(b));
+    put::<U, N>(array::from_fn(|i| f(a[i], b[i])))
+}
+
+/// Widens the low or high half of the lanes.
+fn extend<T: Lane, U: Lane, const N: usize, const M: usize>(
+    v: u128,
+    high: bool,
+    f: impl Fn(T) -> U,
+) -> u128 {
+    let lanes = get::<T, N>(v);
+    let base = if high { M } else { 0 };
+    put::<U, M>(array::from_fn(|i| f(lanes[base + i])))
+}
+
+fn extmul<T: Lane, U: Lane, const N: usize, const M: usize>(
+    a: u128,
+    b: u128,
+    high: bool,
+    f: impl Fn(T, T) -> U,
+) -> u128 {
+    let (a, b) = (get::<T, N>(a), get::<T, N>(b));
+    let base = if high { M } else { 0 };
+    put::<U, M>(array::from_fn(|i| f(a[base + i], b[base + i])))
+}
+
+fn pairwise<T: Lane, U: Lane, const N: usize, const M: usize>(
+    v: u128,
+    f: impl Fn(T, T) -> U,
+) -> u128 {
+    let lanes = get::<T, N>(v);
+    put::<U, M>(array::from_fn(|i| f(lanes[2 * i], lanes[2 * i + 1])))
+}
+
+/// Saturates the lanes of `a` followed by those of `b` into lanes half as
+/// wide.
+fn narrow<T: Lane, U: Lane, const N: usize, const M: usize>(
+    a: u128,
+    b: u128,
+    f: impl Fn(T) -> U,
+) -> u128 {
+    let (a, b) = (get::<T, N>(a), get::<T, N>(b));
+    put::<U, M>(array::from_fn(|i| f(if i < N { a[i] } else { b[i - N] })))
+}
+
+fn bitmask<T: Lane, const N: usize>(v: u128, negative: impl Fn(T) -> bool) -> Value {
+    let lanes = get::<T, N>(v);
+    Value::I32((0..N).map(|i| (negative(lanes[i]) as i32) << i).sum())
+}
+
+fn all_true<T: Lane + PartialEq + Default, const N: usize>(v: u128) -> Value {
+    Value::I32(get::<T, N>(v).iter().all(|&x| x != T::default()) as i32)
+}
+
+fn mask<T: From<i8>>(c: bool) -> T {
+    T::from(-(c as i8))
+}
+
+fn f32_op(op: Opcode, args: &[f32]) -> f32 {
+    let args: Vec<_> = args.iter().map(|&x| Num::F32(x)).collect();
+    match super::eval(op, &args) {
+        Some(Ok(Num::F32(x))) => x,
+        _ => f32::NAN,
+    }
+}
+
+fn f64_op(op: Opcode, args: &[f64]) -> f64 {
+    let args: Vec<_> = args.iter().map(|&x| Num::F64(x)).collect();
+    match super::eval(op, &args) {
+        Some(Ok(Num::F64(x))) => x,
+        _ => f64::NAN,
+    }
+}
+
+fn convert(op: Opcode, x: Num) -> Num {
+    super::eval(op, &[x])
+        .and_then(Result::ok)
+        .unwrap_or(Num::I32(0))
+}
+
+fn trunc_sat(op: TruncSat, x: Num) -> i32 {
+    match numeric::trunc_sat(op, x) {
+        Some(Num::I32(n)) => n,
+        _ => 0,
+    }
+}
+
+/// Applies a vector instruction without immediates to its operands.
+pub(super) fn no_immediate(op: VectorNoImmediate, args: &[Value]) -> Option<Value> {
+    use VectorNoImmediate::*;
+    let v = |i: usize| match args.get(i) {
+        Some(Value::V128(v)) => Some(*v),
+        _ => None,
+    };
+    let scalar = |i: usize| match args.get(i) {
+        Some(Value::I32(n)) => Some(*n as u32 as u64),
+        Some(Value::I64(n)) => Some(*n as u64),
+        Some(Value::F32(n)) => Some(n.0 as u64),
+        Some(Value::F64(n)) => Some(n.0),
+        _ => None,
+    };
+    let shift = || scalar(1).map(|s| s as u32);
+
+    Some(Value::V128(match op {
+        I8x16Swizzle => {
+            let (a, s) = (get::<u8, 16>(v(0)?), get::<u8, 16>(v(1)?));
+            put::<u8, 16>(array::from_fn(|i| {
+                a.get(s[i] as usize).copied().unwrap_or(0)
+            }))
+        }
+        I8x16Splat => put([scalar(0)? as u8; 16]),
+        I16x8Splat => put([scalar(0)? as u16; 8]),
+        I32x4Splat | F32x4Splat => put([scalar(0)? as u32; 4]),
+        I64x2Splat | F64x2Splat => put([scalar(0)?; 2]),
+
+        I8x16Eq => zip::<i8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a == b)),
+        I8x16Ne => zip::<i8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a != b)),
+        I8x16LtS => zip::<i8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        I8x16LtU => zip::<u8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        I8x16GtS => zip::<i8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        I8x16GtU => zip::<u8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        I8x16LeS => zip::<i8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        I8x16LeU => zip::<u8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        I8x16GeS => zip::<i8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        I8x16GeU => zip::<u8, i8, 16>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        I16x8Eq => zip::<i16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a == b)),
+        I16x8Ne => zip::<i16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a != b)),
+        I16x8LtS => zip::<i16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        I16x8LtU => zip::<u16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        I16x8GtS => zip::<i16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        I16x8GtU => zip::<u16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        I16x8LeS => zip::<i16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        I16x8LeU => zip::<u16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        I16x8GeS => zip::<i16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        I16x8GeU => zip::<u16, i16, 8>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        I32x4Eq => zip::<i32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a == b)),
+        I32x4Ne => zip::<i32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a != b)),
+        I32x4LtS => zip::<i32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        I32x4LtU => zip::<u32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        I32x4GtS => zip::<i32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        I32x4GtU => zip::<u32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        I32x4LeS => zip::<i32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        I32x4LeU => zip::<u32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        I32x4GeS => zip::<i32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        I32x4GeU => zip::<u32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        I64x2Eq => zip::<i64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a == b)),
+        I64x2Ne => zip::<i64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a != b)),
+        I64x2LtS => zip::<i64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        I64x2GtS => zip::<i64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        I64x2LeS => zip::<i64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        I64x2GeS => zip::<i64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        F32x4Eq => zip::<f32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a == b)),
+        F32x4Ne => zip::<f32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a != b)),
+        F32x4Lt => zip::<f32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        F32x4Gt => zip::<f32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        F32x4Le => zip::<f32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        F32x4Ge => zip::<f32, i32, 4>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+        F64x2Eq => zip::<f64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a == b)),
+        F64x2Ne => zip::<f64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a != b)),
+        F64x2Lt => zip::<f64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a < b)),
+        F64x2Gt => zip::<f64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a > b)),
+        F64x2Le => zip::<f64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a <= b)),
+        F64x2Ge => zip::<f64, i64, 2>(v(0)?, v(1)?, |a, b| mask(a >= b)),
+
+        V128Not => !v(0)?,
+        V128And => v(0)? & v(1)?,
+        V128AndNot => v(0)? & !v(1)?,
+        V128Or => v(0)? | v(1)?,
+        V128Xor => v(0)? ^ v(1)?,
+        V128Bitselect => (v(0)? & v(2)?) | (v(1)? & !v(2)?),
+        V128AnyTrue => return Some(Value::I32((v(0)? != 0) as i32)),
+
+        I8x16Abs => map::<i8, i8, 16>(v(0)?, i8::wrapping_abs),
+        I8x16Neg => map::<i8, i8, 16>(v(0)?, i8::wrapping_neg),
+        I8x16Popcnt => map::<u8, u8, 16>(v(0)?, |x| x.count_ones() as u8),
+        I8x16AllTrue => return Some(all_true::<u8, 16>(v(0)?)),
+        I8x16Bitmask => return Some(bitmask::<i8, 16>(v(0)?, |x| x < 0)),
+        I8x16NarrowI16x8S => narrow::<i16, i8, 8, 16>(v(0)?, v(1)?, |x| {
+            x.clamp(i8::MIN as i16, i8::MAX as i16) as i8
+        }),
+        I8x16NarrowI16x8U => narrow::<i16, u8, 8, 16>(v(0)?, v(1)?, |x| x.clamp(0, 255) as u8),
+        I8x16Shl => map::<i8, i8, 16>(v(0)?, |x| x.wrapping_shl(shift().unwrap_or(0))),
+        I8x16ShrS => map::<i8, i8, 16>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I8x16ShrU => map::<u8, u8, 16>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I8x16Add => zip::<i8, i8, 16>(v(0)?, v(1)?, i8::wrapping_add),
+        I8x16AddSatS => zip::<i8, i8, 16>(v(0)?, v(1)?, i8::saturating_add),
+        I8x16AddSatU => zip::<u8, u8, 16>(v(0)?, v(1)?, u8::saturating_add),
+        I8x16Sub => zip::<i8, i8, 16>(v(0)?, v(1)?, i8::wrapping_sub),
+        I8x16SubSatS => zip::<i8, i8, 16>(v(0)?, v(1)?, i8::saturating_sub),
+        I8x16SubSatU => zip::<u8, u8, 16>(v(0)?, v(1)?, u8::saturating_sub),
+        I8x16MinS => zip::<i8, i8, 16>(v(0)?, v(1)?, i8::min),
+        I8x16MinU => zip::<u8, u8, 16>(v(0)?, v(1)?, u8::min),
+        I8x16MaxS => zip::<i8, i8, 16>(v(0)?, v(1)?, i8::max),
+        I8x16MaxU => zip::<u8, u8, 16>(v(0)?, v(1)?, u8::max),
+        I8x16AvgrU => {
+            zip::<u8, u8, 16>(v(0)?, v(1)?, |a, b| (a as u16 + b as u16).div_ceil(2) as u8)
+        }
+
+        I16x8ExtaddPairwiseI8x16S => pairwise::<i8, i16, 16, 8>(v(0)?, |a, b| a as i16 + b as i16),
+        I16x8ExtaddPairwiseI8x16U => pairwise::<u8, u16, 16, 8>(v(0)?, |a, b| a as u16 + b as u16),
+        I16x8Abs => map::<i16, i16, 8>(v(0)?, i16::wrapping_abs),
+        I16x8Neg => map::<i16, i16, 8>(v(0)?, i16::wrapping_neg),
+        I16x8Q15MulrSatS => zip::<i16, i16, 8>(v(0)?, v(1)?, |a, b| {
+            ((a as i32 * b as i32 + 0x4000) >> 15).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+        }),
+        I16x8AllTrue => return Some(all_true::<u16, 8>(v(0)?)),
+        I16x8Bitmask => return Some(bitmask::<i16, 8>(v(0)?, |x| x < 0)),
+        I16x8NarrowI32x4S => narrow::<i32, i16, 4, 8>(v(0)?, v(1)?, |x| {
+            x.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+        }),
+        I16x8NarrowI32x4U => {
+            narrow::<i32, u16, 4, 8>(v(0)?, v(1)?, |x| x.clamp(0, u16::MAX as i32) as u16)
+        }
+        I16x8ExtendLowI8x16S => extend::<i8, i16, 16, 8>(v(0)?, false, i16::from),
+        I16x8ExtendHighI8x16S => extend::<i8, i16, 16, 8>(v(0)?, true, i16::from),
+        I16x8ExtendLowI8x16U => extend::<u8, u16, 16, 8>(v(0)?, false, u16::from),
+        I16x8ExtendHighI8x16U => extend::<u8, u16, 16, 8>(v(0)?, true, u16::from),
+        I16x8Shl => map::<i16, i16, 8>(v(0)?, |x| x.wrapping_shl(shift().unwrap_or(0))),
+        I16x8ShrS => map::<i16, i16, 8>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I16x8ShrU => map::<u16, u16, 8>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I16x8Add => zip::<i16, i16, 8>(v(0)?, v(1)?, i16::wrapping_add),
+        I16x8AddSatS => zip::<i16, i16, 8>(v(0)?, v(1)?, i16::saturating_add),
+        I16x8AddSatU => zip::<u16, u16, 8>(v(0)?, v(1)?, u16::saturating_add),
+        I16x8Sub => zip::<i16, i16, 8>(v(0)?, v(1)?, i16::wrapping_sub),
+        I16x8SubSatS => zip::<i16, i16, 8>(v(0)?, v(1)?, i16::saturating_sub),
+        I16x8SubSatU => zip::<u16, u16, 8>(v(0)?, v(1)?, u16::saturating_sub),
+        I16x8Mul => zip::<i16, i16, 8>(v(0)?, v(1)?, i16::wrapping_mul),
+        I16x8MinS => zip::<i16, i16, 8>(v(0)?, v(1)?, i16::min),
+        I16x8MinU => zip::<u16, u16, 8>(v(0)?, v(1)?, u16::min),
+        I16x8MaxS => zip::<i16, i16, 8>(v(0)?, v(1)?, i16::max),
+        I16x8MaxU => zip::<u16, u16, 8>(v(0)?, v(1)?, u16::max),
+        I16x8AvgrU => zip::<u16, u16, 8>(v(0)?, v(1)?, |a, b| {
+            (a as u32 + b as u32).div_ceil(2) as u16
+        }),
+        I16x8ExtmulLowI8x16S => {
+            extmul::<i8, i16, 16, 8>(v(0)?, v(1)?, false, |a, b| a as i16 * b as i16)
+        }
+        I16x8ExtmulHighI8x16S => {
+            extmul::<i8, i16, 16, 8>(v(0)?, v(1)?, true, |a, b| a as i16 * b as i16)
+        }
+        I16x8ExtmulLowI8x16U => {
+            extmul::<u8, u16, 16, 8>(v(0)?, v(1)?, false, |a, b| a as u16 * b as u16)
+        }
+        I16x8ExtmulHighI8x16U => {
+            extmul::<u8, u16, 16, 8>(v(0)?, v(1)?, true, |a, b| a as u16 * b as u16)
+        }
+
+        I32x4ExtaddPairwiseI16x8S => pairwise::<i16, i32, 8, 4>(v(0)?, |a, b| a as i32 + b as i32),
+        I32x4ExtaddPairwiseI16x8U => pairwise::<u16, u32, 8, 4>(v(0)?, |a, b| a as u32 + b as u32),
+        I32x4Abs => map::<i32, i32, 4>(v(0)?, i32::wrapping_abs),
+        I32x4Neg => map::<i32, i32, 4>(v(0)?, i32::wrapping_neg),
+        I32x4AllTrue => return Some(all_true::<u32, 4>(v(0)?)),
+        I32x4Bitmask => return Some(bitmask::<i32, 4>(v(0)?, |x| x < 0)),
+        I32x4ExtendLowI16x8S => extend::<i16, i32, 8, 4>(v(0)?, false, i32::from),
+        I32x4ExtendHighI16x8S => extend::<i16, i32, 8, 4>(v(0)?, true, i32::from),
+        I32x4ExtendLowI16x8U => extend::<u16, u32, 8, 4>(v(0)?, false, u32::from),
+        I32x4ExtendHighI16x8U => extend::<u16, u32, 8, 4>(v(0)?, true, u32::from),
+        I32x4Shl => map::<i32, i32, 4>(v(0)?, |x| x.wrapping_shl(shift().unwrap_or(0))),
+        I32x4ShrS => map::<i32, i32, 4>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I32x4ShrU => map::<u32, u32, 4>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I32x4Add => zip::<i32, i32, 4>(v(0)?, v(1)?, i32::wrapping_add),
+        I32x4Sub => zip::<i32, i32, 4>(v(0)?, v(1)?, i32::wrapping_sub),
+        I32x4Mul => zip::<i32, i32, 4>(v(0)?, v(1)?, i32::wrapping_mul),
+        I32x4MinS => zip::<i32, i32, 4>(v(0)?, v(1)?, i32::min),
+        I32x4MinU => zip::<u32, u32, 4>(v(0)?, v(1)?, u32::min),
+        I32x4MaxS => zip::<i32, i32, 4>(v(0)?, v(1)?, i32::max),
+        I32x4MaxU => zip::<u32, u32, 4>(v(0)?, v(1)?, u32::max),
+        I32x4DotI16x8S => {
+            let (a, b) = (get::<i16, 8>(v(0)?), get::<i16, 8>(v(1)?));
+            put::<i32, 4>(array::from_fn(|i| {
+                (a[2 * i] as i32 * b[2 * i] as i32)
+                    .wrapping_add(a[2 * i + 1] as i32 * b[2 * i + 1] as i32)
+            }))
+        }
+        I32x4ExtmulLowI16x8S => {
+            extmul::<i16, i32, 8, 4>(v(0)?, v(1)?, false, |a, b| a as i32 * b as i32)
+        }
+        I32x4ExtmulHighI16x8S => {
+            extmul::<i16, i32, 8, 4>(v(0)?, v(1)?, true, |a, b| a as i32 * b as i32)
+        }
+        I32x4ExtmulLowI16x8U => {
+            extmul::<u16, u32, 8, 4>(v(0)?, v(1)?, false, |a, b| a as u32 * b as u32)
+        }
+        I32x4ExtmulHighI16x8U => {
+            extmul::<u16, u32, 8, 4>(v(0)?, v(1)?, true, |a, b| a as u32 * b as u32)
+        }
+
+        I64x2Abs => map::<i64, i64, 2>(v(0)?, i64::wrapping_abs),
+        I64x2Neg => map::<i64, i64, 2>(v(0)?, i64::wrapping_neg),
+        I64x2AllTrue => return Some(all_true::<u64, 2>(v(0)?)),
+        I64x2Bitmask => return Some(bitmask::<i64, 2>(v(0)?, |x| x < 0)),
+        I64x2ExtendLowI32x4S => extend::<i32, i64, 4, 2>(v(0)?, false, i64::from),
+        I64x2ExtendHighI32x4S => extend::<i32, i64, 4, 2>(v(0)?, true, i64::from),
+        I64x2ExtendLowI32x4U => extend::<u32, u64, 4, 2>(v(0)?, false, u64::from),
+        I64x2ExtendHighI32x4U => extend::<u32, u64, 4, 2>(v(0)?, true, u64::from),
+        I64x2Shl => map::<i64, i64, 2>(v(0)?, |x| x.wrapping_shl(shift().unwrap_or(0))),
+        I64x2ShrS => map::<i64, i64, 2>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I64x2ShrU => map::<u64, u64, 2>(v(0)?, |x| x.wrapping_shr(shift().unwrap_or(0))),
+        I64x2Add => zip::<i64, i64, 2>(v(0)?, v(1)?, i64::wrapping_add),
+        I64x2Sub => zip::<i64, i64, 2>(v(0)?, v(1)?, i64::wrapping_sub),
+        I64x2Mul => zip::<i64, i64, 2>(v(0)?, v(1)?, i64::wrapping_mul),
+        I64x2ExtmulLowI32x4S => {
+            extmul::<i32, i64, 4, 2>(v(0)?, v(1)?, false, |a, b| a as i64 * b as i64)
+        }
+        I64x2ExtmulHighI32x4S => {
+            extmul::<i32, i64, 4, 2>(v(0)?, v(1)?, true, |a, b| a as i64 * b as i64)
+        }
+        I64x2ExtmulLowI32x4U => {
+            extmul::<u32, u64, 4, 2>(v(0)?, v(1)?, false, |a, b| a as u64 * b as u64)
+        }
+        I64x2ExtmulHighI32x4U => {
+            extmul::<u32, u64, 4, 2>(v(0)?, v(1)?, true, |a, b| a as u64 * b as u64)
+        }
+
+        F32x4Ceil => map::<f32, f32, 4>(v(0)?, |x| f32_op(Opcode::F32Ceil, &[x])),
+        F32x4Floor => map::<f32, f32, 4>(v(0)?, |x| f32_op(Opcode::F32Floor, &[x])),
+        F32x4Trunc => map::<f32, f32, 4>(v(0)?, |x| f32_op(Opcode::F32Trunc, &[x])),
+        F32x4Nearest => map::<f32, f32, 4>(v(0)?, |x| f32_op(Opcode::F32Nearest, &[x])),
+        F32x4Abs => map::<f32, f32, 4>(v(0)?, |x| f32_op(Opcode::F32Abs, &[x])),
+        F32x4Neg => map::<f32, f32, 4>(v(0)?, |x| f32_op(Opcode::F32Neg, &[x])),
+        F32x4Sqrt => map::<f32, f32, 4>(v(0)?, |x| f32_op(Opcode::F32Sqrt, &[x])),
+        F32x4Add => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| f32_op(Opcode::F32Add, &[a, b])),
+        F32x4Sub => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| f32_op(Opcode::F32Sub, &[a, b])),
+        F32x4Mul => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| f32_op(Opcode::F32Mul, &[a, b])),
+        F32x4Div => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| f32_op(Opcode::F32Div, &[a, b])),
+        F32x4Min => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| f32_op(Opcode::F32Min, &[a, b])),
+        F32x4Max => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| f32_op(Opcode::F32Max, &[a, b])),
+        F32x4Pmin => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| if b < a { b } else { a }),
+        F32x4Pmax => zip::<f32, f32, 4>(v(0)?, v(1)?, |a, b| if a < b { b } else { a }),
+
+        F64x2Ceil => map::<f64, f64, 2>(v(0)?, |x| f64_op(Opcode::F64Ceil, &[x])),
+        F64x2Floor => map::<f64, f64, 2>(v(0)?, |x| f64_op(Opcode::F64Floor, &[x])),
+        F64x2Trunc => map::<f64, f64, 2>(v(0)?, |x| f64_op(Opcode::F64Trunc, &[x])),
+        F64x2Nearest => map::<f64, f64, 2>(v(0)?, |x| f64_op(Opcode::F64Nearest, &[x])),
+        F64x2Abs => map::<f64, f64, 2>(v(0)?, |x| f64_op(Opcode::F64Abs, &[x])),
+        F64x2Neg => map::<f64, f64, 2>(v(0)?, |x| f64_op(Opcode::F64Neg, &[x])),
+        F64x2Sqrt => map::<f64, f64, 2>(v(0)?, |x| f64_op(Opcode::F64Sqrt, &[x])),
+        F64x2Add => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| f64_op(Opcode::F64Add, &[a, b])),
+        F64x2Sub => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| f64_op(Opcode::F64Sub, &[a, b])),
+        F64x2Mul => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| f64_op(Opcode::F64Mul, &[a, b])),
+        F64x2Div => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| f64_op(Opcode::F64Div, &[a, b])),
+        F64x2Min => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| f64_op(Opcode::F64Min, &[a, b])),
+        F64x2Max => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| f64_op(Opcode::F64Max, &[a, b])),
+        F64x2Pmin => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| if b < a { b } else { a }),
+        F64x2Pmax => zip::<f64, f64, 2>(v(0)?, v(1)?, |a, b| if a < b { b } else { a }),
+
+        I32x4TruncSatF32x4S => {
+            map::<f32, i32, 4>(v(0)?, |x| trunc_sat(TruncSat::I32TruncSatF32S, Num::F32(x)))
+        }
+        I32x4TruncSatF32x4U => {
+            map::<f32, i32, 4>(v(0)?, |x| trunc_sat(TruncSat::I32TruncSatF32U, Num::F32(x)))
+        }
+        F32x4ConvertI32x4S => map::<i32, f32, 4>(v(0)?, |x| {
+            match convert(Opcode::F32ConvertI32S, Num::I32(x)) {
+                Num::F32(x) => x,
+                _ => 0.0,
+            }
+        }),
+        F32x4ConvertI32x4U => map::<i32, f32, 4>(v(0)?, |x| {
+            match convert(Opcode::F32ConvertI32U, Num::I32(x)) {
+                Num::F32(x) => x,
+                _ => 0.0,
+            }
+        }),
+        I32x4TruncSatF64x2SZero | I32x4TruncSatF64x2UZero => {
+            let op = match op {
+                I32x4TruncSatF64x2SZero => TruncSat::I32TruncSatF64S,
+                _ => TruncSat::I32TruncSatF64U,
+            };
+            let a = get::<f64, 2>(v(0)?);
+            put::<i32, 4>([
+                trunc_sat(op, Num::F64(a[0])),
+                trunc_sat(op, Num::F64(a[1])),
+                0,
+                0,
+            ])
+        }
+        F64x2ConvertLowI32x4S | F64x2ConvertLowI32x4U => {
+            let op = match op {
+                F64x2ConvertLowI32x4S => Opcode::F64ConvertI32S,
+                _ => Opcode::F64ConvertI32U,
+            };
+            let a = get::<i32, 4>(v(0)?);
+            put::<f64, 2>(array::from_fn(|i| match convert(op, Num::I32(a[i])) {
+                Num::F64(x) => x,
+                _ => 0.0,
+            }))
+        }
+        F32x4DemoteF64x2Zero => {
+            let a = get::<f64, 2>(v(0)?);
+            let demote = |x| match convert(Opcode::F32DemoteF64, Num::F64(x)) {
+                Num::F32(x) => x,
+                _ => 0.0,
+            };
+            put::<f32, 4>([demote(a[0]), demote(a[1]), 0.0, 0.0])
+        }
+        F64x2PromoteLowF32x4 => {
+            let a = get::<f32, 4>(v(0)?);
+            put::<f64, 2>(array::from_fn(|i| {
+                match convert(Opcode::F64PromoteF32, Num::F32(a[i])) {
+                    Num::F64(x) => x,
+                    _ => 0.0,
+                }
+            }))
+        }
+    }))
+}
+
+/// Extracts or replaces a lane. The operands are the vector and, for
+/// replacements, the new lane value.
+pub(super) fn lane(op: VectorLaneidx, l: u8, args: &[Value]) -> Option<Value> {
+    use VectorLaneidx::*;
+    let Some(&Value::V128(v)) = args.first() else {
+        return None;
+    };
+    let l = l as usize;
+    let x = args.get(1).copied();
+    Some(match (op, x) {
+        (I8x16ExtractLaneS, _) => Value::I32(*get::<i8, 16>(v).get(l)? as i32),
+        (I8x16ExtractLaneU, _) => Value::I32(*get::<u8, 16>(v).get(l)? as i32),
+        (I16x8ExtractLaneS, _) => Value::I32(*get::<i16, 8>(v).get(l)? as i32),
+        (I16x8ExtractLaneU, _) => Value::I32(*get::<u16, 8>(v).get(l)? as i32),
+        (I32x4ExtractLane, _) => Value::I32(*get::<i32, 4>(v).get(l)?),
+        (I64x2ExtractLane, _) => Value::I64(*get::<i64, 2>(v).get(l)?),
+        (F32x4ExtractLane, _) => Value::F32(F32(*get::<u32, 4>(v).get(l)?)),
+        (F64x2ExtractLane, _) => Value::F64(F64(*get::<u64, 2>(v).get(l)?)),
+        (I8x16ReplaceLane, Some(Value::I32(x))) => Value::V128(replace::<u8, 16>(v, l, x as u8)?),
+        (I16x8ReplaceLane, Some(Value::I32(x))) => Value::V128(replace::<u16, 8>(v, l, x as u16)?),
+        (I32x4ReplaceLane, Some(Value::I32(x))) => Value::V128(replace::<i32, 4>(v, l, x)?),
+        (I64x2ReplaceLane, Some(Value::I64(x))) => Value::V128(replace::<i64, 2>(v, l, x)?),
+        (F32x4ReplaceLane, Some(Value::F32(x))) => Value::V128(replace::<u32, 4>(v, l, x.0)?),
+        (F64x2ReplaceLane, Some(Value::F64(x))) => Value::V128(replace::<u64, 2>(v, l, x.0)?),
+        _ => return None,
+    })
+}
+
+fn replace<T: Lane, const N: usize>(v: u128, l: usize, x: T) -> Option<u128> {
+    let mut lanes = get::<T, N>(v);
+    *lanes.get_mut(l)? = x;
+    Some(put(lanes))
+}
+
+/// Picks each byte from either vector, `a`'s numbered first.
+pub(super) fn shuffle(a: u128, b: u128, lanes: &[u8; 16]) -> u128 {
+    let (a, b) = (a.to_le_bytes(), b.to_le_bytes());
+    u128::from_le_bytes(array::from_fn(|i| {
+        let l = lanes[i] as usize;
+        if l < 16 {
+            a[l]
+        } else {
+            b[l % 16]
+        }
+    }))
+}
+
+/// Widens eight bytes loaded from memory into a vector.
+pub(super) fn load_extend(bytes: [u8; 8], op: crate::instructions::VectorMemarg) -> u128 {
+    use crate::instructions::VectorMemarg::*;
+    let v = u64::from_le_bytes(bytes) as u128;
+    match op {
+        V128Load8x8S => extend::<i8, i16, 16, 8>(v, false, i16::from),
+        V128Load8x8U => extend::<u8, u16, 16, 8>(v, false, u16::from),
+        V128Load16x4S => extend::<i16, i32, 8, 4>(v, false, i32::from),
+        V128Load16x4U => extend::<u16, u32, 8, 4>(v, false, u32::from),
+        V128Load32x2S => extend::<i32, i64, 4, 2>(v, false, i64::from),
+        _ => extend::<u32, u64, 4, 2>(v, false, u64::from),
+    }
+}
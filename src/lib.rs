@@ -1,7 +1,27 @@
+pub mod branch_hints;
+pub mod cfg;
+pub mod decode;
+pub mod dylink;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+#[cfg(feature = "arbitrary")]
+pub mod generate;
 pub mod instructions;
+pub mod interpreter;
 pub mod modules;
+pub mod names;
+pub mod numeric;
+pub mod offsets;
+pub mod passes;
+pub mod reduce;
+#[cfg(feature = "ssa")]
+pub mod ssa;
+pub mod stack;
 pub mod types;
+pub mod validate;
 pub mod values;
+pub mod visit;
+pub mod wast;
 
 use std::io::{self, Write};
 
@@ -14,12 +34,13 @@ macro_rules! write_all {
     ($w:expr, $($e:expr),*) => {
         {
             $($e.write($w)?;)*
-            Ok(())
+            ::std::io::Result::Ok(())
         }
     };
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Vector<T>(pub Box<[T]>);
 
 impl<T> Grammar for Vector<T>
@@ -37,7 +58,7 @@ where
     T: Grammar,
 {
     fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
-        self.iter().map(|i| i.write(w)).collect()
+        self.iter().try_for_each(|i| i.write(w))
     }
 }
 
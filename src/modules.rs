@@ -2,6 +2,7 @@ use crate::{
     instructions::Expr,
     types::{Functype, Globaltype, Memtype, Reftype, Tabletype, Valtype},
     values::Name,
+    visit::Visit,
     write_all, Grammar, Vector,
 };
 use std::io::{self, Write};
@@ -9,7 +10,8 @@ use std::io::{self, Write};
 macro_rules! idx {
     ($t:ident) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        pub struct $t(u32);
+        #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+        pub struct $t(pub u32);
 
         impl Grammar for $t {
             fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
@@ -29,7 +31,8 @@ idx!(Dataidx);
 idx!(Localidx);
 idx!(Labelidx);
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Section<const N: u8, T>(pub T);
 
 impl<const N: u8, T> Grammar for Section<N, T>
@@ -46,6 +49,7 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Custom {
     pub name: Name,
     pub contents: Box<[u8]>,
@@ -58,6 +62,7 @@ impl Grammar for Custom {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Importdesc {
     Func(Typeidx),
     Table(Tabletype),
@@ -77,6 +82,7 @@ impl Grammar for Importdesc {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Import {
     pub r#mod: Name,
     pub nm: Name,
@@ -91,7 +97,8 @@ impl Grammar for Import {
 
 macro_rules! section {
     ($i:ident, $n:expr, $t:ty) => {
-        #[derive(Debug, Clone, PartialEq, PartialOrd)]
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
         pub struct $i(pub Section<$n, $t>);
 
         impl Grammar for $i {
@@ -103,6 +110,7 @@ macro_rules! section {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Table(pub Tabletype);
 
 impl Grammar for Table {
@@ -112,6 +120,7 @@ impl Grammar for Table {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Mem(pub Memtype);
 
 impl Grammar for Mem {
@@ -120,7 +129,8 @@ impl Grammar for Mem {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Global {
     pub gt: Globaltype,
     pub e: Expr,
@@ -133,6 +143,7 @@ impl Grammar for Global {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Exportdesc {
     Func(Funcidx),
     Table(Tableidx),
@@ -152,6 +163,7 @@ impl Grammar for Exportdesc {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Export {
     pub nm: Name,
     pub d: Exportdesc,
@@ -164,6 +176,7 @@ impl Grammar for Export {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Start(pub Funcidx);
 
 impl Grammar for Start {
@@ -173,6 +186,7 @@ impl Grammar for Start {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Elemkind;
 
 impl Grammar for Elemkind {
@@ -181,7 +195,8 @@ impl Grammar for Elemkind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Elem {
     FuncrefFuncActive(Expr, Vector<Funcidx>),
     ElemkindFuncPassive(Elemkind, Vector<Funcidx>),
@@ -209,6 +224,7 @@ impl Grammar for Elem {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Locals {
     pub n: u32,
     pub t: Valtype,
@@ -220,7 +236,7 @@ impl Grammar for Locals {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Func {
     pub t: Vector<Locals>,
     pub e: Expr,
@@ -232,7 +248,8 @@ impl Grammar for Func {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Code(pub Func);
 
 impl Grammar for Code {
@@ -244,7 +261,8 @@ impl Grammar for Code {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Data {
     ActiveAtZero(Expr, Vector<u8>),
     Passive(Vector<u8>),
@@ -275,6 +293,97 @@ section!(Codesec, 10, Vector<Code>);
 section!(Datasec, 11, Vector<Data>);
 section!(Datacountsec, 12, u32);
 
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SectionId {
+    Custom = 0,
+    Type,
+    Import,
+    Func,
+    Table,
+    Mem,
+    Global,
+    Export,
+    Start,
+    Elem,
+    Code,
+    Data,
+    Datacount,
+}
+
+/// The sections other than custom sections, in the order they are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KnownSection {
+    Type,
+    Import,
+    Func,
+    Table,
+    Mem,
+    Global,
+    Export,
+    Start,
+    Elem,
+    Datacount,
+    Code,
+    Data,
+}
+
+impl KnownSection {
+    pub const ALL: [KnownSection; 12] = [
+        KnownSection::Type,
+        KnownSection::Import,
+        KnownSection::Func,
+        KnownSection::Table,
+        KnownSection::Mem,
+        KnownSection::Global,
+        KnownSection::Export,
+        KnownSection::Start,
+        KnownSection::Elem,
+        KnownSection::Datacount,
+        KnownSection::Code,
+        KnownSection::Data,
+    ];
+
+    pub fn id(self) -> SectionId {
+        match self {
+            KnownSection::Type => SectionId::Type,
+            KnownSection::Import => SectionId::Import,
+            KnownSection::Func => SectionId::Func,
+            KnownSection::Table => SectionId::Table,
+            KnownSection::Mem => SectionId::Mem,
+            KnownSection::Global => SectionId::Global,
+            KnownSection::Export => SectionId::Export,
+            KnownSection::Start => SectionId::Start,
+            KnownSection::Elem => SectionId::Elem,
+            KnownSection::Datacount => SectionId::Datacount,
+            KnownSection::Code => SectionId::Code,
+            KnownSection::Data => SectionId::Data,
+        }
+    }
+}
+
+/// Where a custom section goes relative to the known sections. `Before` and
+/// `After` refer to the spot the known section occupies whether or not the
+/// module contains it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Placement {
+    First,
+    Before(KnownSection),
+    After(KnownSection),
+    Last,
+}
+
+impl Placement {
+    fn slot(self) -> usize {
+        match self {
+            Placement::First => 0,
+            Placement::Before(s) => s as usize,
+            Placement::After(s) => s as usize + 1,
+            Placement::Last => KnownSection::ALL.len(),
+        }
+    }
+}
+
 pub struct Magic;
 
 impl Grammar for Magic {
@@ -291,6 +400,11 @@ impl Grammar for Version {
     }
 }
 
+/// A module, with its sections in encoding order. Each `customN` field holds
+/// the custom sections between the known sections on either side of it, so
+/// `custom9` follows the element section, `custom10` the data count section,
+/// `custom11` the code section and `custom12` the data section.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Module {
     pub custom0: Box<[Customsec]>,
     pub typesec: Option<Typesec>,
@@ -311,16 +425,190 @@ pub struct Module {
     pub custom8: Box<[Customsec]>,
     pub elemsec: Option<Elemsec>,
     pub custom9: Box<[Customsec]>,
-    pub codesec: Option<Codesec>,
+    /// Emitted automatically when left empty and the code uses `memory.init`
+    /// or `data.drop`.
+    pub datacountsec: Option<Datacountsec>,
     pub custom10: Box<[Customsec]>,
-    pub datasec: Option<Datasec>,
+    pub codesec: Option<Codesec>,
     pub custom11: Box<[Customsec]>,
-    pub datacountsec: Option<Datacountsec>,
+    pub datasec: Option<Datasec>,
     pub custom12: Box<[Customsec]>,
 }
 
-impl Grammar for Module {
-    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+impl Module {
+    pub fn imported_funcs(&self) -> u32 {
+        self.count_imports(|d| matches!(d, Importdesc::Func(_)))
+    }
+
+    pub fn imported_tables(&self) -> u32 {
+        self.count_imports(|d| matches!(d, Importdesc::Table(_)))
+    }
+
+    pub fn imported_mems(&self) -> u32 {
+        self.count_imports(|d| matches!(d, Importdesc::Mem(_)))
+    }
+
+    pub fn imported_globals(&self) -> u32 {
+        self.count_imports(|d| matches!(d, Importdesc::Global(_)))
+    }
+
+    /// The type of a function, whether imported or defined.
+    pub fn func_type(&self, x: Funcidx) -> Option<&Functype> {
+        let mut funcs = self
+            .importsec
+            .iter()
+            .flat_map(|i| i.0 .0 .0.iter())
+            .filter_map(|i| match i.d {
+                Importdesc::Func(t) => Some(t),
+                _ => None,
+            })
+            .chain(self.funcsec.iter().flat_map(|f| f.0 .0 .0.iter().copied()));
+        let t = funcs.nth(x.0 as usize)?;
+        self.typesec.as_ref()?.0 .0 .0.get(t.0 as usize)
+    }
+
+    /// The type of a table, whether imported or defined.
+    pub fn table_type(&self, x: Tableidx) -> Option<Tabletype> {
+        self.importsec
+            .iter()
+            .flat_map(|i| i.0 .0 .0.iter())
+            .filter_map(|i| match i.d {
+                Importdesc::Table(t) => Some(t),
+                _ => None,
+            })
+            .chain(
+                self.tablesec
+                    .iter()
+                    .flat_map(|t| t.0 .0 .0.iter().map(|t| t.0)),
+            )
+            .nth(x.0 as usize)
+    }
+
+    /// The type of a global, whether imported or defined.
+    pub fn global_type(&self, x: Globalidx) -> Option<Globaltype> {
+        self.importsec
+            .iter()
+            .flat_map(|i| i.0 .0 .0.iter())
+            .filter_map(|i| match i.d {
+                Importdesc::Global(t) => Some(t),
+                _ => None,
+            })
+            .chain(
+                self.globalsec
+                    .iter()
+                    .flat_map(|g| g.0 .0 .0.iter().map(|g| g.gt)),
+            )
+            .nth(x.0 as usize)
+    }
+
+    fn count_imports(&self, f: impl Fn(&Importdesc) -> bool) -> u32 {
+        self.importsec
+            .as_ref()
+            .map_or(0, |i| i.0 .0 .0.iter().filter(|i| f(&i.d)).count() as u32)
+    }
+
+    fn custom_slots(&self) -> [&[Customsec]; 13] {
+        [
+            &self.custom0,
+            &self.custom1,
+            &self.custom2,
+            &self.custom3,
+            &self.custom4,
+            &self.custom5,
+            &self.custom6,
+            &self.custom7,
+            &self.custom8,
+            &self.custom9,
+            &self.custom10,
+            &self.custom11,
+            &self.custom12,
+        ]
+    }
+
+    fn custom_slots_mut(&mut self) -> [&mut Box<[Customsec]>; 13] {
+        [
+            &mut self.custom0,
+            &mut self.custom1,
+            &mut self.custom2,
+            &mut self.custom3,
+            &mut self.custom4,
+            &mut self.custom5,
+            &mut self.custom6,
+            &mut self.custom7,
+            &mut self.custom8,
+            &mut self.custom9,
+            &mut self.custom10,
+            &mut self.custom11,
+            &mut self.custom12,
+        ]
+    }
+
+    /// Custom sections in the order they are encoded.
+    pub fn customs(&self) -> impl Iterator<Item = &Custom> {
+        self.custom_slots()
+            .into_iter()
+            .flat_map(|slot| slot.iter().map(|c| &c.0 .0))
+    }
+
+    pub fn custom(&self, name: &str) -> Option<&Custom> {
+        self.customs().find(|c| c.name.0 == name)
+    }
+
+    pub fn custom_mut(&mut self, name: &str) -> Option<&mut Custom> {
+        self.custom_slots_mut()
+            .into_iter()
+            .flat_map(|slot| slot.iter_mut().map(|c| &mut c.0 .0))
+            .find(|c| c.name.0 == name)
+    }
+
+    /// Inserts a custom section as close as possible to the given placement,
+    /// so that `Before` puts it after any sections already in that spot and
+    /// `After` puts it ahead of them.
+    pub fn insert_custom(&mut self, placement: Placement, custom: Custom) {
+        let slot = &mut self.custom_slots_mut()[placement.slot()];
+        let mut sections = std::mem::take(*slot).into_vec();
+        let section = Customsec(Section(custom));
+        match placement {
+            Placement::First | Placement::After(_) => sections.insert(0, section),
+            Placement::Before(_) | Placement::Last => sections.push(section),
+        }
+        **slot = sections.into_boxed_slice();
+    }
+
+    /// Replaces the first custom section called `name` in place, returning
+    /// the old contents.
+    pub fn replace_custom(&mut self, name: &str, custom: Custom) -> Option<Custom> {
+        self.custom_mut(name)
+            .map(|old| std::mem::replace(old, custom))
+    }
+
+    /// Removes the first custom section called `name`.
+    pub fn remove_custom(&mut self, name: &str) -> Option<Custom> {
+        let mut removed = None;
+        self.retain_customs(|c| {
+            if removed.is_none() && c.name.0 == name {
+                removed = Some(c.clone());
+                false
+            } else {
+                true
+            }
+        });
+        removed
+    }
+
+    pub fn retain_customs(&mut self, mut f: impl FnMut(&Custom) -> bool) {
+        for slot in self.custom_slots_mut() {
+            let mut sections = std::mem::take(slot).into_vec();
+            sections.retain(|c| f(&c.0 .0));
+            *slot = sections.into_boxed_slice();
+        }
+    }
+
+    pub(crate) fn write_with<'a, W: Write>(
+        &'a self,
+        w: &mut W,
+        codesec: impl FnOnce(&'a Codesec, &mut W) -> io::Result<()>,
+    ) -> io::Result<()> {
         write_all!(
             w,
             Magic,
@@ -343,13 +631,40 @@ impl Grammar for Module {
             self.startsec,
             self.custom8,
             self.elemsec,
-            self.custom9,
-            self.codesec,
-            self.custom10,
-            self.datasec,
-            self.custom11,
-            self.datacountsec,
-            self.custom12
-        )
+            self.custom9
+        )?;
+        if self.datacountsec.is_some() {
+            self.datacountsec.write(w)?;
+        } else if self.uses_data_count() {
+            let n = self.datasec.as_ref().map_or(0, |d| d.0 .0 .0.len());
+            Datacountsec(Section(n as u32)).write(w)?;
+        }
+        self.custom10.write(w)?;
+        if let Some(c) = &self.codesec {
+            codesec(c, w)?;
+        }
+        write_all!(w, self.custom11, self.datasec, self.custom12)
+    }
+
+    pub(crate) fn uses_data_count(&self) -> bool {
+        struct UsesDataCount(bool);
+
+        impl Visit for UsesDataCount {
+            fn visit_dataidx(&mut self, _x: &Dataidx) {
+                self.0 = true;
+            }
+        }
+
+        let mut v = UsesDataCount(false);
+        for code in self.codesec.iter().flat_map(|c| c.0 .0 .0.iter()) {
+            v.visit_func(&code.0);
+        }
+        v.0
+    }
+}
+
+impl Grammar for Module {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.write_with(w, |c, w| c.write(w))
     }
 }
@@ -0,0 +1,282 @@
+use crate::{
+    modules::{Custom, Dataidx, Elemidx, Funcidx, Globalidx, Memidx, Module, Tableidx, Typeidx},
+    values::Name,
+    write_all, Grammar, Vector,
+};
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NameAssoc<I> {
+    pub idx: I,
+    pub name: Name,
+}
+
+impl<I: Grammar> Grammar for NameAssoc<I> {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_all!(w, self.idx, self.name)
+    }
+}
+
+/// Names sorted by increasing index.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NameMap<I>(pub Vector<NameAssoc<I>>);
+
+impl<I> Default for NameMap<I> {
+    fn default() -> Self {
+        Self(Vector(Box::new([])))
+    }
+}
+
+impl<I: Ord> NameMap<I> {
+    pub fn is_empty(&self) -> bool {
+        self.0 .0.is_empty()
+    }
+
+    /// Rewrites each index with `f`, dropping the entries it returns `None`
+    /// for, and restores the ordering.
+    pub fn remap(&mut self, mut f: impl FnMut(&I) -> Option<I>) {
+        let names = std::mem::take(&mut self.0 .0).into_vec();
+        let mut names: Vec<_> = names
+            .into_iter()
+            .filter_map(|n| f(&n.idx).map(|idx| NameAssoc { idx, name: n.name }))
+            .collect();
+        names.sort_by(|a, b| a.idx.cmp(&b.idx));
+        self.0 .0 = names.into_boxed_slice();
+    }
+}
+
+impl<I: Grammar> Grammar for NameMap<I> {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.0.write(w)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IndirectNameAssoc<I> {
+    pub idx: I,
+    pub names: NameMap<u32>,
+}
+
+impl<I: Grammar> Grammar for IndirectNameAssoc<I> {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_all!(w, self.idx, self.names)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IndirectNameMap<I>(pub Vector<IndirectNameAssoc<I>>);
+
+impl<I> Default for IndirectNameMap<I> {
+    fn default() -> Self {
+        Self(Vector(Box::new([])))
+    }
+}
+
+impl<I: Ord> IndirectNameMap<I> {
+    pub fn is_empty(&self) -> bool {
+        self.0 .0.is_empty()
+    }
+
+    pub fn remap(&mut self, mut f: impl FnMut(&I) -> Option<I>) {
+        let names = std::mem::take(&mut self.0 .0).into_vec();
+        let mut names: Vec<_> = names
+            .into_iter()
+            .filter_map(|n| {
+                f(&n.idx).map(|idx| IndirectNameAssoc {
+                    idx,
+                    names: n.names,
+                })
+            })
+            .collect();
+        names.sort_by(|a, b| a.idx.cmp(&b.idx));
+        self.0 .0 = names.into_boxed_slice();
+    }
+}
+
+impl<I: Grammar> Grammar for IndirectNameMap<I> {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.0.write(w)
+    }
+}
+
+/// The contents of the `name` custom section, including the subsections of
+/// the extended name section proposal. Subsections this crate doesn't
+/// understand are kept as raw bytes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Names {
+    pub module: Option<Name>,
+    pub funcs: NameMap<Funcidx>,
+    pub locals: IndirectNameMap<Funcidx>,
+    pub labels: IndirectNameMap<Funcidx>,
+    pub types: NameMap<Typeidx>,
+    pub tables: NameMap<Tableidx>,
+    pub mems: NameMap<Memidx>,
+    pub globals: NameMap<Globalidx>,
+    pub elems: NameMap<Elemidx>,
+    pub datas: NameMap<Dataidx>,
+    pub other: Box<[(u8, Box<[u8]>)]>,
+}
+
+impl Names {
+    pub const NAME: &'static str = "name";
+
+    /// Parses the module's name section, if it has one.
+    pub fn from_module(m: &Module) -> io::Result<Option<Self>> {
+        m.custom(Self::NAME)
+            .map(|c| Self::parse(&c.contents))
+            .transpose()
+    }
+
+    pub fn parse(mut bytes: &[u8]) -> io::Result<Self> {
+        let mut names = Self::default();
+        let mut other = vec![];
+        while !bytes.is_empty() {
+            let id = read_u8(&mut bytes)?;
+            let len = read_u32(&mut bytes)? as usize;
+            if len > bytes.len() {
+                return Err(malformed("subsection extends past the section"));
+            }
+            let (mut sub, rest) = bytes.split_at(len);
+            bytes = rest;
+            match id {
+                0 => names.module = Some(read_name(&mut sub)?),
+                1 => names.funcs = read_name_map(&mut sub, Funcidx)?,
+                2 => names.locals = read_indirect_name_map(&mut sub, Funcidx)?,
+                3 => names.labels = read_indirect_name_map(&mut sub, Funcidx)?,
+                4 => names.types = read_name_map(&mut sub, Typeidx)?,
+                5 => names.tables = read_name_map(&mut sub, Tableidx)?,
+                6 => names.mems = read_name_map(&mut sub, Memidx)?,
+                7 => names.globals = read_name_map(&mut sub, Globalidx)?,
+                8 => names.elems = read_name_map(&mut sub, Elemidx)?,
+                9 => names.datas = read_name_map(&mut sub, Dataidx)?,
+                _ => {
+                    other.push((id, sub.into()));
+                    continue;
+                }
+            }
+            if !sub.is_empty() {
+                return Err(malformed("trailing bytes in subsection"));
+            }
+        }
+        names.other = other.into_boxed_slice();
+        Ok(names)
+    }
+
+    pub fn custom(&self) -> io::Result<Custom> {
+        let mut contents = vec![];
+        self.write(&mut contents)?;
+        Ok(Custom {
+            name: Name(Self::NAME.to_string()),
+            contents: contents.into_boxed_slice(),
+        })
+    }
+}
+
+impl Grammar for Names {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut subsections = vec![];
+        if let Some(module) = &self.module {
+            subsection(&mut subsections, 0, module)?;
+        }
+        if !self.funcs.is_empty() {
+            subsection(&mut subsections, 1, &self.funcs)?;
+        }
+        if !self.locals.is_empty() {
+            subsection(&mut subsections, 2, &self.locals)?;
+        }
+        if !self.labels.is_empty() {
+            subsection(&mut subsections, 3, &self.labels)?;
+        }
+        if !self.types.is_empty() {
+            subsection(&mut subsections, 4, &self.types)?;
+        }
+        if !self.tables.is_empty() {
+            subsection(&mut subsections, 5, &self.tables)?;
+        }
+        if !self.mems.is_empty() {
+            subsection(&mut subsections, 6, &self.mems)?;
+        }
+        if !self.globals.is_empty() {
+            subsection(&mut subsections, 7, &self.globals)?;
+        }
+        if !self.elems.is_empty() {
+            subsection(&mut subsections, 8, &self.elems)?;
+        }
+        if !self.datas.is_empty() {
+            subsection(&mut subsections, 9, &self.datas)?;
+        }
+        for (id, payload) in self.other.iter() {
+            subsections.push((*id, payload.to_vec()));
+        }
+        subsections.sort_by_key(|(id, _)| *id);
+        for (id, payload) in subsections {
+            write_all!(w, id, payload.len() as u32, payload.as_slice())?;
+        }
+        Ok(())
+    }
+}
+
+fn subsection(out: &mut Vec<(u8, Vec<u8>)>, id: u8, payload: &impl Grammar) -> io::Result<()> {
+    let mut buf = vec![];
+    payload.write(&mut buf)?;
+    out.push((id, buf));
+    Ok(())
+}
+
+pub(crate) fn malformed(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+pub(crate) fn read_u8(bytes: &mut &[u8]) -> io::Result<u8> {
+    let (&b, rest) = bytes
+        .split_first()
+        .ok_or_else(|| malformed("unexpected end of section"))?;
+    *bytes = rest;
+    Ok(b)
+}
+
+pub(crate) fn read_u32(bytes: &mut &[u8]) -> io::Result<u32> {
+    let n = leb128::read::unsigned(bytes).map_err(|_| malformed("invalid integer"))?;
+    u32::try_from(n).map_err(|_| malformed("integer too large"))
+}
+
+pub(crate) fn read_name(bytes: &mut &[u8]) -> io::Result<Name> {
+    let len = read_u32(bytes)? as usize;
+    if len > bytes.len() {
+        return Err(malformed("unexpected end of section"));
+    }
+    let (name, rest) = bytes.split_at(len);
+    *bytes = rest;
+    String::from_utf8(name.to_vec())
+        .map(Name)
+        .map_err(|_| malformed("malformed UTF-8 encoding"))
+}
+
+fn read_name_map<I>(bytes: &mut &[u8], idx: impl Fn(u32) -> I) -> io::Result<NameMap<I>> {
+    let n = read_u32(bytes)?;
+    let names = (0..n)
+        .map(|_| {
+            Ok(NameAssoc {
+                idx: idx(read_u32(bytes)?),
+                name: read_name(bytes)?,
+            })
+        })
+        .collect::<io::Result<_>>()?;
+    Ok(NameMap(Vector(names)))
+}
+
+fn read_indirect_name_map<I>(
+    bytes: &mut &[u8],
+    idx: impl Fn(u32) -> I,
+) -> io::Result<IndirectNameMap<I>> {
+    let n = read_u32(bytes)?;
+    let names = (0..n)
+        .map(|_| {
+            Ok(IndirectNameAssoc {
+                idx: idx(read_u32(bytes)?),
+                names: read_name_map(bytes, |i| i)?,
+            })
+        })
+        .collect::<io::Result<_>>()?;
+    Ok(IndirectNameMap(Vector(names)))
+}
@@ -0,0 +1,301 @@
+use crate::instructions::{Opcode, TruncSat};
+
+/// A value of one of the number types.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Num {
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+}
+
+impl Num {
+    pub fn is_nan(&self) -> bool {
+        match *self {
+            Num::F32(x) => x.is_nan(),
+            Num::F64(x) => x.is_nan(),
+            Num::I32(_) | Num::I64(_) => false,
+        }
+    }
+
+    /// Whether this is a NaN with only the most significant mantissa bit set.
+    pub fn is_canonical_nan(&self) -> bool {
+        match *self {
+            Num::F32(x) => x.to_bits() & 0x7fff_ffff == 0x7fc0_0000,
+            Num::F64(x) => x.to_bits() & 0x7fff_ffff_ffff_ffff == 0x7ff8_0000_0000_0000,
+            Num::I32(_) | Num::I64(_) => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Trap {
+    IntegerDivideByZero,
+    IntegerOverflow,
+    InvalidConversionToInteger,
+}
+
+/// Whether the operation only moves bits around, so that NaN payloads pass
+/// through it unchanged rather than being chosen by the engine.
+pub fn is_bitwise(op: Opcode) -> bool {
+    use Opcode::*;
+    matches!(
+        op,
+        F32Abs
+            | F32Neg
+            | F32Copysign
+            | F64Abs
+            | F64Neg
+            | F64Copysign
+            | I32ReinterpretF32
+            | I64ReinterpretF64
+            | F32ReinterpretI32
+            | F64ReinterpretI64
+    )
+}
+
+/// Applies a numeric instruction to its operands. Returns `None` if the
+/// opcode isn't numeric or the operands don't match its type.
+pub fn eval(op: Opcode, args: &[Num]) -> Option<Result<Num, Trap>> {
+    use Num::*;
+    use Opcode::*;
+    let n = match (op, args) {
+        (I32Eqz, &[I32(a)]) => I32((a == 0) as i32),
+        (I32Eq, &[I32(a), I32(b)]) => I32((a == b) as i32),
+        (I32Ne, &[I32(a), I32(b)]) => I32((a != b) as i32),
+        (I32LtS, &[I32(a), I32(b)]) => I32((a < b) as i32),
+        (I32LtU, &[I32(a), I32(b)]) => I32(((a as u32) < b as u32) as i32),
+        (I32GtS, &[I32(a), I32(b)]) => I32((a > b) as i32),
+        (I32GtU, &[I32(a), I32(b)]) => I32((a as u32 > b as u32) as i32),
+        (I32LeS, &[I32(a), I32(b)]) => I32((a <= b) as i32),
+        (I32LeU, &[I32(a), I32(b)]) => I32((a as u32 <= b as u32) as i32),
+        (I32GeS, &[I32(a), I32(b)]) => I32((a >= b) as i32),
+        (I32GeU, &[I32(a), I32(b)]) => I32((a as u32 >= b as u32) as i32),
+
+        (I64Eqz, &[I64(a)]) => I32((a == 0) as i32),
+        (I64Eq, &[I64(a), I64(b)]) => I32((a == b) as i32),
+        (I64Ne, &[I64(a), I64(b)]) => I32((a != b) as i32),
+        (I64LtS, &[I64(a), I64(b)]) => I32((a < b) as i32),
+        (I64LtU, &[I64(a), I64(b)]) => I32(((a as u64) < b as u64) as i32),
+        (I64GtS, &[I64(a), I64(b)]) => I32((a > b) as i32),
+        (I64GtU, &[I64(a), I64(b)]) => I32((a as u64 > b as u64) as i32),
+        (I64LeS, &[I64(a), I64(b)]) => I32((a <= b) as i32),
+        (I64LeU, &[I64(a), I64(b)]) => I32((a as u64 <= b as u64) as i32),
+        (I64GeS, &[I64(a), I64(b)]) => I32((a >= b) as i32),
+        (I64GeU, &[I64(a), I64(b)]) => I32((a as u64 >= b as u64) as i32),
+
+        (F32Eq, &[F32(a), F32(b)]) => I32((a == b) as i32),
+        (F32Ne, &[F32(a), F32(b)]) => I32((a != b) as i32),
+        (F32Lt, &[F32(a), F32(b)]) => I32((a < b) as i32),
+        (F32Gt, &[F32(a), F32(b)]) => I32((a > b) as i32),
+        (F32Le, &[F32(a), F32(b)]) => I32((a <= b) as i32),
+        (F32Ge, &[F32(a), F32(b)]) => I32((a >= b) as i32),
+
+        (F64Eq, &[F64(a), F64(b)]) => I32((a == b) as i32),
+        (F64Ne, &[F64(a), F64(b)]) => I32((a != b) as i32),
+        (F64Lt, &[F64(a), F64(b)]) => I32((a < b) as i32),
+        (F64Gt, &[F64(a), F64(b)]) => I32((a > b) as i32),
+        (F64Le, &[F64(a), F64(b)]) => I32((a <= b) as i32),
+        (F64Ge, &[F64(a), F64(b)]) => I32((a >= b) as i32),
+
+        (I32Clz, &[I32(a)]) => I32(a.leading_zeros() as i32),
+        (I32Ctz, &[I32(a)]) => I32(a.trailing_zeros() as i32),
+        (I32Popcnt, &[I32(a)]) => I32(a.count_ones() as i32),
+        (I32Add, &[I32(a), I32(b)]) => I32(a.wrapping_add(b)),
+        (I32Sub, &[I32(a), I32(b)]) => I32(a.wrapping_sub(b)),
+        (I32Mul, &[I32(a), I32(b)]) => I32(a.wrapping_mul(b)),
+        (I32DivS, &[I32(a), I32(b)]) => match (a, b) {
+            (_, 0) => return Some(Err(Trap::IntegerDivideByZero)),
+            (i32::MIN, -1) => return Some(Err(Trap::IntegerOverflow)),
+            _ => I32(a / b),
+        },
+        (I32DivU, &[I32(a), I32(b)]) => match b {
+            0 => return Some(Err(Trap::IntegerDivideByZero)),
+            _ => I32((a as u32 / b as u32) as i32),
+        },
+        (I32RemS, &[I32(a), I32(b)]) => match b {
+            0 => return Some(Err(Trap::IntegerDivideByZero)),
+            _ => I32(a.wrapping_rem(b)),
+        },
+        (I32RemU, &[I32(a), I32(b)]) => match b {
+            0 => return Some(Err(Trap::IntegerDivideByZero)),
+            _ => I32((a as u32 % b as u32) as i32),
+        },
+        (I32And, &[I32(a), I32(b)]) => I32(a & b),
+        (I32Or, &[I32(a), I32(b)]) => I32(a | b),
+        (I32Xor, &[I32(a), I32(b)]) => I32(a ^ b),
+        (I32Shl, &[I32(a), I32(b)]) => I32(a.wrapping_shl(b as u32)),
+        (I32ShrS, &[I32(a), I32(b)]) => I32(a.wrapping_shr(b as u32)),
+        (I32ShrU, &[I32(a), I32(b)]) => I32((a as u32).wrapping_shr(b as u32) as i32),
+        (I32Rotl, &[I32(a), I32(b)]) => I32(a.rotate_left((b & 31) as u32)),
+        (I32Rotr, &[I32(a), I32(b)]) => I32(a.rotate_right((b & 31) as u32)),
+
+        (I64Clz, &[I64(a)]) => I64(a.leading_zeros() as i64),
+        (I64Ctz, &[I64(a)]) => I64(a.trailing_zeros() as i64),
+        (I64Popcnt, &[I64(a)]) => I64(a.count_ones() as i64),
+        (I64Add, &[I64(a), I64(b)]) => I64(a.wrapping_add(b)),
+        (I64Sub, &[I64(a), I64(b)]) => I64(a.wrapping_sub(b)),
+        (I64Mul, &[I64(a), I64(b)]) => I64(a.wrapping_mul(b)),
+        (I64DivS, &[I64(a), I64(b)]) => match (a, b) {
+            (_, 0) => return Some(Err(Trap::IntegerDivideByZero)),
+            (i64::MIN, -1) => return Some(Err(Trap::IntegerOverflow)),
+            _ => I64(a / b),
+        },
+        (I64DivU, &[I64(a), I64(b)]) => match b {
+            0 => return Some(Err(Trap::IntegerDivideByZero)),
+            _ => I64((a as u64 / b as u64) as i64),
+        },
+        (I64RemS, &[I64(a), I64(b)]) => match b {
+            0 => return Some(Err(Trap::IntegerDivideByZero)),
+            _ => I64(a.wrapping_rem(b)),
+        },
+        (I64RemU, &[I64(a), I64(b)]) => match b {
+            0 => return Some(Err(Trap::IntegerDivideByZero)),
+            _ => I64((a as u64 % b as u64) as i64),
+        },
+        (I64And, &[I64(a), I64(b)]) => I64(a & b),
+        (I64Or, &[I64(a), I64(b)]) => I64(a | b),
+        (I64Xor, &[I64(a), I64(b)]) => I64(a ^ b),
+        (I64Shl, &[I64(a), I64(b)]) => I64(a.wrapping_shl(b as u32)),
+        (I64ShrS, &[I64(a), I64(b)]) => I64(a.wrapping_shr(b as u32)),
+        (I64ShrU, &[I64(a), I64(b)]) => I64((a as u64).wrapping_shr(b as u32) as i64),
+        (I64Rotl, &[I64(a), I64(b)]) => I64(a.rotate_left((b & 63) as u32)),
+        (I64Rotr, &[I64(a), I64(b)]) => I64(a.rotate_right((b & 63) as u32)),
+
+        (F32Abs, &[F32(a)]) => F32(f32::from_bits(a.to_bits() & 0x7fff_ffff)),
+        (F32Neg, &[F32(a)]) => F32(f32::from_bits(a.to_bits() ^ 0x8000_0000)),
+        (F32Ceil, &[F32(a)]) => F32(a.ceil()),
+        (F32Floor, &[F32(a)]) => F32(a.floor()),
+        (F32Trunc, &[F32(a)]) => F32(a.trunc()),
+        (F32Nearest, &[F32(a)]) => F32(a.round_ties_even()),
+        (F32Sqrt, &[F32(a)]) => F32(a.sqrt()),
+        (F32Add, &[F32(a), F32(b)]) => F32(a + b),
+        (F32Sub, &[F32(a), F32(b)]) => F32(a - b),
+        (F32Mul, &[F32(a), F32(b)]) => F32(a * b),
+        (F32Div, &[F32(a), F32(b)]) => F32(a / b),
+        (F32Min, &[F32(a), F32(b)]) => F32(fmin(a as f64, b as f64) as f32),
+        (F32Max, &[F32(a), F32(b)]) => F32(fmax(a as f64, b as f64) as f32),
+        (F32Copysign, &[F32(a), F32(b)]) => F32(f32::from_bits(
+            (a.to_bits() & 0x7fff_ffff) | (b.to_bits() & 0x8000_0000),
+        )),
+
+        (F64Abs, &[F64(a)]) => F64(f64::from_bits(a.to_bits() & 0x7fff_ffff_ffff_ffff)),
+        (F64Neg, &[F64(a)]) => F64(f64::from_bits(a.to_bits() ^ 0x8000_0000_0000_0000)),
+        (F64Ceil, &[F64(a)]) => F64(a.ceil()),
+        (F64Floor, &[F64(a)]) => F64(a.floor()),
+        (F64Trunc, &[F64(a)]) => F64(a.trunc()),
+        (F64Nearest, &[F64(a)]) => F64(a.round_ties_even()),
+        (F64Sqrt, &[F64(a)]) => F64(a.sqrt()),
+        (F64Add, &[F64(a), F64(b)]) => F64(a + b),
+        (F64Sub, &[F64(a), F64(b)]) => F64(a - b),
+        (F64Mul, &[F64(a), F64(b)]) => F64(a * b),
+        (F64Div, &[F64(a), F64(b)]) => F64(a / b),
+        (F64Min, &[F64(a), F64(b)]) => F64(fmin(a, b)),
+        (F64Max, &[F64(a), F64(b)]) => F64(fmax(a, b)),
+        (F64Copysign, &[F64(a), F64(b)]) => F64(f64::from_bits(
+            (a.to_bits() & 0x7fff_ffff_ffff_ffff) | (b.to_bits() & 0x8000_0000_0000_0000),
+        )),
+
+        (I32WrapI64, &[I64(a)]) => I32(a as i32),
+        (I32TruncF32S, &[F32(a)]) => return Some(trunc(a as f64, I32_S).map(|x| I32(x as i32))),
+        (I32TruncF32U, &[F32(a)]) => {
+            return Some(trunc(a as f64, I32_U).map(|x| I32(x as u32 as i32)))
+        }
+        (I32TruncF64S, &[F64(a)]) => return Some(trunc(a, I32_S).map(|x| I32(x as i32))),
+        (I32TruncF64U, &[F64(a)]) => return Some(trunc(a, I32_U).map(|x| I32(x as u32 as i32))),
+        (I64ExtendI32S, &[I32(a)]) => I64(a as i64),
+        (I64ExtendI32U, &[I32(a)]) => I64(a as u32 as i64),
+        (I64TruncF32S, &[F32(a)]) => return Some(trunc(a as f64, I64_S).map(|x| I64(x as i64))),
+        (I64TruncF32U, &[F32(a)]) => {
+            return Some(trunc(a as f64, I64_U).map(|x| I64(x as u64 as i64)))
+        }
+        (I64TruncF64S, &[F64(a)]) => return Some(trunc(a, I64_S).map(|x| I64(x as i64))),
+        (I64TruncF64U, &[F64(a)]) => return Some(trunc(a, I64_U).map(|x| I64(x as u64 as i64))),
+        (F32ConvertI32S, &[I32(a)]) => F32(a as f32),
+        (F32ConvertI32U, &[I32(a)]) => F32(a as u32 as f32),
+        (F32ConvertI64S, &[I64(a)]) => F32(a as f32),
+        (F32ConvertI64U, &[I64(a)]) => F32(a as u64 as f32),
+        (F32DemoteF64, &[F64(a)]) => F32(a as f32),
+        (F64ConvertI32S, &[I32(a)]) => F64(a as f64),
+        (F64ConvertI32U, &[I32(a)]) => F64(a as u32 as f64),
+        (F64ConvertI64S, &[I64(a)]) => F64(a as f64),
+        (F64ConvertI64U, &[I64(a)]) => F64(a as u64 as f64),
+        (F64PromoteF32, &[F32(a)]) => F64(a as f64),
+        (I32ReinterpretF32, &[F32(a)]) => I32(a.to_bits() as i32),
+        (I64ReinterpretF64, &[F64(a)]) => I64(a.to_bits() as i64),
+        (F32ReinterpretI32, &[I32(a)]) => F32(f32::from_bits(a as u32)),
+        (F64ReinterpretI64, &[I64(a)]) => F64(f64::from_bits(a as u64)),
+        (I32Extend8S, &[I32(a)]) => I32(a as i8 as i32),
+        (I32Extend16S, &[I32(a)]) => I32(a as i16 as i32),
+        (I64Extend8S, &[I64(a)]) => I64(a as i8 as i64),
+        (I64Extend16S, &[I64(a)]) => I64(a as i16 as i64),
+        (I64Extend32S, &[I64(a)]) => I64(a as i32 as i64),
+        _ => return None,
+    };
+    Some(Ok(n))
+}
+
+/// Applies a saturating truncation. Returns `None` if the operand doesn't
+/// match its type.
+pub fn trunc_sat(op: TruncSat, x: Num) -> Option<Num> {
+    use Num::*;
+    use TruncSat::*;
+    // Float to integer `as` casts saturate and map NaN to zero, exactly like
+    // the Wasm instructions.
+    Some(match (op, x) {
+        (I32TruncSatF32S, F32(a)) => I32(a as i32),
+        (I32TruncSatF32U, F32(a)) => I32(a as u32 as i32),
+        (I32TruncSatF64S, F64(a)) => I32(a as i32),
+        (I32TruncSatF64U, F64(a)) => I32(a as u32 as i32),
+        (I64TruncSatF32S, F32(a)) => I64(a as i64),
+        (I64TruncSatF32U, F32(a)) => I64(a as u64 as i64),
+        (I64TruncSatF64S, F64(a)) => I64(a as i64),
+        (I64TruncSatF64U, F64(a)) => I64(a as u64 as i64),
+        _ => return None,
+    })
+}
+
+/// Exclusive bounds of the values that truncate into each integer type.
+const I32_S: (f64, f64) = (-2147483649.0, 2147483648.0);
+const I32_U: (f64, f64) = (-1.0, 4294967296.0);
+const I64_S: (f64, f64) = (-9223372036854777856.0, 9223372036854775808.0);
+const I64_U: (f64, f64) = (-1.0, 18446744073709551616.0);
+
+fn trunc(x: f64, (lo, hi): (f64, f64)) -> Result<f64, Trap> {
+    if x.is_nan() {
+        Err(Trap::InvalidConversionToInteger)
+    } else if x <= lo || x >= hi {
+        Err(Trap::IntegerOverflow)
+    } else {
+        Ok(x.trunc())
+    }
+}
+
+/// Unlike `f64::min`, propagates NaN and orders -0 below +0.
+fn fmin(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else if a == 0.0 && b == 0.0 {
+        if a.is_sign_negative() {
+            a
+        } else {
+            b
+        }
+    } else {
+        a.min(b)
+    }
+}
+
+fn fmax(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else if a == 0.0 && b == 0.0 {
+        if a.is_sign_positive() {
+            a
+        } else {
+            b
+        }
+    } else {
+        a.max(b)
+    }
+}
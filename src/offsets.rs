@@ -0,0 +1,91 @@
+use crate::{
+    instructions::Instr,
+    modules::{Codesec, Module},
+    Grammar,
+};
+use std::io;
+
+/// The absolute position of an instruction's opcode in the encoded module.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InstrOffset<'a> {
+    pub offset: usize,
+    pub instr: &'a Instr,
+}
+
+/// Absolute positions within one entry of the code section.
+///
+/// `body` is the start of the entry's size prefix and `locals` is the first
+/// byte after it, where the local declarations begin. `instrs` lists every
+/// instruction in encoding order, with the contents of `block`, `loop` and
+/// `if` following the instruction that opens them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuncOffsets<'a> {
+    pub body: usize,
+    pub locals: usize,
+    pub instrs: Box<[InstrOffset<'a>]>,
+}
+
+impl Module {
+    /// Encodes the module, recording the offset of each function in the code
+    /// section in the same order as `Codesec`.
+    pub fn write_with_offsets(&self) -> io::Result<(Vec<u8>, Box<[FuncOffsets<'_>]>)> {
+        let mut bytes = vec![];
+        let mut funcs = vec![];
+        self.write_with(&mut bytes, |c, w| {
+            funcs = write_codesec(c, w)?;
+            Ok(())
+        })?;
+        Ok((bytes, funcs.into_boxed_slice()))
+    }
+}
+
+pub(crate) fn write_codesec<'a>(
+    codesec: &'a Codesec,
+    w: &mut Vec<u8>,
+) -> io::Result<Vec<FuncOffsets<'a>>> {
+    let codes = &codesec.0 .0 .0;
+    let mut contents = vec![];
+    (codes.len() as u32).write(&mut contents)?;
+
+    let mut funcs = vec![];
+    for code in codes.iter() {
+        let mut func = vec![];
+        let mut instrs = vec![];
+        code.0.t.write(&mut func)?;
+        for instr in code.0.e.0.iter() {
+            instr.write_with(&mut func, &mut |w: &Vec<u8>, instr| {
+                instrs.push(InstrOffset {
+                    offset: w.len(),
+                    instr,
+                })
+            })?;
+        }
+        0x0bu8.write(&mut func)?;
+
+        let body = contents.len();
+        (func.len() as u32).write(&mut contents)?;
+        let locals = contents.len();
+        contents.extend_from_slice(&func);
+        for instr in instrs.iter_mut() {
+            instr.offset += locals;
+        }
+        funcs.push(FuncOffsets {
+            body,
+            locals,
+            instrs: instrs.into_boxed_slice(),
+        });
+    }
+
+    10u8.write(w)?;
+    (contents.len() as u32).write(w)?;
+    let base = w.len();
+    w.extend_from_slice(&contents);
+    for func in funcs.iter_mut() {
+        func.body += base;
+        func.locals += base;
+        for instr in func.instrs.iter_mut() {
+            instr.offset += base;
+        }
+    }
+    Ok(funcs)
+}
@@ -0,0 +1,7 @@
+pub mod dedup;
+pub mod fold;
+pub mod gc;
+pub mod inline;
+pub mod locals;
+pub mod remap;
+pub mod trim;
@@ -0,0 +1,77 @@
+use crate::{
+    modules::{Funcidx, Module},
+    names::Names,
+    passes::remap::Remap,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+};
+
+/// Merges defined functions that have the same type and identical bodies,
+/// redirecting every reference to the one with the lowest index. Repeats
+/// until no duplicates remain, since functions differing only in which of
+/// two duplicates they call become identical once those are merged.
+pub fn dedup(m: &mut Module) -> io::Result<()> {
+    while let Some((remap, dups)) = merge(m) {
+        if let Some(mut names) = Names::from_module(m)? {
+            let live = |x: &Funcidx| (!dups.contains(x)).then_some(*x);
+            names.funcs.remap(live);
+            names.locals.remap(live);
+            names.labels.remap(live);
+            m.replace_custom(Names::NAME, names.custom()?);
+        }
+        remap.apply(m)?;
+    }
+    Ok(())
+}
+
+/// Removes one round of duplicates, returning the index changes along with
+/// the removed functions.
+fn merge(m: &mut Module) -> Option<(Remap, HashSet<Funcidx>)> {
+    let imported = m.imported_funcs();
+    let (Some(funcsec), Some(codesec)) = (&mut m.funcsec, &mut m.codesec) else {
+        return None;
+    };
+    let (types, codes) = (&mut funcsec.0 .0 .0, &mut codesec.0 .0 .0);
+
+    let mut seen = HashMap::new();
+    let mut survivors = vec![];
+    for (i, (t, code)) in types.iter().zip(codes.iter()).enumerate() {
+        survivors.push(*seen.entry((t, code)).or_insert(i));
+    }
+    if survivors.iter().enumerate().all(|(i, &s)| i == s) {
+        return None;
+    }
+
+    let mut remap = Remap::default();
+    let mut dups = HashSet::new();
+    let mut new = vec![0; survivors.len()];
+    let mut kept = 0;
+    for (i, &s) in survivors.iter().enumerate() {
+        let old = Funcidx(imported + i as u32);
+        new[i] = if s == i {
+            kept += 1;
+            imported + kept - 1
+        } else {
+            dups.insert(old);
+            new[s]
+        };
+        if new[i] != old.0 {
+            remap.funcs.insert(old, Funcidx(new[i]));
+        }
+    }
+
+    keep_survivors(types, &survivors);
+    keep_survivors(codes, &survivors);
+    Some((remap, dups))
+}
+
+fn keep_survivors<T>(items: &mut Box<[T]>, survivors: &[usize]) {
+    *items = std::mem::take(items)
+        .into_vec()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, item)| (survivors[i] == i).then_some(item))
+        .collect();
+}
@@ -0,0 +1,106 @@
+use crate::{
+    instructions::{Instr, Opcode},
+    modules::{Func, Module},
+    numeric::{self, Num},
+    visit::{self, VisitMut},
+};
+
+/// Folds constants and applies peephole rewrites in every function in the
+/// module.
+pub fn fold(m: &mut Module) {
+    for code in m.codesec.iter_mut().flat_map(|c| c.0 .0 .0.iter_mut()) {
+        fold_func(&mut code.0);
+    }
+}
+
+/// Evaluates numeric instructions whose operands are constants and applies
+/// a few peephole rewrites:
+///
+/// - `local.set x; local.get x` becomes `local.tee x`
+/// - `i32.eqz; i32.eqz; br_if l` becomes `br_if l`
+/// - a constant followed by `drop` is removed
+///
+/// Operations that would trap are left in place. So are those producing a NaN
+/// whose payload the engine would choose, unless the result is required to
+/// be the canonical NaN.
+pub fn fold_func(f: &mut Func) {
+    Folder.visit_func_mut(f);
+}
+
+struct Folder;
+
+impl VisitMut for Folder {
+    fn visit_instrs_mut(&mut self, i: &mut Box<[Instr]>) {
+        visit::walk_instrs_mut(self, i);
+        let mut out = vec![];
+        for instr in std::mem::take(i).into_vec() {
+            out.push(instr);
+            while reduce(&mut out) {}
+        }
+        *i = out.into_boxed_slice();
+    }
+}
+
+/// Rewrites the end of the sequence, returning whether anything changed.
+fn reduce(out: &mut Vec<Instr>) -> bool {
+    let n = out.len();
+    let (len, instr) = match &out[..] {
+        [.., Instr::LocalSet(x), Instr::LocalGet(y)] if x == y => (2, Some(Instr::LocalTee(*x))),
+        [.., Instr::Opcode(Opcode::I32Eqz), Instr::Opcode(Opcode::I32Eqz), Instr::BrIf(l)] => {
+            (3, Some(Instr::BrIf(*l)))
+        }
+        [.., c, Instr::Opcode(Opcode::Drop)] if is_const(c) => (2, None),
+        [.., a, b, Instr::Opcode(op)] if fold_op(*op, &[a, b]).is_some() => {
+            (3, fold_op(*op, &[a, b]))
+        }
+        [.., a, Instr::Opcode(op)] if fold_op(*op, &[a]).is_some() => (2, fold_op(*op, &[a])),
+        [.., a, Instr::TruncSat(op)] if num(a).is_some() => {
+            match numeric::trunc_sat(*op, num(a).unwrap()) {
+                Some(c) => (2, Some(constant(c))),
+                None => return false,
+            }
+        }
+        _ => return false,
+    };
+    out.truncate(n - len);
+    out.extend(instr);
+    true
+}
+
+fn fold_op(op: Opcode, args: &[&Instr]) -> Option<Instr> {
+    let args = args.iter().map(|a| num(a)).collect::<Option<Vec<_>>>()?;
+    let c = numeric::eval(op, &args)?.ok()?;
+    if !c.is_nan() || numeric::is_bitwise(op) {
+        Some(constant(c))
+    } else if args.iter().all(|a| !a.is_nan() || a.is_canonical_nan()) {
+        Some(constant(match c {
+            Num::F32(_) => Num::F32(f32::NAN),
+            _ => Num::F64(f64::NAN),
+        }))
+    } else {
+        None
+    }
+}
+
+fn is_const(i: &Instr) -> bool {
+    num(i).is_some() || matches!(i, Instr::V128Const(_))
+}
+
+fn num(i: &Instr) -> Option<Num> {
+    match *i {
+        Instr::I32Const(n) => Some(Num::I32(n)),
+        Instr::I64Const(n) => Some(Num::I64(n)),
+        Instr::F32Const(n) => Some(Num::F32(n.get())),
+        Instr::F64Const(n) => Some(Num::F64(n.get())),
+        _ => None,
+    }
+}
+
+fn constant(n: Num) -> Instr {
+    match n {
+        Num::I32(n) => Instr::I32Const(n),
+        Num::I64(n) => Instr::I64Const(n),
+        Num::F32(n) => Instr::F32Const(n.into()),
+        Num::F64(n) => Instr::F64Const(n.into()),
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{
+    instructions::{Blocktype, Instr},
+    modules::{
+        Data, Dataidx, Elem, Elemidx, Funcidx, Globalidx, Importdesc, Memidx, Module, Section,
+        Tableidx, Typeidx,
+    },
+    names::Names,
+    passes::remap::Remap,
+    visit::{self, Visit},
+    Vector,
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    hash::Hash,
+    io,
+};
+
+/// Removes the functions, globals, types, tables, memories and segments that
+/// can't be reached from the exports, the start function, the imports or the
+/// active segments, then compacts the remaining indices.
+pub fn gc(m: &mut Module) -> io::Result<()> {
+    let mut names = Names::from_module(m)?;
+    let mut live = Marker::mark(m);
+    keep_declarations(m, &mut live);
+    let remap = compact(m, &live);
+    if let Some(names) = &mut names {
+        names
+            .funcs
+            .remap(|x| live.funcs.contains(&x.0).then_some(*x));
+        names
+            .locals
+            .remap(|x| live.funcs.contains(&x.0).then_some(*x));
+        names
+            .labels
+            .remap(|x| live.funcs.contains(&x.0).then_some(*x));
+        names
+            .types
+            .remap(|x| live.types.contains(&x.0).then_some(*x));
+        names
+            .tables
+            .remap(|x| live.tables.contains(&x.0).then_some(*x));
+        names.mems.remap(|x| live.mems.contains(&x.0).then_some(*x));
+        names
+            .globals
+            .remap(|x| live.globals.contains(&x.0).then_some(*x));
+        names
+            .elems
+            .remap(|x| live.elems.contains(&x.0).then_some(*x));
+        names
+            .datas
+            .remap(|x| live.datas.contains(&x.0).then_some(*x));
+        m.replace_custom(Names::NAME, names.custom()?);
+    }
+    remap.apply(m)
+}
+
+#[derive(Debug, Default)]
+struct Live {
+    types: BTreeSet<u32>,
+    funcs: BTreeSet<u32>,
+    tables: BTreeSet<u32>,
+    mems: BTreeSet<u32>,
+    globals: BTreeSet<u32>,
+    elems: BTreeSet<u32>,
+    datas: BTreeSet<u32>,
+}
+
+enum Item {
+    Func(u32),
+    Global(u32),
+    Elem(u32),
+    Data(u32),
+}
+
+struct Marker {
+    live: Live,
+    queue: Vec<Item>,
+}
+
+impl Marker {
+    fn mark(m: &Module) -> Live {
+        let mut marker = Self {
+            live: Live::default(),
+            queue: vec![],
+        };
+
+        let (mut funcs, mut tables, mut mems, mut globals) = (0, 0, 0, 0);
+        for i in items(m.importsec.as_ref().map(|s| &s.0)) {
+            marker.visit_import(i);
+            let (set, n) = match i.d {
+                Importdesc::Func(_) => (&mut marker.live.funcs, &mut funcs),
+                Importdesc::Table(_) => (&mut marker.live.tables, &mut tables),
+                Importdesc::Mem(_) => (&mut marker.live.mems, &mut mems),
+                Importdesc::Global(_) => (&mut marker.live.globals, &mut globals),
+            };
+            set.insert(*n);
+            *n += 1;
+        }
+        for e in items(m.exportsec.as_ref().map(|s| &s.0)) {
+            marker.visit_export(e);
+        }
+        if let Some(s) = &m.startsec {
+            marker.visit_start(&s.0 .0);
+        }
+        for (y, e) in items(m.elemsec.as_ref().map(|s| &s.0)).iter().enumerate() {
+            if is_active(e) {
+                marker.visit_elemidx(&Elemidx(y as u32));
+            }
+        }
+        for (y, d) in items(m.datasec.as_ref().map(|s| &s.0)).iter().enumerate() {
+            if !matches!(d, Data::Passive(_)) {
+                marker.visit_dataidx(&Dataidx(y as u32));
+            }
+        }
+
+        while let Some(item) = marker.queue.pop() {
+            match item {
+                Item::Func(x) => {
+                    let Some(i) = x.checked_sub(funcs) else {
+                        continue;
+                    };
+                    if let Some(t) = items(m.funcsec.as_ref().map(|s| &s.0)).get(i as usize) {
+                        marker.visit_typeidx(t);
+                    }
+                    if let Some(c) = items(m.codesec.as_ref().map(|s| &s.0)).get(i as usize) {
+                        marker.visit_func(&c.0);
+                    }
+                }
+                Item::Global(x) => {
+                    let Some(i) = x.checked_sub(globals) else {
+                        continue;
+                    };
+                    if let Some(g) = items(m.globalsec.as_ref().map(|s| &s.0)).get(i as usize) {
+                        marker.visit_global(g);
+                    }
+                }
+                Item::Elem(y) => {
+                    if let Some(e) = items(m.elemsec.as_ref().map(|s| &s.0)).get(y as usize) {
+                        marker.visit_elem(e);
+                    }
+                }
+                Item::Data(y) => {
+                    if let Some(d) = items(m.datasec.as_ref().map(|s| &s.0)).get(y as usize) {
+                        marker.visit_data(d);
+                    }
+                }
+            }
+        }
+        marker.live
+    }
+}
+
+impl Visit for Marker {
+    fn visit_elem(&mut self, e: &Elem) {
+        if matches!(e, Elem::FuncrefFuncActive(..) | Elem::FuncrefExprActive(..)) {
+            self.live.tables.insert(0);
+        }
+        visit::walk_elem(self, e)
+    }
+
+    fn visit_data(&mut self, d: &Data) {
+        if let Data::ActiveAtZero(..) = d {
+            self.live.mems.insert(0);
+        }
+        visit::walk_data(self, d)
+    }
+
+    fn visit_memory_instr(&mut self, i: &Instr) {
+        self.live.mems.insert(0);
+        visit::walk_memory_instr(self, i)
+    }
+
+    fn visit_vector_instr(&mut self, i: &Instr) {
+        if let Instr::VectorMemarg(..) | Instr::VectorMemargLaneidx(..) = i {
+            self.live.mems.insert(0);
+        }
+    }
+
+    fn visit_blocktype(&mut self, bt: &Blocktype) {
+        if let Blocktype::TypeIndex(n) = bt {
+            self.live.types.insert(n.0 as u32);
+        }
+    }
+
+    fn visit_typeidx(&mut self, x: &Typeidx) {
+        self.live.types.insert(x.0);
+    }
+
+    fn visit_funcidx(&mut self, x: &Funcidx) {
+        if self.live.funcs.insert(x.0) {
+            self.queue.push(Item::Func(x.0));
+        }
+    }
+
+    fn visit_tableidx(&mut self, x: &Tableidx) {
+        self.live.tables.insert(x.0);
+    }
+
+    fn visit_memidx(&mut self, x: &Memidx) {
+        self.live.mems.insert(x.0);
+    }
+
+    fn visit_globalidx(&mut self, x: &Globalidx) {
+        if self.live.globals.insert(x.0) {
+            self.queue.push(Item::Global(x.0));
+        }
+    }
+
+    fn visit_elemidx(&mut self, x: &Elemidx) {
+        if self.live.elems.insert(x.0) {
+            self.queue.push(Item::Elem(x.0));
+        }
+    }
+
+    fn visit_dataidx(&mut self, x: &Dataidx) {
+        if self.live.datas.insert(x.0) {
+            self.queue.push(Item::Data(x.0));
+        }
+    }
+}
+
+fn items<const N: u8, T>(s: Option<&Section<N, Vector<T>>>) -> &[T] {
+    s.map_or(&[], |s| &s.0 .0)
+}
+
+fn is_active(e: &Elem) -> bool {
+    matches!(
+        e,
+        Elem::FuncrefFuncActive(..)
+            | Elem::ElemkindFuncActive(..)
+            | Elem::FuncrefExprActive(..)
+            | Elem::ReftypeExprActive(..)
+    )
+}
+
+/// Declarative segments only make `ref.func` valid, so they survive for as
+/// long as they declare a live function.
+fn keep_declarations(m: &mut Module, live: &mut Live) {
+    let Some(elems) = &mut m.elemsec else {
+        return;
+    };
+    for (y, e) in elems.0 .0 .0.iter_mut().enumerate() {
+        if live.elems.contains(&(y as u32)) {
+            continue;
+        }
+        let declares_live = match e {
+            Elem::ElemkindFuncDeclarative(_, funcs) => {
+                retain(&mut funcs.0, |x| live.funcs.contains(&x.0));
+                !funcs.0.is_empty()
+            }
+            Elem::ReftypeExprDeclarative(_, exprs) => {
+                retain(
+                    &mut exprs.0,
+                    |e| matches!(*e.0, [Instr::RefFunc(x)] if live.funcs.contains(&x.0)),
+                );
+                !exprs.0.is_empty()
+            }
+            _ => false,
+        };
+        if declares_live {
+            live.elems.insert(y as u32);
+        }
+    }
+}
+
+fn retain<T>(items: &mut Box<[T]>, f: impl FnMut(&T) -> bool) {
+    let mut v = std::mem::take(items).into_vec();
+    v.retain(f);
+    *items = v.into_boxed_slice();
+}
+
+fn compact(m: &mut Module, live: &Live) -> Remap {
+    let mut remap = Remap::default();
+    let funcs = m.imported_funcs();
+    let tables = m.imported_tables();
+    let mems = m.imported_mems();
+    let globals = m.imported_globals();
+
+    if let Some(s) = &mut m.typesec {
+        remap.types = compact_items(&mut s.0 .0 .0, 0, &live.types, Typeidx);
+    }
+    if let Some(s) = &mut m.funcsec {
+        remap.funcs = compact_items(&mut s.0 .0 .0, funcs, &live.funcs, Funcidx);
+    }
+    if let Some(s) = &mut m.codesec {
+        compact_items(&mut s.0 .0 .0, funcs, &live.funcs, Funcidx);
+    }
+    if let Some(s) = &mut m.tablesec {
+        remap.tables = compact_items(&mut s.0 .0 .0, tables, &live.tables, Tableidx);
+    }
+    if let Some(s) = &mut m.memsec {
+        remap.mems = compact_items(&mut s.0 .0 .0, mems, &live.mems, Memidx);
+    }
+    if let Some(s) = &mut m.globalsec {
+        remap.globals = compact_items(&mut s.0 .0 .0, globals, &live.globals, Globalidx);
+    }
+    if let Some(s) = &mut m.elemsec {
+        remap.elems = compact_items(&mut s.0 .0 .0, 0, &live.elems, Elemidx);
+    }
+    if let Some(s) = &mut m.datasec {
+        remap.datas = compact_items(&mut s.0 .0 .0, 0, &live.datas, Dataidx);
+        if let Some(count) = &mut m.datacountsec {
+            count.0 .0 = s.0 .0 .0.len() as u32;
+        }
+    }
+    remap
+}
+
+/// Keeps the live items of an index space whose first `base` entries are
+/// imports, returning the indices that moved.
+fn compact_items<T, I: Eq + Hash>(
+    items: &mut Box<[T]>,
+    base: u32,
+    live: &BTreeSet<u32>,
+    idx: impl Fn(u32) -> I,
+) -> HashMap<I, I> {
+    let mut map = HashMap::new();
+    let mut kept = vec![];
+    for (i, item) in std::mem::take(items).into_vec().into_iter().enumerate() {
+        let old = base + i as u32;
+        if live.contains(&old) {
+            let new = base + kept.len() as u32;
+            if new != old {
+                map.insert(idx(old), idx(new));
+            }
+            kept.push(item);
+        }
+    }
+    *items = kept.into_boxed_slice();
+    map
+}
@@ -0,0 +1,229 @@
+use crate::{
+    instructions::{Blocktype, Instr, Opcode, S33},
+    modules::{Func, Funcidx, Labelidx, Localidx, Locals, Module, Section, Typesec},
+    types::{Functype, Resulttype, Valtype},
+    visit::{self, Visit, VisitMut},
+    Vector,
+};
+use std::{collections::HashMap, iter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InlineConfig {
+    /// Callees with at most this many instructions, counting those in nested
+    /// blocks, are inlined at every call site.
+    pub max_size: usize,
+    /// Whether to inline callees with exactly one call site regardless of
+    /// their size.
+    pub single_call_site: bool,
+}
+
+impl Default for InlineConfig {
+    fn default() -> Self {
+        Self {
+            max_size: 12,
+            single_call_site: true,
+        }
+    }
+}
+
+/// Replaces calls to small or single-use functions with a block containing
+/// the callee's body. The arguments move into fresh locals of the caller and
+/// `return` in the callee becomes a branch out of the block. Recursive
+/// callees are skipped, and so are calls in code that was just inlined.
+///
+/// Callees stay in the module, so run [`gc`](crate::passes::gc::gc)
+/// afterwards to drop the ones no longer referenced.
+pub fn inline(m: &mut Module, config: &InlineConfig) {
+    let imported = m.imported_funcs();
+    let Some(codesec) = &m.codesec else {
+        return;
+    };
+
+    let mut calls = CallCounter(HashMap::new());
+    for code in codesec.0 .0 .0.iter() {
+        calls.visit_func(&code.0);
+    }
+
+    let mut callees = HashMap::new();
+    for (i, code) in codesec.0 .0 .0.iter().enumerate() {
+        let x = Funcidx(imported + i as u32);
+        let Some(ty) = m.func_type(x) else {
+            continue;
+        };
+        let mut own = CallCounter(HashMap::new());
+        own.visit_func(&code.0);
+        if own.0.contains_key(&x) {
+            continue;
+        }
+        let single = config.single_call_site && calls.0.get(&x) == Some(&1);
+        if single || size(&code.0.e.0) <= config.max_size {
+            callees.insert(x, (ty.clone(), code.0.clone()));
+        }
+    }
+    if callees.is_empty() {
+        return;
+    }
+
+    let params: Vec<_> = (0..codesec.0 .0 .0.len() as u32)
+        .map(|i| {
+            m.func_type(Funcidx(imported + i))
+                .map_or(0, |t| t.parameters.0 .0.len() as u32)
+        })
+        .collect();
+    let mut types = m.typesec.take().map_or(vec![], |t| t.0 .0 .0.into_vec());
+    for (i, code) in m
+        .codesec
+        .iter_mut()
+        .flat_map(|c| c.0 .0 .0.iter_mut())
+        .enumerate()
+    {
+        let func = &mut code.0;
+        let mut inliner = Inliner {
+            caller: Funcidx(imported + i as u32),
+            next_local: params[i] + func.t.0.iter().map(|l| l.n).sum::<u32>(),
+            new_locals: vec![],
+            callees: &callees,
+            types: &mut types,
+        };
+        inliner.visit_func_mut(func);
+        if !inliner.new_locals.is_empty() {
+            let mut locals = std::mem::take(&mut func.t.0).into_vec();
+            for t in inliner.new_locals {
+                match locals.last_mut() {
+                    Some(l) if l.t == t => l.n += 1,
+                    _ => locals.push(Locals { n: 1, t }),
+                }
+            }
+            func.t.0 = locals.into_boxed_slice();
+        }
+    }
+    if !types.is_empty() {
+        m.typesec = Some(Typesec(Section(Vector(types.into_boxed_slice()))));
+    }
+}
+
+fn size(instrs: &[Instr]) -> usize {
+    instrs
+        .iter()
+        .map(|i| match i {
+            Instr::Block(_, r#in) | Instr::Loop(_, r#in) | Instr::If(_, r#in) => 1 + size(r#in),
+            Instr::IfElse(_, in1, in2) => 1 + size(in1) + size(in2),
+            _ => 1,
+        })
+        .sum()
+}
+
+struct CallCounter(HashMap<Funcidx, usize>);
+
+impl Visit for CallCounter {
+    fn visit_control_instr(&mut self, i: &Instr) {
+        if let Instr::Call(x) = i {
+            *self.0.entry(*x).or_default() += 1;
+        }
+        visit::walk_control_instr(self, i)
+    }
+}
+
+struct Inliner<'a> {
+    caller: Funcidx,
+    next_local: u32,
+    new_locals: Vec<Valtype>,
+    callees: &'a HashMap<Funcidx, (Functype, Func)>,
+    types: &'a mut Vec<Functype>,
+}
+
+impl Inliner<'_> {
+    fn expand(&mut self, ty: &Functype, f: &Func, out: &mut Vec<Instr>) {
+        let base = self.next_local;
+        let params = ty.parameters.0 .0.len() as u32;
+        let locals: Vec<_> = ty
+            .parameters
+            .0
+             .0
+            .iter()
+            .copied()
+            .chain(f.t.0.iter().flat_map(|l| iter::repeat_n(l.t, l.n as usize)))
+            .collect();
+        self.next_local += locals.len() as u32;
+        self.new_locals.extend_from_slice(&locals);
+
+        for i in (0..params).rev() {
+            out.push(Instr::LocalSet(Localidx(base + i)));
+        }
+        for (i, &t) in locals.iter().enumerate().skip(params as usize) {
+            out.push(t.zero_instr());
+            out.push(Instr::LocalSet(Localidx(base + i as u32)));
+        }
+        let mut body = f.e.0.clone();
+        Rebase { base, depth: 0 }.visit_instrs_mut(&mut body);
+        out.push(Instr::Block(self.blocktype(&ty.results), body));
+    }
+
+    fn blocktype(&mut self, results: &Resulttype) -> Blocktype {
+        match *results.0 .0 {
+            [] => Blocktype::Empty,
+            [t] => Blocktype::ValueType(t),
+            _ => {
+                let ty = Functype {
+                    parameters: Resulttype(Vector(Box::new([]))),
+                    results: results.clone(),
+                };
+                let x = match self.types.iter().position(|t| *t == ty) {
+                    Some(x) => x,
+                    None => {
+                        self.types.push(ty);
+                        self.types.len() - 1
+                    }
+                };
+                Blocktype::TypeIndex(S33(x as i64))
+            }
+        }
+    }
+}
+
+impl VisitMut for Inliner<'_> {
+    fn visit_instrs_mut(&mut self, i: &mut Box<[Instr]>) {
+        visit::walk_instrs_mut(self, i);
+        let (caller, callees) = (self.caller, self.callees);
+        let inlined = |instr: &Instr| match instr {
+            Instr::Call(x) if *x != caller => callees.get(x),
+            _ => None,
+        };
+        if !i.iter().any(|instr| inlined(instr).is_some()) {
+            return;
+        }
+        let mut out = vec![];
+        for instr in std::mem::take(i).into_vec() {
+            match inlined(&instr) {
+                Some((ty, f)) => self.expand(ty, f, &mut out),
+                None => out.push(instr),
+            }
+        }
+        *i = out.into_boxed_slice();
+    }
+}
+
+/// Moves a callee body's locals up to `base` and turns `return` into a branch
+/// to the block wrapping the body.
+struct Rebase {
+    base: u32,
+    depth: u32,
+}
+
+impl VisitMut for Rebase {
+    fn visit_control_instr_mut(&mut self, i: &mut Instr) {
+        match i {
+            Instr::Opcode(Opcode::Return) => *i = Instr::Br(Labelidx(self.depth)),
+            Instr::Block(..) | Instr::Loop(..) | Instr::If(..) | Instr::IfElse(..) => {
+                self.depth += 1;
+                visit::walk_control_instr_mut(self, i);
+                self.depth -= 1;
+            }
+            _ => visit::walk_control_instr_mut(self, i),
+        }
+    }
+
+    fn visit_localidx_mut(&mut self, x: &mut Localidx) {
+        x.0 += self.base;
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    instructions::Instr,
+    modules::{Func, Funcidx, Localidx, Locals, Module},
+    names::Names,
+    types::Valtype,
+    visit::VisitMut,
+    Vector,
+};
+use std::io;
+
+/// Coalesces the locals of every function in the module. Local names are
+/// kept for locals that end up with a slot of their own and dropped for the
+/// rest.
+pub fn coalesce(m: &mut Module) -> io::Result<()> {
+    let mut names = Names::from_module(m)?;
+    let imported = m.imported_funcs();
+    let params: Vec<_> = (0..m.codesec.as_ref().map_or(0, |c| c.0 .0 .0.len() as u32))
+        .map(|i| {
+            m.func_type(Funcidx(imported + i))
+                .map_or(0, |t| t.parameters.0 .0.len() as u32)
+        })
+        .collect();
+    for (i, code) in m
+        .codesec
+        .iter_mut()
+        .flat_map(|c| c.0 .0 .0.iter_mut())
+        .enumerate()
+    {
+        let slots = coalesce_func(&mut code.0, params[i]);
+        let Some(names) = &mut names else {
+            continue;
+        };
+        let x = Funcidx(imported + i as u32);
+        for assoc in names.locals.0 .0.iter_mut().filter(|a| a.idx == x) {
+            assoc.names.remap(|&l| {
+                let slot = (*slots.get(l as usize)?)?;
+                let shared = slots.iter().filter(|&&s| s == Some(slot)).count() > 1;
+                (!shared).then_some(slot)
+            });
+        }
+    }
+    if let Some(names) = names {
+        m.replace_custom(Names::NAME, names.custom()?);
+    }
+    Ok(())
+}
+
+/// Merges locals of the same type whose live ranges don't overlap, drops
+/// locals that are never accessed, and regroups the rest into one run per
+/// type. Parameters, the first `params` locals, keep their indices.
+///
+/// Returns the new index of each old local, or `None` for those that were
+/// dropped.
+///
+/// Live ranges are intervals over the instructions in program order. A
+/// local's range starts at its first access if that is a write dominating
+/// every other access, and at the function entry otherwise, since it may
+/// observe the zero the local starts with. A local carried around a loop is
+/// live for the whole loop.
+pub fn coalesce_func(f: &mut Func, params: u32) -> Vec<Option<u32>> {
+    let types: Vec<_> =
+        f.t.0
+            .iter()
+            .flat_map(|l| std::iter::repeat_n(l.t, l.n as usize))
+            .collect();
+    let mut liveness = Liveness {
+        params,
+        pos: 0,
+        accesses: vec![vec![]; types.len()],
+        scopes: vec![],
+        loops: vec![],
+    };
+    liveness.seq(&f.e.0);
+
+    let mut ranges: Vec<_> = liveness
+        .accesses
+        .iter()
+        .enumerate()
+        .filter_map(|(l, a)| liveness.range(a).map(|r| (r, l)))
+        .collect();
+    ranges.sort();
+
+    // Greedy coloring in order of start is optimal for interval graphs.
+    let mut colors: Vec<(Valtype, u32)> = vec![];
+    let mut color_of = vec![None; types.len()];
+    for ((start, end), l) in ranges {
+        let t = types[l];
+        let color = match colors.iter().position(|&(u, e)| u == t && e < start) {
+            Some(c) => c,
+            None => {
+                colors.push((t, 0));
+                colors.len() - 1
+            }
+        };
+        colors[color].1 = end;
+        color_of[l] = Some(color);
+    }
+
+    let mut groups: Vec<(Valtype, Vec<usize>)> = vec![];
+    for (c, &(t, _)) in colors.iter().enumerate() {
+        match groups.iter_mut().find(|(u, _)| *u == t) {
+            Some((_, g)) => g.push(c),
+            None => groups.push((t, vec![c])),
+        }
+    }
+    let mut slot_of = vec![0; colors.len()];
+    let mut next = params;
+    for (_, g) in &groups {
+        for &c in g {
+            slot_of[c] = next;
+            next += 1;
+        }
+    }
+
+    let slots: Vec<_> = (0..params)
+        .map(Some)
+        .chain(color_of.iter().map(|c| c.map(|c| slot_of[c])))
+        .collect();
+    Renumber(&slots).visit_func_mut(f);
+    f.t = Vector(
+        groups
+            .into_iter()
+            .map(|(t, g)| Locals {
+                n: g.len() as u32,
+                t,
+            })
+            .collect(),
+    );
+    slots
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Access {
+    pos: u32,
+    set: bool,
+    scope: usize,
+}
+
+struct Liveness {
+    params: u32,
+    pos: u32,
+    /// Accesses to each non-parameter local in program order.
+    accesses: Vec<Vec<Access>>,
+    /// The end of each instruction sequence.
+    scopes: Vec<u32>,
+    /// The start and end of each loop.
+    loops: Vec<(u32, u32)>,
+}
+
+impl Liveness {
+    fn seq(&mut self, instrs: &[Instr]) {
+        let scope = self.scopes.len();
+        self.scopes.push(0);
+        for i in instrs {
+            let pos = self.pos;
+            self.pos += 1;
+            match i {
+                Instr::LocalGet(x) => self.access(*x, pos, false, scope),
+                Instr::LocalSet(x) | Instr::LocalTee(x) => self.access(*x, pos, true, scope),
+                Instr::Block(_, r#in) | Instr::If(_, r#in) => self.seq(r#in),
+                Instr::Loop(_, r#in) => {
+                    self.seq(r#in);
+                    self.loops.push((pos, self.pos));
+                }
+                Instr::IfElse(_, in1, in2) => {
+                    self.seq(in1);
+                    self.seq(in2);
+                }
+                _ => {}
+            }
+        }
+        self.scopes[scope] = self.pos;
+    }
+
+    fn access(&mut self, x: Localidx, pos: u32, set: bool, scope: usize) {
+        if let Some(a) =
+            x.0.checked_sub(self.params)
+                .and_then(|l| self.accesses.get_mut(l as usize))
+        {
+            a.push(Access { pos, set, scope });
+        }
+    }
+
+    /// Whether the first access is a write that every later access in the
+    /// list is nested after.
+    fn dominated(&self, a: &[Access]) -> bool {
+        match (a.first(), a.last()) {
+            (Some(first), Some(last)) => first.set && last.pos < self.scopes[first.scope],
+            _ => true,
+        }
+    }
+
+    fn range(&self, a: &[Access]) -> Option<(u32, u32)> {
+        let (first, last) = (a.first()?, a.last()?);
+        let (mut start, mut end) = (first.pos, last.pos);
+        if !self.dominated(a) {
+            start = 0;
+        }
+        for &(ls, le) in &self.loops {
+            let from = a.partition_point(|a| a.pos < ls);
+            let to = a.partition_point(|a| a.pos < le);
+            if !self.dominated(&a[from..to]) {
+                start = start.min(ls);
+                end = end.max(le);
+            }
+        }
+        Some((start, end))
+    }
+}
+
+struct Renumber<'a>(&'a [Option<u32>]);
+
+impl VisitMut for Renumber<'_> {
+    fn visit_localidx_mut(&mut self, x: &mut Localidx) {
+        if let Some(Some(l)) = self.0.get(x.0 as usize) {
+            x.0 = *l;
+        }
+    }
+}
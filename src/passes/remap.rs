@@ -0,0 +1,89 @@
+use crate::{
+    instructions::{Blocktype, S33},
+    modules::{Dataidx, Elemidx, Funcidx, Globalidx, Memidx, Module, Tableidx, Typeidx},
+    names::Names,
+    visit::VisitMut,
+};
+use std::{collections::HashMap, io};
+
+/// Old-to-new index maps for each index space. Indices without an entry are
+/// left alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Remap {
+    pub types: HashMap<Typeidx, Typeidx>,
+    pub funcs: HashMap<Funcidx, Funcidx>,
+    pub tables: HashMap<Tableidx, Tableidx>,
+    pub mems: HashMap<Memidx, Memidx>,
+    pub globals: HashMap<Globalidx, Globalidx>,
+    pub elems: HashMap<Elemidx, Elemidx>,
+    pub datas: HashMap<Dataidx, Dataidx>,
+}
+
+impl Remap {
+    /// Rewrites every index in the module, including those in the name
+    /// section. Fails without changing the module if the name section is
+    /// malformed.
+    pub fn apply(&self, m: &mut Module) -> io::Result<()> {
+        let names = Names::from_module(m)?;
+        Rewriter(self).visit_module_mut(m);
+        if let Some(mut names) = names {
+            self.apply_names(&mut names);
+            m.replace_custom(Names::NAME, names.custom()?);
+        }
+        Ok(())
+    }
+
+    pub fn apply_names(&self, names: &mut Names) {
+        names.funcs.remap(|x| Some(get(&self.funcs, x)));
+        names.locals.remap(|x| Some(get(&self.funcs, x)));
+        names.labels.remap(|x| Some(get(&self.funcs, x)));
+        names.types.remap(|x| Some(get(&self.types, x)));
+        names.tables.remap(|x| Some(get(&self.tables, x)));
+        names.mems.remap(|x| Some(get(&self.mems, x)));
+        names.globals.remap(|x| Some(get(&self.globals, x)));
+        names.elems.remap(|x| Some(get(&self.elems, x)));
+        names.datas.remap(|x| Some(get(&self.datas, x)));
+    }
+}
+
+fn get<I: Copy + Eq + std::hash::Hash>(map: &HashMap<I, I>, x: &I) -> I {
+    map.get(x).copied().unwrap_or(*x)
+}
+
+struct Rewriter<'a>(&'a Remap);
+
+impl VisitMut for Rewriter<'_> {
+    fn visit_blocktype_mut(&mut self, bt: &mut Blocktype) {
+        if let Blocktype::TypeIndex(S33(n)) = bt {
+            *n = get(&self.0.types, &Typeidx(*n as u32)).0 as i64;
+        }
+    }
+
+    fn visit_typeidx_mut(&mut self, x: &mut Typeidx) {
+        *x = get(&self.0.types, x);
+    }
+
+    fn visit_funcidx_mut(&mut self, x: &mut Funcidx) {
+        *x = get(&self.0.funcs, x);
+    }
+
+    fn visit_tableidx_mut(&mut self, x: &mut Tableidx) {
+        *x = get(&self.0.tables, x);
+    }
+
+    fn visit_memidx_mut(&mut self, x: &mut Memidx) {
+        *x = get(&self.0.mems, x);
+    }
+
+    fn visit_globalidx_mut(&mut self, x: &mut Globalidx) {
+        *x = get(&self.0.globals, x);
+    }
+
+    fn visit_elemidx_mut(&mut self, x: &mut Elemidx) {
+        *x = get(&self.0.elems, x);
+    }
+
+    fn visit_dataidx_mut(&mut self, x: &mut Dataidx) {
+        *x = get(&self.0.datas, x);
+    }
+}
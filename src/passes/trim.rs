@@ -0,0 +1,57 @@
+use crate::{
+    instructions::{Blocktype, Instr, Opcode},
+    modules::{Func, Module},
+    visit::{self, VisitMut},
+};
+
+/// Trims unreachable code from every function in the module.
+pub fn trim(m: &mut Module) {
+    for code in m.codesec.iter_mut().flat_map(|c| c.0 .0 .0.iter_mut()) {
+        trim_func(&mut code.0);
+    }
+}
+
+/// Removes instructions that follow an unconditional branch in the same
+/// block, resolves `if` on a constant condition to the branch it takes and
+/// drops blocks that end up empty.
+pub fn trim_func(f: &mut Func) {
+    Trimmer.visit_func_mut(f);
+}
+
+struct Trimmer;
+
+impl VisitMut for Trimmer {
+    fn visit_instrs_mut(&mut self, i: &mut Box<[Instr]>) {
+        visit::walk_instrs_mut(self, i);
+        *i = trim_instrs(std::mem::take(i));
+    }
+}
+
+fn trim_instrs(instrs: Box<[Instr]>) -> Box<[Instr]> {
+    let mut out: Vec<Instr> = vec![];
+    for instr in instrs.into_vec() {
+        let instr = match (instr, out.last()) {
+            (Instr::If(bt, r#in), Some(&Instr::I32Const(c))) => {
+                out.pop();
+                Instr::Block(bt, if c != 0 { r#in } else { Box::new([]) })
+            }
+            (Instr::IfElse(bt, in1, in2), Some(&Instr::I32Const(c))) => {
+                out.pop();
+                Instr::Block(bt, if c != 0 { in1 } else { in2 })
+            }
+            (instr, _) => instr,
+        };
+        let diverges = matches!(
+            instr,
+            Instr::Opcode(Opcode::Unreachable | Opcode::Return) | Instr::Br(_) | Instr::BrTable(..)
+        );
+        match instr {
+            Instr::Block(Blocktype::Empty | Blocktype::TypeIndex(_), r#in) if r#in.is_empty() => {}
+            instr => out.push(instr),
+        }
+        if diverges {
+            break;
+        }
+    }
+    out.into_boxed_slice()
+}
@@ -0,0 +1,480 @@
+//! Delta-debugging test-case reduction.
+
+use crate::{
+    instructions::{Instr, Opcode},
+    modules::{
+        Dataidx, Elemidx, Funcidx, Globalidx, Importdesc, Labelidx, Memidx, Module, Section,
+        Tableidx, Typeidx,
+    },
+    names::Names,
+    passes::{gc::gc, remap::Remap},
+    stack::FuncContext,
+    validate::validate,
+    visit::{self, Visit, VisitMut},
+    Vector,
+};
+use std::{cmp::Ordering, collections::HashMap, hash::Hash, iter};
+
+/// Shrinks a module for as long as `interesting` keeps holding, returning the
+/// smallest module found. It removes custom sections, exports, the start
+/// function, imports and other items, and instructions, unwraps blocks and
+/// replaces instructions with constants, keeping only changes that leave the
+/// module valid. The input should itself be valid and interesting.
+pub fn reduce(m: Module, interesting: impl FnMut(&Module) -> bool) -> Module {
+    let mut r = Reducer {
+        m,
+        interesting,
+        changed: true,
+    };
+    while r.changed {
+        r.changed = false;
+        r.customs();
+        r.exports();
+        r.attempt(|m| m.startsec.take().map(|_| ()));
+        r.items();
+        r.attempt(|m| gc(m).ok());
+        r.attempt(remove_empty);
+        for k in 0.. {
+            let Some(&len) = lists(&r.m).get(k) else {
+                break;
+            };
+            r.instrs(k, len);
+            r.drops(k);
+            r.unwrap_blocks(k);
+            r.simplify(k);
+        }
+    }
+    r.m
+}
+
+struct Reducer<F> {
+    m: Module,
+    interesting: F,
+    changed: bool,
+}
+
+impl<F: FnMut(&Module) -> bool> Reducer<F> {
+    /// Applies `f` to a copy of the module and keeps the result if `f`
+    /// succeeded and the copy is still valid and interesting.
+    fn attempt(&mut self, f: impl FnOnce(&mut Module) -> Option<()>) -> bool {
+        let mut c = self.m.clone();
+        if f(&mut c).is_none() || c == self.m || validate(&c).is_err() || !(self.interesting)(&c) {
+            return false;
+        }
+        self.m = c;
+        self.changed = true;
+        true
+    }
+
+    fn customs(&mut self) {
+        let mut j = 0;
+        while j < self.m.customs().count() {
+            let removed = self.attempt(|m| {
+                let mut n = 0;
+                m.retain_customs(|_| {
+                    n += 1;
+                    n != j + 1
+                });
+                Some(())
+            });
+            if !removed {
+                j += 1;
+            }
+        }
+    }
+
+    fn exports(&mut self) {
+        let len = |m: &Module| m.exportsec.as_ref().map_or(0, |s| s.0 .0 .0.len());
+        ddmin(len(&self.m), |chunk, i| {
+            if i >= len(&self.m) {
+                return None;
+            }
+            Some(self.attempt(|m| {
+                let s = m.exportsec.as_mut()?;
+                let mut v = std::mem::take(&mut s.0 .0 .0).into_vec();
+                v.drain(i..(i + chunk).min(v.len()));
+                s.0 .0 .0 = v.into_boxed_slice();
+                Some(())
+            }))
+        });
+    }
+
+    /// Removes imports, functions, globals, tables, memories, types and
+    /// segments one at a time, last first so that fewer indices move.
+    fn items(&mut self) {
+        for space in Space::ALL {
+            for x in (0..space.len(&self.m)).rev() {
+                if x < space.len(&self.m) {
+                    self.attempt(|m| remove_item(m, space, x));
+                }
+            }
+        }
+    }
+
+    fn instrs(&mut self, k: usize, len: usize) {
+        ddmin(len, |chunk, i| {
+            if i >= lists(&self.m)[k] {
+                return None;
+            }
+            Some(self.attempt(|m| {
+                edit(m, k, |l| {
+                    let mut v = std::mem::take(l).into_vec();
+                    v.drain(i..(i + chunk).min(v.len()));
+                    *l = v.into_boxed_slice();
+                })
+            }))
+        });
+    }
+
+    /// Removes each `drop` along with whatever produced the value it drops,
+    /// which the chunks above miss when they aren't aligned with it: either
+    /// every instruction back to some earlier one or just that one.
+    fn drops(&mut self, k: usize) {
+        let mut j = 0;
+        while j < lists(&self.m)[k] {
+            let removed = (0..j).rev().find(|&i| {
+                [true, false].into_iter().any(|run| {
+                    self.attempt(|m| {
+                        let mut ok = None;
+                        edit(m, k, |l| {
+                            if l[j] != Instr::Opcode(Opcode::Drop) {
+                                return;
+                            }
+                            let mut v = std::mem::take(l).into_vec();
+                            v.remove(j);
+                            if run {
+                                v.drain(i..j);
+                            } else {
+                                v.remove(i);
+                            }
+                            *l = v.into_boxed_slice();
+                            ok = Some(());
+                        })?;
+                        ok
+                    })
+                })
+            });
+            j = removed.unwrap_or(j + 1);
+        }
+    }
+
+    /// Replaces blocks with their bodies, dropping the condition of an `if`
+    /// and keeping either arm of an `if`/`else`.
+    fn unwrap_blocks(&mut self, k: usize) {
+        let mut i = 0;
+        while i < lists(&self.m)[k] {
+            let unwrapped = (0..2).any(|arm| {
+                self.attempt(|m| {
+                    let mut ok = None;
+                    edit(m, k, |l| {
+                        let (prefix, body) = match &l[i] {
+                            Instr::Block(_, b) | Instr::Loop(_, b) if arm == 0 => (None, b),
+                            Instr::If(_, b) | Instr::IfElse(_, b, _) if arm == 0 => {
+                                (Some(Instr::Opcode(Opcode::Drop)), b)
+                            }
+                            Instr::IfElse(_, _, b) => (Some(Instr::Opcode(Opcode::Drop)), b),
+                            _ => return,
+                        };
+                        let mut body = body.clone();
+                        let mut outer = Outer::default();
+                        outer.visit_instrs_mut(&mut body);
+                        if outer.targeted {
+                            return;
+                        }
+                        let mut v = std::mem::take(l).into_vec();
+                        v.splice(i..=i, prefix.into_iter().chain(body.into_vec()));
+                        *l = v.into_boxed_slice();
+                        ok = Some(());
+                    })?;
+                    ok
+                })
+            });
+            if !unwrapped {
+                i += 1;
+            }
+        }
+    }
+
+    /// Replaces instructions with drops of their operands and zeros of their
+    /// results, which also zeroes constants and, along with `drops`, lets
+    /// operands be removed when other instructions sit between them and
+    /// their consumer.
+    fn simplify(&mut self, k: usize) {
+        // Only list `k` changes, so the index spaces stay the same.
+        let snapshot = self.m.clone();
+        let ctx = FuncContext {
+            module: &snapshot,
+            locals: [].into(),
+            labels: vec![],
+            results: [].into(),
+        };
+        let mut i = 0;
+        while i < lists(&self.m)[k] {
+            self.attempt(|m| {
+                let mut ok = None;
+                edit(m, k, |l| {
+                    let Some(ty) = l[i].stack_effect(&ctx) else {
+                        return;
+                    };
+                    let drops = ty
+                        .parameters
+                        .0
+                         .0
+                        .iter()
+                        .map(|_| Instr::Opcode(Opcode::Drop));
+                    let zeros = ty.results.0 .0.iter().map(|t| t.zero_instr());
+                    let mut v = std::mem::take(l).into_vec();
+                    v.splice(i..=i, drops.chain(zeros));
+                    *l = v.into_boxed_slice();
+                    ok = Some(());
+                })?;
+                ok
+            });
+            i += 1;
+        }
+    }
+}
+
+/// Tries removing chunks of `len` items, halving the chunk size down to one.
+/// `attempt(chunk, i)` removes the chunk at `i`, returning whether it did or
+/// `None` once `i` is past the end.
+fn ddmin(len: usize, mut attempt: impl FnMut(usize, usize) -> Option<bool>) {
+    let mut chunk = len;
+    while chunk > 0 {
+        let mut i = 0;
+        while let Some(removed) = attempt(chunk, i) {
+            if !removed {
+                i += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Space {
+    Elem,
+    Data,
+    Func,
+    Global,
+    Table,
+    Mem,
+    Type,
+}
+
+impl Space {
+    /// Segments first, since they keep everything else alive.
+    const ALL: [Space; 7] = [
+        Space::Elem,
+        Space::Data,
+        Space::Func,
+        Space::Global,
+        Space::Table,
+        Space::Mem,
+        Space::Type,
+    ];
+
+    fn imported(self, m: &Module) -> u32 {
+        match self {
+            Space::Func => m.imported_funcs(),
+            Space::Table => m.imported_tables(),
+            Space::Mem => m.imported_mems(),
+            Space::Global => m.imported_globals(),
+            Space::Type | Space::Elem | Space::Data => 0,
+        }
+    }
+
+    fn len(self, m: &Module) -> u32 {
+        fn len<const N: u8, T>(s: Option<&Section<N, Vector<T>>>) -> u32 {
+            s.map_or(0, |s| s.0 .0.len() as u32)
+        }
+        self.imported(m)
+            + match self {
+                Space::Type => len(m.typesec.as_ref().map(|s| &s.0)),
+                Space::Func => len(m.funcsec.as_ref().map(|s| &s.0)),
+                Space::Table => len(m.tablesec.as_ref().map(|s| &s.0)),
+                Space::Mem => len(m.memsec.as_ref().map(|s| &s.0)),
+                Space::Global => len(m.globalsec.as_ref().map(|s| &s.0)),
+                Space::Elem => len(m.elemsec.as_ref().map(|s| &s.0)),
+                Space::Data => len(m.datasec.as_ref().map(|s| &s.0)),
+            }
+    }
+
+    fn is_import(self, d: &Importdesc) -> bool {
+        matches!(
+            (self, d),
+            (Space::Func, Importdesc::Func(_))
+                | (Space::Table, Importdesc::Table(_))
+                | (Space::Mem, Importdesc::Mem(_))
+                | (Space::Global, Importdesc::Global(_))
+        )
+    }
+}
+
+/// Removes an item, imported or defined, and shifts down the ones after it.
+/// References to it are left dangling, so the module fails to validate if it
+/// was still used.
+fn remove_item(m: &mut Module, space: Space, x: u32) -> Option<()> {
+    let total = space.len(m);
+    match x.checked_sub(space.imported(m)).map(|i| i as usize) {
+        Some(i) => match space {
+            Space::Type => remove(&mut m.typesec.as_mut()?.0 .0 .0, i),
+            Space::Func => {
+                remove(&mut m.funcsec.as_mut()?.0 .0 .0, i);
+                remove(&mut m.codesec.as_mut()?.0 .0 .0, i);
+            }
+            Space::Table => remove(&mut m.tablesec.as_mut()?.0 .0 .0, i),
+            Space::Mem => remove(&mut m.memsec.as_mut()?.0 .0 .0, i),
+            Space::Global => remove(&mut m.globalsec.as_mut()?.0 .0 .0, i),
+            Space::Elem => remove(&mut m.elemsec.as_mut()?.0 .0 .0, i),
+            Space::Data => {
+                remove(&mut m.datasec.as_mut()?.0 .0 .0, i);
+                if let Some(count) = &mut m.datacountsec {
+                    count.0 .0 -= 1;
+                }
+            }
+        },
+        None => {
+            let imports = &mut m.importsec.as_mut()?.0 .0 .0;
+            let i = imports
+                .iter()
+                .enumerate()
+                .filter(|(_, i)| space.is_import(&i.d))
+                .nth(x as usize)?
+                .0;
+            remove(imports, i);
+        }
+    }
+
+    if let Some(mut names) = Names::from_module(m).ok()? {
+        match space {
+            Space::Type => names.types.remap(|y| (y.0 != x).then_some(*y)),
+            Space::Func => {
+                names.funcs.remap(|y| (y.0 != x).then_some(*y));
+                names.locals.remap(|y| (y.0 != x).then_some(*y));
+                names.labels.remap(|y| (y.0 != x).then_some(*y));
+            }
+            Space::Table => names.tables.remap(|y| (y.0 != x).then_some(*y)),
+            Space::Mem => names.mems.remap(|y| (y.0 != x).then_some(*y)),
+            Space::Global => names.globals.remap(|y| (y.0 != x).then_some(*y)),
+            Space::Elem => names.elems.remap(|y| (y.0 != x).then_some(*y)),
+            Space::Data => names.datas.remap(|y| (y.0 != x).then_some(*y)),
+        }
+        m.replace_custom(Names::NAME, names.custom().ok()?);
+    }
+
+    fn moved<I: Eq + Hash>(x: u32, total: u32, idx: impl Fn(u32) -> I) -> HashMap<I, I> {
+        iter::once((x, u32::MAX))
+            .chain((x + 1..total).map(|y| (y, y - 1)))
+            .map(|(old, new)| (idx(old), idx(new)))
+            .collect()
+    }
+    let mut remap = Remap::default();
+    match space {
+        Space::Type => remap.types = moved(x, total, Typeidx),
+        Space::Func => remap.funcs = moved(x, total, Funcidx),
+        Space::Table => remap.tables = moved(x, total, Tableidx),
+        Space::Mem => remap.mems = moved(x, total, Memidx),
+        Space::Global => remap.globals = moved(x, total, Globalidx),
+        Space::Elem => remap.elems = moved(x, total, Elemidx),
+        Space::Data => remap.datas = moved(x, total, Dataidx),
+    }
+    remap.apply(m).ok()
+}
+
+fn remove_empty(m: &mut Module) -> Option<()> {
+    fn clear<S>(s: &mut Option<S>, is_empty: impl Fn(&S) -> bool) {
+        if s.as_ref().is_some_and(is_empty) {
+            *s = None;
+        }
+    }
+    clear(&mut m.typesec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.importsec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.funcsec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.tablesec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.memsec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.globalsec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.exportsec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.elemsec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.codesec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.datasec, |s| s.0 .0 .0.is_empty());
+    clear(&mut m.datacountsec, |s| s.0 .0 == 0);
+    Some(())
+}
+
+fn remove<T>(items: &mut Box<[T]>, i: usize) {
+    let mut v = std::mem::take(items).into_vec();
+    v.remove(i);
+    *items = v.into_boxed_slice();
+}
+
+/// The lengths of every instruction sequence in the module, nested ones
+/// included, in visiting order.
+fn lists(m: &Module) -> Vec<usize> {
+    struct Lists(Vec<usize>);
+
+    impl Visit for Lists {
+        fn visit_instrs(&mut self, i: &[Instr]) {
+            self.0.push(i.len());
+            visit::walk_instrs(self, i)
+        }
+    }
+
+    let mut l = Lists(vec![]);
+    l.visit_module(m);
+    l.0
+}
+
+/// Applies `f` to the `k`th instruction sequence, numbered as in [`lists`].
+fn edit(m: &mut Module, k: usize, f: impl FnOnce(&mut Box<[Instr]>)) -> Option<()> {
+    struct Edit<F> {
+        k: usize,
+        f: Option<F>,
+    }
+
+    impl<F: FnOnce(&mut Box<[Instr]>)> VisitMut for Edit<F> {
+        fn visit_instrs_mut(&mut self, i: &mut Box<[Instr]>) {
+            if self.k == 0 {
+                if let Some(f) = self.f.take() {
+                    f(i);
+                }
+                return;
+            }
+            self.k -= 1;
+            visit::walk_instrs_mut(self, i)
+        }
+    }
+
+    let mut e = Edit { k, f: Some(f) };
+    e.visit_module_mut(m);
+    e.f.is_none().then_some(())
+}
+
+/// Renumbers the labels in a block body for the block being removed, noting
+/// whether any branch targeted it.
+#[derive(Default)]
+struct Outer {
+    depth: u32,
+    targeted: bool,
+}
+
+impl VisitMut for Outer {
+    fn visit_control_instr_mut(&mut self, i: &mut Instr) {
+        match i {
+            Instr::Block(..) | Instr::Loop(..) | Instr::If(..) | Instr::IfElse(..) => {
+                self.depth += 1;
+                visit::walk_control_instr_mut(self, i);
+                self.depth -= 1;
+            }
+            _ => visit::walk_control_instr_mut(self, i),
+        }
+    }
+
+    fn visit_labelidx_mut(&mut self, l: &mut Labelidx) {
+        match l.0.cmp(&self.depth) {
+            Ordering::Less => {}
+            Ordering::Equal => self.targeted = true,
+            Ordering::Greater => l.0 -= 1,
+        }
+    }
+}
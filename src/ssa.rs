@@ -0,0 +1,410 @@
+use crate::{
+    cfg::{self, BasicBlock, Cfg},
+    instructions::{Expr, Instr, Opcode},
+    modules::{Func, Localidx, Locals},
+    passes::locals,
+    types::{Numtype, Valtype},
+    Vector,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Value(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlockId(pub u32);
+
+/// A non-control instruction applied to SSA values. `op` pops `args` in
+/// order and pushes `results`. Control instructions and those accessing
+/// locals have no meaning here.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Inst {
+    pub op: Instr,
+    pub args: Vec<Value>,
+    pub results: Vec<Value>,
+}
+
+/// An edge to a block, passing a value for each of its parameters.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Target {
+    pub block: BlockId,
+    pub args: Vec<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Terminator {
+    Jump(Target),
+    Branch {
+        cond: Value,
+        then: Target,
+        otherwise: Target,
+    },
+    Table {
+        index: Value,
+        targets: Box<[Target]>,
+        default: Target,
+    },
+    Return(Vec<Value>),
+    Unreachable,
+}
+
+impl Terminator {
+    fn targets(&self) -> Vec<&Target> {
+        match self {
+            Terminator::Jump(t) => vec![t],
+            Terminator::Branch {
+                then, otherwise, ..
+            } => vec![then, otherwise],
+            Terminator::Table {
+                targets, default, ..
+            } => targets.iter().chain([default]).collect(),
+            Terminator::Return(_) | Terminator::Unreachable => vec![],
+        }
+    }
+
+    /// The operands the terminator takes from the stack at the end of its
+    /// block. Arguments to conditional targets are copied inside the branch
+    /// instead.
+    fn operands(&self) -> Vec<Value> {
+        match self {
+            Terminator::Jump(t) => t.args.clone(),
+            Terminator::Branch { cond, .. } => vec![*cond],
+            Terminator::Table { index, .. } => vec![*index],
+            Terminator::Return(vals) => vals.clone(),
+            Terminator::Unreachable => vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Block {
+    pub params: Vec<Value>,
+    pub insts: Vec<Inst>,
+    pub terminator: Terminator,
+}
+
+/// A function in SSA form. The entry is block 0, and its parameters are the
+/// function's parameters.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Function {
+    pub results: Vec<Valtype>,
+    /// The type of each value.
+    pub values: Vec<Valtype>,
+    pub blocks: Vec<Block>,
+}
+
+impl Function {
+    pub fn new(params: &[Valtype], results: &[Valtype]) -> Self {
+        let mut f = Self {
+            results: results.to_vec(),
+            values: vec![],
+            blocks: vec![],
+        };
+        f.add_block(params);
+        f
+    }
+
+    pub fn entry(&self) -> BlockId {
+        BlockId(0)
+    }
+
+    /// Adds a block that ends in `unreachable` until it is given a
+    /// terminator.
+    pub fn add_block(&mut self, params: &[Valtype]) -> BlockId {
+        let params = params.iter().map(|&t| self.value(t)).collect();
+        self.blocks.push(Block {
+            params,
+            insts: vec![],
+            terminator: Terminator::Unreachable,
+        });
+        BlockId(self.blocks.len() as u32 - 1)
+    }
+
+    pub fn params(&self, b: BlockId) -> &[Value] {
+        &self.blocks[b.0 as usize].params
+    }
+
+    /// Appends an instruction to a block, returning its results.
+    pub fn push(
+        &mut self,
+        b: BlockId,
+        op: Instr,
+        args: &[Value],
+        results: &[Valtype],
+    ) -> Vec<Value> {
+        let results: Vec<_> = results.iter().map(|&t| self.value(t)).collect();
+        self.blocks[b.0 as usize].insts.push(Inst {
+            op,
+            args: args.to_vec(),
+            results: results.clone(),
+        });
+        results
+    }
+
+    pub fn terminate(&mut self, b: BlockId, t: Terminator) {
+        self.blocks[b.0 as usize].terminator = t;
+    }
+
+    pub fn ty(&self, v: Value) -> Valtype {
+        self.values[v.0 as usize]
+    }
+
+    fn value(&mut self, t: Valtype) -> Value {
+        self.values.push(t);
+        Value(self.values.len() as u32 - 1)
+    }
+
+    /// Lowers to a stack-machine function body.
+    ///
+    /// A value whose only use comes later in the block that defines it stays
+    /// on the operand stack when the instructions between leave it in
+    /// position. Every other value, as well as every block parameter, lives
+    /// in a local. Control flow is rebuilt with
+    /// [`Cfg::structurize`], and the locals are then coalesced with
+    /// [`coalesce_func`](locals::coalesce_func).
+    pub fn lower(&self) -> Func {
+        let params = self.blocks[0].params.len() as u32;
+        let mut uses = vec![0; self.values.len()];
+        let mut pinned = vec![false; self.values.len()];
+        for b in &self.blocks {
+            for v in b.insts.iter().flat_map(|i| &i.args) {
+                uses[v.0 as usize] += 1;
+            }
+            for v in b.terminator.operands() {
+                uses[v.0 as usize] += 1;
+            }
+            if !matches!(b.terminator, Terminator::Jump(_)) {
+                for v in b.terminator.targets().iter().flat_map(|t| &t.args) {
+                    uses[v.0 as usize] += 1;
+                    pinned[v.0 as usize] = true;
+                }
+            }
+        }
+
+        let mut slots = Slots {
+            types: &self.values,
+            consts: HashMap::new(),
+            locals: vec![],
+            local_of: vec![None; self.values.len()],
+        };
+        for inst in self.blocks.iter().flat_map(|b| &b.insts) {
+            if let (true, [r]) = (is_const(&inst.op), &*inst.results) {
+                slots.consts.insert(*r, inst.op.clone());
+            }
+        }
+        for &v in &self.blocks[0].params {
+            slots.get(v);
+        }
+
+        let mut blocks = vec![];
+        let mut edges = vec![];
+        for b in &self.blocks {
+            let stacked = self.schedule(b, &uses, &pinned, &slots.consts);
+            let mut instrs = vec![];
+            for (inst, &m) in b.insts.iter().zip(&stacked.operands) {
+                if inst.results.len() == 1 && slots.consts.contains_key(&inst.results[0]) {
+                    continue;
+                }
+                slots.load(&inst.args[m..], &mut instrs);
+                instrs.push(inst.op.clone());
+                for &r in inst.results.iter().rev() {
+                    if stacked.values.contains(&r) {
+                        continue;
+                    }
+                    instrs.push(match uses[r.0 as usize] {
+                        0 => Instr::Opcode(Opcode::Drop),
+                        _ => Instr::LocalSet(slots.get(r)),
+                    });
+                }
+            }
+            let m = stacked.operands[b.insts.len()];
+            slots.load(&b.terminator.operands()[m..], &mut instrs);
+
+            let terminator = match &b.terminator {
+                Terminator::Jump(t) => {
+                    slots.store(self.params(t.block), &mut instrs);
+                    cfg::Terminator::Jump(t.block.0 as usize)
+                }
+                Terminator::Branch {
+                    then, otherwise, ..
+                } => cfg::Terminator::Branch {
+                    then: self.edge_block(then, &mut edges),
+                    otherwise: self.edge_block(otherwise, &mut edges),
+                },
+                Terminator::Table {
+                    targets, default, ..
+                } => cfg::Terminator::Table {
+                    targets: targets
+                        .iter()
+                        .map(|t| self.edge_block(t, &mut edges))
+                        .collect(),
+                    default: self.edge_block(default, &mut edges),
+                },
+                Terminator::Return(_) => cfg::Terminator::Return,
+                Terminator::Unreachable => cfg::Terminator::Unreachable,
+            };
+            blocks.push(BasicBlock { instrs, terminator });
+        }
+
+        // Conditional edges passing arguments get a block of their own to
+        // copy them into the target's parameters.
+        for t in &edges {
+            let mut instrs = vec![];
+            slots.load(&t.args, &mut instrs);
+            slots.store(self.params(t.block), &mut instrs);
+            let terminator = cfg::Terminator::Jump(t.block.0 as usize);
+            blocks.push(BasicBlock { instrs, terminator });
+        }
+
+        let mut locals = slots.locals;
+        let label = locals.len() as u32;
+        locals.push(Valtype::Numtype(Numtype::I32));
+        let e = Cfg { blocks }.structurize(Localidx(label));
+        let mut f = Func {
+            t: Vector(
+                locals
+                    .into_iter()
+                    .skip(params as usize)
+                    .map(|t| Locals { n: 1, t })
+                    .collect(),
+            ),
+            e: Expr(e),
+        };
+        locals::coalesce_func(&mut f, params);
+        f
+    }
+
+    /// The block a conditional edge lands on: the target itself, or a block
+    /// to be added that copies the arguments.
+    fn edge_block(&self, t: &Target, edges: &mut Vec<Target>) -> usize {
+        if t.args.is_empty() {
+            t.block.0 as usize
+        } else {
+            edges.push(t.clone());
+            self.blocks.len() + edges.len() - 1
+        }
+    }
+
+    /// Decides which values of a block stay on the stack by simulating it.
+    fn schedule(
+        &self,
+        b: &Block,
+        uses: &[u32],
+        pinned: &[bool],
+        consts: &HashMap<Value, Instr>,
+    ) -> Stacked {
+        // Where each value's only stackable use is, if it is in this block.
+        let mut user = HashMap::new();
+        let operands: Vec<_> = b
+            .insts
+            .iter()
+            .map(|i| i.args.clone())
+            .chain([b.terminator.operands()])
+            .collect();
+        for (j, args) in operands.iter().enumerate() {
+            for &a in args {
+                user.insert(a, j);
+            }
+        }
+        let candidate = |v: Value, j: usize| {
+            uses[v.0 as usize] == 1
+                && !pinned[v.0 as usize]
+                && !consts.contains_key(&v)
+                && user.get(&v) == Some(&j)
+        };
+
+        let mut stack: Vec<Value> = vec![];
+        let mut stacked = Stacked {
+            values: vec![],
+            operands: vec![],
+        };
+        for (j, args) in operands.iter().enumerate() {
+            let m = (0..=args.len())
+                .rev()
+                .find(|&m| {
+                    args[..m]
+                        .iter()
+                        .all(|&a| stack.contains(&a) && candidate(a, j))
+                        && {
+                            let rest: Vec<_> = stack
+                                .iter()
+                                .copied()
+                                .filter(|v| !args[m..].contains(v))
+                                .collect();
+                            rest.ends_with(&args[..m])
+                        }
+                })
+                .unwrap_or(0);
+            stack.retain(|v| !args[m..].contains(v));
+            stacked.values.retain(|v| !args[m..].contains(v));
+            stack.truncate(stack.len() - m);
+            stacked.operands.push(m);
+
+            if let Some(inst) = b.insts.get(j) {
+                if let [r] = *inst.results {
+                    if user.get(&r).is_some_and(|&u| u > j && candidate(r, u)) {
+                        stack.push(r);
+                        stacked.values.push(r);
+                    }
+                }
+            }
+        }
+        stacked
+    }
+}
+
+/// Assigns locals to values as they are first needed.
+struct Slots<'a> {
+    types: &'a [Valtype],
+    /// Constants, which are materialized at each use instead.
+    consts: HashMap<Value, Instr>,
+    locals: Vec<Valtype>,
+    local_of: Vec<Option<u32>>,
+}
+
+impl Slots<'_> {
+    fn get(&mut self, v: Value) -> Localidx {
+        let x = *self.local_of[v.0 as usize].get_or_insert_with(|| {
+            self.locals.push(self.types[v.0 as usize]);
+            self.locals.len() as u32 - 1
+        });
+        Localidx(x)
+    }
+
+    fn load(&mut self, vals: &[Value], instrs: &mut Vec<Instr>) {
+        for &v in vals {
+            let instr = match self.consts.get(&v) {
+                Some(c) => c.clone(),
+                None => Instr::LocalGet(self.get(v)),
+            };
+            instrs.push(instr);
+        }
+    }
+
+    fn store(&mut self, vals: &[Value], instrs: &mut Vec<Instr>) {
+        for &v in vals.iter().rev() {
+            instrs.push(Instr::LocalSet(self.get(v)));
+        }
+    }
+}
+
+fn is_const(op: &Instr) -> bool {
+    matches!(
+        op,
+        Instr::I32Const(_)
+            | Instr::I64Const(_)
+            | Instr::F32Const(_)
+            | Instr::F64Const(_)
+            | Instr::V128Const(_)
+            | Instr::RefNull(_)
+            | Instr::RefFunc(_)
+    )
+}
+
+struct Stacked {
+    /// The values that never leave the stack.
+    values: Vec<Value>,
+    /// How many leading operands of each instruction, and then the
+    /// terminator, are already on the stack.
+    operands: Vec<usize>,
+}
@@ -0,0 +1,414 @@
+use crate::{
+    instructions::{
+        Blocktype, Instr, MemoryMemarg, Opcode, TruncSat, VectorLaneidx, VectorMemarg,
+        VectorMemargLaneidx, VectorNoImmediate,
+    },
+    modules::{Func, Funcidx, Globalidx, Labelidx, Localidx, Module, Tableidx, Typeidx},
+    types::{Functype, Numtype, Reftype, Resulttype, Valtype, Vectype},
+    Vector,
+};
+
+const I32: Valtype = Valtype::Numtype(Numtype::I32);
+const I64: Valtype = Valtype::Numtype(Numtype::I64);
+const F32: Valtype = Valtype::Numtype(Numtype::F32);
+const F64: Valtype = Valtype::Numtype(Numtype::F64);
+const V128: Valtype = Valtype::Vectype(Vectype::V128);
+const FUNCREF: Valtype = Valtype::Reftype(Reftype::Funcref);
+
+/// Resolves the indices an instruction's stack effect depends on.
+pub trait Context {
+    fn ty(&self, x: Typeidx) -> Option<Functype>;
+    fn func(&self, x: Funcidx) -> Option<Functype>;
+    fn local(&self, x: Localidx) -> Option<Valtype>;
+    fn global(&self, x: Globalidx) -> Option<Valtype>;
+    fn table(&self, x: Tableidx) -> Option<Reftype>;
+    /// The operands a branch to the label carries.
+    fn label(&self, l: Labelidx) -> Option<Box<[Valtype]>>;
+    /// The results of the enclosing function.
+    fn results(&self) -> Option<Box<[Valtype]>>;
+}
+
+/// A [`Context`] for the body of a function in a module. Labels are pushed
+/// and popped by the caller while walking nested blocks, innermost last:
+/// the results of a `block` or `if`, and the parameters of a `loop`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuncContext<'a> {
+    pub module: &'a Module,
+    pub locals: Box<[Valtype]>,
+    pub labels: Vec<Box<[Valtype]>>,
+    pub results: Box<[Valtype]>,
+}
+
+impl<'a> FuncContext<'a> {
+    /// Starts with the function's parameters and locals and, as the only
+    /// label, the function body.
+    pub fn new(module: &'a Module, ty: &Functype, f: &Func) -> Self {
+        let locals = ty
+            .parameters
+            .0
+             .0
+            .iter()
+            .copied()
+            .chain(
+                f.t.0
+                    .iter()
+                    .flat_map(|l| std::iter::repeat_n(l.t, l.n as usize)),
+            )
+            .collect();
+        Self {
+            module,
+            locals,
+            labels: vec![ty.results.0 .0.clone()],
+            results: ty.results.0 .0.clone(),
+        }
+    }
+}
+
+impl Context for FuncContext<'_> {
+    fn ty(&self, x: Typeidx) -> Option<Functype> {
+        self.module
+            .typesec
+            .as_ref()?
+            .0
+             .0
+             .0
+            .get(x.0 as usize)
+            .cloned()
+    }
+
+    fn func(&self, x: Funcidx) -> Option<Functype> {
+        self.module.func_type(x).cloned()
+    }
+
+    fn local(&self, x: Localidx) -> Option<Valtype> {
+        self.locals.get(x.0 as usize).copied()
+    }
+
+    fn global(&self, x: Globalidx) -> Option<Valtype> {
+        self.module.global_type(x).map(|g| g.ty)
+    }
+
+    fn table(&self, x: Tableidx) -> Option<Reftype> {
+        self.module.table_type(x).map(|t| t.element_type)
+    }
+
+    fn label(&self, l: Labelidx) -> Option<Box<[Valtype]>> {
+        let i = self.labels.len().checked_sub(l.0 as usize + 1)?;
+        self.labels.get(i).cloned()
+    }
+
+    fn results(&self) -> Option<Box<[Valtype]>> {
+        Some(self.results.clone())
+    }
+}
+
+impl Blocktype {
+    pub fn functype(&self, ctx: &impl Context) -> Option<Functype> {
+        match *self {
+            Blocktype::Empty => Some(sig(&[], &[])),
+            Blocktype::ValueType(t) => Some(sig(&[], &[t])),
+            Blocktype::TypeIndex(x) => ctx.ty(Typeidx(u32::try_from(x.0).ok()?)),
+        }
+    }
+}
+
+impl Instr {
+    /// The types the instruction pops and pushes, as a function type.
+    ///
+    /// Branches, `return` and `unreachable` give the operands they consume
+    /// and push nothing, though code after them is stack-polymorphic. `drop`,
+    /// `select` without a type annotation and `ref.is_null` accept operands
+    /// of more than one type and give `None`, as do indices the context
+    /// can't resolve.
+    pub fn stack_effect(&self, ctx: &impl Context) -> Option<Functype> {
+        Some(match self {
+            Instr::Opcode(op) => op.stack_effect(ctx)?,
+            Instr::Block(bt, _) | Instr::Loop(bt, _) => bt.functype(ctx)?,
+            Instr::If(bt, _) | Instr::IfElse(bt, _, _) => {
+                let ty = bt.functype(ctx)?;
+                let mut params = ty.parameters.0 .0.into_vec();
+                params.push(I32);
+                Functype {
+                    parameters: Resulttype(Vector(params.into_boxed_slice())),
+                    results: ty.results,
+                }
+            }
+            Instr::Br(l) => sig(&ctx.label(*l)?, &[]),
+            Instr::BrIf(l) => {
+                let label = ctx.label(*l)?;
+                let mut params = label.to_vec();
+                params.push(I32);
+                sig(&params, &label)
+            }
+            Instr::BrTable(_, l) => {
+                let mut params = ctx.label(*l)?.into_vec();
+                params.push(I32);
+                sig(&params, &[])
+            }
+            Instr::Call(x) => ctx.func(*x)?,
+            Instr::CallIndirect(y, _) => {
+                let ty = ctx.ty(*y)?;
+                let mut params = ty.parameters.0 .0.into_vec();
+                params.push(I32);
+                Functype {
+                    parameters: Resulttype(Vector(params.into_boxed_slice())),
+                    results: ty.results,
+                }
+            }
+
+            Instr::RefNull(t) => sig(&[], &[Valtype::Reftype(*t)]),
+            Instr::RefFunc(_) => sig(&[], &[FUNCREF]),
+
+            Instr::Select(None) => return None,
+            Instr::Select(Some(t)) => match *t.0 {
+                [t] => sig(&[t, t, I32], &[t]),
+                _ => return None,
+            },
+
+            Instr::LocalGet(x) => sig(&[], &[ctx.local(*x)?]),
+            Instr::LocalSet(x) => sig(&[ctx.local(*x)?], &[]),
+            Instr::LocalTee(x) => {
+                let t = ctx.local(*x)?;
+                sig(&[t], &[t])
+            }
+            Instr::GlobalGet(x) => sig(&[], &[ctx.global(*x)?]),
+            Instr::GlobalSet(x) => sig(&[ctx.global(*x)?], &[]),
+
+            Instr::TableGet(x) => sig(&[I32], &[Valtype::Reftype(ctx.table(*x)?)]),
+            Instr::TableSet(x) => sig(&[I32, Valtype::Reftype(ctx.table(*x)?)], &[]),
+            Instr::TableInit(..) | Instr::TableCopy(..) => sig(&[I32, I32, I32], &[]),
+            Instr::ElemDrop(_) => sig(&[], &[]),
+            Instr::TableGrow(x) => sig(&[Valtype::Reftype(ctx.table(*x)?), I32], &[I32]),
+            Instr::TableSize(_) => sig(&[], &[I32]),
+            Instr::TableFill(x) => sig(&[I32, Valtype::Reftype(ctx.table(*x)?), I32], &[]),
+
+            Instr::MemoryMemarg(op, _) => op.stack_effect(),
+            Instr::MemorySize => sig(&[], &[I32]),
+            Instr::MemoryGrow => sig(&[I32], &[I32]),
+            Instr::MemoryInit(_) | Instr::MemoryCopy | Instr::MemoryFill => {
+                sig(&[I32, I32, I32], &[])
+            }
+            Instr::DataDrop(_) => sig(&[], &[]),
+
+            Instr::I32Const(_) => sig(&[], &[I32]),
+            Instr::I64Const(_) => sig(&[], &[I64]),
+            Instr::F32Const(_) => sig(&[], &[F32]),
+            Instr::F64Const(_) => sig(&[], &[F64]),
+            Instr::TruncSat(op) => op.stack_effect(),
+
+            Instr::V128Const(_) => sig(&[], &[V128]),
+            Instr::I8x16Shuffle(_) => sig(&[V128, V128], &[V128]),
+            Instr::VectorMemarg(op, _) => op.stack_effect(),
+            Instr::VectorMemargLaneidx(op, _, _) => op.stack_effect(),
+            Instr::VectorLaneidx(op, _) => op.stack_effect(),
+            Instr::VectorNoImmediate(op) => op.stack_effect(),
+        })
+    }
+}
+
+impl Opcode {
+    /// See [`Instr::stack_effect`].
+    pub fn stack_effect(&self, ctx: &impl Context) -> Option<Functype> {
+        use Opcode::*;
+        Some(match self {
+            Unreachable | Nop => sig(&[], &[]),
+            Return => sig(&ctx.results()?, &[]),
+            RefIsNull | Drop => return None,
+
+            I32Eqz => sig(&[I32], &[I32]),
+            I32Eq | I32Ne | I32LtS | I32LtU | I32GtS | I32GtU | I32LeS | I32LeU | I32GeS
+            | I32GeU => sig(&[I32, I32], &[I32]),
+            I64Eqz => sig(&[I64], &[I32]),
+            I64Eq | I64Ne | I64LtS | I64LtU | I64GtS | I64GtU | I64LeS | I64LeU | I64GeS
+            | I64GeU => sig(&[I64, I64], &[I32]),
+            F32Eq | F32Ne | F32Lt | F32Gt | F32Le | F32Ge => sig(&[F32, F32], &[I32]),
+            F64Eq | F64Ne | F64Lt | F64Gt | F64Le | F64Ge => sig(&[F64, F64], &[I32]),
+
+            I32Clz | I32Ctz | I32Popcnt | I32Extend8S | I32Extend16S => sig(&[I32], &[I32]),
+            I32Add | I32Sub | I32Mul | I32DivS | I32DivU | I32RemS | I32RemU | I32And | I32Or
+            | I32Xor | I32Shl | I32ShrS | I32ShrU | I32Rotl | I32Rotr => sig(&[I32, I32], &[I32]),
+            I64Clz | I64Ctz | I64Popcnt | I64Extend8S | I64Extend16S | I64Extend32S => {
+                sig(&[I64], &[I64])
+            }
+            I64Add | I64Sub | I64Mul | I64DivS | I64DivU | I64RemS | I64RemU | I64And | I64Or
+            | I64Xor | I64Shl | I64ShrS | I64ShrU | I64Rotl | I64Rotr => sig(&[I64, I64], &[I64]),
+            F32Abs | F32Neg | F32Ceil | F32Floor | F32Trunc | F32Nearest | F32Sqrt => {
+                sig(&[F32], &[F32])
+            }
+            F32Add | F32Sub | F32Mul | F32Div | F32Min | F32Max | F32Copysign => {
+                sig(&[F32, F32], &[F32])
+            }
+            F64Abs | F64Neg | F64Ceil | F64Floor | F64Trunc | F64Nearest | F64Sqrt => {
+                sig(&[F64], &[F64])
+            }
+            F64Add | F64Sub | F64Mul | F64Div | F64Min | F64Max | F64Copysign => {
+                sig(&[F64, F64], &[F64])
+            }
+
+            I32WrapI64 => sig(&[I64], &[I32]),
+            I32TruncF32S | I32TruncF32U | I32ReinterpretF32 => sig(&[F32], &[I32]),
+            I32TruncF64S | I32TruncF64U => sig(&[F64], &[I32]),
+            I64ExtendI32S | I64ExtendI32U => sig(&[I32], &[I64]),
+            I64TruncF32S | I64TruncF32U => sig(&[F32], &[I64]),
+            I64TruncF64S | I64TruncF64U | I64ReinterpretF64 => sig(&[F64], &[I64]),
+            F32ConvertI32S | F32ConvertI32U | F32ReinterpretI32 => sig(&[I32], &[F32]),
+            F32ConvertI64S | F32ConvertI64U => sig(&[I64], &[F32]),
+            F32DemoteF64 => sig(&[F64], &[F32]),
+            F64ConvertI32S | F64ConvertI32U => sig(&[I32], &[F64]),
+            F64ConvertI64S | F64ConvertI64U | F64ReinterpretI64 => sig(&[I64], &[F64]),
+            F64PromoteF32 => sig(&[F32], &[F64]),
+        })
+    }
+}
+
+impl MemoryMemarg {
+    pub fn stack_effect(&self) -> Functype {
+        use MemoryMemarg::*;
+        match self {
+            I32Load | I32Load8S | I32Load8U | I32Load16S | I32Load16U => sig(&[I32], &[I32]),
+            I64Load | I64Load8S | I64Load8U | I64Load16S | I64Load16U | I64Load32S | I64Load32U => {
+                sig(&[I32], &[I64])
+            }
+            F32Load => sig(&[I32], &[F32]),
+            F64Load => sig(&[I32], &[F64]),
+            I32Store | I32Store8 | I32Store16 => sig(&[I32, I32], &[]),
+            I64Store | I64Store8 | I64Store16 | I64Store32 => sig(&[I32, I64], &[]),
+            F32Store => sig(&[I32, F32], &[]),
+            F64Store => sig(&[I32, F64], &[]),
+        }
+    }
+}
+
+impl TruncSat {
+    pub fn stack_effect(&self) -> Functype {
+        use TruncSat::*;
+        match self {
+            I32TruncSatF32S | I32TruncSatF32U => sig(&[F32], &[I32]),
+            I32TruncSatF64S | I32TruncSatF64U => sig(&[F64], &[I32]),
+            I64TruncSatF32S | I64TruncSatF32U => sig(&[F32], &[I64]),
+            I64TruncSatF64S | I64TruncSatF64U => sig(&[F64], &[I64]),
+        }
+    }
+}
+
+impl VectorMemarg {
+    pub fn stack_effect(&self) -> Functype {
+        match self {
+            VectorMemarg::V128Store => sig(&[I32, V128], &[]),
+            _ => sig(&[I32], &[V128]),
+        }
+    }
+}
+
+impl VectorMemargLaneidx {
+    pub fn stack_effect(&self) -> Functype {
+        use VectorMemargLaneidx::*;
+        match self {
+            V128Load8Lane | V128Load16Lane | V128Load32Lane | V128Load64Lane => {
+                sig(&[I32, V128], &[V128])
+            }
+            V128Store8Lane | V128Store16Lane | V128Store32Lane | V128Store64Lane => {
+                sig(&[I32, V128], &[])
+            }
+        }
+    }
+}
+
+impl VectorLaneidx {
+    pub fn stack_effect(&self) -> Functype {
+        use VectorLaneidx::*;
+        match self {
+            I8x16ExtractLaneS | I8x16ExtractLaneU | I16x8ExtractLaneS | I16x8ExtractLaneU
+            | I32x4ExtractLane => sig(&[V128], &[I32]),
+            I64x2ExtractLane => sig(&[V128], &[I64]),
+            F32x4ExtractLane => sig(&[V128], &[F32]),
+            F64x2ExtractLane => sig(&[V128], &[F64]),
+            I8x16ReplaceLane | I16x8ReplaceLane | I32x4ReplaceLane => sig(&[V128, I32], &[V128]),
+            I64x2ReplaceLane => sig(&[V128, I64], &[V128]),
+            F32x4ReplaceLane => sig(&[V128, F32], &[V128]),
+            F64x2ReplaceLane => sig(&[V128, F64], &[V128]),
+        }
+    }
+}
+
+impl VectorNoImmediate {
+    pub fn stack_effect(&self) -> Functype {
+        use VectorNoImmediate::*;
+        match self {
+            I8x16Splat | I16x8Splat | I32x4Splat => sig(&[I32], &[V128]),
+            I64x2Splat => sig(&[I64], &[V128]),
+            F32x4Splat => sig(&[F32], &[V128]),
+            F64x2Splat => sig(&[F64], &[V128]),
+
+            V128AnyTrue | I8x16AllTrue | I8x16Bitmask | I16x8AllTrue | I16x8Bitmask
+            | I32x4AllTrue | I32x4Bitmask | I64x2AllTrue | I64x2Bitmask => sig(&[V128], &[I32]),
+
+            I8x16Shl | I8x16ShrS | I8x16ShrU | I16x8Shl | I16x8ShrS | I16x8ShrU | I32x4Shl
+            | I32x4ShrS | I32x4ShrU | I64x2Shl | I64x2ShrS | I64x2ShrU => {
+                sig(&[V128, I32], &[V128])
+            }
+
+            V128Bitselect => sig(&[V128, V128, V128], &[V128]),
+
+            V128Not
+            | I8x16Abs
+            | I8x16Neg
+            | I8x16Popcnt
+            | I16x8ExtaddPairwiseI8x16S
+            | I16x8ExtaddPairwiseI8x16U
+            | I16x8Abs
+            | I16x8Neg
+            | I16x8ExtendLowI8x16S
+            | I16x8ExtendHighI8x16S
+            | I16x8ExtendLowI8x16U
+            | I16x8ExtendHighI8x16U
+            | I32x4ExtaddPairwiseI16x8S
+            | I32x4ExtaddPairwiseI16x8U
+            | I32x4Abs
+            | I32x4Neg
+            | I32x4ExtendLowI16x8S
+            | I32x4ExtendHighI16x8S
+            | I32x4ExtendLowI16x8U
+            | I32x4ExtendHighI16x8U
+            | I64x2Abs
+            | I64x2Neg
+            | I64x2ExtendLowI32x4S
+            | I64x2ExtendHighI32x4S
+            | I64x2ExtendLowI32x4U
+            | I64x2ExtendHighI32x4U
+            | F32x4Ceil
+            | F32x4Floor
+            | F32x4Trunc
+            | F32x4Nearest
+            | F32x4Abs
+            | F32x4Neg
+            | F32x4Sqrt
+            | F64x2Ceil
+            | F64x2Floor
+            | F64x2Trunc
+            | F64x2Nearest
+            | F64x2Abs
+            | F64x2Neg
+            | F64x2Sqrt
+            | I32x4TruncSatF32x4S
+            | I32x4TruncSatF32x4U
+            | F32x4ConvertI32x4S
+            | F32x4ConvertI32x4U
+            | I32x4TruncSatF64x2SZero
+            | I32x4TruncSatF64x2UZero
+            | F64x2ConvertLowI32x4S
+            | F64x2ConvertLowI32x4U
+            | F32x4DemoteF64x2Zero
+            | F64x2PromoteLowF32x4 => sig(&[V128], &[V128]),
+
+            _ => sig(&[V128, V128], &[V128]),
+        }
+    }
+}
+
+fn sig(params: &[Valtype], results: &[Valtype]) -> Functype {
+    Functype {
+        parameters: Resulttype(Vector(params.into())),
+        results: Resulttype(Vector(results.into())),
+    }
+}
@@ -1,8 +1,13 @@
-use crate::{Grammar, Vector};
+use crate::{
+    instructions::Instr,
+    values::{F32, F64},
+    Grammar, Vector,
+};
 use std::io::{self, Write};
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Numtype {
     I32 = 0x7f,
     I64 = 0x7e,
@@ -18,6 +23,7 @@ impl Grammar for Numtype {
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Vectype {
     V128 = 0x7b,
 }
@@ -30,6 +36,7 @@ impl Grammar for Vectype {
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Reftype {
     Funcref = 0x70,
     Externref = 0x6f,
@@ -42,6 +49,7 @@ impl Grammar for Reftype {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Valtype {
     Numtype(Numtype),
     Vectype(Vectype),
@@ -59,7 +67,22 @@ impl Grammar for Valtype {
     }
 }
 
+impl Valtype {
+    /// A constant instruction pushing the type's zero or null value.
+    pub fn zero_instr(self) -> Instr {
+        match self {
+            Valtype::Numtype(Numtype::I32) => Instr::I32Const(0),
+            Valtype::Numtype(Numtype::I64) => Instr::I64Const(0),
+            Valtype::Numtype(Numtype::F32) => Instr::F32Const(F32(0)),
+            Valtype::Numtype(Numtype::F64) => Instr::F64Const(F64(0)),
+            Valtype::Vectype(Vectype::V128) => Instr::V128Const([0; 16]),
+            Valtype::Reftype(r) => Instr::RefNull(r),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Resulttype(pub Vector<Valtype>);
 
 impl Grammar for Resulttype {
@@ -69,6 +92,7 @@ impl Grammar for Resulttype {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Functype {
     pub parameters: Resulttype,
     pub results: Resulttype,
@@ -83,6 +107,7 @@ impl Grammar for Functype {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Limits {
     Min(u32),
     MinMax(u32, u32),
@@ -105,6 +130,7 @@ impl Grammar for Limits {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Memtype(pub Limits);
 
 impl Grammar for Memtype {
@@ -114,6 +140,7 @@ impl Grammar for Memtype {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Tabletype {
     pub element_type: Reftype,
     pub limits: Limits,
@@ -128,6 +155,7 @@ impl Grammar for Tabletype {
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Mut {
     Const = 0x00,
     Var = 0x01,
@@ -140,6 +168,7 @@ impl Grammar for Mut {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Globaltype {
     pub ty: Valtype,
     pub mutability: Mut,
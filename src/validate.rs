@@ -0,0 +1,630 @@
+use crate::{
+    instructions::{
+        Blocktype, Expr, Instr, MemoryMemarg, Opcode, VectorLaneidx, VectorMemarg,
+        VectorMemargLaneidx,
+    },
+    modules::{Data, Elem, Exportdesc, Funcidx, Importdesc, Module, Tableidx},
+    stack::{Context, FuncContext},
+    types::{Functype, Limits, Mut, Numtype, Reftype, Valtype},
+};
+use std::{collections::HashSet, fmt};
+
+const I32: Valtype = Valtype::Numtype(Numtype::I32);
+
+/// The most pages a 32-bit memory can have.
+const MAX_PAGES: u32 = 65536;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Error(pub String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+type Result<T> = std::result::Result<T, Error>;
+
+macro_rules! bail {
+    ($($arg:tt)*) => {
+        return Err(Error(format!($($arg)*)))
+    };
+}
+
+/// Checks that a module is valid, as the spec's validation rules define it.
+pub fn validate(m: &Module) -> Result<()> {
+    Validator::new(m).module()
+}
+
+struct Validator<'a> {
+    m: &'a Module,
+    types: usize,
+    funcs: u32,
+    tables: u32,
+    mems: u32,
+    globals: u32,
+    elems: u32,
+    datas: Option<u32>,
+    /// Functions `ref.func` may name: those referenced outside of function
+    /// bodies.
+    refs: HashSet<u32>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(m: &'a Module) -> Self {
+        let len = |n: Option<usize>| n.unwrap_or(0) as u32;
+        let mut refs = HashSet::new();
+        let mut add = |x: Funcidx| {
+            refs.insert(x.0);
+        };
+        for g in m.globalsec.iter().flat_map(|g| g.0 .0 .0.iter()) {
+            expr_refs(&g.e, &mut add);
+        }
+        for e in m.elemsec.iter().flat_map(|e| e.0 .0 .0.iter()) {
+            match e {
+                Elem::FuncrefFuncActive(_, y)
+                | Elem::ElemkindFuncPassive(_, y)
+                | Elem::ElemkindFuncActive(_, _, _, y)
+                | Elem::ElemkindFuncDeclarative(_, y) => y.0.iter().copied().for_each(&mut add),
+                Elem::FuncrefExprActive(_, el)
+                | Elem::ReftypeExprPassive(_, el)
+                | Elem::ReftypeExprActive(_, _, _, el)
+                | Elem::ReftypeExprDeclarative(_, el) => {
+                    el.0.iter().for_each(|e| expr_refs(e, &mut add))
+                }
+            }
+        }
+        for e in m.exportsec.iter().flat_map(|e| e.0 .0 .0.iter()) {
+            if let Exportdesc::Func(x) = e.d {
+                add(x);
+            }
+        }
+        Self {
+            m,
+            types: m.typesec.as_ref().map_or(0, |t| t.0 .0 .0.len()),
+            funcs: m.imported_funcs() + len(m.funcsec.as_ref().map(|f| f.0 .0 .0.len())),
+            tables: m.imported_tables() + len(m.tablesec.as_ref().map(|t| t.0 .0 .0.len())),
+            mems: m.imported_mems() + len(m.memsec.as_ref().map(|t| t.0 .0 .0.len())),
+            globals: m.imported_globals() + len(m.globalsec.as_ref().map(|g| g.0 .0 .0.len())),
+            elems: len(m.elemsec.as_ref().map(|e| e.0 .0 .0.len())),
+            datas: m.datacountsec.as_ref().map(|n| n.0 .0),
+            refs,
+        }
+    }
+
+    fn module(&self) -> Result<()> {
+        let m = self.m;
+        for i in m.importsec.iter().flat_map(|i| i.0 .0 .0.iter()) {
+            match i.d {
+                Importdesc::Func(t) => self.typeidx(t.0)?,
+                Importdesc::Table(t) => limits(t.limits, u32::MAX, "table")?,
+                Importdesc::Mem(t) => limits(t.0, MAX_PAGES, "memory")?,
+                Importdesc::Global(_) => {}
+            }
+        }
+        for t in m.funcsec.iter().flat_map(|f| f.0 .0 .0.iter()) {
+            self.typeidx(t.0)?;
+        }
+        for t in m.tablesec.iter().flat_map(|t| t.0 .0 .0.iter()) {
+            limits(t.0.limits, u32::MAX, "table")?;
+        }
+        for t in m.memsec.iter().flat_map(|t| t.0 .0 .0.iter()) {
+            limits(t.0 .0, MAX_PAGES, "memory")?;
+        }
+        if self.mems > 1 {
+            bail!("multiple memories");
+        }
+        for g in m.globalsec.iter().flat_map(|g| g.0 .0 .0.iter()) {
+            self.const_expr(&g.e, g.gt.ty)?;
+        }
+
+        let mut names = HashSet::new();
+        for e in m.exportsec.iter().flat_map(|e| e.0 .0 .0.iter()) {
+            if !names.insert(&e.nm.0) {
+                bail!("duplicate export name {:?}", e.nm.0);
+            }
+            match e.d {
+                Exportdesc::Func(x) => self.index(x.0, self.funcs, "function")?,
+                Exportdesc::Table(x) => self.index(x.0, self.tables, "table")?,
+                Exportdesc::Mem(x) => self.index(x.0, self.mems, "memory")?,
+                Exportdesc::Global(x) => self.index(x.0, self.globals, "global")?,
+            }
+        }
+
+        if let Some(start) = &m.startsec {
+            let x = start.0 .0 .0;
+            self.index(x.0, self.funcs, "function")?;
+            match m.func_type(x) {
+                Some(t) if t.parameters.0 .0.is_empty() && t.results.0 .0.is_empty() => {}
+                _ => bail!("start function"),
+            }
+        }
+
+        for e in m.elemsec.iter().flat_map(|e| e.0 .0 .0.iter()) {
+            self.elem(e)?;
+        }
+
+        let datas = m.datasec.as_ref().map_or(0, |d| d.0 .0 .0.len());
+        if self.datas.is_some_and(|n| n as usize != datas) {
+            bail!("data count and data section have inconsistent lengths");
+        }
+        for d in m.datasec.iter().flat_map(|d| d.0 .0 .0.iter()) {
+            match d {
+                Data::Passive(_) => {}
+                Data::ActiveAtZero(e, _) => {
+                    self.index(0, self.mems, "memory")?;
+                    self.const_expr(e, I32)?;
+                }
+                Data::ActiveAtIndex(x, e, _) => {
+                    self.index(x.0, self.mems, "memory")?;
+                    self.const_expr(e, I32)?;
+                }
+            }
+        }
+
+        let funcs = m.funcsec.as_ref().map_or(&[][..], |f| &f.0 .0 .0);
+        let codes = m.codesec.as_ref().map_or(&[][..], |c| &c.0 .0 .0);
+        if funcs.len() != codes.len() {
+            bail!("function and code section have inconsistent lengths");
+        }
+        for (i, (t, code)) in funcs.iter().zip(codes.iter()).enumerate() {
+            let ty = &m.typesec.as_ref().unwrap().0 .0 .0[t.0 as usize];
+            let ctx = FuncContext::new(m, ty, &code.0);
+            Checker::new(self, ctx)
+                .run(&code.0.e.0, &ty.results.0 .0)
+                .map_err(|Error(e)| Error(format!("function {i}: {e}")))?;
+        }
+        Ok(())
+    }
+
+    fn index(&self, x: u32, n: u32, what: &str) -> Result<()> {
+        if x >= n {
+            bail!("unknown {what} {x}");
+        }
+        Ok(())
+    }
+
+    fn typeidx(&self, x: u32) -> Result<()> {
+        self.index(x, self.types as u32, "type")
+    }
+
+    fn table(&self, x: Tableidx) -> Result<Reftype> {
+        match self.m.table_type(x) {
+            Some(t) => Ok(t.element_type),
+            None => bail!("unknown table {}", x.0),
+        }
+    }
+
+    fn elem_type(&self, x: u32) -> Result<Reftype> {
+        match self
+            .m
+            .elemsec
+            .as_ref()
+            .and_then(|e| e.0 .0 .0.get(x as usize))
+        {
+            Some(e) => Ok(elem_type(e)),
+            None => bail!("unknown elem segment {x}"),
+        }
+    }
+
+    fn elem(&self, e: &Elem) -> Result<()> {
+        let t = elem_type(e);
+        match e {
+            Elem::FuncrefFuncActive(offset, _) | Elem::FuncrefExprActive(offset, _) => {
+                self.active(Tableidx(0), offset, t)?
+            }
+            Elem::ElemkindFuncActive(x, offset, _, _)
+            | Elem::ReftypeExprActive(x, offset, _, _) => self.active(*x, offset, t)?,
+            _ => {}
+        }
+        match e {
+            Elem::FuncrefFuncActive(_, y)
+            | Elem::ElemkindFuncPassive(_, y)
+            | Elem::ElemkindFuncActive(_, _, _, y)
+            | Elem::ElemkindFuncDeclarative(_, y) => {
+                for x in y.0.iter() {
+                    self.index(x.0, self.funcs, "function")?;
+                }
+            }
+            Elem::FuncrefExprActive(_, el)
+            | Elem::ReftypeExprPassive(_, el)
+            | Elem::ReftypeExprActive(_, _, _, el)
+            | Elem::ReftypeExprDeclarative(_, el) => {
+                for e in el.0.iter() {
+                    self.const_expr(e, Valtype::Reftype(t))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn active(&self, x: Tableidx, offset: &Expr, t: Reftype) -> Result<()> {
+        if self.table(x)? != t {
+            bail!("type mismatch: elem segment doesn't match table {}", x.0);
+        }
+        self.const_expr(offset, I32)
+    }
+
+    /// Checks that an initializer only uses constant instructions and
+    /// produces a `t`.
+    fn const_expr(&self, e: &Expr, t: Valtype) -> Result<()> {
+        for i in e.0.iter() {
+            match i {
+                Instr::I32Const(_)
+                | Instr::I64Const(_)
+                | Instr::F32Const(_)
+                | Instr::F64Const(_)
+                | Instr::V128Const(_)
+                | Instr::RefNull(_)
+                | Instr::RefFunc(_) => {}
+                // Only imported immutable globals are in scope.
+                Instr::GlobalGet(x) => {
+                    self.index(x.0, self.m.imported_globals(), "global")?;
+                    if self.m.global_type(*x).unwrap().mutability != Mut::Const {
+                        bail!("constant expression required");
+                    }
+                }
+                _ => bail!("constant expression required"),
+            }
+        }
+        let ctx = FuncContext {
+            module: self.m,
+            locals: Box::new([]),
+            labels: vec![Box::new([t])],
+            results: Box::new([t]),
+        };
+        Checker::new(self, ctx).run(&e.0, &[t])
+    }
+}
+
+fn ensure(ok: bool, message: &str) -> Result<()> {
+    match ok {
+        true => Ok(()),
+        false => Err(Error(message.into())),
+    }
+}
+
+fn limits(l: Limits, max: u32, what: &str) -> Result<()> {
+    let (min, m) = match l {
+        Limits::Min(min) => (min, None),
+        Limits::MinMax(min, m) => (min, Some(m)),
+    };
+    if min > max || m.is_some_and(|m| m > max) {
+        bail!("{what} size must be at most {max}");
+    }
+    if m.is_some_and(|m| m < min) {
+        bail!("size minimum must not be greater than maximum");
+    }
+    Ok(())
+}
+
+pub(crate) fn elem_type(e: &Elem) -> Reftype {
+    match e {
+        Elem::FuncrefFuncActive(..)
+        | Elem::ElemkindFuncPassive(..)
+        | Elem::ElemkindFuncActive(..)
+        | Elem::ElemkindFuncDeclarative(..)
+        | Elem::FuncrefExprActive(..) => Reftype::Funcref,
+        Elem::ReftypeExprPassive(t, _)
+        | Elem::ReftypeExprActive(_, _, t, _)
+        | Elem::ReftypeExprDeclarative(t, _) => *t,
+    }
+}
+
+fn expr_refs(e: &Expr, f: &mut impl FnMut(Funcidx)) {
+    for i in e.0.iter() {
+        if let Instr::RefFunc(x) = i {
+            f(*x)
+        }
+    }
+}
+
+/// log2 of the bytes a memory instruction accesses.
+pub(crate) fn memory_width(op: MemoryMemarg) -> u32 {
+    use MemoryMemarg::*;
+    match op {
+        I32Load8S | I32Load8U | I64Load8S | I64Load8U | I32Store8 | I64Store8 => 0,
+        I32Load16S | I32Load16U | I64Load16S | I64Load16U | I32Store16 | I64Store16 => 1,
+        I32Load | F32Load | I64Load32S | I64Load32U | I32Store | F32Store | I64Store32 => 2,
+        I64Load | F64Load | I64Store | F64Store => 3,
+    }
+}
+
+pub(crate) fn vector_width(op: VectorMemarg) -> u32 {
+    use VectorMemarg::*;
+    match op {
+        V128Load8Splat => 0,
+        V128Load16Splat => 1,
+        V128Load32Splat | V128Load32Zero => 2,
+        V128Load8x8S | V128Load8x8U | V128Load16x4S | V128Load16x4U | V128Load32x2S
+        | V128Load32x2U | V128Load64Splat | V128Load64Zero => 3,
+        V128Load | V128Store => 4,
+    }
+}
+
+pub(crate) fn lane_width(op: VectorMemargLaneidx) -> u32 {
+    use VectorMemargLaneidx::*;
+    match op {
+        V128Load8Lane | V128Store8Lane => 0,
+        V128Load16Lane | V128Store16Lane => 1,
+        V128Load32Lane | V128Store32Lane => 2,
+        V128Load64Lane | V128Store64Lane => 3,
+    }
+}
+
+pub(crate) fn lanes(op: VectorLaneidx) -> u8 {
+    use VectorLaneidx::*;
+    match op {
+        I8x16ExtractLaneS | I8x16ExtractLaneU | I8x16ReplaceLane => 16,
+        I16x8ExtractLaneS | I16x8ExtractLaneU | I16x8ReplaceLane => 8,
+        I32x4ExtractLane | I32x4ReplaceLane | F32x4ExtractLane | F32x4ReplaceLane => 4,
+        I64x2ExtractLane | I64x2ReplaceLane | F64x2ExtractLane | F64x2ReplaceLane => 2,
+    }
+}
+
+/// Type checks a function body with an operand stack of types, `None`
+/// standing for any type once the code is unreachable.
+struct Checker<'v, 'a> {
+    v: &'v Validator<'a>,
+    ctx: FuncContext<'a>,
+    vals: Vec<Option<Valtype>>,
+    /// The operand stack height at the start of the innermost block.
+    height: usize,
+    unreachable: bool,
+}
+
+impl<'v, 'a> Checker<'v, 'a> {
+    fn new(v: &'v Validator<'a>, ctx: FuncContext<'a>) -> Self {
+        Self {
+            v,
+            ctx,
+            vals: vec![],
+            height: 0,
+            unreachable: false,
+        }
+    }
+
+    fn run(mut self, body: &[Instr], results: &[Valtype]) -> Result<()> {
+        self.instrs(body)?;
+        self.end(results)
+    }
+
+    fn push(&mut self, t: Option<Valtype>) {
+        self.vals.push(t);
+    }
+
+    fn pop(&mut self, expected: Option<Valtype>) -> Result<Option<Valtype>> {
+        if self.vals.len() == self.height {
+            if self.unreachable {
+                return Ok(None);
+            }
+            bail!("type mismatch: expected {expected:?} but the stack is empty");
+        }
+        let actual = self.vals.pop().unwrap();
+        match (actual, expected) {
+            (Some(a), Some(e)) if a != e => {
+                bail!("type mismatch: expected {e:?}, found {a:?}")
+            }
+            _ => Ok(actual),
+        }
+    }
+
+    fn pop_all(&mut self, types: &[Valtype]) -> Result<Vec<Option<Valtype>>> {
+        let mut popped = types
+            .iter()
+            .rev()
+            .map(|&t| self.pop(Some(t)))
+            .collect::<Result<Vec<_>>>()?;
+        popped.reverse();
+        Ok(popped)
+    }
+
+    /// Checks that the block leaves exactly its results.
+    fn end(&mut self, results: &[Valtype]) -> Result<()> {
+        self.pop_all(results)?;
+        if self.vals.len() != self.height {
+            bail!("type mismatch: values remaining on the stack at end of block");
+        }
+        Ok(())
+    }
+
+    /// Code after this is unreachable until the end of the block.
+    fn polymorphic(&mut self) {
+        self.vals.truncate(self.height);
+        self.unreachable = true;
+    }
+
+    fn block(&mut self, ty: &Functype, label: &[Valtype], bodies: &[&[Instr]]) -> Result<()> {
+        let params = &ty.parameters.0 .0;
+        let results = &ty.results.0 .0;
+        self.pop_all(params)?;
+        let outer = (self.height, self.unreachable);
+        self.ctx.labels.push(label.into());
+        for body in bodies {
+            self.height = self.vals.len();
+            self.unreachable = false;
+            self.vals.extend(params.iter().map(|&t| Some(t)));
+            self.instrs(body)?;
+            self.end(results)?;
+        }
+        self.ctx.labels.pop();
+        (self.height, self.unreachable) = outer;
+        self.vals.extend(results.iter().map(|&t| Some(t)));
+        Ok(())
+    }
+
+    fn blocktype(&self, bt: &Blocktype) -> Result<Functype> {
+        match bt.functype(&self.ctx) {
+            Some(ty) => Ok(ty),
+            None => bail!("unknown type"),
+        }
+    }
+
+    fn label(&self, l: u32) -> Result<Box<[Valtype]>> {
+        match self.ctx.label(crate::modules::Labelidx(l)) {
+            Some(t) => Ok(t),
+            None => bail!("unknown label {l}"),
+        }
+    }
+
+    fn memory(&self) -> Result<()> {
+        self.v.index(0, self.v.mems, "memory")
+    }
+
+    fn align(&self, align: u32, max: u32) -> Result<()> {
+        if align > max {
+            bail!("alignment must not be larger than natural");
+        }
+        Ok(())
+    }
+
+    fn data(&self, x: u32) -> Result<()> {
+        match self.v.datas {
+            Some(n) => self.v.index(x, n, "data segment"),
+            None => bail!("data count section required"),
+        }
+    }
+
+    fn instrs(&mut self, body: &[Instr]) -> Result<()> {
+        body.iter().try_for_each(|i| self.instr(i))
+    }
+
+    fn instr(&mut self, i: &Instr) -> Result<()> {
+        let v = self.v;
+        match i {
+            Instr::Block(bt, body) => {
+                let ty = self.blocktype(bt)?;
+                return self.block(&ty, &ty.results.0 .0.clone(), &[body]);
+            }
+            Instr::Loop(bt, body) => {
+                let ty = self.blocktype(bt)?;
+                return self.block(&ty, &ty.parameters.0 .0.clone(), &[body]);
+            }
+            Instr::If(bt, then) => {
+                let ty = self.blocktype(bt)?;
+                if ty.parameters.0 .0 != ty.results.0 .0 {
+                    bail!("type mismatch: if without else must leave its parameters");
+                }
+                self.pop(Some(I32))?;
+                return self.block(&ty, &ty.results.0 .0.clone(), &[then]);
+            }
+            Instr::IfElse(bt, then, els) => {
+                let ty = self.blocktype(bt)?;
+                self.pop(Some(I32))?;
+                return self.block(&ty, &ty.results.0 .0.clone(), &[then, els]);
+            }
+
+            Instr::Opcode(Opcode::Drop) => {
+                self.pop(None)?;
+                return Ok(());
+            }
+            Instr::Select(None) => {
+                self.pop(Some(I32))?;
+                let t1 = self.pop(None)?;
+                let t2 = self.pop(None)?;
+                let t = match (t1, t2) {
+                    (Some(a), Some(b)) if a != b => bail!("type mismatch in select"),
+                    (a, b) => a.or(b),
+                };
+                if let Some(Valtype::Reftype(_)) = t {
+                    bail!("type mismatch: select without a type needs a numeric or vector type");
+                }
+                self.push(t);
+                return Ok(());
+            }
+            Instr::Opcode(Opcode::RefIsNull) => {
+                if let Some(Valtype::Numtype(_) | Valtype::Vectype(_)) = self.pop(None)? {
+                    bail!("type mismatch: ref.is_null needs a reference");
+                }
+                self.push(Some(I32));
+                return Ok(());
+            }
+            Instr::BrTable(ls, l) => {
+                self.pop(Some(I32))?;
+                let default = self.label(l.0)?;
+                for l in ls.0.iter() {
+                    let label = self.label(l.0)?;
+                    if label.len() != default.len() {
+                        bail!("type mismatch: br_table targets have different arities");
+                    }
+                    let popped = self.pop_all(&label)?;
+                    self.vals.extend(popped);
+                }
+                self.pop_all(&default)?;
+                self.polymorphic();
+                return Ok(());
+            }
+
+            Instr::CallIndirect(_, x) => ensure(
+                v.table(*x)? == Reftype::Funcref,
+                "type mismatch: call_indirect needs a funcref table",
+            )?,
+            Instr::RefFunc(x) => {
+                v.index(x.0, v.funcs, "function")?;
+                if !v.refs.contains(&x.0) {
+                    bail!("undeclared function reference {}", x.0);
+                }
+            }
+            Instr::TableInit(y, x) => ensure(
+                v.elem_type(y.0)? == v.table(*x)?,
+                "type mismatch: elem segment doesn't match table",
+            )?,
+            Instr::TableCopy(x, y) => ensure(
+                v.table(*x)? == v.table(*y)?,
+                "type mismatch: tables have different types",
+            )?,
+            Instr::GlobalSet(x) => ensure(
+                v.m.global_type(*x).is_none_or(|g| g.mutability == Mut::Var),
+                "global is immutable",
+            )?,
+            Instr::ElemDrop(y) => v.index(y.0, v.elems, "elem segment")?,
+            Instr::TableSize(x) => {
+                v.table(*x)?;
+            }
+
+            Instr::MemoryMemarg(op, m) => {
+                self.memory()?;
+                self.align(m.align, memory_width(*op))?;
+            }
+            Instr::MemorySize | Instr::MemoryGrow | Instr::MemoryCopy | Instr::MemoryFill => {
+                self.memory()?
+            }
+            Instr::MemoryInit(x) => {
+                self.memory()?;
+                self.data(x.0)?;
+            }
+            Instr::DataDrop(x) => self.data(x.0)?,
+
+            Instr::I8x16Shuffle(lanes) => {
+                ensure(lanes.iter().all(|l| l.0 < 32), "invalid lane index")?
+            }
+            Instr::VectorMemarg(op, m) => {
+                self.memory()?;
+                self.align(m.align, vector_width(*op))?;
+            }
+            Instr::VectorMemargLaneidx(op, m, l) => {
+                self.memory()?;
+                let width = lane_width(*op);
+                self.align(m.align, width)?;
+                ensure((l.0 as u32) < 16 >> width, "invalid lane index")?;
+            }
+            Instr::VectorLaneidx(op, l) => ensure(l.0 < lanes(*op), "invalid lane index")?,
+            _ => {}
+        }
+
+        let Some(ty) = i.stack_effect(&self.ctx) else {
+            bail!("unknown index in {i:?}");
+        };
+        self.pop_all(&ty.parameters.0 .0)?;
+        self.vals.extend(ty.results.0 .0.iter().map(|&t| Some(t)));
+        if matches!(
+            i,
+            Instr::Br(_) | Instr::Opcode(Opcode::Unreachable | Opcode::Return)
+        ) {
+            self.polymorphic();
+        }
+        Ok(())
+    }
+}
@@ -46,6 +46,7 @@ impl Grammar for f64 {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Unsigned {
     U32(u32),
     U64(u64),
@@ -62,6 +63,7 @@ impl Grammar for Unsigned {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Signed {
     S32(i32),
     S64(i64),
@@ -78,6 +80,7 @@ impl Grammar for Signed {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Uninterpreted {
     I8(i8),
     I16(i16),
@@ -97,10 +100,58 @@ impl Grammar for Uninterpreted {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// An `f32` stored as its bit pattern. Comparing and hashing by bits makes it
+/// `Eq` and keeps NaN payloads intact, which matters for an encoder.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct F32(pub u32);
+
+impl F32 {
+    pub fn get(self) -> f32 {
+        f32::from_bits(self.0)
+    }
+}
+
+impl From<f32> for F32 {
+    fn from(n: f32) -> Self {
+        Self(n.to_bits())
+    }
+}
+
+impl Grammar for F32 {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.0.to_le_bytes())
+    }
+}
+
+/// An `f64` stored as its bit pattern. See [`F32`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct F64(pub u64);
+
+impl F64 {
+    pub fn get(self) -> f64 {
+        f64::from_bits(self.0)
+    }
+}
+
+impl From<f64> for F64 {
+    fn from(n: f64) -> Self {
+        Self(n.to_bits())
+    }
+}
+
+impl Grammar for F64 {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.0.to_le_bytes())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Float {
-    F32(f32),
-    F64(f64),
+    F32(F32),
+    F64(F64),
 }
 
 impl Grammar for Float {
@@ -113,10 +164,12 @@ impl Grammar for Float {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Name(String);
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Name(pub String);
 
 impl Grammar for Name {
     fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (self.0.len() as u32).write(w)?;
         w.write_all(self.0.as_bytes())
     }
 }
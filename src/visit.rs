@@ -0,0 +1,786 @@
+use crate::{
+    instructions::{Blocktype, Expr, Instr, Opcode},
+    modules::{
+        Custom, Data, Dataidx, Elem, Elemidx, Export, Exportdesc, Func, Funcidx, Global, Globalidx,
+        Import, Importdesc, Labelidx, Localidx, Mem, Memidx, Module, Start, Table, Tableidx,
+        Typeidx,
+    },
+    types::Functype,
+    Vector,
+};
+
+pub trait Visit {
+    fn visit_module(&mut self, m: &Module) {
+        walk_module(self, m)
+    }
+
+    fn visit_custom(&mut self, _c: &Custom) {}
+
+    fn visit_functype(&mut self, _t: &Functype) {}
+
+    fn visit_import(&mut self, i: &Import) {
+        walk_import(self, i)
+    }
+
+    fn visit_table(&mut self, _t: &Table) {}
+
+    fn visit_mem(&mut self, _m: &Mem) {}
+
+    fn visit_global(&mut self, g: &Global) {
+        walk_global(self, g)
+    }
+
+    fn visit_export(&mut self, e: &Export) {
+        walk_export(self, e)
+    }
+
+    fn visit_start(&mut self, s: &Start) {
+        walk_start(self, s)
+    }
+
+    fn visit_elem(&mut self, e: &Elem) {
+        walk_elem(self, e)
+    }
+
+    fn visit_func(&mut self, f: &Func) {
+        walk_func(self, f)
+    }
+
+    fn visit_data(&mut self, d: &Data) {
+        walk_data(self, d)
+    }
+
+    fn visit_expr(&mut self, e: &Expr) {
+        walk_expr(self, e)
+    }
+
+    fn visit_instrs(&mut self, i: &[Instr]) {
+        walk_instrs(self, i)
+    }
+
+    fn visit_instr(&mut self, i: &Instr) {
+        walk_instr(self, i)
+    }
+
+    fn visit_control_instr(&mut self, i: &Instr) {
+        walk_control_instr(self, i)
+    }
+
+    fn visit_reference_instr(&mut self, i: &Instr) {
+        walk_reference_instr(self, i)
+    }
+
+    fn visit_parametric_instr(&mut self, _i: &Instr) {}
+
+    fn visit_variable_instr(&mut self, i: &Instr) {
+        walk_variable_instr(self, i)
+    }
+
+    fn visit_table_instr(&mut self, i: &Instr) {
+        walk_table_instr(self, i)
+    }
+
+    fn visit_memory_instr(&mut self, i: &Instr) {
+        walk_memory_instr(self, i)
+    }
+
+    fn visit_numeric_instr(&mut self, _i: &Instr) {}
+
+    fn visit_vector_instr(&mut self, _i: &Instr) {}
+
+    fn visit_blocktype(&mut self, _bt: &Blocktype) {}
+
+    fn visit_typeidx(&mut self, _x: &Typeidx) {}
+
+    fn visit_funcidx(&mut self, _x: &Funcidx) {}
+
+    fn visit_tableidx(&mut self, _x: &Tableidx) {}
+
+    fn visit_memidx(&mut self, _x: &Memidx) {}
+
+    fn visit_globalidx(&mut self, _x: &Globalidx) {}
+
+    fn visit_elemidx(&mut self, _x: &Elemidx) {}
+
+    fn visit_dataidx(&mut self, _x: &Dataidx) {}
+
+    fn visit_localidx(&mut self, _x: &Localidx) {}
+
+    fn visit_labelidx(&mut self, _x: &Labelidx) {}
+}
+
+pub fn walk_module<V: Visit + ?Sized>(v: &mut V, m: &Module) {
+    fn items<T>(s: Option<&Vector<T>>) -> impl Iterator<Item = &T> {
+        s.into_iter().flat_map(|s| s.0.iter())
+    }
+
+    for c in m.custom0.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for t in items(m.typesec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_functype(t);
+    }
+    for c in m.custom1.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for i in items(m.importsec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_import(i);
+    }
+    for c in m.custom2.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for x in items(m.funcsec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_typeidx(x);
+    }
+    for c in m.custom3.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for t in items(m.tablesec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_table(t);
+    }
+    for c in m.custom4.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for mem in items(m.memsec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_mem(mem);
+    }
+    for c in m.custom5.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for g in items(m.globalsec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_global(g);
+    }
+    for c in m.custom6.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for e in items(m.exportsec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_export(e);
+    }
+    for c in m.custom7.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    if let Some(s) = m.startsec.as_ref() {
+        v.visit_start(&s.0 .0);
+    }
+    for c in m.custom8.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for e in items(m.elemsec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_elem(e);
+    }
+    for c in m.custom9.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for c in m.custom10.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for code in items(m.codesec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_func(&code.0);
+    }
+    for c in m.custom11.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+    for d in items(m.datasec.as_ref().map(|s| &s.0 .0)) {
+        v.visit_data(d);
+    }
+    for c in m.custom12.iter() {
+        v.visit_custom(&c.0 .0);
+    }
+}
+
+pub fn walk_import<V: Visit + ?Sized>(v: &mut V, i: &Import) {
+    if let Importdesc::Func(x) = &i.d {
+        v.visit_typeidx(x);
+    }
+}
+
+pub fn walk_global<V: Visit + ?Sized>(v: &mut V, g: &Global) {
+    v.visit_expr(&g.e);
+}
+
+pub fn walk_export<V: Visit + ?Sized>(v: &mut V, e: &Export) {
+    match &e.d {
+        Exportdesc::Func(x) => v.visit_funcidx(x),
+        Exportdesc::Table(x) => v.visit_tableidx(x),
+        Exportdesc::Mem(x) => v.visit_memidx(x),
+        Exportdesc::Global(x) => v.visit_globalidx(x),
+    }
+}
+
+pub fn walk_start<V: Visit + ?Sized>(v: &mut V, s: &Start) {
+    v.visit_funcidx(&s.0);
+}
+
+pub fn walk_elem<V: Visit + ?Sized>(v: &mut V, e: &Elem) {
+    match e {
+        Elem::FuncrefFuncActive(offset, y) => {
+            v.visit_expr(offset);
+            for x in y.0.iter() {
+                v.visit_funcidx(x);
+            }
+        }
+        Elem::ElemkindFuncPassive(_, y) | Elem::ElemkindFuncDeclarative(_, y) => {
+            for x in y.0.iter() {
+                v.visit_funcidx(x);
+            }
+        }
+        Elem::ElemkindFuncActive(x, offset, _, y) => {
+            v.visit_tableidx(x);
+            v.visit_expr(offset);
+            for x in y.0.iter() {
+                v.visit_funcidx(x);
+            }
+        }
+        Elem::FuncrefExprActive(offset, el) => {
+            v.visit_expr(offset);
+            for e in el.0.iter() {
+                v.visit_expr(e);
+            }
+        }
+        Elem::ReftypeExprPassive(_, el) | Elem::ReftypeExprDeclarative(_, el) => {
+            for e in el.0.iter() {
+                v.visit_expr(e);
+            }
+        }
+        Elem::ReftypeExprActive(x, offset, _, el) => {
+            v.visit_tableidx(x);
+            v.visit_expr(offset);
+            for e in el.0.iter() {
+                v.visit_expr(e);
+            }
+        }
+    }
+}
+
+pub fn walk_func<V: Visit + ?Sized>(v: &mut V, f: &Func) {
+    v.visit_expr(&f.e);
+}
+
+pub fn walk_data<V: Visit + ?Sized>(v: &mut V, d: &Data) {
+    match d {
+        Data::ActiveAtZero(offset, _) => v.visit_expr(offset),
+        Data::Passive(_) => {}
+        Data::ActiveAtIndex(x, offset, _) => {
+            v.visit_memidx(x);
+            v.visit_expr(offset);
+        }
+    }
+}
+
+pub fn walk_expr<V: Visit + ?Sized>(v: &mut V, e: &Expr) {
+    v.visit_instrs(&e.0);
+}
+
+pub fn walk_instrs<V: Visit + ?Sized>(v: &mut V, i: &[Instr]) {
+    for i in i.iter() {
+        v.visit_instr(i);
+    }
+}
+
+/// Dispatches to the hook for the instruction's family.
+pub fn walk_instr<V: Visit + ?Sized>(v: &mut V, i: &Instr) {
+    match i {
+        Instr::Opcode(Opcode::Unreachable | Opcode::Nop | Opcode::Return)
+        | Instr::Block(..)
+        | Instr::Loop(..)
+        | Instr::If(..)
+        | Instr::IfElse(..)
+        | Instr::Br(_)
+        | Instr::BrIf(_)
+        | Instr::BrTable(..)
+        | Instr::Call(_)
+        | Instr::CallIndirect(..) => v.visit_control_instr(i),
+        Instr::Opcode(Opcode::RefIsNull) | Instr::RefNull(_) | Instr::RefFunc(_) => {
+            v.visit_reference_instr(i)
+        }
+        Instr::Opcode(Opcode::Drop) | Instr::Select(_) => v.visit_parametric_instr(i),
+        Instr::LocalGet(_)
+        | Instr::LocalSet(_)
+        | Instr::LocalTee(_)
+        | Instr::GlobalGet(_)
+        | Instr::GlobalSet(_) => v.visit_variable_instr(i),
+        Instr::TableGet(_)
+        | Instr::TableSet(_)
+        | Instr::TableInit(..)
+        | Instr::ElemDrop(_)
+        | Instr::TableCopy(..)
+        | Instr::TableGrow(_)
+        | Instr::TableSize(_)
+        | Instr::TableFill(_) => v.visit_table_instr(i),
+        Instr::MemoryMemarg(..)
+        | Instr::MemorySize
+        | Instr::MemoryGrow
+        | Instr::MemoryInit(_)
+        | Instr::DataDrop(_)
+        | Instr::MemoryCopy
+        | Instr::MemoryFill => v.visit_memory_instr(i),
+        Instr::Opcode(_)
+        | Instr::I32Const(_)
+        | Instr::I64Const(_)
+        | Instr::F32Const(_)
+        | Instr::F64Const(_)
+        | Instr::TruncSat(_) => v.visit_numeric_instr(i),
+        Instr::V128Const(_)
+        | Instr::I8x16Shuffle(_)
+        | Instr::VectorMemarg(..)
+        | Instr::VectorMemargLaneidx(..)
+        | Instr::VectorLaneidx(..)
+        | Instr::VectorNoImmediate(_) => v.visit_vector_instr(i),
+    }
+}
+
+/// Visits the instruction's indices and recurses into nested bodies.
+pub fn walk_control_instr<V: Visit + ?Sized>(v: &mut V, i: &Instr) {
+    match i {
+        Instr::Block(bt, r#in) | Instr::Loop(bt, r#in) | Instr::If(bt, r#in) => {
+            v.visit_blocktype(bt);
+            v.visit_instrs(r#in);
+        }
+        Instr::IfElse(bt, in1, in2) => {
+            v.visit_blocktype(bt);
+            v.visit_instrs(in1);
+            v.visit_instrs(in2);
+        }
+        Instr::Br(l) | Instr::BrIf(l) => v.visit_labelidx(l),
+        Instr::BrTable(ls, default) => {
+            for l in ls.0.iter() {
+                v.visit_labelidx(l);
+            }
+            v.visit_labelidx(default);
+        }
+        Instr::Call(x) => v.visit_funcidx(x),
+        Instr::CallIndirect(y, x) => {
+            v.visit_typeidx(y);
+            v.visit_tableidx(x);
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_reference_instr<V: Visit + ?Sized>(v: &mut V, i: &Instr) {
+    if let Instr::RefFunc(x) = i {
+        v.visit_funcidx(x);
+    }
+}
+
+pub fn walk_variable_instr<V: Visit + ?Sized>(v: &mut V, i: &Instr) {
+    match i {
+        Instr::LocalGet(x) | Instr::LocalSet(x) | Instr::LocalTee(x) => v.visit_localidx(x),
+        Instr::GlobalGet(x) | Instr::GlobalSet(x) => v.visit_globalidx(x),
+        _ => {}
+    }
+}
+
+pub fn walk_table_instr<V: Visit + ?Sized>(v: &mut V, i: &Instr) {
+    match i {
+        Instr::TableGet(x)
+        | Instr::TableSet(x)
+        | Instr::TableGrow(x)
+        | Instr::TableSize(x)
+        | Instr::TableFill(x) => v.visit_tableidx(x),
+        Instr::TableInit(y, x) => {
+            v.visit_elemidx(y);
+            v.visit_tableidx(x);
+        }
+        Instr::ElemDrop(y) => v.visit_elemidx(y),
+        Instr::TableCopy(x, y) => {
+            v.visit_tableidx(x);
+            v.visit_tableidx(y);
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_memory_instr<V: Visit + ?Sized>(v: &mut V, i: &Instr) {
+    if let Instr::MemoryInit(x) | Instr::DataDrop(x) = i {
+        v.visit_dataidx(x);
+    }
+}
+
+pub trait VisitMut {
+    fn visit_module_mut(&mut self, m: &mut Module) {
+        walk_module_mut(self, m)
+    }
+
+    fn visit_custom_mut(&mut self, _c: &mut Custom) {}
+
+    fn visit_functype_mut(&mut self, _t: &mut Functype) {}
+
+    fn visit_import_mut(&mut self, i: &mut Import) {
+        walk_import_mut(self, i)
+    }
+
+    fn visit_table_mut(&mut self, _t: &mut Table) {}
+
+    fn visit_mem_mut(&mut self, _m: &mut Mem) {}
+
+    fn visit_global_mut(&mut self, g: &mut Global) {
+        walk_global_mut(self, g)
+    }
+
+    fn visit_export_mut(&mut self, e: &mut Export) {
+        walk_export_mut(self, e)
+    }
+
+    fn visit_start_mut(&mut self, s: &mut Start) {
+        walk_start_mut(self, s)
+    }
+
+    fn visit_elem_mut(&mut self, e: &mut Elem) {
+        walk_elem_mut(self, e)
+    }
+
+    fn visit_func_mut(&mut self, f: &mut Func) {
+        walk_func_mut(self, f)
+    }
+
+    fn visit_data_mut(&mut self, d: &mut Data) {
+        walk_data_mut(self, d)
+    }
+
+    fn visit_expr_mut(&mut self, e: &mut Expr) {
+        walk_expr_mut(self, e)
+    }
+
+    fn visit_instrs_mut(&mut self, i: &mut Box<[Instr]>) {
+        walk_instrs_mut(self, i)
+    }
+
+    fn visit_instr_mut(&mut self, i: &mut Instr) {
+        walk_instr_mut(self, i)
+    }
+
+    fn visit_control_instr_mut(&mut self, i: &mut Instr) {
+        walk_control_instr_mut(self, i)
+    }
+
+    fn visit_reference_instr_mut(&mut self, i: &mut Instr) {
+        walk_reference_instr_mut(self, i)
+    }
+
+    fn visit_parametric_instr_mut(&mut self, _i: &mut Instr) {}
+
+    fn visit_variable_instr_mut(&mut self, i: &mut Instr) {
+        walk_variable_instr_mut(self, i)
+    }
+
+    fn visit_table_instr_mut(&mut self, i: &mut Instr) {
+        walk_table_instr_mut(self, i)
+    }
+
+    fn visit_memory_instr_mut(&mut self, i: &mut Instr) {
+        walk_memory_instr_mut(self, i)
+    }
+
+    fn visit_numeric_instr_mut(&mut self, _i: &mut Instr) {}
+
+    fn visit_vector_instr_mut(&mut self, _i: &mut Instr) {}
+
+    fn visit_blocktype_mut(&mut self, _bt: &mut Blocktype) {}
+
+    fn visit_typeidx_mut(&mut self, _x: &mut Typeidx) {}
+
+    fn visit_funcidx_mut(&mut self, _x: &mut Funcidx) {}
+
+    fn visit_tableidx_mut(&mut self, _x: &mut Tableidx) {}
+
+    fn visit_memidx_mut(&mut self, _x: &mut Memidx) {}
+
+    fn visit_globalidx_mut(&mut self, _x: &mut Globalidx) {}
+
+    fn visit_elemidx_mut(&mut self, _x: &mut Elemidx) {}
+
+    fn visit_dataidx_mut(&mut self, _x: &mut Dataidx) {}
+
+    fn visit_localidx_mut(&mut self, _x: &mut Localidx) {}
+
+    fn visit_labelidx_mut(&mut self, _x: &mut Labelidx) {}
+}
+
+pub fn walk_module_mut<V: VisitMut + ?Sized>(v: &mut V, m: &mut Module) {
+    fn items<T>(s: Option<&mut Vector<T>>) -> impl Iterator<Item = &mut T> {
+        s.into_iter().flat_map(|s| s.0.iter_mut())
+    }
+
+    for c in m.custom0.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for t in items(m.typesec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_functype_mut(t);
+    }
+    for c in m.custom1.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for i in items(m.importsec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_import_mut(i);
+    }
+    for c in m.custom2.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for x in items(m.funcsec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_typeidx_mut(x);
+    }
+    for c in m.custom3.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for t in items(m.tablesec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_table_mut(t);
+    }
+    for c in m.custom4.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for mem in items(m.memsec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_mem_mut(mem);
+    }
+    for c in m.custom5.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for g in items(m.globalsec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_global_mut(g);
+    }
+    for c in m.custom6.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for e in items(m.exportsec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_export_mut(e);
+    }
+    for c in m.custom7.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    if let Some(s) = m.startsec.as_mut() {
+        v.visit_start_mut(&mut s.0 .0);
+    }
+    for c in m.custom8.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for e in items(m.elemsec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_elem_mut(e);
+    }
+    for c in m.custom9.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for c in m.custom10.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for code in items(m.codesec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_func_mut(&mut code.0);
+    }
+    for c in m.custom11.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+    for d in items(m.datasec.as_mut().map(|s| &mut s.0 .0)) {
+        v.visit_data_mut(d);
+    }
+    for c in m.custom12.iter_mut() {
+        v.visit_custom_mut(&mut c.0 .0);
+    }
+}
+
+pub fn walk_import_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Import) {
+    if let Importdesc::Func(x) = &mut i.d {
+        v.visit_typeidx_mut(x);
+    }
+}
+
+pub fn walk_global_mut<V: VisitMut + ?Sized>(v: &mut V, g: &mut Global) {
+    v.visit_expr_mut(&mut g.e);
+}
+
+pub fn walk_export_mut<V: VisitMut + ?Sized>(v: &mut V, e: &mut Export) {
+    match &mut e.d {
+        Exportdesc::Func(x) => v.visit_funcidx_mut(x),
+        Exportdesc::Table(x) => v.visit_tableidx_mut(x),
+        Exportdesc::Mem(x) => v.visit_memidx_mut(x),
+        Exportdesc::Global(x) => v.visit_globalidx_mut(x),
+    }
+}
+
+pub fn walk_start_mut<V: VisitMut + ?Sized>(v: &mut V, s: &mut Start) {
+    v.visit_funcidx_mut(&mut s.0);
+}
+
+pub fn walk_elem_mut<V: VisitMut + ?Sized>(v: &mut V, e: &mut Elem) {
+    match e {
+        Elem::FuncrefFuncActive(offset, y) => {
+            v.visit_expr_mut(offset);
+            for x in y.0.iter_mut() {
+                v.visit_funcidx_mut(x);
+            }
+        }
+        Elem::ElemkindFuncPassive(_, y) | Elem::ElemkindFuncDeclarative(_, y) => {
+            for x in y.0.iter_mut() {
+                v.visit_funcidx_mut(x);
+            }
+        }
+        Elem::ElemkindFuncActive(x, offset, _, y) => {
+            v.visit_tableidx_mut(x);
+            v.visit_expr_mut(offset);
+            for x in y.0.iter_mut() {
+                v.visit_funcidx_mut(x);
+            }
+        }
+        Elem::FuncrefExprActive(offset, el) => {
+            v.visit_expr_mut(offset);
+            for e in el.0.iter_mut() {
+                v.visit_expr_mut(e);
+            }
+        }
+        Elem::ReftypeExprPassive(_, el) | Elem::ReftypeExprDeclarative(_, el) => {
+            for e in el.0.iter_mut() {
+                v.visit_expr_mut(e);
+            }
+        }
+        Elem::ReftypeExprActive(x, offset, _, el) => {
+            v.visit_tableidx_mut(x);
+            v.visit_expr_mut(offset);
+            for e in el.0.iter_mut() {
+                v.visit_expr_mut(e);
+            }
+        }
+    }
+}
+
+pub fn walk_func_mut<V: VisitMut + ?Sized>(v: &mut V, f: &mut Func) {
+    v.visit_expr_mut(&mut f.e);
+}
+
+pub fn walk_data_mut<V: VisitMut + ?Sized>(v: &mut V, d: &mut Data) {
+    match d {
+        Data::ActiveAtZero(offset, _) => v.visit_expr_mut(offset),
+        Data::Passive(_) => {}
+        Data::ActiveAtIndex(x, offset, _) => {
+            v.visit_memidx_mut(x);
+            v.visit_expr_mut(offset);
+        }
+    }
+}
+
+pub fn walk_expr_mut<V: VisitMut + ?Sized>(v: &mut V, e: &mut Expr) {
+    v.visit_instrs_mut(&mut e.0);
+}
+
+pub fn walk_instrs_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Box<[Instr]>) {
+    for i in i.iter_mut() {
+        v.visit_instr_mut(i);
+    }
+}
+
+/// Dispatches to the hook for the instruction's family.
+pub fn walk_instr_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Instr) {
+    match i {
+        Instr::Opcode(Opcode::Unreachable | Opcode::Nop | Opcode::Return)
+        | Instr::Block(..)
+        | Instr::Loop(..)
+        | Instr::If(..)
+        | Instr::IfElse(..)
+        | Instr::Br(_)
+        | Instr::BrIf(_)
+        | Instr::BrTable(..)
+        | Instr::Call(_)
+        | Instr::CallIndirect(..) => v.visit_control_instr_mut(i),
+        Instr::Opcode(Opcode::RefIsNull) | Instr::RefNull(_) | Instr::RefFunc(_) => {
+            v.visit_reference_instr_mut(i)
+        }
+        Instr::Opcode(Opcode::Drop) | Instr::Select(_) => v.visit_parametric_instr_mut(i),
+        Instr::LocalGet(_)
+        | Instr::LocalSet(_)
+        | Instr::LocalTee(_)
+        | Instr::GlobalGet(_)
+        | Instr::GlobalSet(_) => v.visit_variable_instr_mut(i),
+        Instr::TableGet(_)
+        | Instr::TableSet(_)
+        | Instr::TableInit(..)
+        | Instr::ElemDrop(_)
+        | Instr::TableCopy(..)
+        | Instr::TableGrow(_)
+        | Instr::TableSize(_)
+        | Instr::TableFill(_) => v.visit_table_instr_mut(i),
+        Instr::MemoryMemarg(..)
+        | Instr::MemorySize
+        | Instr::MemoryGrow
+        | Instr::MemoryInit(_)
+        | Instr::DataDrop(_)
+        | Instr::MemoryCopy
+        | Instr::MemoryFill => v.visit_memory_instr_mut(i),
+        Instr::Opcode(_)
+        | Instr::I32Const(_)
+        | Instr::I64Const(_)
+        | Instr::F32Const(_)
+        | Instr::F64Const(_)
+        | Instr::TruncSat(_) => v.visit_numeric_instr_mut(i),
+        Instr::V128Const(_)
+        | Instr::I8x16Shuffle(_)
+        | Instr::VectorMemarg(..)
+        | Instr::VectorMemargLaneidx(..)
+        | Instr::VectorLaneidx(..)
+        | Instr::VectorNoImmediate(_) => v.visit_vector_instr_mut(i),
+    }
+}
+
+/// Visits the instruction's indices and recurses into nested bodies.
+pub fn walk_control_instr_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Instr) {
+    match i {
+        Instr::Block(bt, r#in) | Instr::Loop(bt, r#in) | Instr::If(bt, r#in) => {
+            v.visit_blocktype_mut(bt);
+            v.visit_instrs_mut(r#in);
+        }
+        Instr::IfElse(bt, in1, in2) => {
+            v.visit_blocktype_mut(bt);
+            v.visit_instrs_mut(in1);
+            v.visit_instrs_mut(in2);
+        }
+        Instr::Br(l) | Instr::BrIf(l) => v.visit_labelidx_mut(l),
+        Instr::BrTable(ls, default) => {
+            for l in ls.0.iter_mut() {
+                v.visit_labelidx_mut(l);
+            }
+            v.visit_labelidx_mut(default);
+        }
+        Instr::Call(x) => v.visit_funcidx_mut(x),
+        Instr::CallIndirect(y, x) => {
+            v.visit_typeidx_mut(y);
+            v.visit_tableidx_mut(x);
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_reference_instr_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Instr) {
+    if let Instr::RefFunc(x) = i {
+        v.visit_funcidx_mut(x);
+    }
+}
+
+pub fn walk_variable_instr_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Instr) {
+    match i {
+        Instr::LocalGet(x) | Instr::LocalSet(x) | Instr::LocalTee(x) => v.visit_localidx_mut(x),
+        Instr::GlobalGet(x) | Instr::GlobalSet(x) => v.visit_globalidx_mut(x),
+        _ => {}
+    }
+}
+
+pub fn walk_table_instr_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Instr) {
+    match i {
+        Instr::TableGet(x)
+        | Instr::TableSet(x)
+        | Instr::TableGrow(x)
+        | Instr::TableSize(x)
+        | Instr::TableFill(x) => v.visit_tableidx_mut(x),
+        Instr::TableInit(y, x) => {
+            v.visit_elemidx_mut(y);
+            v.visit_tableidx_mut(x);
+        }
+        Instr::ElemDrop(y) => v.visit_elemidx_mut(y),
+        Instr::TableCopy(x, y) => {
+            v.visit_tableidx_mut(x);
+            v.visit_tableidx_mut(y);
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_memory_instr_mut<V: VisitMut + ?Sized>(v: &mut V, i: &mut Instr) {
+    if let Instr::MemoryInit(x) | Instr::DataDrop(x) = i {
+        v.visit_dataidx_mut(x);
+    }
+}
@@ -0,0 +1,862 @@
+//! Runs spec test scripts in the `.wast` format against the decoder,
+//! validator and interpreter.
+//!
+//! There is no text format parser, so modules written as text or quoted
+//! text are skipped, along with any command that uses them. Binary modules
+//! and everything else the core spec tests need are supported, and any other
+//! command fails.
+
+use crate::{
+    decode::decode,
+    interpreter::{self, Extern, Instance, Store, Trap, Value},
+    modules::Module,
+    numeric,
+    types::{
+        Functype, Globaltype, Limits, Memtype, Mut, Numtype, Reftype, Resulttype, Tabletype,
+        Valtype,
+    },
+    validate::validate,
+    values::{F32, F64},
+    Vector,
+};
+use std::{collections::HashMap, fmt};
+
+/// A failed command, or a script that doesn't parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Error {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Report {
+    pub passed: usize,
+    /// Commands that need the text format.
+    pub skipped: usize,
+    pub failures: Vec<Error>,
+}
+
+/// Runs every command in a script, collecting the ones that fail.
+pub fn run(script: &str) -> Result<Report, Error> {
+    let commands = Parser::new(script).script()?;
+    let mut runner = Runner::new();
+    for (line, c) in &commands {
+        match runner.command(c) {
+            Ok(Outcome::Pass) => runner.report.passed += 1,
+            Ok(Outcome::Skip) => runner.report.skipped += 1,
+            Err(message) => runner.report.failures.push(Error {
+                line: *line,
+                message,
+            }),
+        }
+    }
+    Ok(runner.report)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Sexp {
+    List(Vec<Sexp>),
+    Atom(String),
+    Str(Vec<u8>),
+}
+
+impl Sexp {
+    fn atom(&self) -> Option<&str> {
+        match self {
+            Sexp::Atom(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    fn list(&self) -> Option<&[Sexp]> {
+        match self {
+            Sexp::List(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// The keyword a list starts with.
+    fn head(&self) -> Option<&str> {
+        self.list()?.first()?.atom()
+    }
+
+    fn string(&self) -> Option<&[u8]> {
+        match self {
+            Sexp::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    src: &'a [u8],
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            src: src.as_bytes(),
+            pos: 0,
+            line: 1,
+        }
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, Error> {
+        Err(Error {
+            line: self.line,
+            message: message.into(),
+        })
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<u8> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == b'\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_space(&mut self) -> Result<(), Error> {
+        loop {
+            match (self.peek(), self.src.get(self.pos + 1)) {
+                (Some(c), _) if c.is_ascii_whitespace() => {
+                    self.bump();
+                }
+                (Some(b';'), Some(b';')) => {
+                    while self.peek().is_some_and(|c| c != b'\n') {
+                        self.bump();
+                    }
+                }
+                (Some(b'('), Some(b';')) => {
+                    let mut depth = 0;
+                    loop {
+                        match (self.bump(), self.peek()) {
+                            (Some(b'('), Some(b';')) => {
+                                self.bump();
+                                depth += 1;
+                            }
+                            (Some(b';'), Some(b')')) => {
+                                self.bump();
+                                depth -= 1;
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            (None, _) => return self.error("unterminated block comment"),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Top-level commands, each with the line it starts on.
+    fn script(&mut self) -> Result<Vec<(usize, Sexp)>, Error> {
+        let mut commands = vec![];
+        loop {
+            self.skip_space()?;
+            if self.peek().is_none() {
+                return Ok(commands);
+            }
+            let line = self.line;
+            commands.push((line, self.sexp()?));
+        }
+    }
+
+    fn sexp(&mut self) -> Result<Sexp, Error> {
+        self.skip_space()?;
+        match self.peek() {
+            None => self.error("unexpected end of script"),
+            Some(b'(') => {
+                self.bump();
+                let mut items = vec![];
+                loop {
+                    self.skip_space()?;
+                    if self.peek() == Some(b')') {
+                        self.bump();
+                        return Ok(Sexp::List(items));
+                    }
+                    items.push(self.sexp()?);
+                }
+            }
+            Some(b')') => self.error("unexpected `)`"),
+            Some(b'"') => {
+                self.bump();
+                self.string().map(Sexp::Str)
+            }
+            Some(_) => {
+                let start = self.pos;
+                while self
+                    .peek()
+                    .is_some_and(|c| !c.is_ascii_whitespace() && !b"()\";".contains(&c))
+                {
+                    self.bump();
+                }
+                let atom = std::str::from_utf8(&self.src[start..self.pos]).unwrap();
+                Ok(Sexp::Atom(atom.to_string()))
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<Vec<u8>, Error> {
+        let mut s = vec![];
+        loop {
+            match self.bump() {
+                None => return self.error("unterminated string"),
+                Some(b'"') => return Ok(s),
+                Some(b'\\') => match self.bump() {
+                    Some(b'n') => s.push(b'\n'),
+                    Some(b't') => s.push(b'\t'),
+                    Some(b'r') => s.push(b'\r'),
+                    Some(c @ (b'"' | b'\'' | b'\\')) => s.push(c),
+                    Some(b'u') => {
+                        let start = self.pos + 1;
+                        while self.bump().is_some_and(|c| c != b'}') {}
+                        let hex = std::str::from_utf8(&self.src[start..self.pos - 1]).unwrap();
+                        match u32::from_str_radix(&hex.replace('_', ""), 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                        {
+                            Some(c) => s.extend(c.to_string().bytes()),
+                            None => return self.error("malformed unicode escape"),
+                        }
+                    }
+                    Some(hi) => {
+                        let digit = |c: u8| (c as char).to_digit(16);
+                        match (digit(hi), self.bump().and_then(digit)) {
+                            (Some(hi), Some(lo)) => s.push((hi * 16 + lo) as u8),
+                            _ => return self.error("malformed escape"),
+                        }
+                    }
+                    None => return self.error("unterminated string"),
+                },
+                Some(c) => s.push(c),
+            }
+        }
+    }
+}
+
+enum Outcome {
+    Pass,
+    Skip,
+}
+
+type Check = Result<Outcome, String>;
+
+/// Something a command refers to that uses the text format.
+struct Skipped;
+
+/// What an `assert_return` accepts in place of a value.
+enum Expected {
+    Value(Value),
+    F32(Nan),
+    F64(Nan),
+    V128(Shape, Vec<Lane>),
+    /// Any non-null function reference.
+    RefFunc,
+    Either(Vec<Expected>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Nan {
+    Canonical,
+    Arithmetic,
+}
+
+enum Lane {
+    Bits(u64),
+    Nan(Nan),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    I8x16,
+    I16x8,
+    I32x4,
+    I64x2,
+    F32x4,
+    F64x2,
+}
+
+impl Shape {
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "i8x16" => Shape::I8x16,
+            "i16x8" => Shape::I16x8,
+            "i32x4" => Shape::I32x4,
+            "i64x2" => Shape::I64x2,
+            "f32x4" => Shape::F32x4,
+            "f64x2" => Shape::F64x2,
+            _ => return None,
+        })
+    }
+
+    fn bits(self) -> u32 {
+        match self {
+            Shape::I8x16 => 8,
+            Shape::I16x8 => 16,
+            Shape::I32x4 | Shape::F32x4 => 32,
+            Shape::I64x2 | Shape::F64x2 => 64,
+        }
+    }
+
+    fn lanes(self) -> usize {
+        128 / self.bits() as usize
+    }
+
+    /// Parses a lane's literal to its bits.
+    fn lane(self, s: &str) -> Option<u64> {
+        match self {
+            Shape::F32x4 => float(s, 23, 8),
+            Shape::F64x2 => float(s, 52, 11),
+            _ => int(s, self.bits()),
+        }
+    }
+}
+
+/// An instance, or a module that was skipped.
+type Slot = Option<usize>;
+
+struct Runner {
+    store: Store,
+    current: Option<Slot>,
+    named: HashMap<String, Slot>,
+    registered: HashMap<String, Slot>,
+    report: Report,
+}
+
+impl Runner {
+    fn new() -> Self {
+        let mut runner = Self {
+            store: Store::default(),
+            current: None,
+            named: HashMap::new(),
+            registered: HashMap::new(),
+            report: Report::default(),
+        };
+        let spectest = runner.spectest();
+        runner.registered.insert("spectest".into(), Some(spectest));
+        runner
+    }
+
+    /// The host module the spec tests import from.
+    fn spectest(&mut self) -> usize {
+        const I32: Valtype = Valtype::Numtype(Numtype::I32);
+        const I64: Valtype = Valtype::Numtype(Numtype::I64);
+        const F32T: Valtype = Valtype::Numtype(Numtype::F32);
+        const F64T: Valtype = Valtype::Numtype(Numtype::F64);
+        let s = &mut self.store;
+        let mut exports = vec![];
+        for (name, params) in [
+            ("print", &[][..]),
+            ("print_i32", &[I32]),
+            ("print_i64", &[I64]),
+            ("print_f32", &[F32T]),
+            ("print_f64", &[F64T]),
+            ("print_i32_f32", &[I32, F32T]),
+            ("print_f64_f64", &[F64T, F64T]),
+        ] {
+            let ty = Functype {
+                parameters: Resulttype(Vector(params.into())),
+                results: Resulttype(Vector(Box::new([]))),
+            };
+            exports.push((name.into(), s.alloc_host_func(ty, |_| Ok(vec![]))));
+        }
+        for (name, value) in [
+            ("global_i32", Value::I32(666)),
+            ("global_i64", Value::I64(666)),
+            ("global_f32", Value::F32(F32::from(666.6))),
+            ("global_f64", Value::F64(F64::from(666.6))),
+        ] {
+            let ty = Globaltype {
+                ty: value.ty(),
+                mutability: Mut::Const,
+            };
+            exports.push((name.into(), s.alloc_global(ty, value)));
+        }
+        let table = Tabletype {
+            element_type: Reftype::Funcref,
+            limits: Limits::MinMax(10, 20),
+        };
+        exports.push(("table".into(), s.alloc_table(table, Value::FuncRef(None))));
+        exports.push(("memory".into(), s.alloc_mem(Memtype(Limits::MinMax(1, 2)))));
+        s.instances.push(Instance {
+            exports,
+            ..Default::default()
+        });
+        s.instances.len() - 1
+    }
+
+    fn command(&mut self, c: &Sexp) -> Check {
+        let items = c.list().ok_or("expected a command")?;
+        match c.head().ok_or("expected a command")? {
+            "module" => {
+                let id = id(items);
+                let slot = match binary(items) {
+                    Err(Skipped) => None,
+                    Ok(bytes) => Some(self.instantiate(&bytes).map_err(|e| e.to_string())?),
+                };
+                self.current = Some(slot);
+                if let Some(id) = id {
+                    self.named.insert(id.into(), slot);
+                }
+                Ok(if slot.is_some() {
+                    Outcome::Pass
+                } else {
+                    Outcome::Skip
+                })
+            }
+            "register" => {
+                let name = items
+                    .get(1)
+                    .and_then(Sexp::string)
+                    .ok_or("expected a name")?;
+                let slot = self.instance(items.get(2).and_then(Sexp::atom))?;
+                self.registered
+                    .insert(String::from_utf8_lossy(name).into(), slot);
+                Ok(Outcome::Pass)
+            }
+            "invoke" | "get" => match self.action(c)? {
+                Err(Skipped) => Ok(Outcome::Skip),
+                Ok(Ok(_)) => Ok(Outcome::Pass),
+                Ok(Err(t)) => Err(format!("trapped: {t:?}")),
+            },
+            "assert_return" => {
+                let action = items.get(1).ok_or("expected an action")?;
+                let expected = items[2..]
+                    .iter()
+                    .map(expected)
+                    .collect::<Result<Vec<_>, _>>()?;
+                let results = match self.action(action)? {
+                    Err(Skipped) => return Ok(Outcome::Skip),
+                    Ok(r) => r.map_err(|t| format!("trapped: {t:?}"))?,
+                };
+                if results.len() == expected.len()
+                    && results.iter().zip(&expected).all(|(v, e)| matches(v, e))
+                {
+                    Ok(Outcome::Pass)
+                } else {
+                    Err(format!("returned {results:?}"))
+                }
+            }
+            "assert_trap" | "assert_exhaustion" | "assert_uninstantiable" => {
+                let what = items.get(1).ok_or("expected an action")?;
+                let message = message(items)?;
+                let trap = if what.head() == Some("module") {
+                    let Ok(bytes) = binary(what.list().unwrap()) else {
+                        return Ok(Outcome::Skip);
+                    };
+                    match self.instantiate(&bytes) {
+                        Ok(_) => return Err("instantiated".into()),
+                        Err(Failure::Trap(t)) => t,
+                        Err(e) => return Err(e.to_string()),
+                    }
+                } else {
+                    match self.action(what)? {
+                        Err(Skipped) => return Ok(Outcome::Skip),
+                        Ok(Ok(results)) => return Err(format!("returned {results:?}")),
+                        Ok(Err(t)) => t,
+                    }
+                };
+                if trap_messages(&trap).iter().any(|m| m.starts_with(&message)) {
+                    Ok(Outcome::Pass)
+                } else {
+                    Err(format!("trapped with {trap:?}, expected {message:?}"))
+                }
+            }
+            "assert_invalid" | "assert_malformed" | "assert_unlinkable" => {
+                let module = items.get(1).ok_or("expected a module")?;
+                message(items)?;
+                let Ok(bytes) = binary(module.list().ok_or("expected a module")?) else {
+                    return Ok(Outcome::Skip);
+                };
+                let failure = match self.instantiate(&bytes) {
+                    Ok(_) => return Err("instantiated".into()),
+                    Err(f) => f,
+                };
+                let kind = match failure {
+                    Failure::Malformed(_) => "assert_malformed",
+                    Failure::Invalid(_) => "assert_invalid",
+                    Failure::Link(_) => "assert_unlinkable",
+                    Failure::Trap(_) => "assert_uninstantiable",
+                };
+                if kind == c.head().unwrap() {
+                    Ok(Outcome::Pass)
+                } else {
+                    Err(failure.to_string())
+                }
+            }
+            other => Err(format!("unknown command {other}")),
+        }
+    }
+
+    fn instance(&self, id: Option<&str>) -> Result<Slot, String> {
+        match id {
+            Some(id) => self
+                .named
+                .get(id)
+                .copied()
+                .ok_or_else(|| format!("unknown module {id}")),
+            None => self.current.ok_or_else(|| "no module".into()),
+        }
+    }
+
+    fn action(&mut self, a: &Sexp) -> Result<Result<Result<Vec<Value>, Trap>, Skipped>, String> {
+        let items = a.list().ok_or("expected an action")?;
+        let (id, rest) = match items.get(1).and_then(Sexp::atom) {
+            Some(id) => (Some(id), &items[2..]),
+            None => (None, &items[1..]),
+        };
+        let name = rest
+            .first()
+            .and_then(Sexp::string)
+            .ok_or("expected a name")?;
+        let name = std::str::from_utf8(name).map_err(|e| e.to_string())?;
+        let Some(instance) = self.instance(id)? else {
+            return Ok(Err(Skipped));
+        };
+        let export = self
+            .store
+            .export(instance, name)
+            .ok_or_else(|| format!("unknown export {name:?}"))?;
+        Ok(Ok(match (a.head(), export) {
+            (Some("invoke"), Extern::Func(f)) => {
+                let args = rest[1..].iter().map(value).collect::<Result<Vec<_>, _>>()?;
+                self.store.invoke(f, &args)
+            }
+            (Some("get"), Extern::Global(g)) => Ok(vec![self.store.globals[g].value]),
+            _ => return Err(format!("can't {a:?}")),
+        }))
+    }
+
+    fn instantiate(&mut self, bytes: &[u8]) -> Result<usize, Failure> {
+        let m = decode(bytes).map_err(|e| Failure::Malformed(e.to_string()))?;
+        validate(&m).map_err(|e| Failure::Invalid(e.0))?;
+        let imports = self.imports(&m)?;
+        self.store.instantiate(&m, &imports).map_err(|e| match e {
+            interpreter::Error::Link(e) => Failure::Link(e),
+            interpreter::Error::Trap(t) => Failure::Trap(t),
+        })
+    }
+
+    fn imports(&self, m: &Module) -> Result<Vec<Extern>, Failure> {
+        m.importsec
+            .iter()
+            .flat_map(|i| i.0 .0 .0.iter())
+            .map(|i| {
+                let Some(Some(instance)) = self.registered.get(&i.r#mod.0) else {
+                    return Err(Failure::Link(format!("unknown import {:?}", i.r#mod.0)));
+                };
+                self.store
+                    .export(*instance, &i.nm.0)
+                    .ok_or_else(|| Failure::Link(format!("unknown import {:?}", i.nm.0)))
+            })
+            .collect()
+    }
+}
+
+/// Why a module didn't instantiate.
+enum Failure {
+    Malformed(String),
+    Invalid(String),
+    Link(String),
+    Trap(Trap),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Malformed(e) => write!(f, "malformed: {e}"),
+            Failure::Invalid(e) => write!(f, "invalid: {e}"),
+            Failure::Link(e) => write!(f, "unlinkable: {e}"),
+            Failure::Trap(t) => write!(f, "trapped: {t:?}"),
+        }
+    }
+}
+
+/// The messages the spec tests use for a trap.
+fn trap_messages(t: &Trap) -> &'static [&'static str] {
+    match t {
+        Trap::Unreachable => &["unreachable"],
+        Trap::Numeric(numeric::Trap::IntegerDivideByZero) => &["integer divide by zero"],
+        Trap::Numeric(numeric::Trap::IntegerOverflow) => &["integer overflow"],
+        Trap::Numeric(numeric::Trap::InvalidConversionToInteger) => {
+            &["invalid conversion to integer"]
+        }
+        Trap::MemoryOutOfBounds => &["out of bounds memory access"],
+        Trap::TableOutOfBounds => &["out of bounds table access", "undefined element"],
+        Trap::UninitializedElement => &["uninitialized element", "undefined element"],
+        Trap::IndirectCallTypeMismatch => &["indirect call type mismatch"],
+        Trap::CallStackExhausted => &["call stack exhausted"],
+        Trap::Host(_) | Trap::Invalid => &[],
+    }
+}
+
+fn id(items: &[Sexp]) -> Option<&str> {
+    items.get(1)?.atom().filter(|a| a.starts_with('$'))
+}
+
+/// The bytes of a `(module binary ...)`.
+fn binary(items: &[Sexp]) -> Result<Vec<u8>, Skipped> {
+    let rest = &items[1 + id(items).is_some() as usize..];
+    match rest.first().and_then(Sexp::atom) {
+        Some("binary") => Ok(rest[1..]
+            .iter()
+            .filter_map(Sexp::string)
+            .flatten()
+            .copied()
+            .collect()),
+        _ => Err(Skipped),
+    }
+}
+
+fn message(items: &[Sexp]) -> Result<String, String> {
+    let m = items
+        .last()
+        .and_then(Sexp::string)
+        .ok_or("expected a message")?;
+    Ok(String::from_utf8_lossy(m).into())
+}
+
+fn value(s: &Sexp) -> Result<Value, String> {
+    match expected(s)? {
+        Expected::Value(v) => Ok(v),
+        _ => Err(format!("expected a value: {s:?}")),
+    }
+}
+
+fn expected(s: &Sexp) -> Result<Expected, String> {
+    let bad = || format!("malformed value {s:?}");
+    let items = s.list().ok_or_else(bad)?;
+    let arg = |i: usize| items.get(i).and_then(Sexp::atom).ok_or_else(bad);
+    let nan = |a: &str| match a {
+        "nan:canonical" => Some(Nan::Canonical),
+        "nan:arithmetic" => Some(Nan::Arithmetic),
+        _ => None,
+    };
+    Ok(match s.head().ok_or_else(bad)? {
+        "i32.const" => Expected::Value(Value::I32(int(arg(1)?, 32).ok_or_else(bad)? as i32)),
+        "i64.const" => Expected::Value(Value::I64(int(arg(1)?, 64).ok_or_else(bad)? as i64)),
+        "f32.const" => match nan(arg(1)?) {
+            Some(n) => Expected::F32(n),
+            None => Expected::Value(Value::F32(F32(
+                float(arg(1)?, 23, 8).ok_or_else(bad)? as u32
+            ))),
+        },
+        "f64.const" => match nan(arg(1)?) {
+            Some(n) => Expected::F64(n),
+            None => Expected::Value(Value::F64(F64(float(arg(1)?, 52, 11).ok_or_else(bad)?))),
+        },
+        "v128.const" => {
+            let shape = Shape::parse(arg(1)?).ok_or_else(bad)?;
+            let lanes = items[2..]
+                .iter()
+                .map(|l| {
+                    let l = l.atom().ok_or_else(bad)?;
+                    match nan(l) {
+                        Some(n) => Ok(Lane::Nan(n)),
+                        None => shape.lane(l).map(Lane::Bits).ok_or_else(bad),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if lanes.len() != shape.lanes() {
+                return Err(bad());
+            }
+            if lanes.iter().any(|l| matches!(l, Lane::Nan(_))) {
+                Expected::V128(shape, lanes)
+            } else {
+                let v = lanes.iter().enumerate().fold(0u128, |v, (i, l)| match l {
+                    Lane::Bits(b) => v | (*b as u128) << (i as u32 * shape.bits()),
+                    Lane::Nan(_) => v,
+                });
+                Expected::Value(Value::V128(v))
+            }
+        }
+        "ref.null" => match arg(1)? {
+            "func" => Expected::Value(Value::FuncRef(None)),
+            "extern" => Expected::Value(Value::ExternRef(None)),
+            _ => return Err(bad()),
+        },
+        "ref.extern" => Expected::Value(Value::ExternRef(Some(
+            int(arg(1)?, 32).ok_or_else(bad)? as u32
+        ))),
+        "ref.func" => Expected::RefFunc,
+        "either" => Expected::Either(items[1..].iter().map(expected).collect::<Result<_, _>>()?),
+        _ => return Err(bad()),
+    })
+}
+
+fn matches(v: &Value, e: &Expected) -> bool {
+    match (v, e) {
+        (_, Expected::Value(e)) => v == e,
+        (Value::F32(x), Expected::F32(n)) => is_nan(x.0 as u64, 23, 8, *n),
+        (Value::F64(x), Expected::F64(n)) => is_nan(x.0, 52, 11, *n),
+        (Value::V128(x), Expected::V128(shape, lanes)) => {
+            let bits = shape.bits();
+            lanes.iter().enumerate().all(|(i, l)| {
+                let lane = (x >> (i as u32 * bits)) as u64 & (u64::MAX >> (64 - bits));
+                match l {
+                    Lane::Bits(b) => lane == *b,
+                    Lane::Nan(n) if *shape == Shape::F32x4 => is_nan(lane, 23, 8, *n),
+                    Lane::Nan(n) => is_nan(lane, 52, 11, *n),
+                }
+            })
+        }
+        (Value::FuncRef(f), Expected::RefFunc) => f.is_some(),
+        (_, Expected::Either(es)) => es.iter().any(|e| matches(v, e)),
+        _ => false,
+    }
+}
+
+/// Whether the bits of a float with `m` mantissa and `e` exponent bits are
+/// a NaN of the given kind, with either sign.
+fn is_nan(bits: u64, m: u32, e: u32, n: Nan) -> bool {
+    let abs = bits & !(1 << (m + e));
+    let quiet = ((1 << e) - 1) << m | 1 << (m - 1);
+    match n {
+        Nan::Canonical => abs == quiet,
+        Nan::Arithmetic => abs & quiet == quiet,
+    }
+}
+
+/// Parses an integer literal of the given width to its two's complement
+/// bits, accepting anything in the signed or unsigned range.
+fn int(s: &str, bits: u32) -> Option<u64> {
+    let s = s.replace('_', "");
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, &s[..]),
+    };
+    let n = match s.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16).ok()?,
+        None => s.parse::<u128>().ok()?,
+    };
+    let mask = u64::MAX >> (64 - bits);
+    if negative {
+        (n <= 1 << (bits - 1)).then_some((n as u64).wrapping_neg() & mask)
+    } else {
+        (n <= mask as u128).then_some(n as u64)
+    }
+}
+
+/// Parses a float literal to the bits of a float with `m` mantissa and `e`
+/// exponent bits.
+fn float(s: &str, m: u32, e: u32) -> Option<u64> {
+    let s = s.replace('_', "");
+    let (sign, s) = match s.as_bytes().first()? {
+        b'-' => (1u64 << (m + e), &s[1..]),
+        b'+' => (0, &s[1..]),
+        _ => (0, &s[..]),
+    };
+    let inf = ((1u64 << e) - 1) << m;
+    let bits = if s == "inf" {
+        inf
+    } else if s == "nan" {
+        inf | 1 << (m - 1)
+    } else if let Some(payload) = s.strip_prefix("nan:0x") {
+        let payload = u64::from_str_radix(payload, 16).ok()?;
+        if payload == 0 || payload >> m != 0 {
+            return None;
+        }
+        inf | payload
+    } else if let Some(hex) = s.strip_prefix("0x") {
+        hex_float(hex, m, e)?
+    } else if !s
+        .bytes()
+        .all(|c| c.is_ascii_digit() || b".eE+-".contains(&c))
+    {
+        return None;
+    } else if m == 23 {
+        s.parse::<f32>().ok()?.to_bits() as u64
+    } else {
+        s.parse::<f64>().ok()?.to_bits()
+    };
+    Some(sign | bits)
+}
+
+/// Parses the digits of a hex float after the `0x`, rounding to nearest
+/// even.
+fn hex_float(s: &str, m: u32, e: u32) -> Option<u64> {
+    let (digits, exp) = match s.split_once(['p', 'P']) {
+        Some((d, x)) => (d, x.parse::<i64>().ok()?),
+        None => (s, 0),
+    };
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    if int.is_empty() {
+        return None;
+    }
+    // The value is `mant * 2^exp`, with `sticky` set for nonzero digits
+    // that didn't fit.
+    let mut mant = 0u128;
+    let mut exp = exp;
+    let mut sticky = false;
+    for (i, c) in int.chars().chain(frac.chars()).enumerate() {
+        let d = c.to_digit(16)? as u128;
+        let fraction = i >= int.len();
+        if mant >> 120 == 0 {
+            mant = mant << 4 | d;
+            if fraction {
+                exp -= 4;
+            }
+        } else {
+            sticky |= d != 0;
+            if !fraction {
+                exp += 4;
+            }
+        }
+    }
+    if mant == 0 {
+        return Some(0);
+    }
+
+    let bias = (1i64 << (e - 1)) - 1;
+    let top = 127 - mant.leading_zeros() as i64;
+    // The exponent of the lowest mantissa bit in the result.
+    let lsb = (top + exp - m as i64).max(1 - bias - m as i64);
+    let shift = lsb - exp;
+    let q = if shift <= 0 {
+        mant << -shift
+    } else if shift >= 128 {
+        0
+    } else {
+        let q = mant >> shift;
+        let rem = mant & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if rem > half || rem == half && (sticky || q & 1 == 1) {
+            q + 1
+        } else {
+            q
+        }
+    };
+    // Normal numbers carry their implicit bit into the exponent field, and
+    // rounding up to the next power of two carries into it too.
+    let biased = lsb + m as i64 + bias - 1;
+    let inf = ((1u64 << e) - 1) << m;
+    if biased >= (1 << e) - 1 {
+        return Some(inf);
+    }
+    let bits = if q >> m == 0 {
+        q as u64
+    } else {
+        ((biased as u64) << m).wrapping_add(q as u64)
+    };
+    Some(bits.min(inf))
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+use wasm_bin::{
+    branch_hints::{BranchHint, BranchHints},
+    decode::decode,
+    modules::{Funcidx, Locals},
+    Vector,
+};
+
+#[rustfmt::skip]
+const MODULE: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+    // type: [() -> i32]
+    0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7f,
+    // func: [0]
+    0x03, 0x02, 0x01, 0x00,
+    // code
+    0x0a, 0x1a, 0x01,
+    0x18, 0x01, 0x01, 0x7f,
+    0x02, 0x40,
+    0x03, 0x40, 0x20, 0x00, 0x0d, 0x01, 0x0b,
+    0x0b,
+    0x41, 0x01,
+    0x04, 0x7f, 0x41, 0x02, 0x05, 0x41, 0x03, 0x0b,
+    0x0b,
+];
+
+fn hints(hints: &[(u32, BranchHint)]) -> BranchHints {
+    let hints = hints
+        .iter()
+        .map(|&(position, hint)| ((Funcidx(0), position), hint));
+    BranchHints(BTreeMap::from_iter(hints))
+}
+
+#[test]
+fn write_with_branch_hints() {
+    let m = decode(MODULE).unwrap();
+    let (_, funcs) = m.write_with_offsets().unwrap();
+    // The `br_if` and the `if`, at these offsets from the local declarations.
+    let offsets: Vec<_> = [3, 5]
+        .map(|i| (funcs[0].instrs[i].offset - funcs[0].locals) as u32)
+        .into();
+    assert_eq!(offsets, [0x09, 0x0f]);
+
+    let mut bytes = vec![];
+    let hints = hints(&[(3, BranchHint::Likely), (5, BranchHint::Unlikely)]);
+    m.write_with_branch_hints(&mut bytes, &hints).unwrap();
+    let mut expected = MODULE[..0x13].to_vec();
+    expected.extend([0x00, 0x23, 0x19]);
+    expected.extend(b"metadata.code.branch_hint");
+    expected.extend([0x01, 0x00, 0x02, 0x09, 0x01, 0x01, 0x0f, 0x01, 0x00]);
+    expected.extend(&MODULE[0x13..]);
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn hints_follow_the_encoding() {
+    // Another local declaration moves every instruction along by three
+    // bytes, and the offsets in the section with them.
+    let mut m = decode(MODULE).unwrap();
+    let code = &mut m.codesec.as_mut().unwrap().0 .0 .0[0].0;
+    let mut locals = code.t.0.to_vec();
+    locals.insert(
+        0,
+        Locals {
+            n: 200,
+            t: locals[0].t,
+        },
+    );
+    code.t = Vector(locals.into());
+
+    let mut bytes = vec![];
+    let hints = hints(&[(3, BranchHint::Likely), (5, BranchHint::Unlikely)]);
+    m.write_with_branch_hints(&mut bytes, &hints).unwrap();
+    let section = b"metadata.code.branch_hint";
+    let at = bytes
+        .windows(section.len())
+        .position(|w| w == section)
+        .unwrap();
+    let contents = &bytes[at + section.len()..][..9];
+    assert_eq!(
+        contents,
+        [0x01, 0x00, 0x02, 0x0c, 0x01, 0x01, 0x12, 0x01, 0x00]
+    );
+}
+
+#[test]
+fn rejects_hints_off_a_branch() {
+    let m = decode(MODULE).unwrap();
+    for position in [0, 4, 40] {
+        let hints = hints(&[(position, BranchHint::Likely)]);
+        assert!(m.write_with_branch_hints(&mut vec![], &hints).is_err());
+    }
+    let hints = BranchHints(BTreeMap::from([((Funcidx(1), 3), BranchHint::Likely)]));
+    assert!(m.write_with_branch_hints(&mut vec![], &hints).is_err());
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeSet;
+use wasm_bin::{
+    cfg::{self, BasicBlock, Cfg, Loop, Terminator},
+    instructions::{Blocktype, Expr, Instr, Opcode},
+    modules::{Func, Labelidx, Localidx},
+    Vector,
+};
+use Instr::*;
+
+fn func(e: Vec<Instr>) -> Func {
+    Func {
+        t: Vector(Box::new([])),
+        e: Expr(e.into()),
+    }
+}
+
+fn block(instrs: Vec<Instr>, terminator: Terminator) -> BasicBlock {
+    BasicBlock { instrs, terminator }
+}
+
+const GET: Instr = LocalGet(Localidx(0));
+
+#[test]
+fn from_func() {
+    let cfg = Cfg::from_func(&func(vec![
+        GET,
+        If(
+            Blocktype::Empty,
+            Box::new([I32Const(1), Opcode(Opcode::Drop)]),
+        ),
+        Loop(Blocktype::Empty, Box::new([GET, BrIf(Labelidx(0))])),
+    ]))
+    .unwrap();
+    use Terminator::*;
+    assert_eq!(
+        cfg.blocks,
+        [
+            block(
+                vec![GET],
+                Branch {
+                    then: 2,
+                    otherwise: 3
+                }
+            ),
+            block(vec![], Return),
+            block(vec![I32Const(1), Opcode(Opcode::Drop)], Jump(3)),
+            block(vec![], Jump(4)),
+            block(
+                vec![GET],
+                Branch {
+                    then: 4,
+                    otherwise: 5
+                }
+            ),
+            block(vec![], Jump(6)),
+            block(vec![], Jump(1)),
+        ]
+    );
+    assert_eq!(cfg.reverse_postorder(), [0, 2, 3, 4, 5, 6, 1]);
+
+    let dom = cfg.dominators();
+    let idoms: Vec<_> = (0..cfg.blocks.len()).map(|b| dom.idom(b)).collect();
+    assert_eq!(
+        idoms,
+        [None, Some(6), Some(0), Some(0), Some(3), Some(4), Some(5)]
+    );
+    let loops = cfg.loops(&dom);
+    assert_eq!(
+        loops.0,
+        [Loop {
+            header: 4,
+            blocks: BTreeSet::from([4]),
+            parent: None,
+        }]
+    );
+}
+
+#[test]
+fn nested_loops_and_unreachable_code() {
+    let cfg = Cfg::from_func(&func(vec![
+        Loop(
+            Blocktype::Empty,
+            Box::new([Loop(
+                Blocktype::Empty,
+                Box::new([GET, BrIf(Labelidx(0)), GET, BrIf(Labelidx(1))]),
+            )]),
+        ),
+        Br(Labelidx(0)),
+        Opcode(Opcode::Nop),
+    ]))
+    .unwrap();
+    assert_eq!(cfg.blocks.len(), 9);
+    assert_eq!(
+        cfg.blocks[8],
+        block(vec![Opcode(Opcode::Nop)], Terminator::Jump(1))
+    );
+    assert_eq!(
+        cfg.predecessors(),
+        [
+            vec![],
+            vec![7, 8],
+            vec![0, 4],
+            vec![2, 3],
+            vec![3],
+            vec![4],
+            vec![5],
+            vec![6],
+            vec![]
+        ]
+    );
+
+    let dom = cfg.dominators();
+    let idoms: Vec<_> = (0..cfg.blocks.len()).map(|b| dom.idom(b)).collect();
+    assert_eq!(
+        idoms,
+        [
+            None,
+            Some(7),
+            Some(0),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            None
+        ]
+    );
+    assert!(!dom.is_reachable(8));
+    assert!(dom.dominates(2, 4) && dom.dominates(3, 1) && dom.dominates(5, 5));
+    assert!(!dom.dominates(4, 3) && !dom.dominates(0, 8));
+    assert_eq!(dom.children()[3], [4]);
+
+    let loops = cfg.loops(&dom);
+    assert_eq!(
+        loops.0,
+        [
+            Loop {
+                header: 2,
+                blocks: BTreeSet::from([2, 3, 4]),
+                parent: None,
+            },
+            Loop {
+                header: 3,
+                blocks: BTreeSet::from([3]),
+                parent: Some(0),
+            },
+        ]
+    );
+    assert_eq!(loops.innermost(3), Some(1));
+    assert_eq!(loops.innermost(4), Some(0));
+    assert_eq!(loops.innermost(5), None);
+    assert_eq!([3, 4, 5].map(|b| loops.depth(b)), [2, 1, 0]);
+}
+
+#[test]
+fn br_table() {
+    let cfg = Cfg::from_func(&func(vec![Block(
+        Blocktype::Empty,
+        Box::new([
+            GET,
+            BrTable(Vector(Box::new([Labelidx(1), Labelidx(0)])), Labelidx(1)),
+        ]),
+    )]))
+    .unwrap();
+    assert_eq!(
+        cfg.blocks[0].terminator,
+        Terminator::Table {
+            targets: Box::new([1, 2]),
+            default: 1,
+        }
+    );
+    assert_eq!(cfg.successors(0), [1, 2]);
+}
+
+#[test]
+fn label_out_of_scope() {
+    let err = Cfg::from_func(&func(vec![Block(
+        Blocktype::Empty,
+        Box::new([Br(Labelidx(2))]),
+    )]))
+    .unwrap_err();
+    assert_eq!(err, cfg::Error(Labelidx(2)));
+    assert_eq!(err.to_string(), "label 2 is not in scope");
+    assert!(Cfg::from_func(&func(vec![BrIf(Labelidx(1))])).is_err());
+    assert!(Cfg::from_func(&func(vec![BrTable(
+        Vector(Box::new([Labelidx(0), Labelidx(1)])),
+        Labelidx(0)
+    )]))
+    .is_err());
+}
@@ -0,0 +1,218 @@
+use std::{env, fs, path::PathBuf, process::Command};
+use wasm_bin::{
+    decode::decode,
+    modules::{Custom, KnownSection, Module, Placement, Section, Typesec},
+    types::{Functype, Resulttype},
+    values::Name,
+    Grammar, Vector,
+};
+
+/// A function returning `i32.const 2` after a block it drops, exported as
+/// `f`, followed by a custom section.
+const MODULE: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+    0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7f, // type
+    0x03, 0x02, 0x01, 0x00, // function
+    0x07, 0x05, 0x01, 0x01, 0x66, 0x00, 0x00, // export
+    0x0a, 0x0e, 0x01, 0x0c, 0x01, 0x01, 0x7e, 0x02, 0x7f, 0x41, 0x01, 0x0b, 0x1a, 0x41, 0x02,
+    0x0b, // code
+    0x00, 0x06, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f, // custom
+];
+
+fn write(name: &str, bytes: &[u8]) -> PathBuf {
+    let path = env::temp_dir().join(format!("wasm-bin-{}-{name}.wasm", std::process::id()));
+    fs::write(&path, bytes).unwrap();
+    path
+}
+
+fn run(args: &[&str]) -> (i32, String, String) {
+    let out = Command::new(env!("CARGO_BIN_EXE_wasm-bin"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        out.status.code().unwrap(),
+        String::from_utf8(out.stdout).unwrap(),
+        String::from_utf8(out.stderr).unwrap(),
+    )
+}
+
+const SECTIONS: &str = "\
+Sections:
+  0x00000008  1 type      size 5        count 1
+  0x0000000f  3 function  size 2        count 1
+  0x00000013  7 export    size 5        count 1
+  0x0000001a 10 code      size 14       count 1
+  0x0000002a  0 custom    size 6        name \"hello\"
+";
+
+#[test]
+fn dump() {
+    let path = write("dump", MODULE);
+    let (code, out, _) = run(&["dump", path.to_str().unwrap()]);
+    assert_eq!(code, 0);
+    assert_eq!(out, SECTIONS);
+}
+
+#[test]
+fn dump_disassembly() {
+    let path = write("dump-d", MODULE);
+    let (code, out, _) = run(&["dump", "-d", path.to_str().unwrap()]);
+    assert_eq!(code, 0);
+    let disassembly = "
+Code:
+func[0] at 0x1d, size 12:
+  0x0000001f: local 1 x i64
+  0x00000021: block (result i32)
+  0x00000023:   i32.const 1
+  0x00000025: end
+  0x00000026: drop
+  0x00000027: i32.const 2
+  0x00000029: end
+";
+    assert_eq!(out, format!("{SECTIONS}{disassembly}"));
+}
+
+#[test]
+fn dump_nan() {
+    // Negative and positive NaNs, each with a non-canonical payload and with
+    // the canonical one.
+    let bytes = [
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type
+        0x03, 0x02, 0x01, 0x00, // function
+        0x0a, 0x24, 0x01, 0x22, 0x00, // code
+        0x43, 0x01, 0x00, 0x80, 0xff, 0x1a, // f32.const -nan:0x1
+        0x43, 0x00, 0x00, 0xc0, 0x7f, 0x1a, // f32.const nan
+        0x44, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x7f, 0x1a, // f64.const nan:0x1
+        0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0xff, 0x1a, // f64.const -nan
+        0x0b,
+    ];
+    let path = write("dump-nan", &bytes);
+    let (code, out, _) = run(&["dump", "-d", path.to_str().unwrap()]);
+    assert_eq!(code, 0);
+    let consts: Vec<_> = out
+        .lines()
+        .filter_map(|l| Some(l.split_once(": ")?.1))
+        .filter(|i| i.contains(".const"))
+        .collect();
+    assert_eq!(
+        consts,
+        [
+            "f32.const -nan:0x1",
+            "f32.const nan",
+            "f64.const nan:0x1",
+            "f64.const -nan",
+        ]
+    );
+}
+
+#[test]
+fn dump_malformed() {
+    let path = write("malformed", &MODULE[..30]);
+    let (code, out, err) = run(&["dump", path.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    assert_eq!(out, "");
+    assert!(err.ends_with("unexpected end at offset 0x1c\n"), "{err}");
+}
+
+#[test]
+fn usage() {
+    assert_eq!(run(&[]).0, 2);
+    assert_eq!(run(&["dump"]).0, 2);
+    assert_eq!(run(&["dump", "-x", "a.wasm"]).0, 2);
+    assert_eq!(run(&["frobnicate", "a.wasm"]).0, 2);
+    assert_eq!(run(&["strip", "--name"]).0, 2);
+    assert_eq!(run(&["strip", "a.wasm", "b.wasm"]).0, 2);
+}
+
+/// A module with a type section and custom sections before and after it.
+fn with_customs(names: &[&str]) -> Module {
+    let mut m = Module {
+        typesec: Some(Typesec(Section(Vector(
+            [Functype {
+                parameters: Resulttype(Vector([].into())),
+                results: Resulttype(Vector([].into())),
+            }]
+            .into(),
+        )))),
+        ..Default::default()
+    };
+    for (i, name) in names.iter().enumerate() {
+        let placement = match i % 2 {
+            0 => Placement::Before(KnownSection::Type),
+            _ => Placement::Before(KnownSection::Import),
+        };
+        let custom = Custom {
+            name: Name(name.to_string()),
+            contents: [i as u8].into(),
+        };
+        m.insert_custom(placement, custom);
+    }
+    m
+}
+
+fn strip(name: &str, m: &Module, args: &[&str]) -> Module {
+    let mut bytes = vec![];
+    m.write(&mut bytes).unwrap();
+    let path = write(name, &bytes);
+    let out = path.with_extension("out.wasm");
+    let path = path.to_str().unwrap();
+    let out = out.to_str().unwrap();
+    let (code, _, err) = run(&[&["strip"], args, &["-o", out, path]].concat());
+    assert_eq!(code, 0, "{err}");
+    decode(&fs::read(out).unwrap()).unwrap()
+}
+
+const NAMES: [&str; 6] = [
+    "name",
+    ".debug_info",
+    "producers",
+    "sourceMappingURL",
+    "hello",
+    ".debugger",
+];
+
+#[test]
+fn strip_all() {
+    let m = with_customs(&NAMES);
+    let mut expected = m.clone();
+    expected.retain_customs(|_| false);
+    assert_eq!(strip("all", &m, &[]), expected);
+}
+
+#[test]
+fn strip_in_place() {
+    let mut m = with_customs(&NAMES);
+    let mut bytes = vec![];
+    m.write(&mut bytes).unwrap();
+    let path = write("in-place", &bytes);
+    let (code, _, _) = run(&["strip", "--debug", path.to_str().unwrap()]);
+    assert_eq!(code, 0);
+    m.retain_customs(|c| ["producers", "hello", ".debugger"].contains(&c.name.0.as_str()));
+    assert_eq!(decode(&fs::read(&path).unwrap()), Ok(m));
+}
+
+#[test]
+fn strip_debug() {
+    let m = with_customs(&NAMES);
+    let mut expected = m.clone();
+    expected.retain_customs(|c| ["producers", "hello", ".debugger"].contains(&c.name.0.as_str()));
+    assert_eq!(strip("debug", &m, &["--debug"]), expected);
+}
+
+#[test]
+fn strip_by_name() {
+    let m = with_customs(&NAMES);
+    let mut expected = m.clone();
+    expected.retain_customs(|c| {
+        ["name", ".debug_info", "sourceMappingURL"].contains(&c.name.0.as_str())
+    });
+    let args = ["--name", "h*o", "--name", "pro?ucers", "--name", "*ger"];
+    assert_eq!(strip("glob", &m, &args), expected);
+
+    let mut expected = m.clone();
+    expected.retain_customs(|c| ["producers", "hello"].contains(&c.name.0.as_str()));
+    let args = ["--debug", "--name", ".debug*"];
+    assert_eq!(strip("debug-glob", &m, &args), expected);
+}
@@ -0,0 +1,105 @@
+//! Builders shared by the integration tests. Each test crate uses some of
+//! them.
+#![allow(dead_code)]
+
+use wasm_bin::{
+    instructions::{Expr, Instr, Opcode},
+    interpreter::{Extern, Store, Trap, Value},
+    modules::{
+        Code, Codesec, Export, Exportdesc, Exportsec, Func, Funcidx, Funcsec, Localidx, Locals,
+        Module, Section, Typeidx, Typesec,
+    },
+    names::{NameAssoc, NameMap},
+    types::{Functype, Numtype, Resulttype, Valtype},
+    values::Name,
+    Vector,
+};
+
+pub const I32: Valtype = Valtype::Numtype(Numtype::I32);
+pub const I64: Valtype = Valtype::Numtype(Numtype::I64);
+
+pub fn functype(parameters: &[Valtype], results: &[Valtype]) -> Functype {
+    Functype {
+        parameters: Resulttype(Vector(parameters.into())),
+        results: Resulttype(Vector(results.into())),
+    }
+}
+
+pub fn code(locals: &[Locals], e: &[Instr]) -> Code {
+    Code(Func {
+        t: Vector(locals.into()),
+        e: Expr(e.into()),
+    })
+}
+
+/// A module with the given types, functions given by their type index and
+/// body, and function exports.
+pub fn module(types: &[Functype], funcs: &[(u32, Code)], exports: &[(&str, u32)]) -> Module {
+    let exportsec = (!exports.is_empty()).then(|| {
+        let exports = exports.iter().map(|&(nm, x)| Export {
+            nm: Name(nm.to_string()),
+            d: Exportdesc::Func(Funcidx(x)),
+        });
+        Exportsec(Section(Vector(exports.collect())))
+    });
+    Module {
+        typesec: Some(Typesec(Section(Vector(types.into())))),
+        funcsec: Some(Funcsec(Section(Vector(
+            funcs.iter().map(|&(t, _)| Typeidx(t)).collect(),
+        )))),
+        exportsec,
+        codesec: Some(Codesec(Section(Vector(
+            funcs.iter().map(|(_, c)| c.clone()).collect(),
+        )))),
+        ..Default::default()
+    }
+}
+
+/// A module exporting `f` of type `[i32] -> [i32]`, with `locals` more i32
+/// locals after the parameter.
+pub fn i32_func(locals: u32, e: &[Instr]) -> Module {
+    module(
+        &[functype(&[I32], &[I32])],
+        &[(0, code(&[Locals { n: locals, t: I32 }], e))],
+        &[("f", 0)],
+    )
+}
+
+/// The first function in the code section.
+pub fn func(m: &Module) -> &Func {
+    &m.codesec.as_ref().unwrap().0 .0 .0[0].0
+}
+
+/// Instantiates the module on its own and calls its export `f`.
+pub fn try_run(m: &Module, args: &[Value]) -> Result<Vec<Value>, Trap> {
+    let mut store = Store::default();
+    let instance = store.instantiate(m, &[]).unwrap();
+    let Some(Extern::Func(f)) = store.export(instance, "f") else {
+        panic!("no function `f`");
+    };
+    store.invoke(f, args)
+}
+
+pub fn run(m: &Module, args: &[Value]) -> Vec<Value> {
+    try_run(m, args).unwrap()
+}
+
+pub fn name_map<I: Copy>(names: &[(I, &str)]) -> NameMap<I> {
+    let names = names.iter().map(|&(idx, name)| NameAssoc {
+        idx,
+        name: Name(name.to_string()),
+    });
+    NameMap(Vector(names.collect()))
+}
+
+pub fn op(op: Opcode) -> Instr {
+    Instr::Opcode(op)
+}
+
+pub fn get(x: u32) -> Instr {
+    Instr::LocalGet(Localidx(x))
+}
+
+pub fn set(x: u32) -> Instr {
+    Instr::LocalSet(Localidx(x))
+}
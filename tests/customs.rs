@@ -0,0 +1,66 @@
+use wasm_bin::{
+    modules::{Custom, KnownSection, Module, Placement},
+    values::Name,
+};
+
+fn custom(name: &str) -> Custom {
+    Custom {
+        name: Name(name.to_string()),
+        contents: Box::new([]),
+    }
+}
+
+fn names(m: &Module) -> Vec<&str> {
+    m.customs().map(|c| c.name.0.as_str()).collect()
+}
+
+#[test]
+fn insert_custom() {
+    let mut m = Module::default();
+    m.insert_custom(Placement::Last, custom("last"));
+    m.insert_custom(Placement::Before(KnownSection::Code), custom("before code"));
+    m.insert_custom(Placement::After(KnownSection::Elem), custom("after elem"));
+    m.insert_custom(Placement::After(KnownSection::Type), custom("after type"));
+    m.insert_custom(Placement::First, custom("first"));
+    m.insert_custom(
+        Placement::Before(KnownSection::Import),
+        custom("before import"),
+    );
+    assert_eq!(
+        names(&m),
+        [
+            "first",
+            "after type",
+            "before import",
+            "after elem",
+            "before code",
+            "last"
+        ]
+    );
+}
+
+#[test]
+fn after_one_section_is_before_the_next() {
+    for pair in KnownSection::ALL.windows(2) {
+        let mut m = Module::default();
+        m.insert_custom(Placement::Before(pair[1]), custom("before"));
+        m.insert_custom(Placement::After(pair[0]), custom("after"));
+        m.insert_custom(Placement::Before(pair[0]), custom("first"));
+        m.insert_custom(Placement::After(pair[1]), custom("last"));
+        assert_eq!(names(&m), ["first", "after", "before", "last"], "{pair:?}");
+    }
+}
+
+#[test]
+fn slots_around_the_data_count_section() {
+    let mut m = Module::default();
+    m.insert_custom(Placement::After(KnownSection::Elem), custom("9"));
+    m.insert_custom(Placement::After(KnownSection::Datacount), custom("10"));
+    m.insert_custom(Placement::After(KnownSection::Code), custom("11"));
+    m.insert_custom(Placement::After(KnownSection::Data), custom("12"));
+    let slots = [&m.custom9, &m.custom10, &m.custom11, &m.custom12];
+    for (slot, name) in slots.into_iter().zip(["9", "10", "11", "12"]) {
+        assert_eq!(slot.len(), 1);
+        assert_eq!(slot[0].0 .0.name.0, name);
+    }
+}
@@ -0,0 +1,68 @@
+use wasm_bin::{
+    instructions::{Instr, Opcode},
+    modules::{Funcidx, Module, Placement},
+    names::Names,
+    passes::dedup::dedup,
+    validate::validate,
+};
+
+mod common;
+use common::*;
+
+/// A module whose functions have type `[] -> [i32]`, with exports and
+/// function names.
+fn module(bodies: &[&[Instr]], exports: &[(&str, u32)], names: &[(u32, &str)]) -> Module {
+    let funcs: Vec<_> = bodies.iter().map(|e| (0, code(&[], e))).collect();
+    let mut m = common::module(&[functype(&[], &[I32])], &funcs, exports);
+    let names: Vec<_> = names.iter().map(|&(x, nm)| (Funcidx(x), nm)).collect();
+    let names = Names {
+        funcs: name_map(&names),
+        ..Default::default()
+    };
+    m.insert_custom(Placement::Last, names.custom().unwrap());
+    m
+}
+
+#[test]
+fn merges_identical_functions() {
+    // 2 and 4 become identical once 0 and 1 are merged.
+    let mut m = module(
+        &[
+            &[Instr::I32Const(1)],
+            &[Instr::I32Const(1)],
+            &[Instr::Call(Funcidx(0))],
+            &[Instr::I32Const(2)],
+            &[Instr::Call(Funcidx(1))],
+        ],
+        &[("a", 2), ("b", 4), ("c", 3)],
+        &[(0, "one"), (1, "uno"), (2, "a"), (3, "two"), (4, "b")],
+    );
+    assert!(validate(&m).is_ok());
+    dedup(&mut m).unwrap();
+    let expected = module(
+        &[
+            &[Instr::I32Const(1)],
+            &[Instr::Call(Funcidx(0))],
+            &[Instr::I32Const(2)],
+        ],
+        &[("a", 1), ("b", 1), ("c", 2)],
+        &[(0, "one"), (1, "a"), (2, "two")],
+    );
+    assert_eq!(m, expected);
+    assert!(validate(&m).is_ok());
+}
+
+#[test]
+fn keeps_distinct_functions() {
+    let mut m = module(
+        &[
+            &[Instr::I32Const(1)],
+            &[Instr::I32Const(1), Instr::Opcode(Opcode::I32Eqz)],
+        ],
+        &[("a", 0), ("b", 1)],
+        &[],
+    );
+    let before = m.clone();
+    dedup(&mut m).unwrap();
+    assert_eq!(m, before);
+}
@@ -0,0 +1,127 @@
+use wasm_bin::{
+    dylink::{Dylink, ExportInfo, ImportInfo, MemInfo, Symflags},
+    modules::{Custom, KnownSection, Module, Placement},
+    values::Name,
+    Vector,
+};
+
+/// A section in the layout wasm-ld writes for an Emscripten side module:
+/// every subsection present, in order, each vector with one entry.
+#[rustfmt::skip]
+const SECTION: &[u8] = &[
+    // mem info: 16 bytes aligned to 4, one table slot aligned to 1
+    0x01, 0x04, 0x10, 0x02, 0x01, 0x00,
+    // needed: "libfoo.so"
+    0x02, 0x0b, 0x01, 0x09,
+    b'l', b'i', b'b', b'f', b'o', b'o', b'.', b's', b'o',
+    // export info: "tls_counter", TLS
+    0x03, 0x0f, 0x01, 0x0b,
+    b't', b'l', b's', b'_', b'c', b'o', b'u', b'n', b't', b'e', b'r',
+    0x80, 0x02,
+    // import info: "env" "maybe_missing", weak
+    0x04, 0x14, 0x01, 0x03, b'e', b'n', b'v', 0x0d,
+    b'm', b'a', b'y', b'b', b'e', b'_', b'm', b'i', b's', b's', b'i', b'n', b'g',
+    0x01,
+];
+
+fn name(s: &str) -> Name {
+    Name(s.to_string())
+}
+
+fn dylink() -> Dylink {
+    Dylink {
+        mem_info: Some(MemInfo {
+            memory_size: 16,
+            memory_alignment: 2,
+            table_size: 1,
+            table_alignment: 0,
+        }),
+        needed: Vector(Box::new([name("libfoo.so")])),
+        export_info: Vector(Box::new([ExportInfo {
+            nm: name("tls_counter"),
+            flags: Symflags::TLS,
+        }])),
+        import_info: Vector(Box::new([ImportInfo {
+            r#mod: name("env"),
+            nm: name("maybe_missing"),
+            flags: Symflags::BINDING_WEAK,
+        }])),
+        other: Box::new([]),
+    }
+}
+
+#[test]
+fn parse() {
+    assert_eq!(Dylink::parse(SECTION).unwrap(), dylink());
+}
+
+#[test]
+fn custom() {
+    let custom = dylink().custom().unwrap();
+    assert_eq!(custom.name.0, Dylink::NAME);
+    assert_eq!(&*custom.contents, SECTION);
+}
+
+#[test]
+fn each_subsection_round_trips() {
+    let full = dylink();
+    let unknown = Dylink {
+        other: Box::new([(0x07, Box::new([0xaa, 0xbb]))]),
+        ..Default::default()
+    };
+    for d in [
+        Dylink {
+            mem_info: full.mem_info,
+            ..Default::default()
+        },
+        Dylink {
+            needed: full.needed.clone(),
+            ..Default::default()
+        },
+        Dylink {
+            export_info: full.export_info.clone(),
+            ..Default::default()
+        },
+        Dylink {
+            import_info: full.import_info.clone(),
+            ..Default::default()
+        },
+        unknown,
+        Dylink::default(),
+    ] {
+        let custom = d.custom().unwrap();
+        assert_eq!(Dylink::parse(&custom.contents).unwrap(), d);
+    }
+}
+
+#[test]
+fn parse_malformed() {
+    // Past the end of the section, a short mem info, trailing bytes and
+    // invalid UTF-8.
+    for bytes in [
+        &[0x02, 0x0b, 0x01][..],
+        &[0x01, 0x03, 0x10, 0x02, 0x01],
+        &[0x02, 0x03, 0x01, 0x00, 0x00],
+        &[0x02, 0x03, 0x01, 0x01, 0xff],
+    ] {
+        assert!(Dylink::parse(bytes).is_err(), "{bytes:?}");
+    }
+}
+
+#[test]
+fn insert_goes_first() {
+    let custom = |nm: &str| Custom {
+        name: name(nm),
+        contents: Box::new([]),
+    };
+    let mut m = Module::default();
+    m.insert_custom(Placement::First, custom("a"));
+    m.insert_custom(Placement::After(KnownSection::Type), custom(Dylink::NAME));
+    m.insert_custom(Placement::Last, custom("b"));
+    dylink().insert(&mut m).unwrap();
+
+    let names: Vec<_> = m.customs().map(|c| c.name.0.as_str()).collect();
+    assert_eq!(names, [Dylink::NAME, "a", "b"]);
+    assert_eq!(Dylink::from_module(&m).unwrap(), Some(dylink()));
+    assert_eq!(Dylink::from_module(&Module::default()).unwrap(), None);
+}
@@ -0,0 +1,155 @@
+use wasm_bin::{
+    instructions::{Instr, Opcode, TruncSat},
+    interpreter::{Trap, Value},
+    modules::Module,
+    numeric,
+    passes::fold::fold,
+    types::{Numtype, Valtype},
+    validate::validate,
+    values::{F32, F64},
+};
+
+mod common;
+use common::*;
+
+/// A module exporting `f`, a function of type `[] -> [t]`.
+fn module(t: Numtype, e: Vec<Instr>) -> Module {
+    let t = functype(&[], &[Valtype::Numtype(t)]);
+    common::module(&[t], &[(0, code(&[], &e))], &[("f", 0)])
+}
+
+/// Whether two results agree, taking any two canonical NaNs as equal since
+/// Wasm leaves their sign to the engine.
+fn agree(a: &Result<Value, Trap>, b: &Result<Value, Trap>) -> bool {
+    match (a, b) {
+        (Ok(Value::F32(a)), Ok(Value::F32(b))) if a != b => {
+            numeric::Num::F32(a.get()).is_canonical_nan()
+                && numeric::Num::F32(b.get()).is_canonical_nan()
+        }
+        (Ok(Value::F64(a)), Ok(Value::F64(b))) if a != b => {
+            numeric::Num::F64(a.get()).is_canonical_nan()
+                && numeric::Num::F64(b.get()).is_canonical_nan()
+        }
+        _ => a == b,
+    }
+}
+
+/// Folds the function and checks that it gives the result it gave before,
+/// which is `expected`. Returns the folded body.
+fn check(t: Numtype, e: Vec<Instr>, expected: Result<Value, Trap>) -> Vec<Instr> {
+    let m = module(t, e);
+    assert!(validate(&m).is_ok());
+    let before = try_run(&m, &[]).map(|v| v[0]);
+    assert!(agree(&before, &expected), "{before:?} != {expected:?}");
+    let mut folded = m.clone();
+    fold(&mut folded);
+    assert!(validate(&folded).is_ok());
+    let after = try_run(&folded, &[]).map(|v| v[0]);
+    assert!(agree(&after, &before), "{after:?} != {before:?}");
+    func(&folded).e.0.to_vec()
+}
+
+fn f32(bits: u32) -> Instr {
+    Instr::F32Const(F32(bits))
+}
+
+fn f64(x: f64) -> Instr {
+    Instr::F64Const(x.into())
+}
+
+const CANONICAL_F32: Value = Value::F32(F32(0x7fc0_0000));
+const CANONICAL_F64: Value = Value::F64(F64(0x7ff8_0000_0000_0000));
+
+#[test]
+fn div_and_rem_traps_stay() {
+    use numeric::Trap::*;
+    let cases = [
+        (1, 0, Opcode::I32DivS, IntegerDivideByZero),
+        (i32::MIN, -1, Opcode::I32DivS, IntegerOverflow),
+        (1, 0, Opcode::I32RemU, IntegerDivideByZero),
+    ];
+    for (a, b, o, trap) in cases {
+        let e = vec![Instr::I32Const(a), Instr::I32Const(b), op(o)];
+        assert_eq!(check(Numtype::I32, e.clone(), Err(Trap::Numeric(trap))), e);
+    }
+    let e = vec![Instr::I64Const(7), Instr::I64Const(0), op(Opcode::I64RemU)];
+    let trap = Err(Trap::Numeric(IntegerDivideByZero));
+    assert_eq!(check(Numtype::I64, e.clone(), trap), e);
+}
+
+#[test]
+fn div_and_rem_fold() {
+    // `rem_s` of the smallest integer by -1 is 0 rather than an overflow.
+    let e = vec![
+        Instr::I32Const(i32::MIN),
+        Instr::I32Const(-1),
+        op(Opcode::I32RemS),
+    ];
+    let folded = check(Numtype::I32, e, Ok(Value::I32(0)));
+    assert_eq!(folded, [Instr::I32Const(0)]);
+
+    let e = vec![Instr::I32Const(-7), Instr::I32Const(2), op(Opcode::I32DivS)];
+    let folded = check(Numtype::I32, e, Ok(Value::I32(-3)));
+    assert_eq!(folded, [Instr::I32Const(-3)]);
+}
+
+#[test]
+fn trunc_overflow() {
+    use numeric::Trap::*;
+    let cases = [
+        (3e9f32.to_bits(), IntegerOverflow),
+        ((-2.5e9f32).to_bits(), IntegerOverflow),
+        (0x7fc0_0000, InvalidConversionToInteger),
+    ];
+    for (bits, trap) in cases {
+        let e = vec![f32(bits), op(Opcode::I32TruncF32S)];
+        assert_eq!(check(Numtype::I32, e.clone(), Err(Trap::Numeric(trap))), e);
+
+        // The saturating forms never trap, so they fold.
+        let e = vec![f32(bits), Instr::TruncSat(TruncSat::I32TruncSatF32S)];
+        let expected = match trap {
+            IntegerOverflow if f32::from_bits(bits) > 0.0 => i32::MAX,
+            IntegerOverflow => i32::MIN,
+            _ => 0,
+        };
+        let folded = check(Numtype::I32, e, Ok(Value::I32(expected)));
+        assert_eq!(folded, [Instr::I32Const(expected)]);
+    }
+
+    let e = vec![f64(-1.0), op(Opcode::I64TruncF64U)];
+    let trap = Err(Trap::Numeric(IntegerOverflow));
+    assert_eq!(check(Numtype::I64, e.clone(), trap), e);
+}
+
+#[test]
+fn nan_canonicalization() {
+    // Arithmetic on non-NaN or canonical NaN operands gives a canonical NaN,
+    // so it folds to one.
+    for e in [
+        vec![f64(0.0), f64(0.0), op(Opcode::F64Div)],
+        vec![f64(-1.0), op(Opcode::F64Sqrt)],
+    ] {
+        assert_eq!(
+            check(Numtype::F64, e, Ok(CANONICAL_F64)),
+            [Instr::F64Const(F64(0x7ff8_0000_0000_0000))]
+        );
+    }
+    for nan in [0x7fc0_0000, 0xffc0_0000] {
+        let e = vec![f32(nan), f32(1f32.to_bits()), op(Opcode::F32Add)];
+        assert_eq!(
+            check(Numtype::F32, e, Ok(CANONICAL_F32)),
+            [f32(0x7fc0_0000)]
+        );
+    }
+
+    // With a NaN payload the result is an arithmetic NaN the engine picks,
+    // so it stays.
+    let e = vec![f32(0x7fa0_0000), f32(1f32.to_bits()), op(Opcode::F32Add)];
+    let folded = check(Numtype::F32, e.clone(), Ok(Value::F32(F32(0x7fe0_0000))));
+    assert_eq!(folded, e);
+
+    // Bitwise operations keep the payload.
+    let e = vec![f32(0x7fa0_0001), op(Opcode::F32Neg)];
+    let folded = check(Numtype::F32, e, Ok(Value::F32(F32(0xffa0_0001))));
+    assert_eq!(folded, [f32(0xffa0_0001)]);
+}
@@ -0,0 +1,96 @@
+use wasm_bin::{
+    instructions::{Expr, Instr, Opcode},
+    modules::{Funcidx, Global, Globalidx, Globalsec, Localidx, Module, Placement, Section},
+    names::Names,
+    passes::gc::gc,
+    types::{Functype, Globaltype, Mut},
+    validate::validate,
+    Vector,
+};
+
+mod common;
+use common::*;
+
+/// A module exporting `run`, with the given types, globals holding
+/// constants and functions.
+fn module(
+    types: &[Functype],
+    globals: &[i32],
+    funcs: &[(u32, &[Instr])],
+    run: u32,
+    names: Names,
+) -> Module {
+    let funcs: Vec<_> = funcs.iter().map(|&(t, e)| (t, code(&[], e))).collect();
+    let mut m = common::module(types, &funcs, &[("run", run)]);
+    let globals = globals.iter().map(|&n| Global {
+        gt: Globaltype {
+            ty: I32,
+            mutability: Mut::Const,
+        },
+        e: Expr(Box::new([Instr::I32Const(n)])),
+    });
+    m.globalsec = Some(Globalsec(Section(Vector(globals.collect()))));
+    m.insert_custom(Placement::Last, names.custom().unwrap());
+    m
+}
+
+#[test]
+fn removes_unreachable_items() {
+    let mut m = module(
+        &[
+            functype(&[], &[]),
+            functype(&[I32], &[I32]),
+            functype(&[], &[I32]),
+        ],
+        &[0, 1],
+        &[
+            (2, &[Instr::I32Const(7)]),
+            (0, &[Instr::Call(Funcidx(3)), Instr::Opcode(Opcode::Drop)]),
+            (1, &[Instr::LocalGet(Localidx(0))]),
+            (2, &[Instr::GlobalGet(Globalidx(1))]),
+        ],
+        1,
+        Names {
+            funcs: name_map(&[
+                (Funcidx(0), "dead"),
+                (Funcidx(1), "run"),
+                (Funcidx(2), "id"),
+                (Funcidx(3), "get"),
+            ]),
+            globals: name_map(&[(Globalidx(0), "zero"), (Globalidx(1), "one")]),
+            ..Default::default()
+        },
+    );
+    assert!(validate(&m).is_ok());
+    gc(&mut m).unwrap();
+    let expected = module(
+        &[functype(&[], &[]), functype(&[], &[I32])],
+        &[1],
+        &[
+            (0, &[Instr::Call(Funcidx(1)), Instr::Opcode(Opcode::Drop)]),
+            (1, &[Instr::GlobalGet(Globalidx(0))]),
+        ],
+        0,
+        Names {
+            funcs: name_map(&[(Funcidx(0), "run"), (Funcidx(1), "get")]),
+            globals: name_map(&[(Globalidx(0), "one")]),
+            ..Default::default()
+        },
+    );
+    assert_eq!(m, expected);
+    assert!(validate(&m).is_ok());
+}
+
+#[test]
+fn keeps_a_module_without_dead_items() {
+    let mut m = module(
+        &[functype(&[], &[I32])],
+        &[5],
+        &[(0, &[Instr::GlobalGet(Globalidx(0))])],
+        0,
+        Names::default(),
+    );
+    let before = m.clone();
+    gc(&mut m).unwrap();
+    assert_eq!(m, before);
+}
@@ -0,0 +1,160 @@
+use wasm_bin::{
+    generate::{generate, GenerateConfig},
+    instructions::{Blocktype, Instr, Opcode},
+    modules::Module,
+    types::{Functype, Valtype},
+    validate::validate,
+    visit::{self, Visit},
+};
+
+const MVP: GenerateConfig = GenerateConfig {
+    simd: false,
+    reference_types: false,
+    bulk_memory: false,
+    multi_value: false,
+    sign_extension: false,
+    saturating_float_to_int: false,
+    max_funcs: 8,
+    max_instrs: 64,
+    imports: true,
+};
+
+fn check(config: &GenerateConfig) {
+    for seed in 0..100 {
+        let m = generate(seed, config);
+        if let Err(e) = validate(&m) {
+            panic!("seed {seed}: {e}: {m:?}");
+        }
+    }
+}
+
+#[test]
+fn valid() {
+    check(&GenerateConfig::default());
+    check(&MVP);
+    check(&GenerateConfig {
+        max_funcs: 2,
+        max_instrs: 1000,
+        imports: false,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn valid_with_each_proposal() {
+    let proposals: [fn(&mut GenerateConfig); 6] = [
+        |c| c.simd = true,
+        |c| c.reference_types = true,
+        |c| c.bulk_memory = true,
+        |c| c.multi_value = true,
+        |c| c.sign_extension = true,
+        |c| c.saturating_float_to_int = true,
+    ];
+    for enable in proposals {
+        let mut config = MVP;
+        enable(&mut config);
+        check(&config);
+    }
+}
+
+#[test]
+fn deterministic() {
+    let config = GenerateConfig::default();
+    assert_eq!(generate(7, &config), generate(7, &config));
+    assert_ne!(generate(7, &config), generate(8, &config));
+}
+
+/// Counts every instruction, including those in nested blocks.
+#[derive(Default)]
+struct Count(usize);
+
+impl Visit for Count {
+    fn visit_instr(&mut self, i: &Instr) {
+        self.0 += 1;
+        visit::walk_instr(self, i)
+    }
+}
+
+#[test]
+fn limits() {
+    for max_instrs in [0, 1, 2, 3, 10] {
+        let config = GenerateConfig {
+            max_funcs: 3,
+            max_instrs,
+            imports: false,
+            ..Default::default()
+        };
+        let mut funcs = 0;
+        for seed in 0..100 {
+            let m = generate(seed, &config);
+            if let Err(e) = validate(&m) {
+                panic!("seed {seed}: {e}: {m:?}");
+            }
+            assert!(m.importsec.is_none());
+            let codes = m.codesec.as_ref().map_or(&[][..], |c| &c.0 .0 .0);
+            assert!(codes.len() <= 3);
+            for code in codes {
+                let mut count = Count::default();
+                for i in code.0.e.0.iter() {
+                    count.visit_instr(i);
+                }
+                assert!(count.0 <= max_instrs as usize, "seed {seed}: {code:?}");
+            }
+            funcs += codes.len();
+        }
+        assert!(funcs > 0);
+    }
+}
+
+/// Fails on anything from a proposal that isn't in the MVP.
+struct Mvp;
+
+impl Visit for Mvp {
+    fn visit_functype(&mut self, t: &Functype) {
+        assert!(t.results.0 .0.len() <= 1, "multi-value: {t:?}");
+        for t in t.parameters.0 .0.iter().chain(t.results.0 .0.iter()) {
+            assert!(matches!(t, Valtype::Numtype(_)), "{t:?}");
+        }
+    }
+
+    fn visit_instr(&mut self, i: &Instr) {
+        use Opcode::*;
+        match i {
+            Instr::TruncSat(_)
+            | Instr::Opcode(
+                I32Extend8S | I32Extend16S | I64Extend8S | I64Extend16S | I64Extend32S,
+            )
+            | Instr::Select(Some(_))
+            | Instr::MemoryCopy
+            | Instr::MemoryFill
+            | Instr::MemoryInit(_)
+            | Instr::DataDrop(_) => panic!("{i:?}"),
+            _ => visit::walk_instr(self, i),
+        }
+    }
+
+    fn visit_reference_instr(&mut self, i: &Instr) {
+        panic!("{i:?}");
+    }
+
+    fn visit_table_instr(&mut self, i: &Instr) {
+        panic!("{i:?}");
+    }
+
+    fn visit_vector_instr(&mut self, i: &Instr) {
+        panic!("{i:?}");
+    }
+
+    fn visit_blocktype(&mut self, bt: &Blocktype) {
+        assert!(!matches!(bt, Blocktype::TypeIndex(_)), "{bt:?}");
+    }
+}
+
+#[test]
+fn mvp() {
+    for seed in 0..100 {
+        let m: Module = generate(seed, &MVP);
+        Mvp.visit_module(&m);
+        assert!(m.datacountsec.is_none());
+    }
+}
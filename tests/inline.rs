@@ -0,0 +1,105 @@
+use wasm_bin::{
+    instructions::{Blocktype, Instr, Opcode},
+    interpreter::Value,
+    modules::{Funcidx, Labelidx, Localidx, Locals, Module},
+    passes::inline::{inline, InlineConfig},
+    validate::validate,
+    visit::{self, Visit},
+};
+use Instr::*;
+
+mod common;
+use common::*;
+
+/// Exports the function `f` calling the others, which the inliner takes in.
+fn module() -> Module {
+    // x < 10 ? x : 2 * x, with a local and a `return` from inside an `if`.
+    let double = code(
+        &[Locals { n: 1, t: I32 }],
+        &[
+            LocalGet(Localidx(0)),
+            LocalSet(Localidx(1)),
+            LocalGet(Localidx(1)),
+            I32Const(10),
+            Opcode(Opcode::I32LtS),
+            If(
+                Blocktype::Empty,
+                Box::new([LocalGet(Localidx(1)), Opcode(Opcode::Return)]),
+            ),
+            LocalGet(Localidx(1)),
+            I32Const(2),
+            Opcode(Opcode::I32Mul),
+        ],
+    );
+    // x != 0 ? x : -1, with a `br_if` to the function's label.
+    let nonzero = code(
+        &[],
+        &[
+            LocalGet(Localidx(0)),
+            LocalGet(Localidx(0)),
+            BrIf(Labelidx(0)),
+            Opcode(Opcode::Drop),
+            I32Const(-1),
+        ],
+    );
+    let f = code(
+        &[Locals { n: 1, t: I64 }],
+        &[
+            I32Const(3),
+            Call(Funcidx(0)),
+            I32Const(20),
+            Call(Funcidx(0)),
+            Opcode(Opcode::I32Add),
+            I32Const(0),
+            Call(Funcidx(1)),
+            Opcode(Opcode::I32Add),
+            I32Const(5),
+            Call(Funcidx(1)),
+            Opcode(Opcode::I32Add),
+        ],
+    );
+    common::module(
+        &[functype(&[I32], &[I32]), functype(&[], &[I32])],
+        &[(0, double), (0, nonzero), (1, f)],
+        &[("f", 2)],
+    )
+}
+
+struct Calls(usize);
+
+impl Visit for Calls {
+    fn visit_control_instr(&mut self, i: &Instr) {
+        self.0 += matches!(i, Call(_)) as usize;
+        visit::walk_control_instr(self, i)
+    }
+}
+
+#[test]
+fn inlines_locals_and_branches_out() {
+    let mut m = module();
+    assert!(validate(&m).is_ok());
+    assert_eq!(run(&m, &[]), [Value::I32(47)]);
+
+    inline(&mut m, &InlineConfig::default());
+    assert!(validate(&m).is_ok());
+    assert_eq!(run(&m, &[]), [Value::I32(47)]);
+
+    let f = &m.codesec.as_ref().unwrap().0 .0 .0[2].0;
+    let mut calls = Calls(0);
+    calls.visit_func(f);
+    assert_eq!(calls.0, 0);
+    // Each inlined call gets fresh locals for the callee's parameter and
+    // locals.
+    assert_eq!(*f.t.0, [Locals { n: 1, t: I64 }, Locals { n: 6, t: I32 }]);
+}
+
+#[test]
+fn skips_large_callees() {
+    let mut m = module();
+    let config = InlineConfig {
+        max_size: 0,
+        single_call_site: true,
+    };
+    inline(&mut m, &config);
+    assert_eq!(m, module());
+}
@@ -0,0 +1,114 @@
+use wasm_bin::{
+    instructions::{Expr, Instr},
+    interpreter::{Error, Extern, Store, Trap, Value},
+    modules::{
+        Global, Globalidx, Globalsec, Mem, Memsec, Module, Section, Table, Tableidx, Tablesec,
+    },
+    types::{Globaltype, Limits, Memtype, Mut, Reftype, Tabletype},
+    Vector,
+};
+
+mod common;
+use common::*;
+
+/// A module with a table and a memory, neither with a maximum, and functions
+/// of type `[i32] -> [i32]` exported under the given names.
+fn module(funcs: &[(&str, &[Instr])]) -> Module {
+    let codes: Vec<_> = funcs.iter().map(|&(_, e)| (0, code(&[], e))).collect();
+    let exports: Vec<_> = (0..).zip(funcs).map(|(i, &(nm, _))| (nm, i)).collect();
+    let mut m = common::module(&[functype(&[I32], &[I32])], &codes, &exports);
+    m.tablesec = Some(Tablesec(Section(Vector(Box::new([Table(Tabletype {
+        element_type: Reftype::Funcref,
+        limits: Limits::Min(0),
+    })])))));
+    m.memsec = Some(Memsec(Section(Vector(Box::new([Mem(Memtype(
+        Limits::Min(1),
+    ))])))));
+    m
+}
+
+fn call(store: &mut Store, instance: usize, name: &str, arg: i32) -> Vec<Value> {
+    let Some(Extern::Func(f)) = store.export(instance, name) else {
+        panic!("no function {name:?}");
+    };
+    store.invoke(f, &[Value::I32(arg)]).unwrap()
+}
+
+#[test]
+fn growth_is_limited_by_the_store() {
+    let m = module(&[
+        (
+            "table",
+            &[
+                Instr::RefNull(Reftype::Funcref),
+                get(0),
+                Instr::TableGrow(Tableidx(0)),
+            ],
+        ),
+        ("memory", &[get(0), Instr::MemoryGrow]),
+    ]);
+    let mut store = Store::default();
+    store.max_table_elements = 10;
+    store.max_pages = 4;
+    let instance = store.instantiate(&m, &[]).unwrap();
+
+    assert_eq!(call(&mut store, instance, "table", 10), [Value::I32(0)]);
+    assert_eq!(call(&mut store, instance, "table", 1), [Value::I32(-1)]);
+    assert_eq!(call(&mut store, instance, "table", -1), [Value::I32(-1)]);
+    assert_eq!(call(&mut store, instance, "table", 0), [Value::I32(10)]);
+
+    assert_eq!(call(&mut store, instance, "memory", 3), [Value::I32(1)]);
+    assert_eq!(call(&mut store, instance, "memory", 1), [Value::I32(-1)]);
+    assert_eq!(
+        call(&mut store, instance, "memory", 65536),
+        [Value::I32(-1)]
+    );
+    assert_eq!(store.mems[0].pages(), 4);
+}
+
+#[test]
+fn failed_allocation_leaves_the_store_unchanged() {
+    let mut store = Store::default();
+    let ok = module(&[("f", &[Instr::I32Const(0)])]);
+    store.instantiate(&ok, &[]).unwrap();
+    let before = (
+        store.funcs.len(),
+        store.tables.clone(),
+        store.mems.clone(),
+        store.globals.clone(),
+        store.instances.clone(),
+    );
+
+    // The second global reads one that doesn't exist, after the functions,
+    // table, memory and first global have been allocated.
+    let get = |x| Global {
+        gt: Globaltype {
+            ty: I32,
+            mutability: Mut::Const,
+        },
+        e: Expr(Box::new([Instr::GlobalGet(Globalidx(x))])),
+    };
+    let mut bad = ok.clone();
+    bad.globalsec = Some(Globalsec(Section(Vector(Box::new([
+        Global {
+            e: Expr(Box::new([Instr::I32Const(1)])),
+            ..get(0)
+        },
+        get(5),
+    ])))));
+    assert!(matches!(
+        store.instantiate(&bad, &[]),
+        Err(Error::Trap(Trap::Invalid))
+    ));
+    let after = (
+        store.funcs.len(),
+        store.tables.clone(),
+        store.mems.clone(),
+        store.globals.clone(),
+        store.instances.clone(),
+    );
+    assert_eq!(after, before);
+
+    // The store is still usable, and the next instance gets the next index.
+    assert_eq!(store.instantiate(&ok, &[]).unwrap(), 1);
+}
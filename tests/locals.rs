@@ -0,0 +1,124 @@
+use wasm_bin::{
+    instructions::{Blocktype, Instr, Opcode},
+    interpreter::Value,
+    modules::Labelidx,
+    passes::locals::coalesce_func,
+    validate::validate,
+};
+use Instr::*;
+
+mod common;
+use common::*;
+
+/// Coalesces the function, checking that it still validates and computes
+/// what it did for a few arguments. Returns the new slot of each local after
+/// the parameter and the number of locals left.
+fn coalesce(locals: u32, e: Vec<Instr>) -> (Vec<Option<u32>>, u32) {
+    let mut m = i32_func(locals, &e);
+    assert!(validate(&m).is_ok());
+    let before: Vec<_> = (0..3).map(|arg| run(&m, &[Value::I32(arg)])).collect();
+    let func = &mut m.codesec.as_mut().unwrap().0 .0 .0[0].0;
+    let slots = coalesce_func(func, 1);
+    let left = func.t.0.iter().map(|l| l.n).sum();
+    assert!(validate(&m).is_ok());
+    let after: Vec<_> = (0..3).map(|arg| run(&m, &[Value::I32(arg)])).collect();
+    assert_eq!(after, before);
+    (slots[1..].to_vec(), left)
+}
+
+#[test]
+fn write_inside_if_read_after() {
+    // `b` is read after the `if` that may not write it, so it starts out
+    // live and can't share with `a`.
+    let (a, b) = (1, 2);
+    let (slots, left) = coalesce(
+        2,
+        vec![
+            I32Const(5),
+            set(a),
+            get(a),
+            op(Opcode::Drop),
+            get(0),
+            If(Blocktype::Empty, Box::new([I32Const(7), set(b)])),
+            get(b),
+        ],
+    );
+    assert_ne!(slots[0], slots[1]);
+    assert_eq!(left, 2);
+}
+
+#[test]
+fn loop_carried() {
+    // s += 2 * c for c from 0 to 3. `c` and `s` carry values around the loop
+    // and `t` is live within it, so none of them can share. `d`, used once
+    // the loop is done, can take one of their slots.
+    let (c, t, s, d) = (1, 2, 3, 4);
+    let (slots, left) = coalesce(
+        4,
+        vec![
+            Loop(
+                Blocktype::Empty,
+                Box::new([
+                    get(c),
+                    I32Const(2),
+                    op(Opcode::I32Mul),
+                    set(t),
+                    get(c),
+                    I32Const(1),
+                    op(Opcode::I32Add),
+                    set(c),
+                    get(s),
+                    get(t),
+                    op(Opcode::I32Add),
+                    set(s),
+                    get(c),
+                    I32Const(4),
+                    op(Opcode::I32LtS),
+                    BrIf(Labelidx(0)),
+                ]),
+            ),
+            get(s),
+            get(0),
+            op(Opcode::I32Add),
+            set(d),
+            get(d),
+        ],
+    );
+    assert_eq!(left, 3);
+    let [c, t, s, d] = [c, t, s, d].map(|l| slots[l as usize - 1]);
+    assert!(c != t && t != s && c != s);
+    assert!(d == c || d == t || d == s);
+}
+
+#[test]
+fn set_and_get_across_if_else() {
+    // `z` is written in one arm and read in the other, where it still holds
+    // zero, so it can't share with `a`. `x` and `y` live in one arm each.
+    let (a, z, x, y) = (1, 2, 3, 4);
+    let (slots, left) = coalesce(
+        4,
+        vec![
+            I32Const(9),
+            set(a),
+            get(0),
+            IfElse(
+                Blocktype::ValueType(I32),
+                Box::new([I32Const(4), set(z), get(z)]),
+                Box::new([get(z)]),
+            ),
+            get(a),
+            op(Opcode::I32Add),
+            get(0),
+            IfElse(
+                Blocktype::ValueType(I32),
+                Box::new([I32Const(1), set(x), get(x)]),
+                Box::new([I32Const(2), set(y), get(y)]),
+            ),
+            op(Opcode::I32Add),
+        ],
+    );
+    let [a, z, x, y] = [a, z, x, y].map(|l| slots[l as usize - 1]);
+    assert_ne!(a, z);
+    assert_eq!(x, y);
+    assert!(left < 4);
+}
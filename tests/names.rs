@@ -0,0 +1,77 @@
+use wasm_bin::{
+    modules::{Funcidx, Globalidx},
+    names::{IndirectNameAssoc, IndirectNameMap, Names},
+    values::Name,
+    Vector,
+};
+
+mod common;
+use common::*;
+
+#[rustfmt::skip]
+const SECTION: &[u8] = &[
+    // module: "m"
+    0x00, 0x02, 0x01, b'm',
+    // funcs: 0 "f", 2 "g"
+    0x01, 0x07, 0x02, 0x00, 0x01, b'f', 0x02, 0x01, b'g',
+    // locals: function 2, local 1 "x"
+    0x02, 0x06, 0x01, 0x02, 0x01, 0x01, 0x01, b'x',
+    // globals: 0 "sp"
+    0x07, 0x05, 0x01, 0x00, 0x02, b's', b'p',
+    // an unknown subsection
+    0x0c, 0x02, 0xaa, 0xbb,
+];
+
+fn names() -> Names {
+    Names {
+        module: Some(Name("m".to_string())),
+        funcs: name_map(&[(Funcidx(0), "f"), (Funcidx(2), "g")]),
+        locals: IndirectNameMap(Vector(Box::new([IndirectNameAssoc {
+            idx: Funcidx(2),
+            names: name_map(&[(1, "x")]),
+        }]))),
+        globals: name_map(&[(Globalidx(0), "sp")]),
+        other: Box::new([(0x0c, Box::new([0xaa, 0xbb]))]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn parse() {
+    assert_eq!(Names::parse(SECTION).unwrap(), names());
+}
+
+#[test]
+fn custom() {
+    let custom = names().custom().unwrap();
+    assert_eq!(custom.name.0, Names::NAME);
+    assert_eq!(&*custom.contents, SECTION);
+}
+
+#[test]
+fn parse_malformed() {
+    // Past the end of the section, trailing bytes and invalid UTF-8.
+    for bytes in [
+        &[0x01, 0x07, 0x02][..],
+        &[0x00, 0x03, 0x01, b'm', 0x00],
+        &[0x00, 0x02, 0x01, 0xff],
+    ] {
+        assert!(Names::parse(bytes).is_err());
+    }
+}
+
+#[test]
+fn remap() {
+    let mut names = names();
+    names.funcs.remap(|x| (x.0 != 0).then(|| Funcidx(x.0 - 1)));
+    names.locals.remap(|x| Some(Funcidx(x.0 - 2)));
+    assert_eq!(names.funcs, name_map(&[(Funcidx(1), "g")]));
+    assert_eq!(names.locals.0 .0[0].idx, Funcidx(0));
+
+    let mut map = name_map(&[(Funcidx(0), "a"), (Funcidx(1), "b"), (Funcidx(2), "c")]);
+    map.remap(|x| Some(Funcidx(2 - x.0)));
+    assert_eq!(
+        map,
+        name_map(&[(Funcidx(0), "c"), (Funcidx(1), "b"), (Funcidx(2), "a")])
+    );
+}
@@ -0,0 +1,46 @@
+use wasm_bin::{
+    decode::{decode, Reader},
+    instructions::Instr,
+    validate::validate,
+    Grammar,
+};
+
+#[rustfmt::skip]
+const MODULE: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+    // type: [() -> i32]
+    0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7f,
+    // func: [0, 0]
+    0x03, 0x03, 0x02, 0x00, 0x00,
+    // code
+    0x0a, 0x1f, 0x02,
+    0x18, 0x01, 0x01, 0x7f,
+    0x02, 0x40,
+    0x03, 0x40, 0x20, 0x00, 0x0d, 0x01, 0x0b,
+    0x0b,
+    0x41, 0x01,
+    0x04, 0x7f, 0x41, 0x02, 0x05, 0x41, 0x03, 0x0b,
+    0x0b,
+    0x04, 0x00, 0x41, 0x07, 0x0b,
+];
+
+#[test]
+fn write_with_offsets() {
+    let m = decode(MODULE).unwrap();
+    assert!(validate(&m).is_ok());
+    let (bytes, funcs) = m.write_with_offsets().unwrap();
+    let mut written = vec![];
+    m.write(&mut written).unwrap();
+    assert_eq!(bytes, written);
+    assert_eq!(bytes, MODULE);
+
+    assert_eq!(funcs.len(), 2);
+    assert_eq!((funcs[0].body, funcs[0].locals), (0x17, 0x18));
+    assert_eq!((funcs[1].body, funcs[1].locals), (0x30, 0x31));
+    let offsets: Vec<_> = funcs[0].instrs.iter().map(|i| i.offset).collect();
+    assert_eq!(offsets, [0x1b, 0x1d, 0x1f, 0x21, 0x25, 0x27, 0x29, 0x2c]);
+    for i in funcs.iter().flat_map(|f| f.instrs.iter()) {
+        let instr: Instr = Reader::new(&bytes[i.offset..]).read().unwrap();
+        assert_eq!(&instr, i.instr);
+    }
+}
@@ -0,0 +1,148 @@
+use arbitrary::{Arbitrary, Unstructured};
+use std::{collections::BTreeSet, fmt::Debug};
+use wasm_bin::{
+    decode::decode,
+    instructions::{
+        Expr, Instr, Laneidx, Memarg, MemoryMemarg, Opcode, TruncSat, VectorLaneidx, VectorMemarg,
+        VectorMemargLaneidx, VectorNoImmediate,
+    },
+    modules::{Code, Codesec, Func, Funcsec, Module, Section, Typeidx, Typesec},
+    types::{Functype, Resulttype},
+    Grammar, Vector,
+};
+
+/// Every variant `arbitrary` produces, which it finds from the enum
+/// definition rather than from `ALL`. The derive maps a `u32` onto the
+/// variants, so evenly spaced values reach each of them.
+fn variants<T: for<'a> Arbitrary<'a> + Ord>() -> BTreeSet<T> {
+    let mut out = BTreeSet::new();
+    for k in 0..1u32 << 16 {
+        for x in [k, k << 16] {
+            for bytes in [x.to_le_bytes(), x.to_be_bytes()] {
+                out.insert(T::arbitrary(&mut Unstructured::new(&bytes)).unwrap());
+            }
+        }
+    }
+    out
+}
+
+/// The text format name of a variant, from its identifier: `I32x4DotI16x8S`
+/// is `i32x4.dot_i16x8_s`. A couple of identifiers split what the text
+/// format keeps as one word.
+fn text_name(ident: &str) -> String {
+    match ident {
+        "V128AndNot" => return "v128.andnot".to_string(),
+        "I16x8Q15MulrSatS" => return "i16x8.q15mulr_sat_s".to_string(),
+        _ => {}
+    }
+    let prefixes = [
+        "I8x16", "I16x8", "I32x4", "I64x2", "F32x4", "F64x2", "V128", "I32", "I64", "F32", "F64",
+        "Ref",
+    ];
+    let (prefix, rest) = match prefixes.iter().find(|p| ident.starts_with(**p)) {
+        Some(p) => (format!("{}.", p.to_lowercase()), &ident[p.len()..]),
+        None => (String::new(), ident),
+    };
+    let mut out = prefix;
+    for (i, c) in rest.char_indices() {
+        if c.is_ascii_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+/// A module whose only function holds the instructions, which needn't
+/// validate.
+fn module(e: Vec<Instr>) -> Module {
+    Module {
+        typesec: Some(Typesec(Section(Vector(Box::new([Functype {
+            parameters: Resulttype(Vector(Box::new([]))),
+            results: Resulttype(Vector(Box::new([]))),
+        }]))))),
+        funcsec: Some(Funcsec(Section(Vector(Box::new([Typeidx(0)]))))),
+        codesec: Some(Codesec(Section(Vector(Box::new([Code(Func {
+            t: Vector(Box::new([])),
+            e: Expr(e.into()),
+        })]))))),
+        ..Default::default()
+    }
+}
+
+/// Checks an enum's `ALL` against its definition, its names against its
+/// identifiers, and that each variant encodes and decodes as itself.
+fn check<T>(all: &[T], name: fn(T) -> &'static str, instr: fn(T) -> Instr)
+where
+    T: for<'a> Arbitrary<'a> + Ord + Copy + Debug,
+{
+    let listed: BTreeSet<_> = all.iter().copied().collect();
+    assert_eq!(listed.len(), all.len(), "`ALL` repeats a variant");
+    assert_eq!(listed, variants(), "`ALL` misses a variant");
+
+    let names: BTreeSet<_> = all.iter().map(|&op| name(op)).collect();
+    assert_eq!(names.len(), all.len(), "two variants share a name");
+    let wrong: Vec<_> = all
+        .iter()
+        .filter(|op| name(**op) != text_name(&format!("{op:?}")))
+        .map(|&op| (op, name(op)))
+        .collect();
+    assert!(wrong.is_empty(), "{wrong:?}");
+
+    let m = module(all.iter().map(|&op| instr(op)).collect());
+    let mut bytes = vec![];
+    m.write(&mut bytes).unwrap();
+    assert_eq!(decode(&bytes), Ok(m));
+}
+
+const MEMARG: Memarg = Memarg {
+    align: 1,
+    offset: 2,
+};
+
+#[test]
+fn opcode() {
+    check(Opcode::ALL, Opcode::name, Instr::Opcode);
+}
+
+#[test]
+fn memory_memarg() {
+    check(MemoryMemarg::ALL, MemoryMemarg::name, |op| {
+        Instr::MemoryMemarg(op, MEMARG)
+    });
+}
+
+#[test]
+fn trunc_sat() {
+    check(TruncSat::ALL, TruncSat::name, Instr::TruncSat);
+}
+
+#[test]
+fn vector_memarg() {
+    check(VectorMemarg::ALL, VectorMemarg::name, |op| {
+        Instr::VectorMemarg(op, MEMARG)
+    });
+}
+
+#[test]
+fn vector_memarg_laneidx() {
+    check(VectorMemargLaneidx::ALL, VectorMemargLaneidx::name, |op| {
+        Instr::VectorMemargLaneidx(op, MEMARG, Laneidx(1))
+    });
+}
+
+#[test]
+fn vector_laneidx() {
+    check(VectorLaneidx::ALL, VectorLaneidx::name, |op| {
+        Instr::VectorLaneidx(op, Laneidx(1))
+    });
+}
+
+#[test]
+fn vector_no_immediate() {
+    check(
+        VectorNoImmediate::ALL,
+        VectorNoImmediate::name,
+        Instr::VectorNoImmediate,
+    );
+}
@@ -0,0 +1,68 @@
+use wasm_bin::{
+    generate::{generate, GenerateConfig},
+    instructions::Instr,
+    modules::Module,
+    reduce::reduce,
+    validate::validate,
+    visit::{self, Visit},
+};
+
+#[derive(Default)]
+struct Count {
+    instrs: usize,
+    loops: usize,
+}
+
+impl Visit for Count {
+    fn visit_instr(&mut self, i: &Instr) {
+        self.instrs += 1;
+        self.loops += matches!(i, Instr::Loop(..)) as usize;
+        visit::walk_instr(self, i)
+    }
+}
+
+fn count(m: &Module) -> Count {
+    let mut c = Count::default();
+    c.visit_module(m);
+    c
+}
+
+#[test]
+fn reduces_to_a_minimal_module() {
+    // Without multiple values a function leaves at most one result.
+    let config = GenerateConfig {
+        multi_value: false,
+        max_instrs: 200,
+        ..Default::default()
+    };
+    let mut reduced = 0;
+    for seed in 0..40 {
+        let m = generate(seed, &config);
+        if count(&m).loops == 0 {
+            continue;
+        }
+        let r = reduce(m.clone(), |m| {
+            assert!(validate(m).is_ok());
+            count(m).loops > 0
+        });
+        assert!(validate(&r).is_ok());
+        let c = count(&r);
+        assert_eq!(c.loops, 1);
+        // A loop with a value or a branch in it, a drop and a result.
+        assert!(c.instrs <= 5, "seed {seed}: {r:?}");
+        assert_eq!(r.customs().count(), 0);
+        assert!(r.importsec.is_none() && r.exportsec.is_none() && r.startsec.is_none());
+        assert!(r.tablesec.is_none() && r.memsec.is_none() && r.globalsec.is_none());
+        assert!(r.elemsec.is_none() && r.datasec.is_none());
+        assert_eq!(r.funcsec.map_or(0, |s| s.0 .0 .0.len()), 1);
+        reduced += 1;
+    }
+    assert!(reduced > 0);
+}
+
+#[test]
+fn keeps_the_input_when_nothing_smaller_is_interesting() {
+    let m = generate(1, &GenerateConfig::default());
+    let r = reduce(m.clone(), |c| c == &m);
+    assert_eq!(r, m);
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use wasm_bin::{
+    instructions::{Instr, Opcode},
+    modules::{Funcidx, Localidx, Module, Placement, Typeidx},
+    names::Names,
+    passes::remap::Remap,
+};
+
+mod common;
+use common::*;
+
+fn module(types: &[u32], bodies: &[&[Instr]], export: u32, names: &[(u32, &str)]) -> Module {
+    let funcs: Vec<_> = types
+        .iter()
+        .zip(bodies)
+        .map(|(&t, e)| (t, code(&[], e)))
+        .collect();
+    let mut m = common::module(
+        &[functype(&[], &[]), functype(&[I32], &[I32])],
+        &funcs,
+        &[("f", export)],
+    );
+    let names: Vec<_> = names.iter().map(|&(x, name)| (Funcidx(x), name)).collect();
+    let names = Names {
+        funcs: name_map(&names),
+        ..Default::default()
+    };
+    m.insert_custom(Placement::Last, names.custom().unwrap());
+    m
+}
+
+#[test]
+fn apply() {
+    let mut m = module(
+        &[1, 0, 0],
+        &[
+            &[Instr::LocalGet(Localidx(0))],
+            &[Instr::Call(Funcidx(2))],
+            &[
+                Instr::I32Const(1),
+                Instr::Call(Funcidx(0)),
+                Instr::Opcode(Opcode::Drop),
+            ],
+        ],
+        2,
+        &[(0, "a"), (1, "b"), (2, "c")],
+    );
+    let remap = Remap {
+        types: HashMap::from([(Typeidx(0), Typeidx(1)), (Typeidx(1), Typeidx(0))]),
+        funcs: HashMap::from([(Funcidx(0), Funcidx(2)), (Funcidx(2), Funcidx(0))]),
+        ..Default::default()
+    };
+    remap.apply(&mut m).unwrap();
+    let expected = module(
+        &[0, 1, 1],
+        &[
+            &[Instr::LocalGet(Localidx(0))],
+            &[Instr::Call(Funcidx(0))],
+            &[
+                Instr::I32Const(1),
+                Instr::Call(Funcidx(2)),
+                Instr::Opcode(Opcode::Drop),
+            ],
+        ],
+        0,
+        &[(0, "c"), (1, "b"), (2, "a")],
+    );
+    assert_eq!(m, expected);
+}
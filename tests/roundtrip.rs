@@ -0,0 +1,28 @@
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::prelude::*;
+use wasm_bin::{decode::decode, fuzz::ValidModule, modules::Module, validate::validate, Grammar};
+
+fn encode(m: &Module) -> Vec<u8> {
+    let mut bytes = vec![];
+    m.write(&mut bytes).unwrap();
+    bytes
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn module(data in prop::collection::vec(any::<u8>(), 0..2048)) {
+        let m = Module::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        prop_assert_eq!(decode(&encode(&m)), Ok(m));
+    }
+
+    #[test]
+    fn valid_module(data in prop::collection::vec(any::<u8>(), 0..4096)) {
+        let ValidModule(m) = ValidModule::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        if let Err(e) = validate(&m) {
+            return Err(TestCaseError::fail(format!("{e}: {m:?}")));
+        }
+        prop_assert_eq!(decode(&encode(&m)), Ok(m));
+    }
+}
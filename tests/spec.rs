@@ -0,0 +1,72 @@
+use std::{fs, path::Path, thread};
+use wasm_bin::wast;
+
+/// Runs a script from `tests/wast`, on a thread with a stack deep enough
+/// for the interpreter's recursion. At most `max_skipped` commands may be
+/// skipped for needing the text format.
+///
+/// The scripts are generated by `tests/wast/generate`, which checks every
+/// expected result against V8 through node:
+///
+/// ```sh
+/// cd tests/wast/generate
+/// python3 numeric.py .. && python3 exec.py .. && python3 validation.py ..
+/// ```
+fn run(name: &str, max_skipped: usize) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/wast")
+        .join(name)
+        .with_extension("wast");
+    let script = fs::read_to_string(&path).unwrap();
+    let report = thread::Builder::new()
+        .stack_size(64 << 20)
+        .spawn(move || wast::run(&script))
+        .unwrap()
+        .join()
+        .unwrap()
+        .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    eprintln!(
+        "{name}: {} passed, {} skipped, {} failed",
+        report.passed,
+        report.skipped,
+        report.failures.len()
+    );
+    let failures: Vec<_> = report.failures.iter().map(|f| f.to_string()).collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} commands failed:\n{}",
+        failures.len(),
+        failures.len() + report.passed + report.skipped,
+        failures.join("\n")
+    );
+    assert!(
+        report.skipped <= max_skipped,
+        "{} commands were skipped, more than the {max_skipped} allowed",
+        report.skipped
+    );
+}
+
+macro_rules! spec {
+    ($($name:ident: $max_skipped:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                run(stringify!($name), $max_skipped);
+            }
+        )*
+    };
+}
+
+spec!(binary: 0, exec: 0, numeric: 0, simd: 0, validation: 0);
+
+#[test]
+fn unknown_command() {
+    let script = "(module binary \"\\00asm\\01\\00\\00\\00\")\n(assert_frobnicated)";
+    let report = wast::run(script).unwrap();
+    assert_eq!(report.passed, 1);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(
+        report.failures[0].to_string(),
+        "line 2: unknown command assert_frobnicated"
+    );
+}
@@ -0,0 +1,129 @@
+use wasm_bin::{
+    instructions::{Instr, Opcode},
+    interpreter::Value,
+    modules::{Code, Module},
+    ssa::{self, Function, Target, Terminator},
+    types::Valtype,
+    validate::validate,
+};
+
+mod common;
+use common::*;
+
+/// A module exporting the lowered function as `f`.
+fn module(f: &Function, params: &[Valtype]) -> Module {
+    let t = functype(params, &f.results);
+    common::module(&[t], &[(0, Code(f.lower()))], &[("f", 0)])
+}
+
+fn locals(m: &Module) -> u32 {
+    func(m).t.0.iter().map(|l| l.n).sum()
+}
+
+fn target(block: ssa::BlockId, args: &[ssa::Value]) -> Target {
+    Target {
+        block,
+        args: args.to_vec(),
+    }
+}
+
+#[test]
+fn straight_line() {
+    // (a + b) * (a - b)
+    let mut f = Function::new(&[I32, I32], &[I32]);
+    let entry = f.entry();
+    let [a, b] = *f.params(entry) else {
+        unreachable!()
+    };
+    let sum = f.push(entry, op(Opcode::I32Add), &[a, b], &[I32]);
+    let difference = f.push(entry, op(Opcode::I32Sub), &[a, b], &[I32]);
+    let product = f.push(entry, op(Opcode::I32Mul), &[sum[0], difference[0]], &[I32]);
+    f.terminate(entry, Terminator::Return(product));
+
+    let m = module(&f, &[I32, I32]);
+    assert!(validate(&m).is_ok(), "{m:?}");
+    // The sum waits on the stack under the difference, so every intermediate
+    // stays there.
+    assert_eq!(locals(&m), 0);
+    for (a, b) in [(0, 0), (7, 3), (-5, 12)] {
+        assert_eq!(
+            run(&m, &[Value::I32(a), Value::I32(b)]),
+            [Value::I32((a + b) * (a - b))]
+        );
+    }
+}
+
+#[test]
+fn loop_with_block_parameters() {
+    // The nth Fibonacci number. The back edge passes the pair swapped, so the
+    // copies into the header's parameters must act in parallel.
+    let mut f = Function::new(&[I32], &[I32]);
+    let entry = f.entry();
+    let n = f.params(entry)[0];
+    let header = f.add_block(&[I32, I32, I32]);
+    let body = f.add_block(&[]);
+    let exit = f.add_block(&[I32]);
+
+    let zero = f.push(entry, Instr::I32Const(0), &[], &[I32])[0];
+    let one = f.push(entry, Instr::I32Const(1), &[], &[I32])[0];
+    f.terminate(entry, Terminator::Jump(target(header, &[n, zero, one])));
+
+    let [i, a, b] = *f.params(header) else {
+        unreachable!()
+    };
+    let done = f.push(header, op(Opcode::I32Eqz), &[i], &[I32])[0];
+    f.terminate(
+        header,
+        Terminator::Branch {
+            cond: done,
+            then: target(exit, &[a]),
+            otherwise: target(body, &[]),
+        },
+    );
+
+    let one = f.push(body, Instr::I32Const(1), &[], &[I32])[0];
+    let next = f.push(body, op(Opcode::I32Sub), &[i, one], &[I32])[0];
+    let c = f.push(body, op(Opcode::I32Add), &[a, b], &[I32])[0];
+    f.terminate(body, Terminator::Jump(target(header, &[next, b, c])));
+
+    let result = f.params(exit)[0];
+    f.terminate(exit, Terminator::Return(vec![result]));
+
+    let m = module(&f, &[I32]);
+    assert!(validate(&m).is_ok(), "{m:?}");
+    let (mut a, mut b) = (0, 1);
+    for n in 0..20 {
+        assert_eq!(run(&m, &[Value::I32(n)]), [Value::I32(a)], "{n}");
+        (a, b) = (b, a + b);
+    }
+}
+
+#[test]
+fn table_with_arguments() {
+    // Each target of a `br_table` passes a different constant to the same
+    // block, and the default passes the index itself.
+    let mut f = Function::new(&[I32], &[I32]);
+    let entry = f.entry();
+    let index = f.params(entry)[0];
+    let exit = f.add_block(&[I32]);
+    let consts: Vec<_> = [10, 20, 30]
+        .into_iter()
+        .map(|c| f.push(entry, Instr::I32Const(c), &[], &[I32])[0])
+        .collect();
+    f.terminate(
+        entry,
+        Terminator::Table {
+            index,
+            targets: consts.iter().map(|&c| target(exit, &[c])).collect(),
+            default: target(exit, &[index]),
+        },
+    );
+    let result = f.params(exit)[0];
+    f.terminate(exit, Terminator::Return(vec![result]));
+
+    let m = module(&f, &[I32]);
+    assert!(validate(&m).is_ok(), "{m:?}");
+    for (index, expected) in [(0, 10), (1, 20), (2, 30), (3, 3), (-1, -1)] {
+        assert_eq!(run(&m, &[Value::I32(index)]), [Value::I32(expected)]);
+    }
+}
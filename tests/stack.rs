@@ -0,0 +1,121 @@
+use wasm_bin::{
+    instructions::{Expr, Instr, Opcode},
+    modules::{Func, Labelidx, Localidx, Locals, Module, Section, Tableidx, Typeidx, Typesec},
+    stack::FuncContext,
+    types::{Numtype, Reftype, Valtype},
+    Vector,
+};
+
+mod common;
+use common::*;
+
+const F32: Valtype = Valtype::Numtype(Numtype::F32);
+
+/// A module whose only type is `[i64 f32] -> [i32]`.
+fn module() -> Module {
+    Module {
+        typesec: Some(Typesec(Section(Vector(Box::new([functype(
+            &[I64, F32],
+            &[I32],
+        )]))))),
+        ..Default::default()
+    }
+}
+
+/// The context of a function of type `[i32] -> [i64]` with an `f32` local.
+fn context(m: &Module) -> FuncContext<'_> {
+    let f = Func {
+        t: Vector(Box::new([Locals { n: 1, t: F32 }])),
+        e: Expr(Box::new([])),
+    };
+    FuncContext::new(m, &functype(&[I32], &[I64]), &f)
+}
+
+#[test]
+fn call_indirect() {
+    let m = module();
+    let ctx = context(&m);
+    assert_eq!(
+        Instr::CallIndirect(Typeidx(0), Tableidx(0)).stack_effect(&ctx),
+        Some(functype(&[I64, F32, I32], &[I32]))
+    );
+    assert_eq!(
+        Instr::CallIndirect(Typeidx(1), Tableidx(0)).stack_effect(&ctx),
+        None
+    );
+}
+
+#[test]
+fn locals() {
+    let m = module();
+    let ctx = context(&m);
+    let effect = |i: Instr| i.stack_effect(&ctx);
+    assert_eq!(
+        effect(Instr::LocalGet(Localidx(0))),
+        Some(functype(&[], &[I32]))
+    );
+    assert_eq!(
+        effect(Instr::LocalGet(Localidx(1))),
+        Some(functype(&[], &[F32]))
+    );
+    assert_eq!(
+        effect(Instr::LocalSet(Localidx(1))),
+        Some(functype(&[F32], &[]))
+    );
+    assert_eq!(
+        effect(Instr::LocalTee(Localidx(0))),
+        Some(functype(&[I32], &[I32]))
+    );
+    assert_eq!(effect(Instr::LocalGet(Localidx(2))), None);
+}
+
+#[test]
+fn br_table() {
+    let m = module();
+    let mut ctx = context(&m);
+    ctx.labels.push(Box::new([F32]));
+    let table = |targets: &[u32], default| {
+        Instr::BrTable(
+            Vector(targets.iter().map(|&l| Labelidx(l)).collect()),
+            Labelidx(default),
+        )
+    };
+    // The default label decides the operands, the innermost carrying an
+    // `f32` and the function body an `i64`.
+    assert_eq!(
+        table(&[0], 0).stack_effect(&ctx),
+        Some(functype(&[F32, I32], &[]))
+    );
+    assert_eq!(
+        table(&[], 1).stack_effect(&ctx),
+        Some(functype(&[I64, I32], &[]))
+    );
+    assert_eq!(table(&[], 2).stack_effect(&ctx), None);
+    // `return` consumes the function's results.
+    assert_eq!(
+        Instr::Opcode(Opcode::Return).stack_effect(&ctx),
+        Some(functype(&[I64], &[]))
+    );
+}
+
+#[test]
+fn polymorphic() {
+    let m = module();
+    let ctx = context(&m);
+    for i in [
+        Instr::Opcode(Opcode::Drop),
+        Instr::Opcode(Opcode::RefIsNull),
+        Instr::Select(None),
+        Instr::Select(Some(Vector(Box::new([])))),
+    ] {
+        assert_eq!(i.stack_effect(&ctx), None, "{i:?}");
+    }
+    assert_eq!(
+        Instr::Select(Some(Vector(Box::new([F32])))).stack_effect(&ctx),
+        Some(functype(&[F32, F32, I32], &[F32]))
+    );
+    assert_eq!(
+        Instr::RefNull(Reftype::Externref).stack_effect(&ctx),
+        Some(functype(&[], &[Valtype::Reftype(Reftype::Externref)]))
+    );
+}
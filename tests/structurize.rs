@@ -0,0 +1,153 @@
+use wasm_bin::{
+    cfg::{BasicBlock, Cfg, Terminator},
+    instructions::{Blocktype, Instr, Opcode},
+    interpreter::Value,
+    modules::{Labelidx, Localidx},
+    validate::validate,
+    visit::{self, Visit},
+    Vector,
+};
+use Instr::*;
+
+mod common;
+use common::*;
+
+#[derive(Default)]
+struct Count {
+    sets: Vec<Localidx>,
+    br_tables: usize,
+}
+
+impl Visit for Count {
+    fn visit_instr(&mut self, i: &Instr) {
+        match i {
+            LocalSet(x) => self.sets.push(*x),
+            BrTable(..) => self.br_tables += 1,
+            _ => {}
+        }
+        visit::walk_instr(self, i)
+    }
+}
+
+fn count(e: &[Instr]) -> Count {
+    let mut c = Count::default();
+    for i in e {
+        c.visit_instr(i);
+    }
+    c
+}
+
+#[test]
+fn reducible_round_trip() {
+    // Sums n down to 1, adding 100 on the way past 5, with a nested `if`, a
+    // loop, a branch out of it and a `br_table`.
+    let (n, acc, label) = (0, 1, 2);
+    let m = i32_func(
+        2,
+        &[
+            Block(
+                Blocktype::Empty,
+                Box::new([Loop(
+                    Blocktype::Empty,
+                    Box::new([
+                        get(n),
+                        op(Opcode::I32Eqz),
+                        BrIf(Labelidx(1)),
+                        get(acc),
+                        get(n),
+                        op(Opcode::I32Add),
+                        set(acc),
+                        get(n),
+                        I32Const(1),
+                        op(Opcode::I32Sub),
+                        set(n),
+                        get(n),
+                        I32Const(5),
+                        op(Opcode::I32Eq),
+                        If(
+                            Blocktype::Empty,
+                            Box::new([get(acc), I32Const(100), op(Opcode::I32Add), set(acc)]),
+                        ),
+                        get(n),
+                        I32Const(3),
+                        op(Opcode::I32LtU),
+                        BrTable(Vector(Box::new([Labelidx(1), Labelidx(0)])), Labelidx(0)),
+                    ]),
+                )]),
+            ),
+            get(acc),
+        ],
+    );
+    assert!(validate(&m).is_ok());
+
+    let cfg = Cfg::from_func(func(&m)).unwrap();
+    let e = cfg.structurize(Localidx(label));
+    // The label local is only for the irreducible fallback.
+    let c = count(&e);
+    assert!(!c.sets.contains(&Localidx(label)));
+    let structured = i32_func(3, &e);
+    assert!(validate(&structured).is_ok(), "{structured:?}");
+    for arg in 0..10 {
+        assert_eq!(
+            run(&structured, &[Value::I32(arg)]),
+            run(&m, &[Value::I32(arg)]),
+            "{arg}"
+        );
+    }
+}
+
+#[test]
+fn irreducible_round_trip() {
+    // Two blocks that jump to each other, entered at either one depending on
+    // the argument: a cycle with two entries, which no structured code
+    // produces.
+    let (n, x, label) = (0, 1, 2);
+    let at_least_10 = || [get(x), I32Const(10), op(Opcode::I32GeS)];
+    let add = |k| [get(x), I32Const(k), op(Opcode::I32Add), set(x)];
+    let branch = |then, otherwise| Terminator::Branch { then, otherwise };
+    let cfg = Cfg {
+        blocks: vec![
+            BasicBlock {
+                instrs: vec![get(n)],
+                terminator: branch(2, 3),
+            },
+            // The result is pushed here rather than before the jump, since
+            // nothing may stay on the stack across an edge.
+            BasicBlock {
+                instrs: vec![get(x)],
+                terminator: Terminator::Return,
+            },
+            BasicBlock {
+                instrs: [add(1).as_slice(), &at_least_10()].concat(),
+                terminator: branch(4, 3),
+            },
+            BasicBlock {
+                instrs: [add(3).as_slice(), &at_least_10()].concat(),
+                terminator: branch(4, 2),
+            },
+            BasicBlock {
+                instrs: vec![],
+                terminator: Terminator::Jump(1),
+            },
+        ],
+    };
+    let e = cfg.structurize(Localidx(label));
+    let c = count(&e);
+    assert!(c.sets.contains(&Localidx(label)));
+    assert!(c.br_tables > 0);
+    let m = i32_func(2, &e);
+    assert!(validate(&m).is_ok(), "{m:?}");
+
+    for arg in 0..3 {
+        let mut x = 0;
+        let mut a = arg != 0;
+        loop {
+            x += if a { 1 } else { 3 };
+            if x >= 10 {
+                break;
+            }
+            a = !a;
+        }
+        assert_eq!(run(&m, &[Value::I32(arg)]), [Value::I32(x)], "{arg}");
+    }
+}
@@ -0,0 +1,88 @@
+use wasm_bin::{
+    instructions::{Blocktype, Instr, Opcode},
+    modules::{Labelidx, Module},
+    passes::trim::trim,
+    validate::validate,
+};
+use Instr::*;
+
+mod common;
+use common::*;
+
+/// A module with one function of type `[] -> [i32]`.
+fn module(e: Vec<Instr>) -> Module {
+    common::module(&[functype(&[], &[I32])], &[(0, code(&[], &e))], &[])
+}
+
+fn check(before: Vec<Instr>, after: Vec<Instr>) {
+    let mut m = module(before);
+    assert!(validate(&m).is_ok());
+    trim(&mut m);
+    assert_eq!(m, module(after));
+    assert!(validate(&m).is_ok());
+}
+
+const RESULT: Blocktype = Blocktype::ValueType(I32);
+
+#[test]
+fn after_a_branch() {
+    check(
+        vec![
+            Block(
+                Blocktype::Empty,
+                Box::new([Br(Labelidx(0)), I32Const(1), Opcode(Opcode::Drop)]),
+            ),
+            I32Const(2),
+            Opcode(Opcode::Return),
+            Opcode(Opcode::Drop),
+            Opcode(Opcode::Unreachable),
+        ],
+        vec![
+            Block(Blocktype::Empty, Box::new([Br(Labelidx(0))])),
+            I32Const(2),
+            Opcode(Opcode::Return),
+        ],
+    );
+}
+
+#[test]
+fn if_on_a_constant() {
+    check(
+        vec![
+            I32Const(0),
+            IfElse(RESULT, Box::new([I32Const(1)]), Box::new([I32Const(2)])),
+            I32Const(1),
+            If(Blocktype::Empty, Box::new([Opcode(Opcode::Nop)])),
+            I32Const(0),
+            If(Blocktype::Empty, Box::new([Opcode(Opcode::Nop)])),
+        ],
+        vec![
+            Block(RESULT, Box::new([I32Const(2)])),
+            Block(Blocktype::Empty, Box::new([Opcode(Opcode::Nop)])),
+        ],
+    );
+}
+
+#[test]
+fn nested_blocks() {
+    // The inner block empties out and goes too.
+    check(
+        vec![
+            Block(
+                Blocktype::Empty,
+                Box::new([Block(
+                    Blocktype::Empty,
+                    Box::new([I32Const(0), If(Blocktype::Empty, Box::new([]))]),
+                )]),
+            ),
+            Loop(
+                RESULT,
+                Box::new([I32Const(3), Opcode(Opcode::Return), I32Const(4)]),
+            ),
+        ],
+        vec![Loop(
+            RESULT,
+            Box::new([I32Const(3), Opcode(Opcode::Return)]),
+        )],
+    );
+}
@@ -0,0 +1,63 @@
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    hash::{BuildHasher, Hash, RandomState},
+};
+use wasm_bin::{
+    decode::{Decode, Reader},
+    values::{F32, F64},
+    Grammar,
+};
+
+fn round_trip<T: Grammar + Decode + PartialEq + Debug>(x: T, bytes: &[u8]) {
+    let mut out = vec![];
+    x.write(&mut out).unwrap();
+    assert_eq!(out, bytes);
+    let mut r = Reader::new(&out);
+    assert_eq!(r.read::<T>().unwrap(), x);
+    assert!(r.is_empty());
+}
+
+/// Checks that the values are pairwise unequal and hash differently.
+fn distinct<T: Eq + Hash + Copy + Debug>(xs: &[T]) {
+    for (i, a) in xs.iter().enumerate() {
+        for b in &xs[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+    let state = RandomState::new();
+    let hashes: HashSet<_> = xs.iter().map(|x| state.hash_one(x)).collect();
+    assert_eq!(hashes.len(), xs.len(), "{xs:?}");
+}
+
+#[test]
+fn nan_payload_and_sign_survive_encoding() {
+    // A negative, signalling NaN with a payload other than the canonical one.
+    let f = F32(0xff80_0001);
+    assert!(f.get().is_nan() && f.get().is_sign_negative());
+    round_trip(f, &[0x01, 0x00, 0x80, 0xff]);
+
+    let f = F64(0xfff0_0000_dead_beef);
+    assert!(f.get().is_nan() && f.get().is_sign_negative());
+    round_trip(f, &[0xef, 0xbe, 0xad, 0xde, 0x00, 0x00, 0xf0, 0xff]);
+}
+
+#[test]
+fn equality_is_by_bits() {
+    distinct(&[
+        F32::from(0.0),
+        F32::from(-0.0),
+        F32(0x7fc0_0000),
+        F32(0xffc0_0000),
+        F32(0x7fc0_0001),
+    ]);
+    distinct(&[
+        F64::from(0.0),
+        F64::from(-0.0),
+        F64(0x7ff8_0000_0000_0000),
+        F64(0xfff8_0000_0000_0000),
+        F64(0x7ff8_0000_0000_0001),
+    ]);
+    assert_eq!(F32::from(f32::NAN), F32::from(f32::NAN));
+    assert_eq!(F64::from(f64::NAN), F64::from(f64::NAN));
+}
@@ -0,0 +1,302 @@
+;; The binary format: modules that don't decode, and valid ones with
+;; custom sections and redundant integer encodings. V8 agrees on which
+;; modules are malformed.
+
+;; An empty file.
+(assert_malformed
+  (module binary
+    "")
+  "unexpected end")
+
+;; A truncated header.
+(assert_malformed
+  (module binary
+    "\00asm\01\00")
+  "unexpected end")
+
+;; A bad magic number.
+(assert_malformed
+  (module binary
+    "\00asn"
+    "\01\00\00\00")
+  "magic header not detected")
+
+;; An unknown version.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\02\00\00\00")
+  "unknown binary version")
+
+;; An unknown section id.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\7f\00")
+  "malformed section id")
+
+;; A section that runs past the end.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\00")
+  "unexpected end")
+
+;; A section with bytes left over.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\02\00\00")
+  "section size mismatch")
+
+;; A function section before the type section.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\03\01\00"
+    "\01\01\00")
+  "unexpected content after last section")
+
+;; Two type sections.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\01\00"
+    "\01\01\00")
+  "unexpected content after last section")
+
+;; A u32 encoded in six bytes.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\06\80\80\80\80\80\00")
+  "integer representation too long")
+
+;; A u32 with bits set past 32.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\80\80\80\80p")
+  "integer too large")
+
+;; An i32.const with bits past 32 that aren't sign extension.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\0b\01\09\00A\80\80\80\80p\1a\0b")
+  "integer too large")
+
+;; An i64.const encoded in eleven bytes.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\11\01\0f\00B\80\80\80\80\80\80\80\80\80\80\00\1a\0b")
+  "integer representation too long")
+
+;; A function without a body.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00")
+  "function and code section have inconsistent lengths")
+
+;; A body without a function.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\0a\04\01\02\00\0b")
+  "function and code section have inconsistent lengths")
+
+;; A data count that doesn't match the data section.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\05\03\01\00\01"
+    "\0c\01\02"
+    "\0b\03\01\01\00")
+  "data count and data section have inconsistent lengths")
+
+;; data.drop without a data count section.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\05\03\01\00\01"
+    "\0a\07\01\05\00\fc\09\00\0b"
+    "\0b\03\01\01\00")
+  "data count section required")
+
+;; memory.size with a nonzero reserved byte.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\05\03\01\00\01"
+    "\0a\07\01\05\00?\01\1a\0b")
+  "zero byte expected")
+
+;; An export name that isn't UTF-8.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\07\06\01\02\c3(\00\00"
+    "\0a\04\01\02\00\0b")
+  "malformed UTF-8 encoding")
+
+;; An unknown opcode.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\05\01\03\00\ff\0b")
+  "illegal opcode")
+
+;; An unknown SIMD opcode.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\07\01\05\00\fd\9a\01\0b")
+  "illegal opcode")
+
+;; An unknown 0xfc opcode.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\06\01\04\00\fc\12\0b")
+  "illegal opcode")
+
+;; An unknown value type.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\01z\00")
+  "malformed value type")
+
+;; A function body that ends early.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\04\01\02\00\01")
+  "unexpected end")
+
+;; A function body with bytes after its end.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\05\01\03\00\0b\01")
+  "section size mismatch")
+
+;; More than 2^32 locals.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\0c\01\0a\02\ff\ff\ff\ff\0f\7f\02~\0b")
+  "too many locals")
+
+;; An unknown element segment kind.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\09\02\01\08")
+  "malformed elements segment kind")
+
+;; An unknown global mutability.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\06\06\01\7f\02A\00\0b")
+  "malformed mutability")
+
+;; An unknown limits flag.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\05\03\01\10\00")
+  "integer too large")
+
+;; A table of i32.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\04\04\01\7f\00\01")
+  "malformed reference type")
+
+;; A custom section whose name runs past its end.
+(assert_malformed
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\00\02\05a")
+  "unexpected end")
+
+;; An empty module.
+(module binary
+  "\00asm"
+  "\01\00\00\00")
+
+;; Custom sections around every other section, and an i32.const and a section
+;; size with redundant LEB128 bytes.
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\00\09\01apayload"
+  "\01\05\01`\00\01\7f"
+  "\00\09\01bpayload"
+  "\03\02\01\00"
+  "\00\09\01cpayload"
+  "\05\03\01\00\01"
+  "\07\05\01\01f\00\00"
+  "\00\09\01dpayload"
+  "\0c\01\01"
+  "\0a\8a\80\80\80\00\01\08\00A\ff\ff\ff\ff\7f\0b"
+  "\00\09\01epayload"
+  "\0b\03\01\01\00"
+  "\00\09\01fpayload")
+(assert_return (invoke "f") (i32.const -1))
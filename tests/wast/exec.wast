@@ -0,0 +1,287 @@
+;; Control flow, calls, memory, tables, globals and linking between
+;; instances. Modules are in binary form; each is preceded by a comment
+;; describing it, and the expected results were checked against V8.
+
+;; Control flow: recursion, loops, br_table, if/else, multi-value blocks
+;; and returns, and branches out of nested blocks.
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\1f\06`\01~\01~`\01\7f\01\7f`\02\7f\7f\01\7f`\00\02\7f~`\00\00`\01\7f\02\7f\7f"
+  "\03\0d\0c\00\00\01\01\01\02\01\03\01\04\04\05"
+  "\07p\0c\03fac\00\00\08fac-iter\00\01\08br_table\00\02\02if\00\03\06select\00\04\0cblock-params\00\05\03sum\00\06\05multi\00\07\06nested\00\08\07runaway\00\09\0bunreachable\00\0a\04pair\00\0b"
+  "\0a\cf\01\0c\15\00 \00P\04~B\01\05 \00 \00B\01}\10\00~\0b\0b%\01\01~B\01!\01\02@\03@ \00P\0d\01 \01 \00~!\01 \00B\01}!\00\0c\00\0b\0b \01\0b%\00\02@\02@\02@\02@ \00\0e\03\00\01\02\03\0bA\e4\00\0f\0bA\e5\00\0f\0bA\e6\00\0f\0bA\e7\00\0b\0c\00 \00\04\7fA\01\05A\02\0b\0b\09\00A\0aA\14 \00\1b\0b\0a\00 \00 \01\02\02j\0b\0b\1a\01\01\7fA\00 \00\03\02\22\01j \01A\01k\22\01 \01\0d\00\1a\0b\0b\06\00A\07Bx\0b\14\00\02@\02@\02@ \00\0d\02A\01\0f\0b\0b\0bA\02\0b\04\00\10\09\0b\03\00\00\0b\09\00 \00 \00A\01j\0b")
+(assert_return (invoke "fac" (i64.const 0)) (i64.const 1))
+(assert_return (invoke "fac-iter" (i64.const 0)) (i64.const 1))
+(assert_return (invoke "fac" (i64.const 1)) (i64.const 1))
+(assert_return (invoke "fac-iter" (i64.const 1)) (i64.const 1))
+(assert_return (invoke "fac" (i64.const 5)) (i64.const 120))
+(assert_return (invoke "fac-iter" (i64.const 5)) (i64.const 120))
+(assert_return (invoke "fac" (i64.const 20)) (i64.const 2432902008176640000))
+(assert_return (invoke "fac-iter" (i64.const 20)) (i64.const 2432902008176640000))
+(assert_return (invoke "br_table" (i32.const 0)) (i32.const 100))
+(assert_return (invoke "br_table" (i32.const 1)) (i32.const 101))
+(assert_return (invoke "br_table" (i32.const 2)) (i32.const 102))
+(assert_return (invoke "br_table" (i32.const 3)) (i32.const 103))
+(assert_return (invoke "br_table" (i32.const 4)) (i32.const 103))
+(assert_return (invoke "br_table" (i32.const -1)) (i32.const 103))
+(assert_return (invoke "if" (i32.const 0)) (i32.const 2))
+(assert_return (invoke "select" (i32.const 0)) (i32.const 20))
+(assert_return (invoke "nested" (i32.const 0)) (i32.const 1))
+(assert_return (invoke "if" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "select" (i32.const 1)) (i32.const 10))
+(assert_return (invoke "nested" (i32.const 1)) (i32.const 2))
+(assert_return (invoke "if" (i32.const 7)) (i32.const 1))
+(assert_return (invoke "select" (i32.const 7)) (i32.const 10))
+(assert_return (invoke "nested" (i32.const 7)) (i32.const 2))
+(assert_return (invoke "block-params" (i32.const 3) (i32.const -2)) (i32.const 1))
+(assert_return (invoke "sum" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "sum" (i32.const 10)) (i32.const 55))
+(assert_return (invoke "sum" (i32.const 100)) (i32.const 5050))
+(assert_return (invoke "multi") (i32.const 7) (i64.const -8))
+(assert_return (invoke "pair" (i32.const 41)) (i32.const 41) (i32.const 42))
+(assert_trap (invoke "unreachable") "unreachable")
+(assert_exhaustion (invoke "runaway") "call stack exhausted")
+
+;; Memory: loads and stores with offsets and sign extension, growth,
+;; bulk operations and passive data segments.
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\1d\06`\01\7f\01\7f`\01\7f\01~`\02\7f~\00`\00\01\7f`\03\7f\7f\7f\00`\00\00"
+  "\03\10\0f\00\00\00\00\00\01\01\02\02\03\00\04\04\04\05"
+  "\05\04\01\01\01\03"
+  "\07\93\01\0f\07load8_u\00\00\07load8_s\00\01\08load16_s\00\02\04load\00\03\0bload-offset\00\04\0ci64.load32_s\00\05\08i64.load\00\06\09i64.store\00\07\0bi64.store16\00\08\04size\00\09\04grow\00\0a\04fill\00\0b\04copy\00\0c\04init\00\0d\04drop\00\0e"
+  "\0c\01\02"
+  "\0a\85\01\0f\07\00 \00-\00\00\0b\07\00 \00,\00\00\0b\07\00 \00.\01\00\0b\07\00 \00(\02\00\0b\07\00 \00(\00\03\0b\07\00 \004\02\00\0b\07\00 \00)\03\00\0b\09\00 \00 \017\03\00\0b\09\00 \00 \01=\01\00\0b\04\00?\00\0b\06\00 \00@\00\0b\0b\00 \00 \01 \02\fc\0b\00\0b\0c\00 \00 \01 \02\fc\0a\00\00\0b\0c\00 \00 \01 \02\fc\08\01\00\0b\05\00\fc\09\01\0b"
+  "\0b\1a\02\00A\00\0b\0b\80\81\82\83hello\fe\ff\01\07passive")
+(assert_return (invoke "load8_u" (i32.const 0)) (i32.const 128))
+(assert_return (invoke "load8_s" (i32.const 0)) (i32.const -128))
+(assert_return (invoke "load16_s" (i32.const 0)) (i32.const -32384))
+(assert_return (invoke "load" (i32.const 0)) (i32.const -2088599168))
+(assert_return (invoke "load-offset" (i32.const 0)) (i32.const 1818585219))
+(assert_return (invoke "i64.load32_s" (i32.const 0)) (i64.const -2088599168))
+(assert_return (invoke "i64.load" (i32.const 0)) (i64.const 7812730953138340224))
+(assert_return (invoke "load8_u" (i32.const 1)) (i32.const 129))
+(assert_return (invoke "load8_s" (i32.const 1)) (i32.const -127))
+(assert_return (invoke "load16_s" (i32.const 1)) (i32.const -32127))
+(assert_return (invoke "load" (i32.const 1)) (i32.const 1753449089))
+(assert_return (invoke "load-offset" (i32.const 1)) (i32.const 1819043176))
+(assert_return (invoke "i64.load32_s" (i32.const 1)) (i64.const 1753449089))
+(assert_return (invoke "i64.load" (i32.const 1)) (i64.const 8028911418495697537))
+(assert_return (invoke "load8_u" (i32.const 3)) (i32.const 131))
+(assert_return (invoke "load8_s" (i32.const 3)) (i32.const -125))
+(assert_return (invoke "load16_s" (i32.const 3)) (i32.const 26755))
+(assert_return (invoke "load" (i32.const 3)) (i32.const 1818585219))
+(assert_return (invoke "load-offset" (i32.const 3)) (i32.const -26252180))
+(assert_return (invoke "i64.load32_s" (i32.const 3)) (i64.const 1818585219))
+(assert_return (invoke "i64.load" (i32.const 3)) (i64.const -440438487684989))
+(assert_return (invoke "load8_u" (i32.const 4)) (i32.const 104))
+(assert_return (invoke "load8_s" (i32.const 4)) (i32.const 104))
+(assert_return (invoke "load16_s" (i32.const 4)) (i32.const 25960))
+(assert_return (invoke "load" (i32.const 4)) (i32.const 1819043176))
+(assert_return (invoke "load-offset" (i32.const 4)) (i32.const -102548))
+(assert_return (invoke "i64.load32_s" (i32.const 4)) (i64.const 1819043176))
+(assert_return (invoke "i64.load" (i32.const 4)) (i64.const 72055873575085416))
+(assert_return (invoke "load8_u" (i32.const 10)) (i32.const 255))
+(assert_return (invoke "load8_s" (i32.const 10)) (i32.const -1))
+(assert_return (invoke "load16_s" (i32.const 10)) (i32.const 255))
+(assert_return (invoke "load" (i32.const 10)) (i32.const 255))
+(assert_return (invoke "load-offset" (i32.const 10)) (i32.const 0))
+(assert_return (invoke "i64.load32_s" (i32.const 10)) (i64.const 255))
+(assert_return (invoke "i64.load" (i32.const 10)) (i64.const 255))
+(assert_return (invoke "load" (i32.const 65532)) (i32.const 0))
+(assert_trap (invoke "load" (i32.const 65533)) "out of bounds memory access")
+(assert_trap (invoke "load-offset" (i32.const -1)) "out of bounds memory access")
+(assert_return (invoke "i64.store" (i32.const 16) (i64.const 72623859790382856)))
+(assert_return (invoke "i64.load" (i32.const 16)) (i64.const 72623859790382856))
+(assert_return (invoke "i64.store16" (i32.const 16) (i64.const -21555)))
+(assert_return (invoke "i64.load" (i32.const 16)) (i64.const 72623859790425037))
+(assert_trap (invoke "i64.store" (i32.const 65529) (i64.const 1)) "out of bounds memory access")
+(assert_return (invoke "load8_u" (i32.const 65529)) (i32.const 0))
+(assert_return (invoke "size") (i32.const 1))
+(assert_return (invoke "grow" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "size") (i32.const 2))
+(assert_return (invoke "load" (i32.const 65533)) (i32.const 0))
+(assert_return (invoke "grow" (i32.const 2)) (i32.const -1))
+(assert_return (invoke "grow" (i32.const 0)) (i32.const 2))
+(assert_return (invoke "fill" (i32.const 32) (i32.const 426) (i32.const 5)))
+(assert_return (invoke "load" (i32.const 33)) (i32.const -1431655766))
+(assert_return (invoke "load8_u" (i32.const 37)) (i32.const 0))
+(assert_trap (invoke "fill" (i32.const 131070) (i32.const 1) (i32.const 3)) "out of bounds memory access")
+(assert_return (invoke "copy" (i32.const 2) (i32.const 0) (i32.const 8)))
+(assert_return (invoke "load" (i32.const 0)) (i32.const -2122284672))
+(assert_return (invoke "load" (i32.const 4)) (i32.const 1701348226))
+(assert_return (invoke "copy" (i32.const 0) (i32.const 3) (i32.const 8)))
+(assert_return (invoke "load" (i32.const 0)) (i32.const 1753449089))
+(assert_return (invoke "copy" (i32.const 131072) (i32.const 0) (i32.const 0)))
+(assert_trap (invoke "copy" (i32.const 131073) (i32.const 0) (i32.const 0)) "out of bounds memory access")
+(assert_return (invoke "init" (i32.const 64) (i32.const 1) (i32.const 4)))
+(assert_return (invoke "load" (i32.const 64)) (i32.const 1769173857))
+(assert_trap (invoke "init" (i32.const 64) (i32.const 5) (i32.const 3)) "out of bounds memory access")
+(assert_return (invoke "drop"))
+(assert_return (invoke "init" (i32.const 64) (i32.const 0) (i32.const 0)))
+(assert_trap (invoke "init" (i32.const 64) (i32.const 0) (i32.const 1)) "out of bounds memory access")
+
+;; Tables: indirect calls, reference instructions and bulk table
+;; operations, with a funcref and an externref table.
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01&\08`\00\01\7f`\01\7f\01\7f`\01\7f\00`\03\7f\7f\7f\00`\00\00`\02\7fo\00`\01\7f\01o`\01o\01\7f"
+  "\03\12\11\00\00\01\01\01\00\01\01\02\02\03\03\04\03\05\06\07"
+  "\04\08\02p\01\05\08o\00\02"
+  "\07\82\01\0e\04call\00\03\06call-1\00\04\04size\00\05\04grow\00\06\07is_null\00\07\08set-null\00\08\08set-func\00\09\04copy\00\0a\04init\00\0b\09elem.drop\00\0c\04fill\00\0d\07ext.set\00\0e\07ext.get\00\0f\0bext.is_null\00\10"
+  "\09\0e\02\00A\00\0b\03\00\01\02\01\00\02\01\00"
+  "\0a\90\01\11\04\00A\0a\0b\04\00A\0b\0b\04\00 \00\0b\07\00 \00\11\00\00\0b\09\00A\05 \00\11\01\00\0b\05\00\fc\10\00\0b\09\00\d0p \00\fc\0f\00\0b\07\00 \00%\00\d1\0b\08\00 \00\d0p&\00\0b\08\00 \00\d2\01&\00\0b\0c\00 \00 \01 \02\fc\0e\00\00\0b\0c\00 \00 \01 \02\fc\0c\01\00\0b\05\00\fc\0d\01\0b\0d\00 \00 \01%\00 \02\fc\11\00\0b\08\00 \00 \01&\01\0b\06\00 \00%\01\0b\05\00 \00\d1\0b")
+(assert_return (invoke "call" (i32.const 0)) (i32.const 10))
+(assert_return (invoke "call" (i32.const 1)) (i32.const 11))
+(assert_trap (invoke "call" (i32.const 2)) "indirect call type mismatch")
+(assert_return (invoke "call-1" (i32.const 2)) (i32.const 5))
+(assert_trap (invoke "call" (i32.const 3)) "uninitialized element")
+(assert_trap (invoke "call" (i32.const 5)) "undefined element")
+(assert_return (invoke "is_null" (i32.const 0)) (i32.const 0))
+(assert_return (invoke "is_null" (i32.const 4)) (i32.const 1))
+(assert_return (invoke "set-func" (i32.const 4)))
+(assert_return (invoke "call" (i32.const 4)) (i32.const 11))
+(assert_return (invoke "set-null" (i32.const 0)))
+(assert_trap (invoke "call" (i32.const 0)) "uninitialized element")
+(assert_trap (invoke "is_null" (i32.const 5)) "out of bounds table access")
+(assert_return (invoke "size") (i32.const 5))
+(assert_return (invoke "grow" (i32.const 2)) (i32.const 5))
+(assert_return (invoke "size") (i32.const 7))
+(assert_return (invoke "grow" (i32.const 2)) (i32.const -1))
+(assert_return (invoke "copy" (i32.const 0) (i32.const 1) (i32.const 2)))
+(assert_return (invoke "call" (i32.const 0)) (i32.const 11))
+(assert_trap (invoke "call" (i32.const 1)) "indirect call type mismatch")
+(assert_trap (invoke "copy" (i32.const 6) (i32.const 0) (i32.const 2)) "out of bounds table access")
+(assert_return (invoke "init" (i32.const 5) (i32.const 0) (i32.const 2)))
+(assert_return (invoke "call" (i32.const 5)) (i32.const 11))
+(assert_return (invoke "call" (i32.const 6)) (i32.const 10))
+(assert_trap (invoke "init" (i32.const 0) (i32.const 1) (i32.const 2)) "out of bounds table access")
+(assert_return (invoke "elem.drop"))
+(assert_trap (invoke "init" (i32.const 0) (i32.const 0) (i32.const 1)) "out of bounds table access")
+(assert_return (invoke "fill" (i32.const 2) (i32.const 5) (i32.const 3)))
+(assert_return (invoke "call" (i32.const 4)) (i32.const 11))
+(assert_trap (invoke "fill" (i32.const 6) (i32.const 0) (i32.const 2)) "out of bounds table access")
+(assert_return (invoke "ext.get" (i32.const 0)) (ref.null extern))
+(assert_return (invoke "ext.set" (i32.const 1) (ref.extern 7)))
+(assert_return (invoke "ext.get" (i32.const 1)) (ref.extern 7))
+(assert_return (invoke "ext.is_null" (ref.extern 3)) (i32.const 0))
+(assert_trap (invoke "ext.set" (i32.const 2) (ref.extern 7)) "out of bounds table access")
+
+;; Globals, including ones initialized from an import, and a start
+;; function that runs at instantiation.
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\08\02`\00\01\7f`\00\00"
+  "\02\18\01\08spectest\0aglobal_i32\03\7f\00"
+  "\03\05\04\00\01\00\01"
+  "\06\0b\02\7f\01#\00\0b~\00B\7f\0b"
+  "\07\22\05\03get\00\00\0aget-import\00\02\03inc\00\03\01g\03\01\01c\03\02"
+  "\08\01\01"
+  "\0a\1f\04\04\00#\01\0b\09\00#\01A\02l$\01\0b\04\00#\00\0b\09\00#\01A\01j$\01\0b")
+(assert_return (invoke "get") (i32.const 1332))
+(assert_return (invoke "inc"))
+(assert_return (get "g") (i32.const 1333))
+(assert_return (get "c") (i64.const -1))
+(assert_return (invoke "get-import") (i32.const 666))
+
+;; Linking: one instance exports a function, global, memory and table, and
+;; another imports them by the name it was registered under.
+(module $M binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\09\02`\00\01\7f`\01\7f\00"
+  "\03\04\03\00\01\00"
+  "\04\04\01p\00\02"
+  "\05\03\01\00\01"
+  "\06\06\01\7f\01A\01\0b"
+  "\07\22\06\01f\00\00\03set\00\01\04peek\00\02\01g\03\00\03mem\02\00\03tab\01\00"
+  "\09\07\01\00A\01\0b\01\00"
+  "\0a\15\03\04\00A*\0b\06\00 \00$\00\0b\07\00A\00-\00\00\0b")
+(register "M" $M)
+(module $N binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\0e\03`\00\01\7f`\01\7f\00`\01\7f\01\7f"
+  "\026\05\01M\01f\00\00\01M\01g\03\7f\01\01M\03mem\02\00\01\01M\03tab\01p\00\01\08spectest\09print_i32\00\01"
+  "\03\05\04\00\00\01\02"
+  "\07#\04\06call-f\00\02\01g\00\03\05store\00\04\0acall-table\00\05"
+  "\0a\1d\04\04\00\10\00\0b\04\00#\00\0b\09\00A\00 \00:\00\00\0b\07\00 \00\11\00\00\0b")
+(assert_return (invoke "call-f") (i32.const 42))
+(assert_return (invoke "g") (i32.const 1))
+(assert_return (invoke $M "set" (i32.const 9)))
+(assert_return (invoke "g") (i32.const 9))
+(assert_return (invoke "store" (i32.const 171)))
+(assert_return (invoke $M "peek") (i32.const 171))
+(assert_return (invoke "call-table" (i32.const 1)) (i32.const 42))
+(assert_trap (invoke "call-table" (i32.const 0)) "uninitialized element")
+(assert_unlinkable
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\02\0d\01\01M\07missing\00\00")
+  "unknown import")
+(assert_unlinkable
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\02\07\01\01M\01f\00\00")
+  "incompatible import type")
+(assert_unlinkable
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\02\08\01\01M\01g\03\7f\00")
+  "incompatible import type")
+(assert_unlinkable
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\02\0a\01\01M\03mem\02\00\02")
+  "incompatible import type")
+(assert_unlinkable
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\02\0c\01\01M\03tab\01p\01\01\02")
+  "incompatible import type")
+(assert_unlinkable
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\02\08\01\01M\01f\03\7f\00")
+  "incompatible import type")
+
+;; Instantiation traps: a failing start function, and active segments that
+;; don't fit.
+(assert_trap
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\08\01\00"
+    "\0a\05\01\03\00\00\0b")
+  "unreachable")
+(assert_trap
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\05\03\01\00\01"
+    "\0b\0a\01\00A\ff\ff\03\0b\02\01\02")
+  "out of bounds memory access")
+(assert_trap
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\04\04\01p\00\01"
+    "\09\07\01\00A\01\0b\01\00"
+    "\0a\04\01\02\00\0b")
+  "out of bounds table access")
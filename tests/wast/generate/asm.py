@@ -0,0 +1,164 @@
+"""Encoding helpers for the generators, the text of `.wast` literals, and a V8
+oracle for single instructions."""
+
+import json, os, re, struct, subprocess, tempfile
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+
+def uleb(n):
+    out = bytearray()
+    while True:
+        b = n & 0x7f; n >>= 7
+        if n: out.append(b | 0x80)
+        else: out.append(b); return bytes(out)
+
+def sleb(n):
+    out = bytearray()
+    while True:
+        b = n & 0x7f; n >>= 7
+        if (n == 0 and not b & 0x40) or (n == -1 and b & 0x40):
+            out.append(b); return bytes(out)
+        out.append(b | 0x80)
+
+T = {'i32': 0x7f, 'i64': 0x7e, 'f32': 0x7d, 'f64': 0x7c, 'v128': 0x7b, 'funcref': 0x70, 'externref': 0x6f}
+
+def vec(items): return uleb(len(items)) + b''.join(items)
+def name(s): b = s.encode(); return uleb(len(b)) + b
+def section(id, body): return bytes([id]) + uleb(len(body)) + body
+def functype(params, results): return b'\x60' + vec([bytes([T[t]]) for t in params]) + vec([bytes([T[t]]) for t in results])
+
+def limits(mn, mx=None):
+    return b'\x00' + uleb(mn) if mx is None else b'\x01' + uleb(mn) + uleb(mx)
+
+def module(types=(), imports=(), funcs=(), tables=(), mems=(), globals=(), exports=(), start=None,
+           elems=(), datacount=None, codes=(), datas=(), customs=()):
+    """Each argument is a list of already-encoded entries; funcs are type indices."""
+    out = [b'\0asm', b'\1\0\0\0']
+    if types: out.append(section(1, vec(list(types))))
+    if imports: out.append(section(2, vec(list(imports))))
+    if funcs: out.append(section(3, vec([uleb(f) for f in funcs])))
+    if tables: out.append(section(4, vec(list(tables))))
+    if mems: out.append(section(5, vec(list(mems))))
+    if globals: out.append(section(6, vec(list(globals))))
+    if exports: out.append(section(7, vec(list(exports))))
+    if start is not None: out.append(section(8, uleb(start)))
+    if elems: out.append(section(9, vec(list(elems))))
+    if datacount is not None: out.append(section(12, uleb(datacount)))
+    if codes: out.append(section(10, vec(list(codes))))
+    if datas: out.append(section(11, vec(list(datas))))
+    for c in customs: out.append(section(0, name(c[0]) + c[1]))
+    return out
+
+def code(body, locals=()):
+    f = vec([uleb(n) + bytes([T[t]]) for n, t in locals]) + body + b'\x0b'
+    return uleb(len(f)) + f
+
+def export(nm, kind, idx): return name(nm) + bytes([kind]) + uleb(idx)
+def imp(mod, nm, desc): return name(mod) + name(nm) + desc
+
+def i32c(v): return b'\x41' + sleb(to_signed(v, 32))
+def i64c(v): return b'\x42' + sleb(to_signed(v, 64))
+def f32c(bits): return b'\x43' + struct.pack('<I', bits)
+def f64c(bits): return b'\x44' + struct.pack('<Q', bits)
+def v128c(bits): return b'\xfd' + uleb(12) + bits.to_bytes(16, 'little')
+
+def to_signed(v, n):
+    v &= (1 << n) - 1
+    return v - (1 << n) if v >> (n - 1) else v
+
+def const(t, bits):
+    return {'i32': i32c, 'i64': i64c, 'f32': f32c, 'f64': f64c, 'v128': v128c}[t](bits)
+
+def esc(b):
+    return ''.join(chr(c) if 0x20 <= c < 0x7f and chr(c) not in '"\\' else '\\%02x' % c for c in b)
+
+def wast_module(parts, id=None, indent='  '):
+    head = '(module' + (f' {id}' if id else '') + ' binary'
+    lines = [head] + [f'{indent}"{esc(p)}"' for p in parts]
+    return '\n'.join(lines) + ')'
+
+# Literals for the .wast files.
+def f32_lit(bits):
+    sign = '-' if bits >> 31 else ''
+    a = bits & 0x7fffffff
+    if a == 0x7f800000: return sign + 'inf'
+    if a > 0x7f800000: return f'{sign}nan:0x{a & 0x7fffff:x}'
+    return sign + float.hex(struct.unpack('<f', struct.pack('<I', a))[0]).replace('0x1.0000000000000p', '0x1p')
+
+def f64_lit(bits):
+    sign = '-' if bits >> 63 else ''
+    a = bits & 0x7fffffffffffffff
+    if a == 0x7ff0000000000000: return sign + 'inf'
+    if a > 0x7ff0000000000000: return f'{sign}nan:0x{a & 0xfffffffffffff:x}'
+    return sign + float.hex(struct.unpack('<d', struct.pack('<Q', a))[0]).replace('0x1.0000000000000p', '0x1p')
+
+def is_nan32(b): return b & 0x7fffffff > 0x7f800000
+def is_nan64(b): return b & 0x7fffffffffffffff > 0x7ff0000000000000
+
+def lit(t, bits, nan_ok=False):
+    if t == 'i32': return f'(i32.const {to_signed(bits, 32)})'
+    if t == 'i64': return f'(i64.const {to_signed(bits, 64)})'
+    if t == 'f32':
+        return '(f32.const nan:arithmetic)' if nan_ok and is_nan32(bits) else f'(f32.const {f32_lit(bits)})'
+    if t == 'f64':
+        return '(f64.const nan:arithmetic)' if nan_ok and is_nan64(bits) else f'(f64.const {f64_lit(bits)})'
+    raise ValueError(t)
+
+def v128_lit(bits, shape='i32x4', nan_ok=False):
+    if shape == 'f32x4':
+        lanes = [(bits >> (32 * i)) & 0xffffffff for i in range(4)]
+        return '(v128.const f32x4 ' + ' '.join('nan:arithmetic' if nan_ok and is_nan32(l) else f32_lit(l) for l in lanes) + ')'
+    if shape == 'f64x2':
+        lanes = [(bits >> (64 * i)) & (2**64 - 1) for i in range(2)]
+        return '(v128.const f64x2 ' + ' '.join('nan:arithmetic' if nan_ok and is_nan64(l) else f64_lit(l) for l in lanes) + ')'
+    w = {'i8x16': 8, 'i16x8': 16, 'i32x4': 32, 'i64x2': 64}[shape]
+    lanes = [(bits >> (w * i)) & ((1 << w) - 1) for i in range(128 // w)]
+    return f'(v128.const {shape} ' + ' '.join('0x%x' % l for l in lanes) + ')'
+
+def spec_name(rust):
+    m = re.match(r'(I8x16|I16x8|I32x4|I64x2|F32x4|F64x2|V128|I32|I64|F32|F64)(.*)', rust)
+    if not m: return rust.lower()
+    rest = re.sub(r'(?<!^)(?=[A-Z])', '_', m.group(2)).lower()
+    rest = re.sub(r'_(\d)', r'\1', rest)
+    return m.group(1).lower() + '.' + rest
+
+STORE = {'i32': b'\x36\x02\x00', 'i64': b'\x37\x03\x00', 'f32': b'\x38\x02\x00', 'f64': b'\x39\x03\x00',
+         'v128': b'\xfd' + uleb(11) + b'\x04\x00'}
+
+def oracle(cases):
+    """cases: list of (op_bytes, [(type, bits)], result_type). Returns list of result bits or trap messages."""
+    jobs = []
+    for chunk in range(0, len(cases), 2000):
+        cs = cases[chunk:chunk + 2000]
+        types = [functype([], [])]
+        exports = [export('mem', 2, 0)] + [export('c%d' % i, 0, i) for i in range(len(cs))]
+        codes = []
+        for op, args, rt in cs:
+            body = i32c(0) + b''.join(const(t, b) for t, b in args) + op
+            body += STORE[rt] if rt else b''
+            codes.append(code(body))
+        wasm = b''.join(module(types=types, funcs=[0] * len(cs), mems=[limits(1)], exports=exports, codes=codes))
+        jobs.append({'wasm': wasm.hex(), 'n': len(cs)})
+    with tempfile.TemporaryDirectory() as tmp:
+        jobs_path, out_path = os.path.join(tmp, 'jobs.json'), os.path.join(tmp, 'out.json')
+        json.dump(jobs, open(jobs_path, 'w'))
+        subprocess.run(['node', os.path.join(HERE, 'oracle.js'), jobs_path, out_path], check=True)
+        res = [r for job in json.load(open(out_path)) for r in job]
+    out = []
+    for (op, args, rt), r in zip(cases, res):
+        if r.startswith('trap:'):
+            out.append(r)
+        else:
+            n = {'i32': 4, 'i64': 8, 'f32': 4, 'f64': 8, 'v128': 16}[rt]
+            out.append(int.from_bytes(bytes.fromhex(r)[:n], 'little'))
+    return out
+
+def trap_message(js, args=()):
+    js = js[5:]
+    if 'by zero' in js: return 'integer divide by zero'
+    if 'unrepresentable' in js:
+        nan = any(is_nan32(b) if t == 'f32' else is_nan64(b) for t, b in args if t in ('f32', 'f64'))
+        return 'invalid conversion to integer' if nan else 'integer overflow'
+    if 'overflow' in js: return 'integer overflow'
+    if 'out of bounds' in js and 'memory' in js: return 'out of bounds memory access'
+    raise ValueError(js)
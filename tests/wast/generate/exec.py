@@ -0,0 +1,255 @@
+"""Generates exec.wast into the directory given as the argument."""
+
+import sys
+from script import *
+
+s = Script(''';; Control flow, calls, memory, tables, globals and linking between
+;; instances. Modules are in binary form; each is preceded by a comment
+;; describing it, and the expected results were checked against V8.''')
+
+# ---------------------------------------------------------------- control
+s.comment('''Control flow: recursion, loops, br_table, if/else, multi-value blocks
+and returns, and branches out of nested blocks.''')
+types = [
+    functype(['i64'], ['i64']),        # 0
+    functype(['i32'], ['i32']),        # 1
+    functype(['i32', 'i32'], ['i32']), # 2
+    functype([], ['i32', 'i64']),      # 3
+    functype([], []),                  # 4
+    functype(['i32'], ['i32', 'i32']), # 5
+]
+fac = if_('i64', I('i64.const') if False else i64c(1), get(0) + get(0) + i64c(1) + I('i64.sub') + call(0) + I('i64.mul'))
+fac = get(0) + I('i64.eqz') + fac
+fac_iter = (i64c(1) + set_(1) +
+            block(None, loop(None, get(0) + I('i64.eqz') + br_if(1) +
+                             get(1) + get(0) + I('i64.mul') + set_(1) +
+                             get(0) + i64c(1) + I('i64.sub') + set_(0) + br(0))) + get(1))
+brt = block(None, block(None, block(None, block(None, get(0) + br_table([0, 1, 2], 3)) + i32c(100) + I('return')) + i32c(101) + I('return')) + i32c(102) + I('return')) + i32c(103)
+ifelse = get(0) + if_('i32', i32c(1), i32c(2))
+sel = i32c(10) + i32c(20) + get(0) + I('select')
+# block with type 2 params: (i32 i32) -> i32, adds them
+mblock = get(0) + get(1) + block(2, I('i32.add'))
+# loop with params summing 1..n: loop (i32 acc, i32 n) ...
+sumloop = (i32c(0) + get(0) + loop(2, tee(1) + I('i32.add') + get(1) + i32c(1) + I('i32.sub') + tee(1) + get(1) + br_if(0) + I('drop')))
+multi = i32c(7) + i64c(-8)
+nested = block(None, block(None, block(None, get(0) + br_if(2) + i32c(1) + I('return')))) + i32c(2)
+unreach = I('unreachable')
+runaway = call(9)
+swap = get(0) + get(0) + i32c(1) + I('i32.add')
+codes = [code(fac), code(fac_iter, [(1, 'i64')]), code(brt), code(ifelse), code(sel), code(mblock),
+         code(sumloop, [(1, 'i32')]), code(multi), code(nested), code(runaway), code(unreach), code(swap)]
+funcs = [0, 0, 1, 1, 1, 2, 1, 3, 1, 4, 4, 5]
+sumloop_fixed = None
+names = ['fac', 'fac-iter', 'br_table', 'if', 'select', 'block-params', 'sum', 'multi', 'nested', 'runaway', 'unreachable', 'pair']
+s.module(module(types=types, funcs=funcs, exports=[export(n, 0, i) for i, n in enumerate(names)], codes=codes))
+for n in [0, 1, 5, 20]:
+    s.invoke('fac', [('i64', n)], ['i64'])
+    s.invoke('fac-iter', [('i64', n)], ['i64'])
+for n in [0, 1, 2, 3, 4, 0xffffffff]:
+    s.invoke('br_table', [('i32', n)], ['i32'])
+for n in [0, 1, 7]:
+    s.invoke('if', [('i32', n)], ['i32'])
+    s.invoke('select', [('i32', n)], ['i32'])
+    s.invoke('nested', [('i32', n)], ['i32'])
+s.invoke('block-params', [('i32', 3), ('i32', 0xfffffffe)], ['i32'])
+for n in [1, 10, 100]:
+    s.invoke('sum', [('i32', n)], ['i32'])
+s.invoke('multi', [], ['i32', 'i64'])
+s.invoke('pair', [('i32', 41)], ['i32', 'i32'])
+s.invoke('unreachable', [], [], trap='unreachable')
+s.invoke('runaway', [], [], trap='call stack exhausted')
+
+# ---------------------------------------------------------------- memory
+s.comment('''Memory: loads and stores with offsets and sign extension, growth,
+bulk operations and passive data segments.''')
+types = [functype(['i32'], ['i32']), functype(['i32'], ['i64']), functype(['i32', 'i64'], []),
+         functype([], ['i32']), functype(['i32', 'i32', 'i32'], []), functype([], [])]
+fns = [
+    ('load8_u', 0, get(0) + mem('i32.load8_u', 0)),
+    ('load8_s', 0, get(0) + mem('i32.load8_s', 0)),
+    ('load16_s', 0, get(0) + mem('i32.load16_s', 1)),
+    ('load', 0, get(0) + mem('i32.load', 2)),
+    ('load-offset', 0, get(0) + mem('i32.load', 0, 3)),
+    ('i64.load32_s', 1, get(0) + mem('i64.load32_s', 2)),
+    ('i64.load', 1, get(0) + mem('i64.load', 3)),
+    ('i64.store', 2, get(0) + get(1) + mem('i64.store', 3)),
+    ('i64.store16', 2, get(0) + get(1) + mem('i64.store16', 1)),
+    ('size', 3, I('memory.size')),
+    ('grow', 0, get(0) + I('memory.grow')),
+    ('fill', 4, get(0) + get(1) + get(2) + I('memory.fill')),
+    ('copy', 4, get(0) + get(1) + get(2) + I('memory.copy')),
+    ('init', 4, get(0) + get(1) + get(2) + fc(8, 1) + b'\x00'),
+    ('drop', 5, fc(9, 1)),
+]
+datas = [b'\x00' + expr(i32c(0)) + vec([bytes([c]) for c in b'\x80\x81\x82\x83hello\xfe\xff']),
+         b'\x01' + vec([bytes([c]) for c in b'passive'])]
+s.module(module(types=types, funcs=[f[1] for f in fns], mems=[limits(1, 3)],
+                exports=[export(f[0], 0, i) for i, f in enumerate(fns)],
+                datacount=2, codes=[code(f[2]) for f in fns], datas=datas))
+for a in [0, 1, 3, 4, 10]:
+    s.invoke('load8_u', [('i32', a)], ['i32'])
+    s.invoke('load8_s', [('i32', a)], ['i32'])
+    s.invoke('load16_s', [('i32', a)], ['i32'])
+    s.invoke('load', [('i32', a)], ['i32'])
+    s.invoke('load-offset', [('i32', a)], ['i32'])
+    s.invoke('i64.load32_s', [('i32', a)], ['i64'])
+    s.invoke('i64.load', [('i32', a)], ['i64'])
+s.invoke('load', [('i32', 65532)], ['i32'])
+s.invoke('load', [('i32', 65533)], ['i32'], trap='out of bounds memory access')
+s.invoke('load-offset', [('i32', 0xffffffff)], ['i32'], trap='out of bounds memory access')
+s.invoke('i64.store', [('i32', 16), ('i64', 0x0102030405060708)])
+s.invoke('i64.load', [('i32', 16)], ['i64'])
+s.invoke('i64.store16', [('i32', 16), ('i64', 0xffffffffffffabcd)])
+s.invoke('i64.load', [('i32', 16)], ['i64'])
+s.invoke('i64.store', [('i32', 65529), ('i64', 1)], trap='out of bounds memory access')
+s.invoke('load8_u', [('i32', 65529)], ['i32'])
+s.invoke('size', [], ['i32'])
+s.invoke('grow', [('i32', 1)], ['i32'])
+s.invoke('size', [], ['i32'])
+s.invoke('load', [('i32', 65533)], ['i32'])
+s.invoke('grow', [('i32', 2)], ['i32'])
+s.invoke('grow', [('i32', 0)], ['i32'])
+s.invoke('fill', [('i32', 32), ('i32', 0x1aa), ('i32', 5)])
+s.invoke('load', [('i32', 33)], ['i32'])
+s.invoke('load8_u', [('i32', 37)], ['i32'])
+s.invoke('fill', [('i32', 131070), ('i32', 1), ('i32', 3)], trap='out of bounds memory access')
+s.invoke('copy', [('i32', 2), ('i32', 0), ('i32', 8)])
+s.invoke('load', [('i32', 0)], ['i32'])
+s.invoke('load', [('i32', 4)], ['i32'])
+s.invoke('copy', [('i32', 0), ('i32', 3), ('i32', 8)])
+s.invoke('load', [('i32', 0)], ['i32'])
+s.invoke('copy', [('i32', 131072), ('i32', 0), ('i32', 0)])
+s.invoke('copy', [('i32', 131073), ('i32', 0), ('i32', 0)], trap='out of bounds memory access')
+s.invoke('init', [('i32', 64), ('i32', 1), ('i32', 4)])
+s.invoke('load', [('i32', 64)], ['i32'])
+s.invoke('init', [('i32', 64), ('i32', 5), ('i32', 3)], trap='out of bounds memory access')
+s.invoke('drop')
+s.invoke('init', [('i32', 64), ('i32', 0), ('i32', 0)])
+s.invoke('init', [('i32', 64), ('i32', 0), ('i32', 1)], trap='out of bounds memory access')
+
+# ---------------------------------------------------------------- tables
+s.comment('''Tables: indirect calls, reference instructions and bulk table
+operations, with a funcref and an externref table.''')
+types = [functype([], ['i32']), functype(['i32'], ['i32']), functype(['i32'], []),
+         functype(['i32', 'i32', 'i32'], []), functype([], []), functype(['i32', 'externref'], []),
+         functype(['i32'], ['externref']), functype(['externref'], ['i32'])]
+fns = [
+    (None, 0, i32c(10)),
+    (None, 0, i32c(11)),
+    (None, 1, get(0)),
+    ('call', 1, get(0) + call_indirect(0)),
+    ('call-1', 1, i32c(5) + get(0) + call_indirect(1)),
+    ('size', 0, fc(16, 0)),
+    ('grow', 1, ref_null('funcref') + get(0) + fc(15, 0)),
+    ('is_null', 1, get(0) + tget(0) + I('ref.is_null')),
+    ('set-null', 2, get(0) + ref_null('funcref') + tset(0)),
+    ('set-func', 2, get(0) + ref_func(1) + tset(0)),
+    ('copy', 3, get(0) + get(1) + get(2) + fc(14, 0, 0)),
+    ('init', 3, get(0) + get(1) + get(2) + fc(12, 1, 0)),
+    ('elem.drop', 4, fc(13, 1)),
+    ('fill', 3, get(0) + get(1) + tget(0) + get(2) + fc(17, 0)),
+    ('ext.set', 5, get(0) + get(1) + tset(1)),
+    ('ext.get', 6, get(0) + tget(1)),
+    ('ext.is_null', 7, get(0) + I('ref.is_null')),
+]
+elems = [b'\x00' + expr(i32c(0)) + vec([uleb(0), uleb(1), uleb(2)]),
+         b'\x01\x00' + vec([uleb(1), uleb(0)])]
+s.module(module(types=types, funcs=[f[1] for f in fns], tables=[table('funcref', 5, 8), table('externref', 2)],
+                exports=[export(f[0], 0, i) for i, f in enumerate(fns) if f[0]],
+                elems=elems, codes=[code(f[2]) for f in fns]))
+s.invoke('call', [('i32', 0)], ['i32'])
+s.invoke('call', [('i32', 1)], ['i32'])
+s.invoke('call', [('i32', 2)], ['i32'], trap='indirect call type mismatch')
+s.invoke('call-1', [('i32', 2)], ['i32'])
+s.invoke('call', [('i32', 3)], ['i32'], trap='uninitialized element')
+s.invoke('call', [('i32', 5)], ['i32'], trap='undefined element')
+s.invoke('is_null', [('i32', 0)], ['i32'])
+s.invoke('is_null', [('i32', 4)], ['i32'])
+s.invoke('set-func', [('i32', 4)])
+s.invoke('call', [('i32', 4)], ['i32'])
+s.invoke('set-null', [('i32', 0)])
+s.invoke('call', [('i32', 0)], ['i32'], trap='uninitialized element')
+s.invoke('is_null', [('i32', 5)], ['i32'], trap='out of bounds table access')
+s.invoke('size', [], ['i32'])
+s.invoke('grow', [('i32', 2)], ['i32'])
+s.invoke('size', [], ['i32'])
+s.invoke('grow', [('i32', 2)], ['i32'])
+s.invoke('copy', [('i32', 0), ('i32', 1), ('i32', 2)])
+s.invoke('call', [('i32', 0)], ['i32'])
+s.invoke('call', [('i32', 1)], ['i32'], trap='indirect call type mismatch')
+s.invoke('copy', [('i32', 6), ('i32', 0), ('i32', 2)], trap='out of bounds table access')
+s.invoke('init', [('i32', 5), ('i32', 0), ('i32', 2)])
+s.invoke('call', [('i32', 5)], ['i32'])
+s.invoke('call', [('i32', 6)], ['i32'])
+s.invoke('init', [('i32', 0), ('i32', 1), ('i32', 2)], trap='out of bounds table access')
+s.invoke('elem.drop')
+s.invoke('init', [('i32', 0), ('i32', 0), ('i32', 1)], trap='out of bounds table access')
+s.invoke('fill', [('i32', 2), ('i32', 5), ('i32', 3)])
+s.invoke('call', [('i32', 4)], ['i32'])
+s.invoke('fill', [('i32', 6), ('i32', 0), ('i32', 2)], trap='out of bounds table access')
+s.invoke('ext.get', [('i32', 0)], ['externref'])
+s.invoke('ext.set', [('i32', 1), ('externref', 7)])
+s.invoke('ext.get', [('i32', 1)], ['externref'])
+s.invoke('ext.is_null', [('externref', 3)], ['i32'])
+s.invoke('ext.set', [('i32', 2), ('externref', 7)], trap='out of bounds table access')
+
+# ---------------------------------------------------------------- globals/start
+s.comment('''Globals, including ones initialized from an import, and a start
+function that runs at instantiation.''')
+types = [functype([], ['i32']), functype([], [])]
+fns = [('get', 0, gget(1)), (None, 1, gget(1) + i32c(2) + I('i32.mul') + gset(1)), ('get-import', 0, gget(0)),
+       ('inc', 1, gget(1) + i32c(1) + I('i32.add') + gset(1))]
+s.module(module(types=types, imports=[imp('spectest', 'global_i32', b'\x03\x7f\x00')],
+                funcs=[f[1] for f in fns],
+                globals=[glob('i32', 1, gget(0)), glob('i64', 0, i64c(-1))],
+                exports=[export(f[0], 0, i) for i, f in enumerate(fns) if f[0]] +
+                        [export('g', 3, 1), export('c', 3, 2)],
+                start=1, codes=[code(f[2]) for f in fns]))
+s.invoke('get', [], ['i32'])
+s.invoke('inc')
+s.get('g', 'i32')
+s.get('c', 'i64')
+s.invoke('get-import', [], ['i32'])
+
+# ---------------------------------------------------------------- linking
+s.comment('''Linking: one instance exports a function, global, memory and table, and
+another imports them by the name it was registered under.''')
+types = [functype([], ['i32']), functype(['i32'], [])]
+fns = [('f', 0, i32c(42)), ('set', 1, get(0) + gset(0)), ('peek', 0, i32c(0) + mem('i32.load8_u', 0))]
+s.module(module(types=types, funcs=[f[1] for f in fns], tables=[table('funcref', 2)], mems=[limits(1)],
+                globals=[glob('i32', 1, i32c(1))],
+                exports=[export(f[0], 0, i) for i, f in enumerate(fns)] +
+                        [export('g', 3, 0), export('mem', 2, 0), export('tab', 1, 0)],
+                elems=[b'\x00' + expr(i32c(1)) + vec([uleb(0)])],
+                codes=[code(f[2]) for f in fns]), '$M')
+s.register('M', '$M')
+types = [functype([], ['i32']), functype(['i32'], []), functype(['i32'], ['i32'])]
+fns = [('call-f', 0, call(0)), ('g', 0, gget(0)), ('store', 1, i32c(0) + get(0) + mem('i32.store8', 0)),
+       ('call-table', 2, get(0) + call_indirect(0))]
+s.module(module(types=types,
+                imports=[imp('M', 'f', b'\x00\x00'), imp('M', 'g', b'\x03\x7f\x01'), imp('M', 'mem', b'\x02' + limits(1)),
+                         imp('M', 'tab', b'\x01' + table('funcref', 1)), imp('spectest', 'print_i32', b'\x00\x01')],
+                funcs=[f[1] for f in fns], exports=[export(f[0], 0, i + 2) for i, f in enumerate(fns)],
+                codes=[code(f[2]) for f in fns]), '$N')
+s.invoke('call-f', [], ['i32'])
+s.invoke('g', [], ['i32'])
+s.invoke('set', [('i32', 9)], [], id='$M')
+s.invoke('g', [], ['i32'])
+s.invoke('store', [('i32', 0xab)])
+s.invoke('peek', [], ['i32'], id='$M')
+s.invoke('call-table', [('i32', 1)], ['i32'])
+s.invoke('call-table', [('i32', 0)], ['i32'], trap='uninitialized element')
+s.reject('assert_unlinkable', module(types=[functype([], [])], imports=[imp('M', 'missing', b'\x00\x00')]), 'unknown import')
+s.reject('assert_unlinkable', module(types=[functype([], [])], imports=[imp('M', 'f', b'\x00\x00')]), 'incompatible import type')
+s.reject('assert_unlinkable', module(imports=[imp('M', 'g', b'\x03\x7f\x00')]), 'incompatible import type')
+s.reject('assert_unlinkable', module(imports=[imp('M', 'mem', b'\x02' + limits(2))]), 'incompatible import type')
+s.reject('assert_unlinkable', module(imports=[imp('M', 'tab', b'\x01' + table('funcref', 1, 2))]), 'incompatible import type')
+s.reject('assert_unlinkable', module(imports=[imp('M', 'f', b'\x03\x7f\x00')]), 'incompatible import type')
+
+s.comment('''Instantiation traps: a failing start function, and active segments that
+don't fit.''')
+s.reject('assert_trap', module(types=[functype([], [])], funcs=[0], start=0, codes=[code(I('unreachable'))]), 'unreachable')
+s.reject('assert_trap', module(mems=[limits(1)], datas=[b'\x00' + expr(i32c(65535)) + vec([b'\x01', b'\x02'])]), 'out of bounds memory access')
+s.reject('assert_trap', module(types=[functype([], [])], funcs=[0], tables=[table('funcref', 1)],
+                               elems=[b'\x00' + expr(i32c(1)) + vec([uleb(0)])], codes=[code(b'')]), 'out of bounds table access')
+s.write(sys.argv[1] + '/exec.wast')
@@ -0,0 +1,132 @@
+"""Generates numeric.wast and simd.wast into the directory given as the
+argument."""
+
+import itertools, os, struct, sys
+from asm import *
+
+ops = []
+for line in open(os.path.join(HERE, 'ops.txt')):
+    if line.startswith('#'): continue
+    parts = line.split()
+    kind, nm, n = parts[0], parts[1], int(parts[2])
+    params = parts[3].split(',') if len(parts) > 3 and parts[3] else []
+    results = parts[4].split(',') if len(parts) > 4 else []
+    ops.append((kind, nm, n, params, results))
+
+def opbytes(kind, n):
+    if kind == 'op': return bytes([n])
+    if kind == 'fc': return b'\xfc' + uleb(n)
+    return b'\xfd' + uleb(n)
+
+def f32(x): return struct.unpack('<I', struct.pack('<f', x))[0]
+def f64(x): return struct.unpack('<Q', struct.pack('<d', x))[0]
+
+POOL = {
+    'i32': [0, 1, 0xffffffff, 0x7fffffff, 0x80000000, 0x12345678, 31, 0xfffffff9, 0x80000001, 2, 0xffff],
+    'i64': [0, 1, 2**64 - 1, 2**63 - 1, 2**63, 0x0123456789abcdef, 63, 2**64 - 7, 0x80000000, 0xffffffff],
+    'f32': [0, 0x80000000, f32(1.0), f32(-1.5), f32(2.5), f32(-3.5), 0x7f800000, 0xff800000, 0x7fc00000,
+            0xffc00001, 0x7f7fffff, 1, f32(2**31), f32(-2**31 - 256), f32(1e10), f32(0.49999997)],
+    'f64': [0, 2**63, f64(1.0), f64(-1.5), f64(2.5), f64(-3.5), 0x7ff0000000000000, 0xfff0000000000000,
+            0x7ff8000000000000, 0xfff8000000000001, 0x7fefffffffffffff, 1, f64(2**63), f64(-2**31 - 1),
+            f64(4294967296.5), f64(1e-300), f64(0.49999999999999994)],
+}
+BIN = {'i32': POOL['i32'][:6], 'i64': POOL['i64'][:6], 'f32': [POOL['f32'][i] for i in (0, 1, 3, 4, 6, 8, 11)], 'f64': [POOL['f64'][i] for i in (0, 1, 3, 4, 6, 8, 11)]}
+
+def v(lanes, w):
+    return sum((l & ((1 << w) - 1)) << (w * i) for i, l in enumerate(lanes))
+VPOOL = [
+    v(range(16), 8),
+    v([0x80000000, 0x7fffffff, 0xffff8000, 0x00017fff], 32),
+    v([f32(1.5), 0x80000000, 0x7f800000, 0x7fc00000], 32),
+    v([f64(-2.5), 1], 64),
+    v([f32(-1e10), f32(3.0), 1, 0xff800000], 32),
+    2**128 - 1,
+    v([0x8001, 0x7ffe, 0xff00, 0x00ff, 0x1234, 0xfedc, 0x0080, 0xff7f], 16),
+    v([f64(2**63), 0xfff8000000000001], 64),
+]
+VBIN = [VPOOL[1], VPOOL[2], VPOOL[4], VPOOL[7]]
+
+BITWISE = {'F32Abs', 'F32Neg', 'F32Copysign', 'F64Abs', 'F64Neg', 'F64Copysign',
+           'F32ReinterpretI32', 'F64ReinterpretI64'}
+
+def inputs(params, simd):
+    if len(params) == 1:
+        t = params[0]
+        return [[x] for x in (VPOOL[:7] if t == 'v128' else POOL[t])]
+    pools = [(VBIN if t == 'v128' else (BIN[t] if not simd else POOL[t][:4])) for t in params]
+    if simd and len(params) == 3:
+        pools = [VBIN[:2], VBIN[1:3], [VPOOL[5] ^ VPOOL[0], VPOOL[0]]]
+    return [list(c) for c in itertools.product(*pools)]
+
+def result_shape(nm):
+    for p in ['I8x16', 'I16x8', 'I32x4', 'I64x2', 'F32x4', 'F64x2']:
+        if nm.startswith(p):
+            s = p.lower()
+            if s[0] == 'f' and any(nm[len(p):].startswith(c) for c in ['Eq', 'Ne', 'Lt', 'Gt', 'Le', 'Ge']):
+                return 'i' + s[1:]
+            return s
+    return 'i32x4'
+
+def gen(kinds, header, path, extra=()):
+    cases = []   # (export name, op bytes, params, results, args, nm)
+    funcs = []   # (export name, op bytes, params, results)
+    for kind, nm, n, params, results in ops:
+        if kind not in kinds or not params or len(results) != 1 or 'ref' in params: continue
+        simd = kind != 'op' and kind != 'fc'
+        imms = [b'']
+        if kind == 'vl':
+            lanes = {'I8x16': 16, 'I16x8': 8, 'I32x4': 4, 'I64x2': 2, 'F32x4': 4, 'F64x2': 2}[nm[:5]]
+            imms = [bytes([0]), bytes([lanes - 1])]
+        for imm in imms:
+            export_name = spec_name(nm) + (f' {imm[0]}' if imm else '')
+            funcs.append((export_name, opbytes(kind, n) + imm, params, results))
+            for args in inputs(params, simd):
+                cases.append((export_name, opbytes(kind, n) + imm, params, results, args, nm))
+    for e in extra:
+        funcs.append(e[:4])
+        for args in e[4]:
+            cases.append((e[0], e[1], e[2], e[3], args, e[0]))
+    res = oracle([(op, list(zip(params, args)), results[0]) for _, op, params, results, args, _ in cases])
+
+    types, tidx = [], {}
+    fidx, codes, exports = [], [], []
+    for i, (en, op, params, results) in enumerate(funcs):
+        key = (tuple(params), tuple(results))
+        if key not in tidx:
+            tidx[key] = len(types); types.append(functype(params, results))
+        fidx.append(tidx[key])
+        codes.append(code(b''.join(b'\x20' + uleb(j) for j in range(len(params))) + op))
+        exports.append(export(en, 0, i))
+    parts = module(types=types, funcs=fidx, exports=exports, codes=codes)
+    out = [header, wast_module(parts), '']
+    for (en, op, params, results, args, nm), r in zip(cases, res):
+        argl = ' '.join(v128_lit(a) if t == 'v128' else lit(t, a) for t, a in zip(params, args))
+        call = f'(invoke "{en}"' + (' ' + argl if argl else '') + ')'
+        if isinstance(r, str):
+            out.append(f'(assert_trap {call} "{trap_message(r, list(zip(params, args)))}")')
+            continue
+        rt = results[0]
+        nan_ok = nm not in BITWISE
+        if rt == 'v128':
+            shape = result_shape(nm)
+            if nm in ('F32x4Abs', 'F32x4Neg', 'F64x2Abs', 'F64x2Neg'): nan_ok = False
+            exp = v128_lit(r, shape, nan_ok)
+        else:
+            exp = lit(rt, r, nan_ok)
+        out.append(f'(assert_return {call} {exp})')
+    open(path, 'w').write('\n'.join(out) + '\n')
+    print(path, len(cases))
+
+if __name__ == '__main__':
+    gen({'op', 'fc'}, ''';; Every numeric instruction applied to boundary values. The module is in
+;; binary form, with one exported function per instruction, and the expected
+;; results come from running the same instructions in V8.
+''', sys.argv[1] + '/numeric.wast')
+    shuffles = [bytes(range(0, 32, 2)), bytes([31, 0, 17, 3, 16, 16, 5, 30, 8, 9, 10, 11, 1, 2, 3, 4])]
+    extra = [(f'i8x16.shuffle {i}', b'\xfd' + uleb(13) + s, ['v128', 'v128'], ['v128'],
+              [[a, b] for a in VBIN[:3] for b in VBIN[:3]]) for i, s in enumerate(shuffles)]
+    gen({'vn', 'vl'}, ''';; Every SIMD instruction without a memory operand, applied to a few vectors
+;; of interesting lanes. The module is in binary form, with one exported
+;; function per instruction and lane index, and the expected results come
+;; from running the same instructions in V8.
+''', sys.argv[1] + '/simd.wast', extra)
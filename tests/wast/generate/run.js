@@ -0,0 +1,69 @@
+const fs = require('fs');
+const cmds = JSON.parse(fs.readFileSync(process.argv[2]));
+const registry = {
+  spectest: {
+    print() {}, print_i32() {}, print_i64() {}, print_f32() {}, print_f64() {}, print_i32_f32() {}, print_f64_f64() {},
+    global_i32: new WebAssembly.Global({value: 'i32'}, 666),
+    global_i64: new WebAssembly.Global({value: 'i64'}, 666n),
+    global_f32: new WebAssembly.Global({value: 'f32'}, 666.6),
+    global_f64: new WebAssembly.Global({value: 'f64'}, 666.6),
+    table: new WebAssembly.Table({element: 'anyfunc', initial: 10, maximum: 20}),
+    memory: new WebAssembly.Memory({initial: 1, maximum: 2}),
+  },
+};
+const externs = new Map();
+const ext = n => { if (!externs.has(n)) externs.set(n, {n}); return externs.get(n); };
+let current; const named = {};
+const f32 = new Float32Array(1), u32 = new Uint32Array(f32.buffer);
+const f64 = new Float64Array(1), u64 = new BigUint64Array(f64.buffer);
+function arg(t, v) {
+  switch (t) {
+    case 'i32': return v | 0;
+    case 'i64': return BigInt.asIntN(64, BigInt(v));
+    case 'f32': u32[0] = v; return f32[0];
+    case 'f64': u64[0] = BigInt(v); return f64[0];
+    case 'externref': return ext(v);
+    case 'funcref': return null;
+  }
+}
+function res(t, v) {
+  switch (t) {
+    case 'i32': return String(v >>> 0);
+    case 'i64': return String(BigInt.asUintN(64, v));
+    case 'f32': f32[0] = v; return String(u32[0]);
+    case 'f64': f64[0] = v; return String(u64[0]);
+    case 'externref': return v === null ? null : v.n;
+    case 'funcref': return v === null ? null : 'func';
+  }
+}
+function instantiate(hex) {
+  const m = new WebAssembly.Module(Buffer.from(hex, 'hex'));
+  const imports = {};
+  for (const i of WebAssembly.Module.imports(m)) {
+    imports[i.module] = registry[i.module] || {};
+  }
+  return new WebAssembly.Instance(m, imports);
+}
+const out = [];
+for (const c of cmds) {
+  try {
+    if (c.kind === 'comment') out.push(null);
+    else if (c.kind === 'module') { current = instantiate(c.wasm); if (c.id) named[c.id] = current; out.push('ok'); }
+    else if (c.kind === 'register') { registry[c.name] = (c.id ? named[c.id] : current).exports; out.push(null); }
+    else if (c.kind === 'invoke' || c.kind === 'get') {
+      const inst = c.id ? named[c.id] : current;
+      try {
+        let r;
+        if (c.kind === 'get') r = inst.exports[c.name].value;
+        else r = inst.exports[c.name](...c.args.map(([t, v]) => arg(t, v)));
+        if (c.results.length === 0) r = [];
+        else if (c.results.length === 1) r = [r];
+        out.push(c.results.map((t, i) => res(t, r[i])));
+      } catch (e) { out.push('trap: ' + e.message); }
+    } else {
+      try { instantiate(c.wasm); out.push('instantiated'); }
+      catch (e) { out.push(e.constructor.name + ': ' + e.message); }
+    }
+  } catch (e) { out.push('error: ' + e.constructor.name + ': ' + e.message); }
+}
+fs.writeFileSync(process.argv[3], JSON.stringify(out));
@@ -0,0 +1,120 @@
+"""Builds a `.wast` script from binary modules and commands, running it in V8
+through node to fill in and check the expected results."""
+
+import json, os, struct, subprocess, tempfile
+from asm import *
+
+OPS = {}
+for line in open(os.path.join(HERE, 'ops.txt')):
+    if line.startswith('#'): continue
+    kind, nm, n = line.split()[:3]
+    n = int(n)
+    b = bytes([n]) if kind in ('op', 'mm') else (b'\xfc' + uleb(n) if kind == 'fc' else b'\xfd' + uleb(n))
+    OPS[spec_name(nm)] = b
+OPS.update({'unreachable': b'\x00', 'nop': b'\x01', 'return': b'\x0f', 'drop': b'\x1a', 'select': b'\x1b',
+            'ref.is_null': b'\xd1', 'memory.size': b'\x3f\x00', 'memory.grow': b'\x40\x00',
+            'memory.copy': b'\xfc\x0a\x00\x00', 'memory.fill': b'\xfc\x0b\x00'})
+
+def I(*names): return b''.join(OPS[n] for n in names)
+def bt(t): return bytes([0x40]) if t is None else (bytes([T[t]]) if isinstance(t, str) else sleb(t))
+def block(t, body): return b'\x02' + bt(t) + body + b'\x0b'
+def loop(t, body): return b'\x03' + bt(t) + body + b'\x0b'
+def if_(t, a, b=None): return b'\x04' + bt(t) + a + (b'\x05' + b if b is not None else b'') + b'\x0b'
+def br(l): return b'\x0c' + uleb(l)
+def br_if(l): return b'\x0d' + uleb(l)
+def br_table(ls, d): return b'\x0e' + vec([uleb(l) for l in ls]) + uleb(d)
+def call(f): return b'\x10' + uleb(f)
+def call_indirect(t, x=0): return b'\x11' + uleb(t) + uleb(x)
+def get(i): return b'\x20' + uleb(i)
+def set_(i): return b'\x21' + uleb(i)
+def tee(i): return b'\x22' + uleb(i)
+def gget(i): return b'\x23' + uleb(i)
+def gset(i): return b'\x24' + uleb(i)
+def mem(op, align, offset=0): return OPS[op] + uleb(align) + uleb(offset)
+def tget(x): return b'\x25' + uleb(x)
+def tset(x): return b'\x26' + uleb(x)
+def ref_null(t): return b'\xd0' + bytes([T[t]])
+def ref_func(x): return b'\xd2' + uleb(x)
+def fc(n, *imm): return b'\xfc' + uleb(n) + b''.join(uleb(i) for i in imm)
+def expr(b): return b + b'\x0b'
+def glob(t, mut, init): return bytes([T[t], mut]) + expr(init)
+def table(t, mn, mx=None): return bytes([T[t]]) + limits(mn, mx)
+
+class Script:
+    def __init__(self, header):
+        self.header = header
+        self.cmds = []
+
+    def comment(self, text):
+        self.cmds.append({'kind': 'comment', 'text': text})
+
+    def module(self, parts, id=None):
+        self.cmds.append({'kind': 'module', 'parts': parts, 'id': id})
+
+    def register(self, name, id=None):
+        self.cmds.append({'kind': 'register', 'name': name, 'id': id})
+
+    def invoke(self, name, args=(), results=(), id=None, trap=None):
+        self.cmds.append({'kind': 'invoke', 'name': name, 'args': list(args), 'results': list(results), 'id': id, 'trap': trap})
+
+    def get(self, name, t, id=None):
+        self.cmds.append({'kind': 'get', 'name': name, 'args': [], 'results': [t], 'id': id, 'trap': None})
+
+    def reject(self, kind, parts, message):
+        """kind: assert_malformed, assert_invalid, assert_unlinkable, assert_trap (module), assert_uninstantiable"""
+        self.cmds.append({'kind': kind, 'parts': parts, 'message': message})
+
+    def write(self, path):
+        jobs = []
+        for c in self.cmds:
+            j = dict(c)
+            if 'parts' in j: j['wasm'] = b''.join(j.pop('parts')).hex()
+            if 'args' in j: j['args'] = [[t, v if t in ('externref', 'funcref') else str(v)] for t, v in j['args']]
+            jobs.append(j)
+        with tempfile.TemporaryDirectory() as tmp:
+            jobs_path, out_path = os.path.join(tmp, 'script.json'), os.path.join(tmp, 'out.json')
+            json.dump(jobs, open(jobs_path, 'w'))
+            subprocess.run(['node', os.path.join(HERE, 'run.js'), jobs_path, out_path], check=True)
+            res = json.load(open(out_path))
+        out = [self.header]
+        for c, r in zip(self.cmds, res):
+            k = c['kind']
+            if k == 'comment':
+                out.append('\n;; ' + c['text'].replace('\n', '\n;; '))
+            elif k == 'module':
+                assert r == 'ok', (r, c)
+                out.append(wast_module(c['parts'], c['id']))
+            elif k == 'register':
+                out.append(f'(register "{c["name"]}"' + (f' {c["id"]}' if c['id'] else '') + ')')
+            elif k in ('invoke', 'get'):
+                args = ' '.join(arg_lit(t, v) for t, v in c['args'])
+                act = f'({k}' + (f' {c["id"]}' if c['id'] else '') + f' "{c["name"]}"' + (' ' + args if args else '') + ')'
+                if c['trap']:
+                    assert r.startswith('trap'), (r, c)
+                    kw = 'assert_exhaustion' if c['trap'] == 'call stack exhausted' else 'assert_trap'
+                    out.append(f'({kw} {act} "{c["trap"]}")')
+                else:
+                    assert not isinstance(r, str), (r, c)
+                    exp = ' '.join(res_lit(t, v) for t, v in zip(c['results'], r))
+                    out.append(f'(assert_return {act}' + (' ' + exp if exp else '') + ')')
+            else:
+                want = {'assert_malformed': 'CompileError', 'assert_invalid': 'CompileError',
+                        'assert_unlinkable': 'LinkError', 'assert_trap': 'RuntimeError',
+                        'assert_uninstantiable': 'RuntimeError'}[k]
+                assert r.startswith(want), (r, c)
+                m = wast_module(c['parts'], None, '    ')
+                out.append(f'({k}\n  {m}\n  "{c["message"]}")')
+        open(path, 'w').write('\n'.join(out) + '\n')
+        print(path, len(self.cmds))
+
+def arg_lit(t, v):
+    if t == 'externref': return f'(ref.extern {v})'
+    if t == 'funcref': return '(ref.null func)'
+    if t in ('f32', 'f64') and isinstance(v, float):
+        v = struct.unpack('<I', struct.pack('<f', v))[0] if t == 'f32' else struct.unpack('<Q', struct.pack('<d', v))[0]
+    return lit(t, v)
+
+def res_lit(t, v):
+    if t == 'externref': return '(ref.null extern)' if v is None else f'(ref.extern {v})'
+    if t == 'funcref': return '(ref.null func)' if v is None else '(ref.func)'
+    return lit(t, int(v))
@@ -0,0 +1,176 @@
+"""Generates validation.wast and binary.wast into the directory given as the
+argument."""
+
+import sys
+from script import *
+
+def func_module(body, params=(), results=(), locals=(), **kw):
+    """A module with one function of the given type, plus anything in kw."""
+    types = [functype(list(params), list(results))] + kw.pop('types', [])
+    return module(types=types, funcs=[0] + kw.pop('funcs', []), codes=[code(body, locals)] + kw.pop('codes', []), **kw)
+
+# ---------------------------------------------------------------- validation
+s = Script(''';; Modules that decode but don't validate, and valid modules that exercise
+;; the corners of the type checker. Modules are in binary form, each
+;; preceded by a comment with its text format; V8 agrees on which are valid.''')
+
+def invalid(text, parts, message):
+    s.comment(text)
+    s.reject('assert_invalid', parts, message)
+
+def valid(text, parts):
+    s.comment(text)
+    s.module(parts)
+
+invalid('(func (result i32) (i64.const 0))', func_module(i64c(0), results=['i32']), 'type mismatch')
+invalid('(func (i32.add))', func_module(I('i32.add')), 'type mismatch')
+invalid('(func (i32.const 1))', func_module(i32c(1)), 'type mismatch')
+invalid('(func (result i32) (block (result i32)) )', func_module(block('i32', b''), results=['i32']), 'type mismatch')
+invalid('(func (result i32) (if (result i32) (i32.const 0) (then (i32.const 1))))',
+        func_module(i32c(0) + if_('i32', i32c(1)), results=['i32']), 'type mismatch')
+invalid('(func (result i32) (i32.const 0) (if (result i32) (then (i32.const 1)) (else (i64.const 1))))',
+        func_module(i32c(0) + if_('i32', i32c(1), i64c(1)), results=['i32']), 'type mismatch')
+invalid('(func (result i32) (block (result f32) (br 0 (i32.const 1))) (drop) (i32.const 0))',
+        func_module(block('f32', i32c(1) + br(0)) + I('drop') + i32c(0), results=['i32']), 'type mismatch')
+invalid('(func (block (result i32) (block (br_table 0 1 (i32.const 0) (i32.const 0)))) (drop))',
+        func_module(block('i32', block(None, i32c(0) + i32c(0) + br_table([0], 1)) + i32c(0)) + I('drop')), 'type mismatch')
+invalid('(func (result i32) (return (i64.const 1)))', func_module(i64c(1) + I('return'), results=['i32']), 'type mismatch')
+invalid('(func (local.get 0) (drop))', func_module(get(0) + I('drop')), 'unknown local')
+invalid('(func (br 1))', func_module(br(1)), 'unknown label')
+invalid('(func (call 1))', func_module(call(1)), 'unknown function')
+invalid('(func (global.get 0) (drop))', func_module(gget(0) + I('drop')), 'unknown global')
+invalid('(global i32 (i32.const 0)) (func (global.set 0 (i32.const 1)))',
+        func_module(i32c(1) + gset(0), globals=[glob('i32', 0, i32c(0))]), 'global is immutable')
+invalid('(func (type 1))', module(types=[functype([], [])], funcs=[1], codes=[code(b'')]), 'unknown type')
+invalid('(func (block (type 1)))', func_module(block(1, b'')), 'unknown type')
+invalid('(func (drop (i32.load (i32.const 0))))', func_module(i32c(0) + mem('i32.load', 2) + I('drop')), 'unknown memory')
+invalid('(memory 1) (func (drop (i32.load align=8 (i32.const 0))))',
+        func_module(i32c(0) + mem('i32.load', 3) + I('drop'), mems=[limits(1)]), 'alignment must not be larger than natural')
+invalid('(memory 1) (func (drop (v128.load8_splat align=2 (i32.const 0))))',
+        func_module(i32c(0) + OPS['v128.load8_splat'] + uleb(1) + uleb(0) + I('drop'), mems=[limits(1)]),
+        'alignment must not be larger than natural')
+invalid('(memory 1) (memory 1)', module(mems=[limits(1), limits(1)]), 'multiple memories')
+invalid('(memory 65537)', module(mems=[limits(65537)]), 'memory size must be at most 65536 pages (4GiB)')
+invalid('(memory 2 1)', module(mems=[limits(2, 1)]), 'size minimum must not be greater than maximum')
+invalid('(table 2 1 funcref)', module(tables=[table('funcref', 2, 1)]), 'size minimum must not be greater than maximum')
+invalid('(func (call_indirect (i32.const 0)))', func_module(i32c(0) + call_indirect(0)), 'unknown table')
+invalid('(table 1 externref) (func (call_indirect (i32.const 0)))',
+        func_module(i32c(0) + call_indirect(0), tables=[table('externref', 1)]), 'type mismatch')
+invalid('(table 1 funcref) (table 1 externref) (func (table.copy 0 1 (i32.const 0) (i32.const 0) (i32.const 0)))',
+        func_module(i32c(0) * 3 + fc(14, 0, 1), tables=[table('funcref', 1), table('externref', 1)]), 'type mismatch')
+invalid('(table 1 funcref) (elem 0 (i32.const 0) externref (ref.null extern))',
+        module(tables=[table('funcref', 1)], elems=[b'\x06\x00' + expr(i32c(0)) + b'\x6f' + vec([expr(ref_null('externref'))])]),
+        'type mismatch')
+invalid('(func (elem.drop 0))', func_module(fc(13, 0)), 'unknown elem segment 0')
+invalid('(memory 1) (func (memory.init 1 (i32.const 0) (i32.const 0) (i32.const 0))) (data "")',
+        func_module(i32c(0) * 3 + fc(8, 1) + b'\x00', mems=[limits(1)], datacount=1, datas=[b'\x01\x00']),
+        'unknown data segment 1')
+invalid('(func (drop (ref.func 0)))', func_module(ref_func(0) + I('drop')), 'undeclared function reference')
+invalid('(func (drop (ref.is_null (i32.const 0))))', func_module(i32c(0) + I('ref.is_null') + I('drop')), 'type mismatch')
+invalid('(func (param funcref) (drop (select (local.get 0) (local.get 0) (i32.const 1))))',
+        func_module(get(0) + get(0) + i32c(1) + I('select') + I('drop'), params=['funcref']), 'type mismatch')
+invalid('(func (drop (select (i32.const 0) (i64.const 0) (i32.const 1))))',
+        func_module(i32c(0) + i64c(0) + i32c(1) + I('select') + I('drop')), 'type mismatch')
+invalid('(func (result i32) (i8x16.extract_lane_s 16 (v128.const i64x2 0 0)))',
+        func_module(v128c(0) + OPS['i8x16.extract_lane_s'] + b'\x10', results=['i32']), 'invalid lane index')
+invalid('(func (result v128) (i8x16.shuffle 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 32 (v128.const i64x2 0 0) (v128.const i64x2 0 0)))',
+        func_module(v128c(0) + v128c(0) + b'\xfd' + uleb(13) + bytes(15) + b'\x20', results=['v128']), 'invalid lane index')
+invalid('(global i32 (i32.const 0) (drop) (i32.const 1))',
+        module(globals=[glob('i32', 0, i32c(0) + I('drop') + i32c(1))]), 'constant expression required')
+invalid('(global i32 (i64.const 0))', module(globals=[glob('i32', 0, i64c(0))]), 'type mismatch')
+invalid('(global i32 (i32.const 0)) (global i32 (global.get 0))',
+        module(globals=[glob('i32', 0, i32c(0)), glob('i32', 0, gget(0))]), 'unknown global 0')
+invalid('(func) (export "a" (func 0)) (export "a" (func 0))',
+        func_module(b'', exports=[export('a', 0, 0), export('a', 0, 0)]), 'duplicate export name')
+invalid('(export "a" (func 0))', module(exports=[export('a', 0, 0)]), 'unknown function 0')
+invalid('(func (param i32)) (start 0)', func_module(b'', params=['i32'], start=0), 'start function')
+invalid('(start 0)', module(start=0), 'unknown function')
+invalid('(data (i32.const 0) "")', module(datas=[b'\x00' + expr(i32c(0)) + b'\x00']), 'unknown memory 0')
+
+valid('(func (result i32) (unreachable) (i32.add))', func_module(I('unreachable', 'i32.add'), results=['i32']))
+valid('(func (result i32) (unreachable) (select))', func_module(I('unreachable', 'select'), results=['i32']))
+valid('(func (block (br 0) (f32.add) (drop)))', func_module(block(None, br(0) + I('f32.add', 'drop'))))
+valid('(func (block (result i32) (block (result f32) (unreachable) (br_table 0 1))) (drop))',
+      func_module(block('i32', block('f32', I('unreachable') + br_table([0], 1)) + I('drop') + i32c(0)) + I('drop')))
+valid('(func (result i32) (loop (result i32) (i32.const 1)))', func_module(loop('i32', i32c(1)), results=['i32']))
+valid('(func (param i32) (result i32) (local.get 0) (if (param i32) (result i32) (i32.const 1) (then (drop (i32.const 2)))))',
+      func_module(get(0) + get(0) + if_(1, b'\x1a' + i32c(2)), params=['i32'], results=['i32'],
+                  types=[functype(['i32'], ['i32'])]))
+valid('(func (drop (ref.func 0))) (elem declare func 0)',
+      func_module(ref_func(0) + I('drop'), elems=[b'\x03\x00' + vec([uleb(0)])]))
+valid('(global funcref (ref.func 0)) (func (drop (ref.func 0)))',
+      func_module(ref_func(0) + I('drop'), globals=[glob('funcref', 0, ref_func(0))]))
+valid('(import "spectest" "global_i32" (global i32)) (global i32 (global.get 0))',
+      module(imports=[imp('spectest', 'global_i32', b'\x03\x7f\x00')], globals=[glob('i32', 0, gget(0))]))
+s.write(sys.argv[1] + '/validation.wast')
+
+# ---------------------------------------------------------------- binary
+s = Script(''';; The binary format: modules that don't decode, and valid ones with
+;; custom sections and redundant integer encodings. V8 agrees on which
+;; modules are malformed.''')
+
+def malformed(text, parts, message):
+    s.comment(text)
+    s.reject('assert_malformed', parts, message)
+
+HDR = [b'\0asm', b'\1\0\0\0']
+malformed('An empty file.', [b''], 'unexpected end')
+malformed('A truncated header.', [b'\0asm\1\0'], 'unexpected end')
+malformed('A bad magic number.', [b'\0asn', b'\1\0\0\0'], 'magic header not detected')
+malformed('An unknown version.', [b'\0asm', b'\2\0\0\0'], 'unknown binary version')
+malformed('An unknown section id.', HDR + [b'\x7f\x00'], 'malformed section id')
+malformed('A section that runs past the end.', HDR + [b'\x01\x05\x00'], 'unexpected end')
+malformed('A section with bytes left over.', HDR + [b'\x01\x02\x00\x00'], 'section size mismatch')
+malformed('A function section before the type section.',
+          HDR + [section(3, vec([])), section(1, vec([]))], 'unexpected content after last section')
+malformed('Two type sections.', HDR + [section(1, vec([])), section(1, vec([]))], 'unexpected content after last section')
+malformed('A u32 encoded in six bytes.', HDR + [b'\x01\x06\x80\x80\x80\x80\x80\x00'], 'integer representation too long')
+malformed('A u32 with bits set past 32.', HDR + [b'\x01\x05\x80\x80\x80\x80\x70'], 'integer too large')
+malformed('An i32.const with bits past 32 that aren\'t sign extension.',
+          func_module(b'\x41\x80\x80\x80\x80\x70\x1a'), 'integer too large')
+malformed('An i64.const encoded in eleven bytes.',
+          func_module(b'\x42' + b'\x80' * 10 + b'\x00\x1a'), 'integer representation too long')
+malformed('A function without a body.', module(types=[functype([], [])], funcs=[0]),
+          'function and code section have inconsistent lengths')
+malformed('A body without a function.', module(types=[functype([], [])], codes=[code(b'')]),
+          'function and code section have inconsistent lengths')
+malformed('A data count that doesn\'t match the data section.',
+          module(mems=[limits(1)], datacount=2, datas=[b'\x01\x00']),
+          'data count and data section have inconsistent lengths')
+malformed('data.drop without a data count section.',
+          func_module(fc(9, 0), mems=[limits(1)], datas=[b'\x01\x00']), 'data count section required')
+malformed('memory.size with a nonzero reserved byte.',
+          func_module(b'\x3f\x01\x1a', mems=[limits(1)]), 'zero byte expected')
+malformed('An export name that isn\'t UTF-8.',
+          func_module(b'', exports=[b'\x02\xc3\x28\x00\x00']), 'malformed UTF-8 encoding')
+malformed('An unknown opcode.', func_module(b'\xff'), 'illegal opcode')
+malformed('An unknown SIMD opcode.', func_module(b'\xfd\x9a\x01'), 'illegal opcode')
+malformed('An unknown 0xfc opcode.', func_module(b'\xfc\x12'), 'illegal opcode')
+malformed('An unknown value type.', HDR + [section(1, vec([b'\x60\x01\x7a\x00']))], 'malformed value type')
+malformed('A function body that ends early.', HDR + [section(1, vec([functype([], [])])), section(3, vec([b'\x00'])),
+                                                     section(10, vec([b'\x02\x00\x01']))], 'unexpected end')
+malformed('A function body with bytes after its end.',
+          HDR + [section(1, vec([functype([], [])])), section(3, vec([b'\x00'])),
+                 section(10, vec([b'\x03\x00\x0b\x01']))], 'section size mismatch')
+malformed('More than 2^32 locals.', HDR + [section(1, vec([functype([], [])])), section(3, vec([b'\x00'])),
+                                         section(10, vec([b'\x0a\x02\xff\xff\xff\xff\x0f\x7f\x02\x7e\x0b']))],
+          'too many locals')
+malformed('An unknown element segment kind.', HDR + [section(9, vec([b'\x08']))], 'malformed elements segment kind')
+malformed('An unknown global mutability.', HDR + [section(6, vec([b'\x7f\x02' + expr(i32c(0))]))], 'malformed mutability')
+malformed('An unknown limits flag.', HDR + [section(5, vec([b'\x10\x00']))], 'integer too large')
+malformed('A table of i32.', HDR + [section(4, vec([b'\x7f\x00\x01']))], 'malformed reference type')
+malformed('A custom section whose name runs past its end.', HDR + [b'\x00\x02\x05a'], 'unexpected end')
+
+s.comment('An empty module.')
+s.module(HDR)
+s.comment('''Custom sections around every other section, and an i32.const and a section
+size with redundant LEB128 bytes.''')
+c = lambda n: section(0, name(n) + b'payload')
+types = section(1, vec([functype([], ['i32'])]))
+parts = HDR + [c('a'), types, c('b'), section(3, vec([b'\x00'])), c('c'), section(5, vec([limits(1)])),
+               section(7, vec([export('f', 0, 0)])), c('d'), section(12, b'\x01'),
+               b'\x0a\x8a\x80\x80\x80\x00\x01\x08\x00\x41\xff\xff\xff\xff\x7f\x0b', c('e'),
+               section(11, vec([b'\x01\x00'])), c('f')]
+s.module(parts)
+s.invoke('f', [], ['i32'])
+s.write(sys.argv[1] + '/binary.wast')
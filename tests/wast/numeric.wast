@@ -0,0 +1,3920 @@
+;; Every numeric instruction applied to boundary values. The module is in
+;; binary form, with one exported function per instruction, and the expected
+;; results come from running the same instructions in V8.
+
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01{\17`\01\7f\01\7f`\02\7f\7f\01\7f`\01~\01\7f`\02~~\01\7f`\02}}\01\7f`\02||\01\7f`\01~\01~`\02~~\01~`\01}\01}`\02}}\01}`\01|\01|`\02||\01|`\01}\01\7f`\01|\01\7f`\01\7f\01~`\01}\01~`\01|\01~`\01\7f\01}`\01~\01}`\01|\01}`\01\7f\01|`\01~\01|`\01}\01|"
+  "\03\8a\01\88\01\00\01\01\01\01\01\01\01\01\01\01\02\03\03\03\03\03\03\03\03\03\03\04\04\04\04\04\04\05\05\05\05\05\05\00\00\00\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\06\06\06\07\07\07\07\07\07\07\07\07\07\07\07\07\07\07\08\08\08\08\08\08\08\09\09\09\09\09\09\09\0a\0a\0a\0a\0a\0a\0a\0b\0b\0b\0b\0b\0b\0b\02\0c\0c\0d\0d\0e\0e\0f\0f\10\10\11\11\12\12\13\14\14\15\15\16\0c\10\11\15\00\00\06\06\06\0c\0c\0d\0d\0f\0f\10\10"
+  "\07\ff\0d\88\01\07i32.eqz\00\00\06i32.eq\00\01\06i32.ne\00\02\08i32.lt_s\00\03\08i32.lt_u\00\04\08i32.gt_s\00\05\08i32.gt_u\00\06\08i32.le_s\00\07\08i32.le_u\00\08\08i32.ge_s\00\09\08i32.ge_u\00\0a\07i64.eqz\00\0b\06i64.eq\00\0c\06i64.ne\00\0d\08i64.lt_s\00\0e\08i64.lt_u\00\0f\08i64.gt_s\00\10\08i64.gt_u\00\11\08i64.le_s\00\12\08i64.le_u\00\13\08i64.ge_s\00\14\08i64.ge_u\00\15\06f32.eq\00\16\06f32.ne\00\17\06f32.lt\00\18\06f32.gt\00\19\06f32.le\00\1a\06f32.ge\00\1b\06f64.eq\00\1c\06f64.ne\00\1d\06f64.lt\00\1e\06f64.gt\00\1f\06f64.le\00 \06f64.ge\00!\07i32.clz\00\22\07i32.ctz\00#\0ai32.popcnt\00$\07i32.add\00%\07i32.sub\00&\07i32.mul\00'\09i32.div_s\00(\09i32.div_u\00)\09i32.rem_s\00*\09i32.rem_u\00+\07i32.and\00,\06i32.or\00-\07i32.xor\00.\07i32.shl\00/\09i32.shr_s\000\09i32.shr_u\001\08i32.rotl\002\08i32.rotr\003\07i64.clz\004\07i64.ctz\005\0ai64.popcnt\006\07i64.add\007\07i64.sub\008\07i64.mul\009\09i64.div_s\00:\09i64.div_u\00;\09i64.rem_s\00<\09i64.rem_u\00=\07i64.and\00>\06i64.or\00?\07i64.xor\00@\07i64.shl\00A\09i64.shr_s\00B\09i64.shr_u\00C\08i64.rotl\00D\08i64.rotr\00E\07f32.abs\00F\07f32.neg\00G\08f32.ceil\00H\09f32.floor\00I\09f32.trunc\00J\0bf32.nearest\00K\08f32.sqrt\00L\07f32.add\00M\07f32.sub\00N\07f32.mul\00O\07f32.div\00P\07f32.min\00Q\07f32.max\00R\0cf32.copysign\00S\07f64.abs\00T\07f64.neg\00U\08f64.ceil\00V\09f64.floor\00W\09f64.trunc\00X\0bf64.nearest\00Y\08f64.sqrt\00Z\07f64.add\00[\07f64.sub\00\5c\07f64.mul\00]\07f64.div\00^\07f64.min\00_\07f64.max\00`\0cf64.copysign\00a\0ci32.wrap_i64\00b\0fi32.trunc_f32_s\00c\0fi32.trunc_f32_u\00d\0fi32.trunc_f64_s\00e\0fi32.trunc_f64_u\00f\10i64.extend_i32_s\00g\10i64.extend_i32_u\00h\0fi64.trunc_f32_s\00i\0fi64.trunc_f32_u\00j\0fi64.trunc_f64_s\00k\0fi64.trunc_f64_u\00l\11f32.convert_i32_s\00m\11f32.convert_i32_u\00n\11f32.convert_i64_s\00o\11f32.convert_i64_u\00p\0ef32.demote_f64\00q\11f64.convert_i32_s\00r\11f64.convert_i32_u\00s\11f64.convert_i64_s\00t\11f64.convert_i64_u\00u\0ff64.promote_f32\00v\13i32.reinterpret_f32\00w\13i64.reinterpret_f64\00x\13f32.reinterpret_i32\00y\13f64.reinterpret_i64\00z\0di32.extend8_s\00{\0ei32.extend16_s\00|\0di64.extend8_s\00}\0ei64.extend16_s\00~\0ei64.extend32_s\00\7f\13i32.trunc_sat_f32_s\00\80\01\13i32.trunc_sat_f32_u\00\81\01\13i32.trunc_sat_f64_s\00\82\01\13i32.trunc_sat_f64_u\00\83\01\13i64.trunc_sat_f32_s\00\84\01\13i64.trunc_sat_f32_u\00\85\01\13i64.trunc_sat_f64_s\00\86\01\13i64.trunc_sat_f64_u\00\87\01"
+  "\0a\d2\07\88\01\05\00 \00E\0b\07\00 \00 \01F\0b\07\00 \00 \01G\0b\07\00 \00 \01H\0b\07\00 \00 \01I\0b\07\00 \00 \01J\0b\07\00 \00 \01K\0b\07\00 \00 \01L\0b\07\00 \00 \01M\0b\07\00 \00 \01N\0b\07\00 \00 \01O\0b\05\00 \00P\0b\07\00 \00 \01Q\0b\07\00 \00 \01R\0b\07\00 \00 \01S\0b\07\00 \00 \01T\0b\07\00 \00 \01U\0b\07\00 \00 \01V\0b\07\00 \00 \01W\0b\07\00 \00 \01X\0b\07\00 \00 \01Y\0b\07\00 \00 \01Z\0b\07\00 \00 \01[\0b\07\00 \00 \01\5c\0b\07\00 \00 \01]\0b\07\00 \00 \01^\0b\07\00 \00 \01_\0b\07\00 \00 \01`\0b\07\00 \00 \01a\0b\07\00 \00 \01b\0b\07\00 \00 \01c\0b\07\00 \00 \01d\0b\07\00 \00 \01e\0b\07\00 \00 \01f\0b\05\00 \00g\0b\05\00 \00h\0b\05\00 \00i\0b\07\00 \00 \01j\0b\07\00 \00 \01k\0b\07\00 \00 \01l\0b\07\00 \00 \01m\0b\07\00 \00 \01n\0b\07\00 \00 \01o\0b\07\00 \00 \01p\0b\07\00 \00 \01q\0b\07\00 \00 \01r\0b\07\00 \00 \01s\0b\07\00 \00 \01t\0b\07\00 \00 \01u\0b\07\00 \00 \01v\0b\07\00 \00 \01w\0b\07\00 \00 \01x\0b\05\00 \00y\0b\05\00 \00z\0b\05\00 \00{\0b\07\00 \00 \01|\0b\07\00 \00 \01}\0b\07\00 \00 \01~\0b\07\00 \00 \01\7f\0b\07\00 \00 \01\80\0b\07\00 \00 \01\81\0b\07\00 \00 \01\82\0b\07\00 \00 \01\83\0b\07\00 \00 \01\84\0b\07\00 \00 \01\85\0b\07\00 \00 \01\86\0b\07\00 \00 \01\87\0b\07\00 \00 \01\88\0b\07\00 \00 \01\89\0b\07\00 \00 \01\8a\0b\05\00 \00\8b\0b\05\00 \00\8c\0b\05\00 \00\8d\0b\05\00 \00\8e\0b\05\00 \00\8f\0b\05\00 \00\90\0b\05\00 \00\91\0b\07\00 \00 \01\92\0b\07\00 \00 \01\93\0b\07\00 \00 \01\94\0b\07\00 \00 \01\95\0b\07\00 \00 \01\96\0b\07\00 \00 \01\97\0b\07\00 \00 \01\98\0b\05\00 \00\99\0b\05\00 \00\9a\0b\05\00 \00\9b\0b\05\00 \00\9c\0b\05\00 \00\9d\0b\05\00 \00\9e\0b\05\00 \00\9f\0b\07\00 \00 \01\a0\0b\07\00 \00 \01\a1\0b\07\00 \00 \01\a2\0b\07\00 \00 \01\a3\0b\07\00 \00 \01\a4\0b\07\00 \00 \01\a5\0b\07\00 \00 \01\a6\0b\05\00 \00\a7\0b\05\00 \00\a8\0b\05\00 \00\a9\0b\05\00 \00\aa\0b\05\00 \00\ab\0b\05\00 \00\ac\0b\05\00 \00\ad\0b\05\00 \00\ae\0b\05\00 \00\af\0b\05\00 \00\b0\0b\05\00 \00\b1\0b\05\00 \00\b2\0b\05\00 \00\b3\0b\05\00 \00\b4\0b\05\00 \00\b5\0b\05\00 \00\b6\0b\05\00 \00\b7\0b\05\00 \00\b8\0b\05\00 \00\b9\0b\05\00 \00\ba\0b\05\00 \00\bb\0b\05\00 \00\bc\0b\05\00 \00\bd\0b\05\00 \00\be\0b\05\00 \00\bf\0b\05\00 \00\c0\0b\05\00 \00\c1\0b\05\00 \00\c2\0b\05\00 \00\c3\0b\05\00 \00\c4\0b\06\00 \00\fc\00\0b\06\00 \00\fc\01\0b\06\00 \00\fc\02\0b\06\00 \00\fc\03\0b\06\00 \00\fc\04\0b\06\00 \00\fc\05\0b\06\00 \00\fc\06\0b\06\00 \00\fc\07\0b")
+
+(assert_return (invoke "i32.eqz" (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.eqz" (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const 31)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const -7)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const -2147483647)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const 2)) (i32.const 0))
+(assert_return (invoke "i32.eqz" (i32.const 65535)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 0) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.eq" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.eq" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.eq" (i32.const -1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 2147483647) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 2147483647) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.eq" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 2147483647) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -2147483648) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.eq" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 305419896) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.eq" (i32.const 305419896) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.ne" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 0) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 0) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 0) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 0) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.ne" (i32.const 1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ne" (i32.const -1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 2147483647) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 2147483647) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 2147483647) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ne" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 2147483647) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -2147483648) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -2147483648) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -2147483648) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.ne" (i32.const -2147483648) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 305419896) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 305419896) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 305419896) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 305419896) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 305419896) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ne" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 0) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 0) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const 1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const -1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const -1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const 2147483647) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 2147483647) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 2147483647) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const -2147483648) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -2147483648) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -2147483648) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const -2147483648) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const 305419896) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 305419896) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_s" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_s" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 0) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 0) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 0) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 0) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const -1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 2147483647) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 2147483647) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 2147483647) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 2147483647) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -2147483648) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -2147483648) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 305419896) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.lt_u" (i32.const 305419896) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 305419896) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 305419896) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.lt_u" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 0) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 0) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const -1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 2147483647) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 2147483647) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 2147483647) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 2147483647) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const -2147483648) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 305419896) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 305419896) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 305419896) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_s" (i32.const 305419896) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.gt_s" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const -1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const -1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const 2147483647) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const 2147483647) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 2147483647) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -2147483648) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -2147483648) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const -2147483648) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const 305419896) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const 305419896) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.gt_u" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.gt_u" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 0) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 0) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 0) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const -1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 2147483647) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 2147483647) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 2147483647) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const -2147483648) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -2147483648) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -2147483648) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const -2147483648) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 305419896) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 305419896) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_s" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.le_s" (i32.const 305419896) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 0) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 0) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 0) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 0) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 0) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const -1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const -1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const 2147483647) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const 2147483647) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const 2147483647) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 2147483647) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -2147483648) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -2147483648) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const 305419896) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.le_u" (i32.const 305419896) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 305419896) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 305419896) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.le_u" (i32.const 305419896) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 0) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const 0) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const 0) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const -1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const -1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const 2147483647) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 2147483647) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 2147483647) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 2147483647) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const -2147483648) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const 305419896) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 305419896) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 305419896) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_s" (i32.const 305419896) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_s" (i32.const 305419896) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 0) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const -1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -1) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 2147483647) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 2147483647) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 2147483647) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -2147483648) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -2147483648) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const -2147483648) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 305419896) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 305419896) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.ge_u" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.ge_u" (i32.const 305419896) (i32.const 305419896)) (i32.const 1))
+(assert_return (invoke "i64.eqz" (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.eqz" (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const 63)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const -7)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const 2147483648)) (i32.const 0))
+(assert_return (invoke "i64.eqz" (i64.const 4294967295)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.eq" (i64.const 0) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 0) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 0) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.eq" (i64.const 1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.eq" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.eq" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.eq" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 81985529216486895) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 81985529216486895) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 81985529216486895) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.eq" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 0) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.ne" (i64.const 0) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 0) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 0) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.ne" (i64.const 1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.ne" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ne" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.ne" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 81985529216486895) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 81985529216486895) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 81985529216486895) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ne" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 0) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 0) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const 0) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 0) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const 1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const -1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const 81985529216486895) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 81985529216486895) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 81985529216486895) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_s" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_s" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 0) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 0) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 0) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 0) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const -1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 81985529216486895) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 81985529216486895) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.lt_u" (i64.const 81985529216486895) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.lt_u" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 0) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 0) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 0) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 0) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const -1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 81985529216486895) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 81985529216486895) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 81985529216486895) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_s" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.gt_s" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 0) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 0) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 0) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 0) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const 1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const -1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const 81985529216486895) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const 81985529216486895) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.gt_u" (i64.const 81985529216486895) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.gt_u" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 0) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 0) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 0) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const -1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 81985529216486895) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 81985529216486895) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 81985529216486895) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_s" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.le_s" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 0) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 0) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 0) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const 1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const -1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const 81985529216486895) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const 81985529216486895) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.le_u" (i64.const 81985529216486895) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.le_u" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 0) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const 0) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 0) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const 1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -1) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -1) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const -1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const 81985529216486895) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 81985529216486895) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 81985529216486895) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_s" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_s" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 0) (i64.const 1)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 0) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 0) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 0) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 0) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 1) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 1) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 1) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 1) (i64.const 81985529216486895)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const -1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -1) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -1) (i64.const -1)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -1) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -1) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -1) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 9223372036854775807) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 9223372036854775807) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 9223372036854775807) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -9223372036854775808) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -9223372036854775808) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -9223372036854775808) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 81985529216486895) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 81985529216486895) (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i64.ge_u" (i64.const 81985529216486895) (i64.const -1)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i64.ge_u" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x0.0p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x0.0p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const inf) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const inf) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const inf) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.eq" (f32.const inf) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const inf) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const nan:0x400000) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const nan:0x400000) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const nan:0x400000) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1p-149) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1p-149) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.eq" (f32.const 0x1p-149) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ne" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ne" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x0.0p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ne" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ne" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x0.0p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.ne" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.ne" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const inf) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const inf) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const inf) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.ne" (f32.const inf) (f32.const nan:0x400000)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const inf) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const nan:0x400000) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const nan:0x400000) (f32.const nan:0x400000)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const nan:0x400000) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1p-149) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1p-149) (f32.const nan:0x400000)) (i32.const 1))
+(assert_return (invoke "f32.ne" (f32.const 0x1p-149) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const 0x0.0p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x0.0p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const inf) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const inf) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const inf) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const inf) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const inf) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const nan:0x400000) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const nan:0x400000) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const nan:0x400000) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const 0x1p-149) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.lt" (f32.const 0x1p-149) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.lt" (f32.const 0x1p-149) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x0.0p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x0.0p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const inf) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const inf) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const inf) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const inf) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const inf) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const nan:0x400000) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const nan:0x400000) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const nan:0x400000) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.gt" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1p-149) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1p-149) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.gt" (f32.const 0x1p-149) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x0.0p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x0.0p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const inf) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const inf) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const inf) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const inf) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const inf) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const nan:0x400000) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const nan:0x400000) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const nan:0x400000) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x1p-149) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.le" (f32.const 0x1p-149) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.le" (f32.const 0x1p-149) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x0.0p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x0.0p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const inf) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const inf) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const inf) (f32.const inf)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const inf) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const inf) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const nan:0x400000) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const nan:0x400000) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const nan:0x400000) (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f32.ge" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x1p-149) (f32.const inf)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x1p-149) (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "f32.ge" (f32.const 0x1p-149) (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x0.0p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const inf) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const inf) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const inf) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.eq" (f64.const inf) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const nan:0x8000000000000) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.eq" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ne" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ne" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x0.0p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.ne" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.ne" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const inf) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const inf) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const inf) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.ne" (f64.const inf) (f64.const nan:0x8000000000000)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const nan:0x8000000000000) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (i32.const 1))
+(assert_return (invoke "f64.ne" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x0.0p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const inf) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const inf) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const inf) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const inf) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const nan:0x8000000000000) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.lt" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x0.0p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const inf) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const inf) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const inf) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const inf) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const nan:0x8000000000000) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.gt" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x0.0p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x0.0p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const inf) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const inf) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const inf) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const inf) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const nan:0x8000000000000) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.le" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.le" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x0.0p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const inf) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const inf) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const inf) (f64.const inf)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const inf) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const nan:0x8000000000000) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (i32.const 1))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "f64.ge" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (i32.const 1))
+(assert_return (invoke "i32.clz" (i32.const 0)) (i32.const 32))
+(assert_return (invoke "i32.clz" (i32.const 1)) (i32.const 31))
+(assert_return (invoke "i32.clz" (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.clz" (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.clz" (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.clz" (i32.const 305419896)) (i32.const 3))
+(assert_return (invoke "i32.clz" (i32.const 31)) (i32.const 27))
+(assert_return (invoke "i32.clz" (i32.const -7)) (i32.const 0))
+(assert_return (invoke "i32.clz" (i32.const -2147483647)) (i32.const 0))
+(assert_return (invoke "i32.clz" (i32.const 2)) (i32.const 30))
+(assert_return (invoke "i32.clz" (i32.const 65535)) (i32.const 16))
+(assert_return (invoke "i32.ctz" (i32.const 0)) (i32.const 32))
+(assert_return (invoke "i32.ctz" (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.ctz" (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.ctz" (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ctz" (i32.const -2147483648)) (i32.const 31))
+(assert_return (invoke "i32.ctz" (i32.const 305419896)) (i32.const 3))
+(assert_return (invoke "i32.ctz" (i32.const 31)) (i32.const 0))
+(assert_return (invoke "i32.ctz" (i32.const -7)) (i32.const 0))
+(assert_return (invoke "i32.ctz" (i32.const -2147483647)) (i32.const 0))
+(assert_return (invoke "i32.ctz" (i32.const 2)) (i32.const 1))
+(assert_return (invoke "i32.ctz" (i32.const 65535)) (i32.const 0))
+(assert_return (invoke "i32.popcnt" (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.popcnt" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.popcnt" (i32.const -1)) (i32.const 32))
+(assert_return (invoke "i32.popcnt" (i32.const 2147483647)) (i32.const 31))
+(assert_return (invoke "i32.popcnt" (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.popcnt" (i32.const 305419896)) (i32.const 13))
+(assert_return (invoke "i32.popcnt" (i32.const 31)) (i32.const 5))
+(assert_return (invoke "i32.popcnt" (i32.const -7)) (i32.const 30))
+(assert_return (invoke "i32.popcnt" (i32.const -2147483647)) (i32.const 2))
+(assert_return (invoke "i32.popcnt" (i32.const 2)) (i32.const 1))
+(assert_return (invoke "i32.popcnt" (i32.const 65535)) (i32.const 16))
+(assert_return (invoke "i32.add" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.add" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.add" (i32.const 0) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.add" (i32.const 0) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.add" (i32.const 0) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.add" (i32.const 0) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.add" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.add" (i32.const 1) (i32.const 1)) (i32.const 2))
+(assert_return (invoke "i32.add" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.add" (i32.const 1) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.add" (i32.const 1) (i32.const -2147483648)) (i32.const -2147483647))
+(assert_return (invoke "i32.add" (i32.const 1) (i32.const 305419896)) (i32.const 305419897))
+(assert_return (invoke "i32.add" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.add" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.add" (i32.const -1) (i32.const -1)) (i32.const -2))
+(assert_return (invoke "i32.add" (i32.const -1) (i32.const 2147483647)) (i32.const 2147483646))
+(assert_return (invoke "i32.add" (i32.const -1) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.add" (i32.const -1) (i32.const 305419896)) (i32.const 305419895))
+(assert_return (invoke "i32.add" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.add" (i32.const 2147483647) (i32.const 1)) (i32.const -2147483648))
+(assert_return (invoke "i32.add" (i32.const 2147483647) (i32.const -1)) (i32.const 2147483646))
+(assert_return (invoke "i32.add" (i32.const 2147483647) (i32.const 2147483647)) (i32.const -2))
+(assert_return (invoke "i32.add" (i32.const 2147483647) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.add" (i32.const 2147483647) (i32.const 305419896)) (i32.const -1842063753))
+(assert_return (invoke "i32.add" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.add" (i32.const -2147483648) (i32.const 1)) (i32.const -2147483647))
+(assert_return (invoke "i32.add" (i32.const -2147483648) (i32.const -1)) (i32.const 2147483647))
+(assert_return (invoke "i32.add" (i32.const -2147483648) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.add" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.add" (i32.const -2147483648) (i32.const 305419896)) (i32.const -1842063752))
+(assert_return (invoke "i32.add" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.add" (i32.const 305419896) (i32.const 1)) (i32.const 305419897))
+(assert_return (invoke "i32.add" (i32.const 305419896) (i32.const -1)) (i32.const 305419895))
+(assert_return (invoke "i32.add" (i32.const 305419896) (i32.const 2147483647)) (i32.const -1842063753))
+(assert_return (invoke "i32.add" (i32.const 305419896) (i32.const -2147483648)) (i32.const -1842063752))
+(assert_return (invoke "i32.add" (i32.const 305419896) (i32.const 305419896)) (i32.const 610839792))
+(assert_return (invoke "i32.sub" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.sub" (i32.const 0) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.sub" (i32.const 0) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.sub" (i32.const 0) (i32.const 2147483647)) (i32.const -2147483647))
+(assert_return (invoke "i32.sub" (i32.const 0) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.sub" (i32.const 0) (i32.const 305419896)) (i32.const -305419896))
+(assert_return (invoke "i32.sub" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.sub" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.sub" (i32.const 1) (i32.const -1)) (i32.const 2))
+(assert_return (invoke "i32.sub" (i32.const 1) (i32.const 2147483647)) (i32.const -2147483646))
+(assert_return (invoke "i32.sub" (i32.const 1) (i32.const -2147483648)) (i32.const -2147483647))
+(assert_return (invoke "i32.sub" (i32.const 1) (i32.const 305419896)) (i32.const -305419895))
+(assert_return (invoke "i32.sub" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.sub" (i32.const -1) (i32.const 1)) (i32.const -2))
+(assert_return (invoke "i32.sub" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.sub" (i32.const -1) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.sub" (i32.const -1) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.sub" (i32.const -1) (i32.const 305419896)) (i32.const -305419897))
+(assert_return (invoke "i32.sub" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.sub" (i32.const 2147483647) (i32.const 1)) (i32.const 2147483646))
+(assert_return (invoke "i32.sub" (i32.const 2147483647) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.sub" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.sub" (i32.const 2147483647) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.sub" (i32.const 2147483647) (i32.const 305419896)) (i32.const 1842063751))
+(assert_return (invoke "i32.sub" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.sub" (i32.const -2147483648) (i32.const 1)) (i32.const 2147483647))
+(assert_return (invoke "i32.sub" (i32.const -2147483648) (i32.const -1)) (i32.const -2147483647))
+(assert_return (invoke "i32.sub" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.sub" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.sub" (i32.const -2147483648) (i32.const 305419896)) (i32.const 1842063752))
+(assert_return (invoke "i32.sub" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.sub" (i32.const 305419896) (i32.const 1)) (i32.const 305419895))
+(assert_return (invoke "i32.sub" (i32.const 305419896) (i32.const -1)) (i32.const 305419897))
+(assert_return (invoke "i32.sub" (i32.const 305419896) (i32.const 2147483647)) (i32.const -1842063751))
+(assert_return (invoke "i32.sub" (i32.const 305419896) (i32.const -2147483648)) (i32.const -1842063752))
+(assert_return (invoke "i32.sub" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.mul" (i32.const 1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.mul" (i32.const 1) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.mul" (i32.const 1) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.mul" (i32.const 1) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.mul" (i32.const -1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const -1) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.mul" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.mul" (i32.const -1) (i32.const 2147483647)) (i32.const -2147483647))
+(assert_return (invoke "i32.mul" (i32.const -1) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.mul" (i32.const -1) (i32.const 305419896)) (i32.const -305419896))
+(assert_return (invoke "i32.mul" (i32.const 2147483647) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 2147483647) (i32.const 1)) (i32.const 2147483647))
+(assert_return (invoke "i32.mul" (i32.const 2147483647) (i32.const -1)) (i32.const -2147483647))
+(assert_return (invoke "i32.mul" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.mul" (i32.const 2147483647) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.mul" (i32.const 2147483647) (i32.const 305419896)) (i32.const -305419896))
+(assert_return (invoke "i32.mul" (i32.const -2147483648) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const -2147483648) (i32.const 1)) (i32.const -2147483648))
+(assert_return (invoke "i32.mul" (i32.const -2147483648) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.mul" (i32.const -2147483648) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.mul" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 305419896) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 305419896) (i32.const 1)) (i32.const 305419896))
+(assert_return (invoke "i32.mul" (i32.const 305419896) (i32.const -1)) (i32.const -305419896))
+(assert_return (invoke "i32.mul" (i32.const 305419896) (i32.const 2147483647)) (i32.const -305419896))
+(assert_return (invoke "i32.mul" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.mul" (i32.const 305419896) (i32.const 305419896)) (i32.const 502585408))
+(assert_trap (invoke "i32.div_s" (i32.const 0) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_s" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.div_s" (i32.const 1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_s" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.div_s" (i32.const 1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.div_s" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.div_s" (i32.const -1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_s" (i32.const -1) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.div_s" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.div_s" (i32.const -1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const -1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const -1) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.div_s" (i32.const 2147483647) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_s" (i32.const 2147483647) (i32.const 1)) (i32.const 2147483647))
+(assert_return (invoke "i32.div_s" (i32.const 2147483647) (i32.const -1)) (i32.const -2147483647))
+(assert_return (invoke "i32.div_s" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.div_s" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 2147483647) (i32.const 305419896)) (i32.const 7))
+(assert_trap (invoke "i32.div_s" (i32.const -2147483648) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_s" (i32.const -2147483648) (i32.const 1)) (i32.const -2147483648))
+(assert_trap (invoke "i32.div_s" (i32.const -2147483648) (i32.const -1)) "integer overflow")
+(assert_return (invoke "i32.div_s" (i32.const -2147483648) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.div_s" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.div_s" (i32.const -2147483648) (i32.const 305419896)) (i32.const -7))
+(assert_trap (invoke "i32.div_s" (i32.const 305419896) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_s" (i32.const 305419896) (i32.const 1)) (i32.const 305419896))
+(assert_return (invoke "i32.div_s" (i32.const 305419896) (i32.const -1)) (i32.const -305419896))
+(assert_return (invoke "i32.div_s" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_s" (i32.const 305419896) (i32.const 305419896)) (i32.const 1))
+(assert_trap (invoke "i32.div_u" (i32.const 0) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_u" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.div_u" (i32.const 1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_u" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.div_u" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.div_u" (i32.const -1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_u" (i32.const -1) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.div_u" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.div_u" (i32.const -1) (i32.const 2147483647)) (i32.const 2))
+(assert_return (invoke "i32.div_u" (i32.const -1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.div_u" (i32.const -1) (i32.const 305419896)) (i32.const 14))
+(assert_trap (invoke "i32.div_u" (i32.const 2147483647) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_u" (i32.const 2147483647) (i32.const 1)) (i32.const 2147483647))
+(assert_return (invoke "i32.div_u" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.div_u" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 2147483647) (i32.const 305419896)) (i32.const 7))
+(assert_trap (invoke "i32.div_u" (i32.const -2147483648) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_u" (i32.const -2147483648) (i32.const 1)) (i32.const -2147483648))
+(assert_return (invoke "i32.div_u" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.div_u" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.div_u" (i32.const -2147483648) (i32.const 305419896)) (i32.const 7))
+(assert_trap (invoke "i32.div_u" (i32.const 305419896) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.div_u" (i32.const 305419896) (i32.const 1)) (i32.const 305419896))
+(assert_return (invoke "i32.div_u" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.div_u" (i32.const 305419896) (i32.const 305419896)) (i32.const 1))
+(assert_trap (invoke "i32.rem_s" (i32.const 0) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_s" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.rem_s" (i32.const 1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_s" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.rem_s" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.rem_s" (i32.const 1) (i32.const 305419896)) (i32.const 1))
+(assert_trap (invoke "i32.rem_s" (i32.const -1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_s" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const -1) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.rem_s" (i32.const -1) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.rem_s" (i32.const -1) (i32.const 305419896)) (i32.const -1))
+(assert_trap (invoke "i32.rem_s" (i32.const 2147483647) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_s" (i32.const 2147483647) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.rem_s" (i32.const 2147483647) (i32.const 305419896)) (i32.const 9544375))
+(assert_trap (invoke "i32.rem_s" (i32.const -2147483648) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_s" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const -2147483648) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.rem_s" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const -2147483648) (i32.const 305419896)) (i32.const -9544376))
+(assert_trap (invoke "i32.rem_s" (i32.const 305419896) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_s" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_s" (i32.const 305419896) (i32.const 2147483647)) (i32.const 305419896))
+(assert_return (invoke "i32.rem_s" (i32.const 305419896) (i32.const -2147483648)) (i32.const 305419896))
+(assert_return (invoke "i32.rem_s" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.rem_u" (i32.const 0) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_u" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_trap (invoke "i32.rem_u" (i32.const 1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_u" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.rem_u" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.rem_u" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.rem_u" (i32.const 1) (i32.const 305419896)) (i32.const 1))
+(assert_trap (invoke "i32.rem_u" (i32.const -1) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_u" (i32.const -1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const -1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.rem_u" (i32.const -1) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.rem_u" (i32.const -1) (i32.const 305419896)) (i32.const 19088751))
+(assert_trap (invoke "i32.rem_u" (i32.const 2147483647) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_u" (i32.const 2147483647) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 2147483647) (i32.const -1)) (i32.const 2147483647))
+(assert_return (invoke "i32.rem_u" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.rem_u" (i32.const 2147483647) (i32.const 305419896)) (i32.const 9544375))
+(assert_trap (invoke "i32.rem_u" (i32.const -2147483648) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_u" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const -2147483648) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.rem_u" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.rem_u" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const -2147483648) (i32.const 305419896)) (i32.const 9544376))
+(assert_trap (invoke "i32.rem_u" (i32.const 305419896) (i32.const 0)) "integer divide by zero")
+(assert_return (invoke "i32.rem_u" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rem_u" (i32.const 305419896) (i32.const -1)) (i32.const 305419896))
+(assert_return (invoke "i32.rem_u" (i32.const 305419896) (i32.const 2147483647)) (i32.const 305419896))
+(assert_return (invoke "i32.rem_u" (i32.const 305419896) (i32.const -2147483648)) (i32.const 305419896))
+(assert_return (invoke "i32.rem_u" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.and" (i32.const 1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.and" (i32.const 1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.and" (i32.const 1) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const -1) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const -1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.and" (i32.const -1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.and" (i32.const -1) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.and" (i32.const -1) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.and" (i32.const -1) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.and" (i32.const 2147483647) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 2147483647) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.and" (i32.const 2147483647) (i32.const -1)) (i32.const 2147483647))
+(assert_return (invoke "i32.and" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.and" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 2147483647) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.and" (i32.const -2147483648) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const -2147483648) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.and" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const -2147483648) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.and" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 305419896) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 305419896) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 305419896) (i32.const -1)) (i32.const 305419896))
+(assert_return (invoke "i32.and" (i32.const 305419896) (i32.const 2147483647)) (i32.const 305419896))
+(assert_return (invoke "i32.and" (i32.const 305419896) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.and" (i32.const 305419896) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.or" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.or" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.or" (i32.const 0) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const 0) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.or" (i32.const 0) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.or" (i32.const 0) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.or" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.or" (i32.const 1) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.or" (i32.const 1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const 1) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.or" (i32.const 1) (i32.const -2147483648)) (i32.const -2147483647))
+(assert_return (invoke "i32.or" (i32.const 1) (i32.const 305419896)) (i32.const 305419897))
+(assert_return (invoke "i32.or" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const -1) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const -1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const -1) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const -1) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const -1) (i32.const 305419896)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.or" (i32.const 2147483647) (i32.const 1)) (i32.const 2147483647))
+(assert_return (invoke "i32.or" (i32.const 2147483647) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.or" (i32.const 2147483647) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const 2147483647) (i32.const 305419896)) (i32.const 2147483647))
+(assert_return (invoke "i32.or" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.or" (i32.const -2147483648) (i32.const 1)) (i32.const -2147483647))
+(assert_return (invoke "i32.or" (i32.const -2147483648) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const -2147483648) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const -2147483648) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.or" (i32.const -2147483648) (i32.const 305419896)) (i32.const -1842063752))
+(assert_return (invoke "i32.or" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.or" (i32.const 305419896) (i32.const 1)) (i32.const 305419897))
+(assert_return (invoke "i32.or" (i32.const 305419896) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.or" (i32.const 305419896) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.or" (i32.const 305419896) (i32.const -2147483648)) (i32.const -1842063752))
+(assert_return (invoke "i32.or" (i32.const 305419896) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.xor" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.xor" (i32.const 0) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.xor" (i32.const 0) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.xor" (i32.const 0) (i32.const 2147483647)) (i32.const 2147483647))
+(assert_return (invoke "i32.xor" (i32.const 0) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.xor" (i32.const 0) (i32.const 305419896)) (i32.const 305419896))
+(assert_return (invoke "i32.xor" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.xor" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.xor" (i32.const 1) (i32.const -1)) (i32.const -2))
+(assert_return (invoke "i32.xor" (i32.const 1) (i32.const 2147483647)) (i32.const 2147483646))
+(assert_return (invoke "i32.xor" (i32.const 1) (i32.const -2147483648)) (i32.const -2147483647))
+(assert_return (invoke "i32.xor" (i32.const 1) (i32.const 305419896)) (i32.const 305419897))
+(assert_return (invoke "i32.xor" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.xor" (i32.const -1) (i32.const 1)) (i32.const -2))
+(assert_return (invoke "i32.xor" (i32.const -1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.xor" (i32.const -1) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.xor" (i32.const -1) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.xor" (i32.const -1) (i32.const 305419896)) (i32.const -305419897))
+(assert_return (invoke "i32.xor" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.xor" (i32.const 2147483647) (i32.const 1)) (i32.const 2147483646))
+(assert_return (invoke "i32.xor" (i32.const 2147483647) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.xor" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.xor" (i32.const 2147483647) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.xor" (i32.const 2147483647) (i32.const 305419896)) (i32.const 1842063751))
+(assert_return (invoke "i32.xor" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.xor" (i32.const -2147483648) (i32.const 1)) (i32.const -2147483647))
+(assert_return (invoke "i32.xor" (i32.const -2147483648) (i32.const -1)) (i32.const 2147483647))
+(assert_return (invoke "i32.xor" (i32.const -2147483648) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.xor" (i32.const -2147483648) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.xor" (i32.const -2147483648) (i32.const 305419896)) (i32.const -1842063752))
+(assert_return (invoke "i32.xor" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.xor" (i32.const 305419896) (i32.const 1)) (i32.const 305419897))
+(assert_return (invoke "i32.xor" (i32.const 305419896) (i32.const -1)) (i32.const -305419897))
+(assert_return (invoke "i32.xor" (i32.const 305419896) (i32.const 2147483647)) (i32.const 1842063751))
+(assert_return (invoke "i32.xor" (i32.const 305419896) (i32.const -2147483648)) (i32.const -1842063752))
+(assert_return (invoke "i32.xor" (i32.const 305419896) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.shl" (i32.const 1) (i32.const 1)) (i32.const 2))
+(assert_return (invoke "i32.shl" (i32.const 1) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const 1) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.shl" (i32.const 1) (i32.const 305419896)) (i32.const 16777216))
+(assert_return (invoke "i32.shl" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.shl" (i32.const -1) (i32.const 1)) (i32.const -2))
+(assert_return (invoke "i32.shl" (i32.const -1) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const -1) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const -1) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.shl" (i32.const -1) (i32.const 305419896)) (i32.const -16777216))
+(assert_return (invoke "i32.shl" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.shl" (i32.const 2147483647) (i32.const 1)) (i32.const -2))
+(assert_return (invoke "i32.shl" (i32.const 2147483647) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const 2147483647) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.shl" (i32.const 2147483647) (i32.const 305419896)) (i32.const -16777216))
+(assert_return (invoke "i32.shl" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const -2147483648) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const -2147483648) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const -2147483648) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.shl" (i32.const -2147483648) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.shl" (i32.const 305419896) (i32.const 1)) (i32.const 610839792))
+(assert_return (invoke "i32.shl" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shl" (i32.const 305419896) (i32.const -2147483648)) (i32.const 305419896))
+(assert_return (invoke "i32.shl" (i32.const 305419896) (i32.const 305419896)) (i32.const 2013265920))
+(assert_return (invoke "i32.shr_s" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.shr_s" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.shr_s" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const -1) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const -1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const -1) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const -1) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const -1) (i32.const 305419896)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.shr_s" (i32.const 2147483647) (i32.const 1)) (i32.const 1073741823))
+(assert_return (invoke "i32.shr_s" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.shr_s" (i32.const 2147483647) (i32.const 305419896)) (i32.const 127))
+(assert_return (invoke "i32.shr_s" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.shr_s" (i32.const -2147483648) (i32.const 1)) (i32.const -1073741824))
+(assert_return (invoke "i32.shr_s" (i32.const -2147483648) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const -2147483648) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.shr_s" (i32.const -2147483648) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.shr_s" (i32.const -2147483648) (i32.const 305419896)) (i32.const -128))
+(assert_return (invoke "i32.shr_s" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.shr_s" (i32.const 305419896) (i32.const 1)) (i32.const 152709948))
+(assert_return (invoke "i32.shr_s" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_s" (i32.const 305419896) (i32.const -2147483648)) (i32.const 305419896))
+(assert_return (invoke "i32.shr_s" (i32.const 305419896) (i32.const 305419896)) (i32.const 18))
+(assert_return (invoke "i32.shr_u" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.shr_u" (i32.const 1) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 1) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 1) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.shr_u" (i32.const 1) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.shr_u" (i32.const -1) (i32.const 1)) (i32.const 2147483647))
+(assert_return (invoke "i32.shr_u" (i32.const -1) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.shr_u" (i32.const -1) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.shr_u" (i32.const -1) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.shr_u" (i32.const -1) (i32.const 305419896)) (i32.const 255))
+(assert_return (invoke "i32.shr_u" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.shr_u" (i32.const 2147483647) (i32.const 1)) (i32.const 1073741823))
+(assert_return (invoke "i32.shr_u" (i32.const 2147483647) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 2147483647) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.shr_u" (i32.const 2147483647) (i32.const 305419896)) (i32.const 127))
+(assert_return (invoke "i32.shr_u" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.shr_u" (i32.const -2147483648) (i32.const 1)) (i32.const 1073741824))
+(assert_return (invoke "i32.shr_u" (i32.const -2147483648) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.shr_u" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.shr_u" (i32.const -2147483648) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.shr_u" (i32.const -2147483648) (i32.const 305419896)) (i32.const 128))
+(assert_return (invoke "i32.shr_u" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.shr_u" (i32.const 305419896) (i32.const 1)) (i32.const 152709948))
+(assert_return (invoke "i32.shr_u" (i32.const 305419896) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 305419896) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.shr_u" (i32.const 305419896) (i32.const -2147483648)) (i32.const 305419896))
+(assert_return (invoke "i32.shr_u" (i32.const 305419896) (i32.const 305419896)) (i32.const 18))
+(assert_return (invoke "i32.rotl" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.rotl" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rotl" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rotl" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.rotl" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.rotl" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.rotl" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.rotl" (i32.const 1) (i32.const 1)) (i32.const 2))
+(assert_return (invoke "i32.rotl" (i32.const 1) (i32.const -1)) (i32.const -2147483648))
+(assert_return (invoke "i32.rotl" (i32.const 1) (i32.const 2147483647)) (i32.const -2147483648))
+(assert_return (invoke "i32.rotl" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.rotl" (i32.const 1) (i32.const 305419896)) (i32.const 16777216))
+(assert_return (invoke "i32.rotl" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.rotl" (i32.const -1) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.rotl" (i32.const -1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.rotl" (i32.const -1) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.rotl" (i32.const -1) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.rotl" (i32.const -1) (i32.const 305419896)) (i32.const -1))
+(assert_return (invoke "i32.rotl" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.rotl" (i32.const 2147483647) (i32.const 1)) (i32.const -2))
+(assert_return (invoke "i32.rotl" (i32.const 2147483647) (i32.const -1)) (i32.const -1073741825))
+(assert_return (invoke "i32.rotl" (i32.const 2147483647) (i32.const 2147483647)) (i32.const -1073741825))
+(assert_return (invoke "i32.rotl" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.rotl" (i32.const 2147483647) (i32.const 305419896)) (i32.const -8388609))
+(assert_return (invoke "i32.rotl" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.rotl" (i32.const -2147483648) (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.rotl" (i32.const -2147483648) (i32.const -1)) (i32.const 1073741824))
+(assert_return (invoke "i32.rotl" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1073741824))
+(assert_return (invoke "i32.rotl" (i32.const -2147483648) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.rotl" (i32.const -2147483648) (i32.const 305419896)) (i32.const 8388608))
+(assert_return (invoke "i32.rotl" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.rotl" (i32.const 305419896) (i32.const 1)) (i32.const 610839792))
+(assert_return (invoke "i32.rotl" (i32.const 305419896) (i32.const -1)) (i32.const 152709948))
+(assert_return (invoke "i32.rotl" (i32.const 305419896) (i32.const 2147483647)) (i32.const 152709948))
+(assert_return (invoke "i32.rotl" (i32.const 305419896) (i32.const -2147483648)) (i32.const 305419896))
+(assert_return (invoke "i32.rotl" (i32.const 305419896) (i32.const 305419896)) (i32.const 2014458966))
+(assert_return (invoke "i32.rotr" (i32.const 0) (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.rotr" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rotr" (i32.const 0) (i32.const -1)) (i32.const 0))
+(assert_return (invoke "i32.rotr" (i32.const 0) (i32.const 2147483647)) (i32.const 0))
+(assert_return (invoke "i32.rotr" (i32.const 0) (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.rotr" (i32.const 0) (i32.const 305419896)) (i32.const 0))
+(assert_return (invoke "i32.rotr" (i32.const 1) (i32.const 0)) (i32.const 1))
+(assert_return (invoke "i32.rotr" (i32.const 1) (i32.const 1)) (i32.const -2147483648))
+(assert_return (invoke "i32.rotr" (i32.const 1) (i32.const -1)) (i32.const 2))
+(assert_return (invoke "i32.rotr" (i32.const 1) (i32.const 2147483647)) (i32.const 2))
+(assert_return (invoke "i32.rotr" (i32.const 1) (i32.const -2147483648)) (i32.const 1))
+(assert_return (invoke "i32.rotr" (i32.const 1) (i32.const 305419896)) (i32.const 256))
+(assert_return (invoke "i32.rotr" (i32.const -1) (i32.const 0)) (i32.const -1))
+(assert_return (invoke "i32.rotr" (i32.const -1) (i32.const 1)) (i32.const -1))
+(assert_return (invoke "i32.rotr" (i32.const -1) (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.rotr" (i32.const -1) (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.rotr" (i32.const -1) (i32.const -2147483648)) (i32.const -1))
+(assert_return (invoke "i32.rotr" (i32.const -1) (i32.const 305419896)) (i32.const -1))
+(assert_return (invoke "i32.rotr" (i32.const 2147483647) (i32.const 0)) (i32.const 2147483647))
+(assert_return (invoke "i32.rotr" (i32.const 2147483647) (i32.const 1)) (i32.const -1073741825))
+(assert_return (invoke "i32.rotr" (i32.const 2147483647) (i32.const -1)) (i32.const -2))
+(assert_return (invoke "i32.rotr" (i32.const 2147483647) (i32.const 2147483647)) (i32.const -2))
+(assert_return (invoke "i32.rotr" (i32.const 2147483647) (i32.const -2147483648)) (i32.const 2147483647))
+(assert_return (invoke "i32.rotr" (i32.const 2147483647) (i32.const 305419896)) (i32.const -129))
+(assert_return (invoke "i32.rotr" (i32.const -2147483648) (i32.const 0)) (i32.const -2147483648))
+(assert_return (invoke "i32.rotr" (i32.const -2147483648) (i32.const 1)) (i32.const 1073741824))
+(assert_return (invoke "i32.rotr" (i32.const -2147483648) (i32.const -1)) (i32.const 1))
+(assert_return (invoke "i32.rotr" (i32.const -2147483648) (i32.const 2147483647)) (i32.const 1))
+(assert_return (invoke "i32.rotr" (i32.const -2147483648) (i32.const -2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.rotr" (i32.const -2147483648) (i32.const 305419896)) (i32.const 128))
+(assert_return (invoke "i32.rotr" (i32.const 305419896) (i32.const 0)) (i32.const 305419896))
+(assert_return (invoke "i32.rotr" (i32.const 305419896) (i32.const 1)) (i32.const 152709948))
+(assert_return (invoke "i32.rotr" (i32.const 305419896) (i32.const -1)) (i32.const 610839792))
+(assert_return (invoke "i32.rotr" (i32.const 305419896) (i32.const 2147483647)) (i32.const 610839792))
+(assert_return (invoke "i32.rotr" (i32.const 305419896) (i32.const -2147483648)) (i32.const 305419896))
+(assert_return (invoke "i32.rotr" (i32.const 305419896) (i32.const 305419896)) (i32.const 878082066))
+(assert_return (invoke "i64.clz" (i64.const 0)) (i64.const 64))
+(assert_return (invoke "i64.clz" (i64.const 1)) (i64.const 63))
+(assert_return (invoke "i64.clz" (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.clz" (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.clz" (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.clz" (i64.const 81985529216486895)) (i64.const 7))
+(assert_return (invoke "i64.clz" (i64.const 63)) (i64.const 58))
+(assert_return (invoke "i64.clz" (i64.const -7)) (i64.const 0))
+(assert_return (invoke "i64.clz" (i64.const 2147483648)) (i64.const 32))
+(assert_return (invoke "i64.clz" (i64.const 4294967295)) (i64.const 32))
+(assert_return (invoke "i64.ctz" (i64.const 0)) (i64.const 64))
+(assert_return (invoke "i64.ctz" (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.ctz" (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.ctz" (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.ctz" (i64.const -9223372036854775808)) (i64.const 63))
+(assert_return (invoke "i64.ctz" (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.ctz" (i64.const 63)) (i64.const 0))
+(assert_return (invoke "i64.ctz" (i64.const -7)) (i64.const 0))
+(assert_return (invoke "i64.ctz" (i64.const 2147483648)) (i64.const 31))
+(assert_return (invoke "i64.ctz" (i64.const 4294967295)) (i64.const 0))
+(assert_return (invoke "i64.popcnt" (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.popcnt" (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.popcnt" (i64.const -1)) (i64.const 64))
+(assert_return (invoke "i64.popcnt" (i64.const 9223372036854775807)) (i64.const 63))
+(assert_return (invoke "i64.popcnt" (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.popcnt" (i64.const 81985529216486895)) (i64.const 32))
+(assert_return (invoke "i64.popcnt" (i64.const 63)) (i64.const 6))
+(assert_return (invoke "i64.popcnt" (i64.const -7)) (i64.const 62))
+(assert_return (invoke "i64.popcnt" (i64.const 2147483648)) (i64.const 1))
+(assert_return (invoke "i64.popcnt" (i64.const 4294967295)) (i64.const 32))
+(assert_return (invoke "i64.add" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.add" (i64.const 0) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.add" (i64.const 0) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.add" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.add" (i64.const 0) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.add" (i64.const 0) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.add" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.add" (i64.const 1) (i64.const 1)) (i64.const 2))
+(assert_return (invoke "i64.add" (i64.const 1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.add" (i64.const 1) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.add" (i64.const 1) (i64.const -9223372036854775808)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.add" (i64.const 1) (i64.const 81985529216486895)) (i64.const 81985529216486896))
+(assert_return (invoke "i64.add" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.add" (i64.const -1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.add" (i64.const -1) (i64.const -1)) (i64.const -2))
+(assert_return (invoke "i64.add" (i64.const -1) (i64.const 9223372036854775807)) (i64.const 9223372036854775806))
+(assert_return (invoke "i64.add" (i64.const -1) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.add" (i64.const -1) (i64.const 81985529216486895)) (i64.const 81985529216486894))
+(assert_return (invoke "i64.add" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.add" (i64.const 9223372036854775807) (i64.const 1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.add" (i64.const 9223372036854775807) (i64.const -1)) (i64.const 9223372036854775806))
+(assert_return (invoke "i64.add" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const -2))
+(assert_return (invoke "i64.add" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.add" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const -9141386507638288914))
+(assert_return (invoke "i64.add" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.add" (i64.const -9223372036854775808) (i64.const 1)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.add" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.add" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.add" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.add" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const -9141386507638288913))
+(assert_return (invoke "i64.add" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.add" (i64.const 81985529216486895) (i64.const 1)) (i64.const 81985529216486896))
+(assert_return (invoke "i64.add" (i64.const 81985529216486895) (i64.const -1)) (i64.const 81985529216486894))
+(assert_return (invoke "i64.add" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const -9141386507638288914))
+(assert_return (invoke "i64.add" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const -9141386507638288913))
+(assert_return (invoke "i64.add" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 163971058432973790))
+(assert_return (invoke "i64.sub" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.sub" (i64.const 0) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.sub" (i64.const 0) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.sub" (i64.const 0) (i64.const 9223372036854775807)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.sub" (i64.const 0) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.sub" (i64.const 0) (i64.const 81985529216486895)) (i64.const -81985529216486895))
+(assert_return (invoke "i64.sub" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.sub" (i64.const 1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.sub" (i64.const 1) (i64.const -1)) (i64.const 2))
+(assert_return (invoke "i64.sub" (i64.const 1) (i64.const 9223372036854775807)) (i64.const -9223372036854775806))
+(assert_return (invoke "i64.sub" (i64.const 1) (i64.const -9223372036854775808)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.sub" (i64.const 1) (i64.const 81985529216486895)) (i64.const -81985529216486894))
+(assert_return (invoke "i64.sub" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.sub" (i64.const -1) (i64.const 1)) (i64.const -2))
+(assert_return (invoke "i64.sub" (i64.const -1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.sub" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.sub" (i64.const -1) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.sub" (i64.const -1) (i64.const 81985529216486895)) (i64.const -81985529216486896))
+(assert_return (invoke "i64.sub" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.sub" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 9223372036854775806))
+(assert_return (invoke "i64.sub" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.sub" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.sub" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.sub" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 9141386507638288912))
+(assert_return (invoke "i64.sub" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.sub" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.sub" (i64.const -9223372036854775808) (i64.const -1)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.sub" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.sub" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.sub" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 9141386507638288913))
+(assert_return (invoke "i64.sub" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.sub" (i64.const 81985529216486895) (i64.const 1)) (i64.const 81985529216486894))
+(assert_return (invoke "i64.sub" (i64.const 81985529216486895) (i64.const -1)) (i64.const 81985529216486896))
+(assert_return (invoke "i64.sub" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const -9141386507638288912))
+(assert_return (invoke "i64.sub" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const -9141386507638288913))
+(assert_return (invoke "i64.sub" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 1) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 1) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.mul" (i64.const 1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.mul" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.mul" (i64.const 1) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const 1) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.mul" (i64.const -1) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const -1) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.mul" (i64.const -1) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.mul" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.mul" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const -1) (i64.const 81985529216486895)) (i64.const -81985529216486895))
+(assert_return (invoke "i64.mul" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.mul" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.mul" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.mul" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 9141386507638288913))
+(assert_return (invoke "i64.mul" (i64.const -9223372036854775808) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const -9223372036854775808) (i64.const 1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const -9223372036854775808) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const 81985529216486895) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.mul" (i64.const 81985529216486895) (i64.const 1)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.mul" (i64.const 81985529216486895) (i64.const -1)) (i64.const -81985529216486895))
+(assert_return (invoke "i64.mul" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 9141386507638288913))
+(assert_return (invoke "i64.mul" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.mul" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const -2547381487788710623))
+(assert_trap (invoke "i64.div_s" (i64.const 0) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_s" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.div_s" (i64.const 1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_s" (i64.const 1) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.div_s" (i64.const 1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.div_s" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 1) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.div_s" (i64.const -1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_s" (i64.const -1) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.div_s" (i64.const -1) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.div_s" (i64.const -1) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const -1) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const -1) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.div_s" (i64.const 9223372036854775807) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_s" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.div_s" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.div_s" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.div_s" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 112))
+(assert_trap (invoke "i64.div_s" (i64.const -9223372036854775808) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_s" (i64.const -9223372036854775808) (i64.const 1)) (i64.const -9223372036854775808))
+(assert_trap (invoke "i64.div_s" (i64.const -9223372036854775808) (i64.const -1)) "integer overflow")
+(assert_return (invoke "i64.div_s" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.div_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.div_s" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const -112))
+(assert_trap (invoke "i64.div_s" (i64.const 81985529216486895) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_s" (i64.const 81985529216486895) (i64.const 1)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.div_s" (i64.const 81985529216486895) (i64.const -1)) (i64.const -81985529216486895))
+(assert_return (invoke "i64.div_s" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_s" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 1))
+(assert_trap (invoke "i64.div_u" (i64.const 0) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_u" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.div_u" (i64.const 1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_u" (i64.const 1) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.div_u" (i64.const 1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 1) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.div_u" (i64.const -1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_u" (i64.const -1) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.div_u" (i64.const -1) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.div_u" (i64.const -1) (i64.const 9223372036854775807)) (i64.const 2))
+(assert_return (invoke "i64.div_u" (i64.const -1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.div_u" (i64.const -1) (i64.const 81985529216486895)) (i64.const 225))
+(assert_trap (invoke "i64.div_u" (i64.const 9223372036854775807) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_u" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.div_u" (i64.const 9223372036854775807) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.div_u" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 112))
+(assert_trap (invoke "i64.div_u" (i64.const -9223372036854775808) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_u" (i64.const -9223372036854775808) (i64.const 1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.div_u" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.div_u" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.div_u" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 112))
+(assert_trap (invoke "i64.div_u" (i64.const 81985529216486895) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.div_u" (i64.const 81985529216486895) (i64.const 1)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.div_u" (i64.const 81985529216486895) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.div_u" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 1))
+(assert_trap (invoke "i64.rem_s" (i64.const 0) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_s" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.rem_s" (i64.const 1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_s" (i64.const 1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.rem_s" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.rem_s" (i64.const 1) (i64.const 81985529216486895)) (i64.const 1))
+(assert_trap (invoke "i64.rem_s" (i64.const -1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_s" (i64.const -1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const -1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.rem_s" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.rem_s" (i64.const -1) (i64.const 81985529216486895)) (i64.const -1))
+(assert_trap (invoke "i64.rem_s" (i64.const 9223372036854775807) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_s" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 9223372036854775807) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rem_s" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 40992764608243567))
+(assert_trap (invoke "i64.rem_s" (i64.const -9223372036854775808) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_s" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.rem_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const -40992764608243568))
+(assert_trap (invoke "i64.rem_s" (i64.const 81985529216486895) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_s" (i64.const 81985529216486895) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 81985529216486895) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_s" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rem_s" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rem_s" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.rem_u" (i64.const 0) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_u" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_trap (invoke "i64.rem_u" (i64.const 1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_u" (i64.const 1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 1) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.rem_u" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.rem_u" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.rem_u" (i64.const 1) (i64.const 81985529216486895)) (i64.const 1))
+(assert_trap (invoke "i64.rem_u" (i64.const -1) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_u" (i64.const -1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const -1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const -1) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.rem_u" (i64.const -1) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rem_u" (i64.const -1) (i64.const 81985529216486895)) (i64.const 240))
+(assert_trap (invoke "i64.rem_u" (i64.const 9223372036854775807) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_u" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 9223372036854775807) (i64.const -1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rem_u" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rem_u" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 40992764608243567))
+(assert_trap (invoke "i64.rem_u" (i64.const -9223372036854775808) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_u" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const -9223372036854775808) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rem_u" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.rem_u" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 40992764608243568))
+(assert_trap (invoke "i64.rem_u" (i64.const 81985529216486895) (i64.const 0)) "integer divide by zero")
+(assert_return (invoke "i64.rem_u" (i64.const 81985529216486895) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rem_u" (i64.const 81985529216486895) (i64.const -1)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rem_u" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rem_u" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rem_u" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 1) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 1) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.and" (i64.const 1) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.and" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.and" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 1) (i64.const 81985529216486895)) (i64.const 1))
+(assert_return (invoke "i64.and" (i64.const -1) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const -1) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.and" (i64.const -1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.and" (i64.const -1) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.and" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.and" (i64.const -1) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.and" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.and" (i64.const 9223372036854775807) (i64.const -1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.and" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.and" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.and" (i64.const -9223372036854775808) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const -9223372036854775808) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.and" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.and" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 81985529216486895) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 81985529216486895) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.and" (i64.const 81985529216486895) (i64.const -1)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.and" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.and" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.and" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.or" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.or" (i64.const 0) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.or" (i64.const 0) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const 0) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.or" (i64.const 0) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.or" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.or" (i64.const 1) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.or" (i64.const 1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const 1) (i64.const -9223372036854775808)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const 1) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.or" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const -1) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const -1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const -1) (i64.const 81985529216486895)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.or" (i64.const -9223372036854775808) (i64.const 1)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const -9223372036854775808) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.or" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const -9141386507638288913))
+(assert_return (invoke "i64.or" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.or" (i64.const 81985529216486895) (i64.const 1)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.or" (i64.const 81985529216486895) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.or" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.or" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const -9141386507638288913))
+(assert_return (invoke "i64.or" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.xor" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.xor" (i64.const 0) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.xor" (i64.const 0) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.xor" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.xor" (i64.const 0) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.xor" (i64.const 0) (i64.const 81985529216486895)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.xor" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.xor" (i64.const 1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.xor" (i64.const 1) (i64.const -1)) (i64.const -2))
+(assert_return (invoke "i64.xor" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 9223372036854775806))
+(assert_return (invoke "i64.xor" (i64.const 1) (i64.const -9223372036854775808)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.xor" (i64.const 1) (i64.const 81985529216486895)) (i64.const 81985529216486894))
+(assert_return (invoke "i64.xor" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.xor" (i64.const -1) (i64.const 1)) (i64.const -2))
+(assert_return (invoke "i64.xor" (i64.const -1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.xor" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.xor" (i64.const -1) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.xor" (i64.const -1) (i64.const 81985529216486895)) (i64.const -81985529216486896))
+(assert_return (invoke "i64.xor" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.xor" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 9223372036854775806))
+(assert_return (invoke "i64.xor" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.xor" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.xor" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.xor" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 9141386507638288912))
+(assert_return (invoke "i64.xor" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.xor" (i64.const -9223372036854775808) (i64.const 1)) (i64.const -9223372036854775807))
+(assert_return (invoke "i64.xor" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.xor" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.xor" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.xor" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const -9141386507638288913))
+(assert_return (invoke "i64.xor" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.xor" (i64.const 81985529216486895) (i64.const 1)) (i64.const 81985529216486894))
+(assert_return (invoke "i64.xor" (i64.const 81985529216486895) (i64.const -1)) (i64.const -81985529216486896))
+(assert_return (invoke "i64.xor" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 9141386507638288912))
+(assert_return (invoke "i64.xor" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const -9141386507638288913))
+(assert_return (invoke "i64.xor" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.shl" (i64.const 1) (i64.const 1)) (i64.const 2))
+(assert_return (invoke "i64.shl" (i64.const 1) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const 1) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.shl" (i64.const 1) (i64.const 81985529216486895)) (i64.const 140737488355328))
+(assert_return (invoke "i64.shl" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.shl" (i64.const -1) (i64.const 1)) (i64.const -2))
+(assert_return (invoke "i64.shl" (i64.const -1) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.shl" (i64.const -1) (i64.const 81985529216486895)) (i64.const -140737488355328))
+(assert_return (invoke "i64.shl" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.shl" (i64.const 9223372036854775807) (i64.const 1)) (i64.const -2))
+(assert_return (invoke "i64.shl" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.shl" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const -140737488355328))
+(assert_return (invoke "i64.shl" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.shl" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.shl" (i64.const 81985529216486895) (i64.const 1)) (i64.const 163971058432973790))
+(assert_return (invoke "i64.shl" (i64.const 81985529216486895) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shl" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.shl" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const -1803832388250238976))
+(assert_return (invoke "i64.shr_s" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.shr_s" (i64.const 1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.shr_s" (i64.const 1) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const -1) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const -1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const -1) (i64.const 81985529216486895)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.shr_s" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 4611686018427387903))
+(assert_return (invoke "i64.shr_s" (i64.const 9223372036854775807) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.shr_s" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 65535))
+(assert_return (invoke "i64.shr_s" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shr_s" (i64.const -9223372036854775808) (i64.const 1)) (i64.const -4611686018427387904))
+(assert_return (invoke "i64.shr_s" (i64.const -9223372036854775808) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.shr_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shr_s" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const -65536))
+(assert_return (invoke "i64.shr_s" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.shr_s" (i64.const 81985529216486895) (i64.const 1)) (i64.const 40992764608243447))
+(assert_return (invoke "i64.shr_s" (i64.const 81985529216486895) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_s" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.shr_s" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 582))
+(assert_return (invoke "i64.shr_u" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.shr_u" (i64.const 1) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 1) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.shr_u" (i64.const 1) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.shr_u" (i64.const -1) (i64.const 1)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.shr_u" (i64.const -1) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.shr_u" (i64.const -1) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.shr_u" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.shr_u" (i64.const -1) (i64.const 81985529216486895)) (i64.const 131071))
+(assert_return (invoke "i64.shr_u" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.shr_u" (i64.const 9223372036854775807) (i64.const 1)) (i64.const 4611686018427387903))
+(assert_return (invoke "i64.shr_u" (i64.const 9223372036854775807) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.shr_u" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const 65535))
+(assert_return (invoke "i64.shr_u" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shr_u" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 4611686018427387904))
+(assert_return (invoke "i64.shr_u" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.shr_u" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.shr_u" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.shr_u" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 65536))
+(assert_return (invoke "i64.shr_u" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.shr_u" (i64.const 81985529216486895) (i64.const 1)) (i64.const 40992764608243447))
+(assert_return (invoke "i64.shr_u" (i64.const 81985529216486895) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.shr_u" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.shr_u" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const 582))
+(assert_return (invoke "i64.rotl" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.rotl" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rotl" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rotl" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.rotl" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.rotl" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.rotl" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.rotl" (i64.const 1) (i64.const 1)) (i64.const 2))
+(assert_return (invoke "i64.rotl" (i64.const 1) (i64.const -1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rotl" (i64.const 1) (i64.const 9223372036854775807)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rotl" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.rotl" (i64.const 1) (i64.const 81985529216486895)) (i64.const 140737488355328))
+(assert_return (invoke "i64.rotl" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.rotl" (i64.const -1) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.rotl" (i64.const -1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.rotl" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.rotl" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.rotl" (i64.const -1) (i64.const 81985529216486895)) (i64.const -1))
+(assert_return (invoke "i64.rotl" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rotl" (i64.const 9223372036854775807) (i64.const 1)) (i64.const -2))
+(assert_return (invoke "i64.rotl" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -4611686018427387905))
+(assert_return (invoke "i64.rotl" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const -4611686018427387905))
+(assert_return (invoke "i64.rotl" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rotl" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const -70368744177665))
+(assert_return (invoke "i64.rotl" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rotl" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.rotl" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 4611686018427387904))
+(assert_return (invoke "i64.rotl" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 4611686018427387904))
+(assert_return (invoke "i64.rotl" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rotl" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 70368744177664))
+(assert_return (invoke "i64.rotl" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rotl" (i64.const 81985529216486895) (i64.const 1)) (i64.const 163971058432973790))
+(assert_return (invoke "i64.rotl" (i64.const 81985529216486895) (i64.const -1)) (i64.const -9182379272246532361))
+(assert_return (invoke "i64.rotl" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const -9182379272246532361))
+(assert_return (invoke "i64.rotl" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rotl" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const -1803831762750290731))
+(assert_return (invoke "i64.rotr" (i64.const 0) (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.rotr" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.rotr" (i64.const 0) (i64.const -1)) (i64.const 0))
+(assert_return (invoke "i64.rotr" (i64.const 0) (i64.const 9223372036854775807)) (i64.const 0))
+(assert_return (invoke "i64.rotr" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.rotr" (i64.const 0) (i64.const 81985529216486895)) (i64.const 0))
+(assert_return (invoke "i64.rotr" (i64.const 1) (i64.const 0)) (i64.const 1))
+(assert_return (invoke "i64.rotr" (i64.const 1) (i64.const 1)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rotr" (i64.const 1) (i64.const -1)) (i64.const 2))
+(assert_return (invoke "i64.rotr" (i64.const 1) (i64.const 9223372036854775807)) (i64.const 2))
+(assert_return (invoke "i64.rotr" (i64.const 1) (i64.const -9223372036854775808)) (i64.const 1))
+(assert_return (invoke "i64.rotr" (i64.const 1) (i64.const 81985529216486895)) (i64.const 131072))
+(assert_return (invoke "i64.rotr" (i64.const -1) (i64.const 0)) (i64.const -1))
+(assert_return (invoke "i64.rotr" (i64.const -1) (i64.const 1)) (i64.const -1))
+(assert_return (invoke "i64.rotr" (i64.const -1) (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.rotr" (i64.const -1) (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.rotr" (i64.const -1) (i64.const -9223372036854775808)) (i64.const -1))
+(assert_return (invoke "i64.rotr" (i64.const -1) (i64.const 81985529216486895)) (i64.const -1))
+(assert_return (invoke "i64.rotr" (i64.const 9223372036854775807) (i64.const 0)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rotr" (i64.const 9223372036854775807) (i64.const 1)) (i64.const -4611686018427387905))
+(assert_return (invoke "i64.rotr" (i64.const 9223372036854775807) (i64.const -1)) (i64.const -2))
+(assert_return (invoke "i64.rotr" (i64.const 9223372036854775807) (i64.const 9223372036854775807)) (i64.const -2))
+(assert_return (invoke "i64.rotr" (i64.const 9223372036854775807) (i64.const -9223372036854775808)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.rotr" (i64.const 9223372036854775807) (i64.const 81985529216486895)) (i64.const -65537))
+(assert_return (invoke "i64.rotr" (i64.const -9223372036854775808) (i64.const 0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rotr" (i64.const -9223372036854775808) (i64.const 1)) (i64.const 4611686018427387904))
+(assert_return (invoke "i64.rotr" (i64.const -9223372036854775808) (i64.const -1)) (i64.const 1))
+(assert_return (invoke "i64.rotr" (i64.const -9223372036854775808) (i64.const 9223372036854775807)) (i64.const 1))
+(assert_return (invoke "i64.rotr" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.rotr" (i64.const -9223372036854775808) (i64.const 81985529216486895)) (i64.const 65536))
+(assert_return (invoke "i64.rotr" (i64.const 81985529216486895) (i64.const 0)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rotr" (i64.const 81985529216486895) (i64.const 1)) (i64.const -9182379272246532361))
+(assert_return (invoke "i64.rotr" (i64.const 81985529216486895) (i64.const -1)) (i64.const 163971058432973790))
+(assert_return (invoke "i64.rotr" (i64.const 81985529216486895) (i64.const 9223372036854775807)) (i64.const 163971058432973790))
+(assert_return (invoke "i64.rotr" (i64.const 81985529216486895) (i64.const -9223372036854775808)) (i64.const 81985529216486895))
+(assert_return (invoke "i64.rotr" (i64.const 81985529216486895) (i64.const 81985529216486895)) (i64.const -8444509509298290106))
+(assert_return (invoke "f32.abs" (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.abs" (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.abs" (f32.const 0x1p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.abs" (f32.const -0x1.8000000000000p+0)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.abs" (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.abs" (f32.const -0x1.c000000000000p+1)) (f32.const 0x1.c000000000000p+1))
+(assert_return (invoke "f32.abs" (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.abs" (f32.const -inf)) (f32.const inf))
+(assert_return (invoke "f32.abs" (f32.const nan:0x400000)) (f32.const nan:0x400000))
+(assert_return (invoke "f32.abs" (f32.const -nan:0x400001)) (f32.const nan:0x400001))
+(assert_return (invoke "f32.abs" (f32.const 0x1.fffffe0000000p+127)) (f32.const 0x1.fffffe0000000p+127))
+(assert_return (invoke "f32.abs" (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.abs" (f32.const 0x1p+31)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.abs" (f32.const -0x1.0000020000000p+31)) (f32.const 0x1.0000020000000p+31))
+(assert_return (invoke "f32.abs" (f32.const 0x1.2a05f20000000p+33)) (f32.const 0x1.2a05f20000000p+33))
+(assert_return (invoke "f32.abs" (f32.const 0x1.fffffe0000000p-2)) (f32.const 0x1.fffffe0000000p-2))
+(assert_return (invoke "f32.neg" (f32.const 0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.neg" (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.neg" (f32.const 0x1p+0)) (f32.const -0x1p+0))
+(assert_return (invoke "f32.neg" (f32.const -0x1.8000000000000p+0)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.neg" (f32.const 0x1.4000000000000p+1)) (f32.const -0x1.4000000000000p+1))
+(assert_return (invoke "f32.neg" (f32.const -0x1.c000000000000p+1)) (f32.const 0x1.c000000000000p+1))
+(assert_return (invoke "f32.neg" (f32.const inf)) (f32.const -inf))
+(assert_return (invoke "f32.neg" (f32.const -inf)) (f32.const inf))
+(assert_return (invoke "f32.neg" (f32.const nan:0x400000)) (f32.const -nan:0x400000))
+(assert_return (invoke "f32.neg" (f32.const -nan:0x400001)) (f32.const nan:0x400001))
+(assert_return (invoke "f32.neg" (f32.const 0x1.fffffe0000000p+127)) (f32.const -0x1.fffffe0000000p+127))
+(assert_return (invoke "f32.neg" (f32.const 0x1p-149)) (f32.const -0x1p-149))
+(assert_return (invoke "f32.neg" (f32.const 0x1p+31)) (f32.const -0x1p+31))
+(assert_return (invoke "f32.neg" (f32.const -0x1.0000020000000p+31)) (f32.const 0x1.0000020000000p+31))
+(assert_return (invoke "f32.neg" (f32.const 0x1.2a05f20000000p+33)) (f32.const -0x1.2a05f20000000p+33))
+(assert_return (invoke "f32.neg" (f32.const 0x1.fffffe0000000p-2)) (f32.const -0x1.fffffe0000000p-2))
+(assert_return (invoke "f32.ceil" (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.ceil" (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.ceil" (f32.const 0x1p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.ceil" (f32.const -0x1.8000000000000p+0)) (f32.const -0x1p+0))
+(assert_return (invoke "f32.ceil" (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.8000000000000p+1))
+(assert_return (invoke "f32.ceil" (f32.const -0x1.c000000000000p+1)) (f32.const -0x1.8000000000000p+1))
+(assert_return (invoke "f32.ceil" (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.ceil" (f32.const -inf)) (f32.const -inf))
+(assert_return (invoke "f32.ceil" (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.ceil" (f32.const -nan:0x400001)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.ceil" (f32.const 0x1.fffffe0000000p+127)) (f32.const 0x1.fffffe0000000p+127))
+(assert_return (invoke "f32.ceil" (f32.const 0x1p-149)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.ceil" (f32.const 0x1p+31)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.ceil" (f32.const -0x1.0000020000000p+31)) (f32.const -0x1.0000020000000p+31))
+(assert_return (invoke "f32.ceil" (f32.const 0x1.2a05f20000000p+33)) (f32.const 0x1.2a05f20000000p+33))
+(assert_return (invoke "f32.ceil" (f32.const 0x1.fffffe0000000p-2)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.floor" (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.floor" (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.floor" (f32.const 0x1p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.floor" (f32.const -0x1.8000000000000p+0)) (f32.const -0x1p+1))
+(assert_return (invoke "f32.floor" (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p+1))
+(assert_return (invoke "f32.floor" (f32.const -0x1.c000000000000p+1)) (f32.const -0x1p+2))
+(assert_return (invoke "f32.floor" (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.floor" (f32.const -inf)) (f32.const -inf))
+(assert_return (invoke "f32.floor" (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.floor" (f32.const -nan:0x400001)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.floor" (f32.const 0x1.fffffe0000000p+127)) (f32.const 0x1.fffffe0000000p+127))
+(assert_return (invoke "f32.floor" (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.floor" (f32.const 0x1p+31)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.floor" (f32.const -0x1.0000020000000p+31)) (f32.const -0x1.0000020000000p+31))
+(assert_return (invoke "f32.floor" (f32.const 0x1.2a05f20000000p+33)) (f32.const 0x1.2a05f20000000p+33))
+(assert_return (invoke "f32.floor" (f32.const 0x1.fffffe0000000p-2)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.trunc" (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.trunc" (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.trunc" (f32.const 0x1p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.trunc" (f32.const -0x1.8000000000000p+0)) (f32.const -0x1p+0))
+(assert_return (invoke "f32.trunc" (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p+1))
+(assert_return (invoke "f32.trunc" (f32.const -0x1.c000000000000p+1)) (f32.const -0x1.8000000000000p+1))
+(assert_return (invoke "f32.trunc" (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.trunc" (f32.const -inf)) (f32.const -inf))
+(assert_return (invoke "f32.trunc" (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.trunc" (f32.const -nan:0x400001)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.trunc" (f32.const 0x1.fffffe0000000p+127)) (f32.const 0x1.fffffe0000000p+127))
+(assert_return (invoke "f32.trunc" (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.trunc" (f32.const 0x1p+31)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.trunc" (f32.const -0x1.0000020000000p+31)) (f32.const -0x1.0000020000000p+31))
+(assert_return (invoke "f32.trunc" (f32.const 0x1.2a05f20000000p+33)) (f32.const 0x1.2a05f20000000p+33))
+(assert_return (invoke "f32.trunc" (f32.const 0x1.fffffe0000000p-2)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.nearest" (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.nearest" (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.nearest" (f32.const 0x1p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.nearest" (f32.const -0x1.8000000000000p+0)) (f32.const -0x1p+1))
+(assert_return (invoke "f32.nearest" (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p+1))
+(assert_return (invoke "f32.nearest" (f32.const -0x1.c000000000000p+1)) (f32.const -0x1p+2))
+(assert_return (invoke "f32.nearest" (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.nearest" (f32.const -inf)) (f32.const -inf))
+(assert_return (invoke "f32.nearest" (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.nearest" (f32.const -nan:0x400001)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.nearest" (f32.const 0x1.fffffe0000000p+127)) (f32.const 0x1.fffffe0000000p+127))
+(assert_return (invoke "f32.nearest" (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.nearest" (f32.const 0x1p+31)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.nearest" (f32.const -0x1.0000020000000p+31)) (f32.const -0x1.0000020000000p+31))
+(assert_return (invoke "f32.nearest" (f32.const 0x1.2a05f20000000p+33)) (f32.const 0x1.2a05f20000000p+33))
+(assert_return (invoke "f32.nearest" (f32.const 0x1.fffffe0000000p-2)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.sqrt" (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.sqrt" (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.sqrt" (f32.const 0x1p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.sqrt" (f32.const -0x1.8000000000000p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sqrt" (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.94c5840000000p+0))
+(assert_return (invoke "f32.sqrt" (f32.const -0x1.c000000000000p+1)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sqrt" (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.sqrt" (f32.const -inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sqrt" (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sqrt" (f32.const -nan:0x400001)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sqrt" (f32.const 0x1.fffffe0000000p+127)) (f32.const 0x1.fffffe0000000p+63))
+(assert_return (invoke "f32.sqrt" (f32.const 0x1p-149)) (f32.const 0x1.6a09e60000000p-75))
+(assert_return (invoke "f32.sqrt" (f32.const 0x1p+31)) (f32.const 0x1.6a09e60000000p+15))
+(assert_return (invoke "f32.sqrt" (f32.const -0x1.0000020000000p+31)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sqrt" (f32.const 0x1.2a05f20000000p+33)) (f32.const 0x1.86a0000000000p+16))
+(assert_return (invoke "f32.sqrt" (f32.const 0x1.fffffe0000000p-2)) (f32.const 0x1.6a09e60000000p-1))
+(assert_return (invoke "f32.add" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.add" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.add" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.add" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.add" (f32.const 0x0.0p+0) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.add" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.add" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.add" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.add" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.add" (f32.const -0x0.0p+0) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.add" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.add" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.add" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+1))
+(assert_return (invoke "f32.add" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.add" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.add" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.add" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.add" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.add" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+2))
+(assert_return (invoke "f32.add" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.add" (f32.const inf) (f32.const 0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const inf) (f32.const -0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const inf) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const inf) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const inf) (f32.const 0x1p-149)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const nan:0x400000) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const nan:0x400000) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const nan:0x400000) (f32.const 0x1p-149)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.add" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.add" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.add" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.add" (f32.const 0x1p-149) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.add" (f32.const 0x1p-149) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.add" (f32.const 0x1p-149) (f32.const 0x1p-149)) (f32.const 0x1p-148))
+(assert_return (invoke "f32.sub" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.sub" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.sub" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.sub" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x1.4000000000000p+1))
+(assert_return (invoke "f32.sub" (f32.const 0x0.0p+0) (f32.const inf)) (f32.const -inf))
+(assert_return (invoke "f32.sub" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (f32.const -0x1p-149))
+(assert_return (invoke "f32.sub" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.sub" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.sub" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.sub" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x1.4000000000000p+1))
+(assert_return (invoke "f32.sub" (f32.const -0x0.0p+0) (f32.const inf)) (f32.const -inf))
+(assert_return (invoke "f32.sub" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (f32.const -0x1p-149))
+(assert_return (invoke "f32.sub" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.sub" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.sub" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.sub" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x1p+2))
+(assert_return (invoke "f32.sub" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (f32.const -inf))
+(assert_return (invoke "f32.sub" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.sub" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.sub" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.sub" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1p+2))
+(assert_return (invoke "f32.sub" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.sub" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (f32.const -inf))
+(assert_return (invoke "f32.sub" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.sub" (f32.const inf) (f32.const 0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.sub" (f32.const inf) (f32.const -0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.sub" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (f32.const inf))
+(assert_return (invoke "f32.sub" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (f32.const inf))
+(assert_return (invoke "f32.sub" (f32.const inf) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const inf) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const inf) (f32.const 0x1p-149)) (f32.const inf))
+(assert_return (invoke "f32.sub" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const nan:0x400000) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const nan:0x400000) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const nan:0x400000) (f32.const 0x1p-149)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.sub" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.sub" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.sub" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (f32.const -0x1.4000000000000p+1))
+(assert_return (invoke "f32.sub" (f32.const 0x1p-149) (f32.const inf)) (f32.const -inf))
+(assert_return (invoke "f32.sub" (f32.const 0x1p-149) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.sub" (f32.const 0x1p-149) (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x0.0p+0) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x0.0p+0) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1.2000000000000p+1))
+(assert_return (invoke "f32.mul" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x1.e000000000000p+1))
+(assert_return (invoke "f32.mul" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (f32.const -inf))
+(assert_return (invoke "f32.mul" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (f32.const -0x1p-148))
+(assert_return (invoke "f32.mul" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.e000000000000p+1))
+(assert_return (invoke "f32.mul" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.9000000000000p+2))
+(assert_return (invoke "f32.mul" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.mul" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (f32.const 0x1p-148))
+(assert_return (invoke "f32.mul" (f32.const inf) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const inf) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (f32.const -inf))
+(assert_return (invoke "f32.mul" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (f32.const inf))
+(assert_return (invoke "f32.mul" (f32.const inf) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.mul" (f32.const inf) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const inf) (f32.const 0x1p-149)) (f32.const inf))
+(assert_return (invoke "f32.mul" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const nan:0x400000) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const nan:0x400000) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const nan:0x400000) (f32.const 0x1p-149)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.mul" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1p-148))
+(assert_return (invoke "f32.mul" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p-148))
+(assert_return (invoke "f32.mul" (f32.const 0x1p-149) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.mul" (f32.const 0x1p-149) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.mul" (f32.const 0x1p-149) (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const 0x0.0p+0) (f32.const inf)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const -0x0.0p+0) (f32.const inf)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (f32.const -inf))
+(assert_return (invoke "f32.div" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.div" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.div" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x1.3333340000000p-1))
+(assert_return (invoke "f32.div" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (f32.const -inf))
+(assert_return (invoke "f32.div" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.div" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (f32.const -inf))
+(assert_return (invoke "f32.div" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.aaaaaa0000000p+0))
+(assert_return (invoke "f32.div" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.div" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (f32.const inf))
+(assert_return (invoke "f32.div" (f32.const inf) (f32.const 0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.div" (f32.const inf) (f32.const -0x0.0p+0)) (f32.const -inf))
+(assert_return (invoke "f32.div" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (f32.const -inf))
+(assert_return (invoke "f32.div" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (f32.const inf))
+(assert_return (invoke "f32.div" (f32.const inf) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const inf) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const inf) (f32.const 0x1p-149)) (f32.const inf))
+(assert_return (invoke "f32.div" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const nan:0x400000) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const nan:0x400000) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const nan:0x400000) (f32.const 0x1p-149)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.div" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (f32.const -inf))
+(assert_return (invoke "f32.div" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1p-149))
+(assert_return (invoke "f32.div" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const 0x1p-149) (f32.const inf)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.div" (f32.const 0x1p-149) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.div" (f32.const 0x1p-149) (f32.const 0x1p-149)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.min" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x0.0p+0) (f32.const inf)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const -0x0.0p+0) (f32.const inf)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.min" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.min" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.min" (f32.const inf) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const inf) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.min" (f32.const inf) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.min" (f32.const inf) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const inf) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.min" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const nan:0x400000) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const nan:0x400000) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const nan:0x400000) (f32.const 0x1p-149)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.min" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.min" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.min" (f32.const 0x1p-149) (f32.const inf)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.min" (f32.const 0x1p-149) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.min" (f32.const 0x1p-149) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.max" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const 0x0.0p+0) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.max" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const -0x0.0p+0) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.max" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.max" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.max" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.max" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const inf) (f32.const 0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const inf) (f32.const -0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const inf) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const inf) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const inf) (f32.const 0x1p-149)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const nan:0x400000) (f32.const inf)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const nan:0x400000) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const nan:0x400000) (f32.const 0x1p-149)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.max" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.max" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.max" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.max" (f32.const 0x1p-149) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.max" (f32.const 0x1p-149) (f32.const nan:0x400000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.max" (f32.const 0x1p-149) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.copysign" (f32.const 0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const 0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const 0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const 0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const 0x0.0p+0) (f32.const inf)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const 0x0.0p+0) (f32.const nan:0x400000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const 0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x0.0p+0) (f32.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x0.0p+0) (f32.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x0.0p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x0.0p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x0.0p+0) (f32.const inf)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x0.0p+0) (f32.const nan:0x400000)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x0.0p+0) (f32.const 0x1p-149)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x1.8000000000000p+0) (f32.const 0x0.0p+0)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x1.8000000000000p+0) (f32.const -0x0.0p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x1.8000000000000p+0) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x1.8000000000000p+0) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x1.8000000000000p+0) (f32.const inf)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x1.8000000000000p+0) (f32.const nan:0x400000)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.copysign" (f32.const -0x1.8000000000000p+0) (f32.const 0x1p-149)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32.copysign" (f32.const 0x1.4000000000000p+1) (f32.const 0x0.0p+0)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.copysign" (f32.const 0x1.4000000000000p+1) (f32.const -0x0.0p+0)) (f32.const -0x1.4000000000000p+1))
+(assert_return (invoke "f32.copysign" (f32.const 0x1.4000000000000p+1) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1.4000000000000p+1))
+(assert_return (invoke "f32.copysign" (f32.const 0x1.4000000000000p+1) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.copysign" (f32.const 0x1.4000000000000p+1) (f32.const inf)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.copysign" (f32.const 0x1.4000000000000p+1) (f32.const nan:0x400000)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.copysign" (f32.const 0x1.4000000000000p+1) (f32.const 0x1p-149)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.copysign" (f32.const inf) (f32.const 0x0.0p+0)) (f32.const inf))
+(assert_return (invoke "f32.copysign" (f32.const inf) (f32.const -0x0.0p+0)) (f32.const -inf))
+(assert_return (invoke "f32.copysign" (f32.const inf) (f32.const -0x1.8000000000000p+0)) (f32.const -inf))
+(assert_return (invoke "f32.copysign" (f32.const inf) (f32.const 0x1.4000000000000p+1)) (f32.const inf))
+(assert_return (invoke "f32.copysign" (f32.const inf) (f32.const inf)) (f32.const inf))
+(assert_return (invoke "f32.copysign" (f32.const inf) (f32.const nan:0x400000)) (f32.const inf))
+(assert_return (invoke "f32.copysign" (f32.const inf) (f32.const 0x1p-149)) (f32.const inf))
+(assert_return (invoke "f32.copysign" (f32.const nan:0x400000) (f32.const 0x0.0p+0)) (f32.const nan:0x400000))
+(assert_return (invoke "f32.copysign" (f32.const nan:0x400000) (f32.const -0x0.0p+0)) (f32.const -nan:0x400000))
+(assert_return (invoke "f32.copysign" (f32.const nan:0x400000) (f32.const -0x1.8000000000000p+0)) (f32.const -nan:0x400000))
+(assert_return (invoke "f32.copysign" (f32.const nan:0x400000) (f32.const 0x1.4000000000000p+1)) (f32.const nan:0x400000))
+(assert_return (invoke "f32.copysign" (f32.const nan:0x400000) (f32.const inf)) (f32.const nan:0x400000))
+(assert_return (invoke "f32.copysign" (f32.const nan:0x400000) (f32.const nan:0x400000)) (f32.const nan:0x400000))
+(assert_return (invoke "f32.copysign" (f32.const nan:0x400000) (f32.const 0x1p-149)) (f32.const nan:0x400000))
+(assert_return (invoke "f32.copysign" (f32.const 0x1p-149) (f32.const 0x0.0p+0)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.copysign" (f32.const 0x1p-149) (f32.const -0x0.0p+0)) (f32.const -0x1p-149))
+(assert_return (invoke "f32.copysign" (f32.const 0x1p-149) (f32.const -0x1.8000000000000p+0)) (f32.const -0x1p-149))
+(assert_return (invoke "f32.copysign" (f32.const 0x1p-149) (f32.const 0x1.4000000000000p+1)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.copysign" (f32.const 0x1p-149) (f32.const inf)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.copysign" (f32.const 0x1p-149) (f32.const nan:0x400000)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.copysign" (f32.const 0x1p-149) (f32.const 0x1p-149)) (f32.const 0x1p-149))
+(assert_return (invoke "f64.abs" (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.abs" (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.abs" (f64.const 0x1p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.abs" (f64.const -0x1.8000000000000p+0)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.abs" (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.abs" (f64.const -0x1.c000000000000p+1)) (f64.const 0x1.c000000000000p+1))
+(assert_return (invoke "f64.abs" (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.abs" (f64.const -inf)) (f64.const inf))
+(assert_return (invoke "f64.abs" (f64.const nan:0x8000000000000)) (f64.const nan:0x8000000000000))
+(assert_return (invoke "f64.abs" (f64.const -nan:0x8000000000001)) (f64.const nan:0x8000000000001))
+(assert_return (invoke "f64.abs" (f64.const 0x1.fffffffffffffp+1023)) (f64.const 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64.abs" (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.abs" (f64.const 0x1p+63)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.abs" (f64.const -0x1.0000000200000p+31)) (f64.const 0x1.0000000200000p+31))
+(assert_return (invoke "f64.abs" (f64.const 0x1.0000000080000p+32)) (f64.const 0x1.0000000080000p+32))
+(assert_return (invoke "f64.abs" (f64.const 0x1.56e1fc2f8f359p-997)) (f64.const 0x1.56e1fc2f8f359p-997))
+(assert_return (invoke "f64.abs" (f64.const 0x1.fffffffffffffp-2)) (f64.const 0x1.fffffffffffffp-2))
+(assert_return (invoke "f64.neg" (f64.const 0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.neg" (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.neg" (f64.const 0x1p+0)) (f64.const -0x1p+0))
+(assert_return (invoke "f64.neg" (f64.const -0x1.8000000000000p+0)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.neg" (f64.const 0x1.4000000000000p+1)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64.neg" (f64.const -0x1.c000000000000p+1)) (f64.const 0x1.c000000000000p+1))
+(assert_return (invoke "f64.neg" (f64.const inf)) (f64.const -inf))
+(assert_return (invoke "f64.neg" (f64.const -inf)) (f64.const inf))
+(assert_return (invoke "f64.neg" (f64.const nan:0x8000000000000)) (f64.const -nan:0x8000000000000))
+(assert_return (invoke "f64.neg" (f64.const -nan:0x8000000000001)) (f64.const nan:0x8000000000001))
+(assert_return (invoke "f64.neg" (f64.const 0x1.fffffffffffffp+1023)) (f64.const -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64.neg" (f64.const 0x0.0000000000001p-1022)) (f64.const -0x0.0000000000001p-1022))
+(assert_return (invoke "f64.neg" (f64.const 0x1p+63)) (f64.const -0x1p+63))
+(assert_return (invoke "f64.neg" (f64.const -0x1.0000000200000p+31)) (f64.const 0x1.0000000200000p+31))
+(assert_return (invoke "f64.neg" (f64.const 0x1.0000000080000p+32)) (f64.const -0x1.0000000080000p+32))
+(assert_return (invoke "f64.neg" (f64.const 0x1.56e1fc2f8f359p-997)) (f64.const -0x1.56e1fc2f8f359p-997))
+(assert_return (invoke "f64.neg" (f64.const 0x1.fffffffffffffp-2)) (f64.const -0x1.fffffffffffffp-2))
+(assert_return (invoke "f64.ceil" (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.ceil" (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.ceil" (f64.const 0x1p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.ceil" (f64.const -0x1.8000000000000p+0)) (f64.const -0x1p+0))
+(assert_return (invoke "f64.ceil" (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.8000000000000p+1))
+(assert_return (invoke "f64.ceil" (f64.const -0x1.c000000000000p+1)) (f64.const -0x1.8000000000000p+1))
+(assert_return (invoke "f64.ceil" (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.ceil" (f64.const -inf)) (f64.const -inf))
+(assert_return (invoke "f64.ceil" (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.ceil" (f64.const -nan:0x8000000000001)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.ceil" (f64.const 0x1.fffffffffffffp+1023)) (f64.const 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64.ceil" (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.ceil" (f64.const 0x1p+63)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.ceil" (f64.const -0x1.0000000200000p+31)) (f64.const -0x1.0000000200000p+31))
+(assert_return (invoke "f64.ceil" (f64.const 0x1.0000000080000p+32)) (f64.const 0x1.0000000100000p+32))
+(assert_return (invoke "f64.ceil" (f64.const 0x1.56e1fc2f8f359p-997)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.ceil" (f64.const 0x1.fffffffffffffp-2)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.floor" (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.floor" (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.floor" (f64.const 0x1p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.floor" (f64.const -0x1.8000000000000p+0)) (f64.const -0x1p+1))
+(assert_return (invoke "f64.floor" (f64.const 0x1.4000000000000p+1)) (f64.const 0x1p+1))
+(assert_return (invoke "f64.floor" (f64.const -0x1.c000000000000p+1)) (f64.const -0x1p+2))
+(assert_return (invoke "f64.floor" (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.floor" (f64.const -inf)) (f64.const -inf))
+(assert_return (invoke "f64.floor" (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.floor" (f64.const -nan:0x8000000000001)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.floor" (f64.const 0x1.fffffffffffffp+1023)) (f64.const 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64.floor" (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.floor" (f64.const 0x1p+63)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.floor" (f64.const -0x1.0000000200000p+31)) (f64.const -0x1.0000000200000p+31))
+(assert_return (invoke "f64.floor" (f64.const 0x1.0000000080000p+32)) (f64.const 0x1p+32))
+(assert_return (invoke "f64.floor" (f64.const 0x1.56e1fc2f8f359p-997)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.floor" (f64.const 0x1.fffffffffffffp-2)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.trunc" (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.trunc" (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.trunc" (f64.const 0x1p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.trunc" (f64.const -0x1.8000000000000p+0)) (f64.const -0x1p+0))
+(assert_return (invoke "f64.trunc" (f64.const 0x1.4000000000000p+1)) (f64.const 0x1p+1))
+(assert_return (invoke "f64.trunc" (f64.const -0x1.c000000000000p+1)) (f64.const -0x1.8000000000000p+1))
+(assert_return (invoke "f64.trunc" (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.trunc" (f64.const -inf)) (f64.const -inf))
+(assert_return (invoke "f64.trunc" (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.trunc" (f64.const -nan:0x8000000000001)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.trunc" (f64.const 0x1.fffffffffffffp+1023)) (f64.const 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64.trunc" (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.trunc" (f64.const 0x1p+63)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.trunc" (f64.const -0x1.0000000200000p+31)) (f64.const -0x1.0000000200000p+31))
+(assert_return (invoke "f64.trunc" (f64.const 0x1.0000000080000p+32)) (f64.const 0x1p+32))
+(assert_return (invoke "f64.trunc" (f64.const 0x1.56e1fc2f8f359p-997)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.trunc" (f64.const 0x1.fffffffffffffp-2)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.nearest" (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.nearest" (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.nearest" (f64.const 0x1p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.nearest" (f64.const -0x1.8000000000000p+0)) (f64.const -0x1p+1))
+(assert_return (invoke "f64.nearest" (f64.const 0x1.4000000000000p+1)) (f64.const 0x1p+1))
+(assert_return (invoke "f64.nearest" (f64.const -0x1.c000000000000p+1)) (f64.const -0x1p+2))
+(assert_return (invoke "f64.nearest" (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.nearest" (f64.const -inf)) (f64.const -inf))
+(assert_return (invoke "f64.nearest" (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.nearest" (f64.const -nan:0x8000000000001)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.nearest" (f64.const 0x1.fffffffffffffp+1023)) (f64.const 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64.nearest" (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.nearest" (f64.const 0x1p+63)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.nearest" (f64.const -0x1.0000000200000p+31)) (f64.const -0x1.0000000200000p+31))
+(assert_return (invoke "f64.nearest" (f64.const 0x1.0000000080000p+32)) (f64.const 0x1p+32))
+(assert_return (invoke "f64.nearest" (f64.const 0x1.56e1fc2f8f359p-997)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.nearest" (f64.const 0x1.fffffffffffffp-2)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.sqrt" (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.sqrt" (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.sqrt" (f64.const 0x1p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.sqrt" (f64.const -0x1.8000000000000p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sqrt" (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.94c583ada5b53p+0))
+(assert_return (invoke "f64.sqrt" (f64.const -0x1.c000000000000p+1)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sqrt" (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.sqrt" (f64.const -inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sqrt" (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sqrt" (f64.const -nan:0x8000000000001)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sqrt" (f64.const 0x1.fffffffffffffp+1023)) (f64.const 0x1.fffffffffffffp+511))
+(assert_return (invoke "f64.sqrt" (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1p-537))
+(assert_return (invoke "f64.sqrt" (f64.const 0x1p+63)) (f64.const 0x1.6a09e667f3bcdp+31))
+(assert_return (invoke "f64.sqrt" (f64.const -0x1.0000000200000p+31)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sqrt" (f64.const 0x1.0000000080000p+32)) (f64.const 0x1.0000000040000p+16))
+(assert_return (invoke "f64.sqrt" (f64.const 0x1.56e1fc2f8f359p-997)) (f64.const 0x1.a2fe76a3f9475p-499))
+(assert_return (invoke "f64.sqrt" (f64.const 0x1.fffffffffffffp-2)) (f64.const 0x1.6a09e667f3bccp-1))
+(assert_return (invoke "f64.add" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.add" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.add" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.add" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.add" (f64.const 0x0.0p+0) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.add" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.add" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.add" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.add" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.add" (f64.const -0x0.0p+0) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.add" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.add" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.add" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+1))
+(assert_return (invoke "f64.add" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.add" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.add" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.add" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.add" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.add" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+2))
+(assert_return (invoke "f64.add" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.add" (f64.const inf) (f64.const 0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const inf) (f64.const -0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const inf) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const inf) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const nan:0x8000000000000) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.add" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.add" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.add" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.add" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.add" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.add" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000002p-1022))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0p+0) (f64.const inf)) (f64.const -inf))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x0.0000000000001p-1022))
+(assert_return (invoke "f64.sub" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.sub" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.sub" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.sub" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64.sub" (f64.const -0x0.0p+0) (f64.const inf)) (f64.const -inf))
+(assert_return (invoke "f64.sub" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x0.0000000000001p-1022))
+(assert_return (invoke "f64.sub" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.sub" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.sub" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.sub" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x1p+2))
+(assert_return (invoke "f64.sub" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (f64.const -inf))
+(assert_return (invoke "f64.sub" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.sub" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.sub" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.sub" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1p+2))
+(assert_return (invoke "f64.sub" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.sub" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (f64.const -inf))
+(assert_return (invoke "f64.sub" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.sub" (f64.const inf) (f64.const 0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.sub" (f64.const inf) (f64.const -0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.sub" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (f64.const inf))
+(assert_return (invoke "f64.sub" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (f64.const inf))
+(assert_return (invoke "f64.sub" (f64.const inf) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const inf) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (f64.const inf))
+(assert_return (invoke "f64.sub" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const nan:0x8000000000000) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (f64.const -inf))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.sub" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0p+0) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x0.0p+0) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1.2000000000000p+1))
+(assert_return (invoke "f64.mul" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x1.e000000000000p+1))
+(assert_return (invoke "f64.mul" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (f64.const -inf))
+(assert_return (invoke "f64.mul" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x0.0000000000002p-1022))
+(assert_return (invoke "f64.mul" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.e000000000000p+1))
+(assert_return (invoke "f64.mul" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.9000000000000p+2))
+(assert_return (invoke "f64.mul" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.mul" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000002p-1022))
+(assert_return (invoke "f64.mul" (f64.const inf) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const inf) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (f64.const -inf))
+(assert_return (invoke "f64.mul" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (f64.const inf))
+(assert_return (invoke "f64.mul" (f64.const inf) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.mul" (f64.const inf) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (f64.const inf))
+(assert_return (invoke "f64.mul" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const nan:0x8000000000000) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0000000000002p-1022))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0000000000002p-1022))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.mul" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const 0x0.0p+0) (f64.const inf)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const -0x0.0p+0) (f64.const inf)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (f64.const -inf))
+(assert_return (invoke "f64.div" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.div" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.div" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x1.3333333333333p-1))
+(assert_return (invoke "f64.div" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -inf))
+(assert_return (invoke "f64.div" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.div" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (f64.const -inf))
+(assert_return (invoke "f64.div" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.aaaaaaaaaaaabp+0))
+(assert_return (invoke "f64.div" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.div" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (f64.const inf))
+(assert_return (invoke "f64.div" (f64.const inf) (f64.const 0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.div" (f64.const inf) (f64.const -0x0.0p+0)) (f64.const -inf))
+(assert_return (invoke "f64.div" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (f64.const -inf))
+(assert_return (invoke "f64.div" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (f64.const inf))
+(assert_return (invoke "f64.div" (f64.const inf) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const inf) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (f64.const inf))
+(assert_return (invoke "f64.div" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const nan:0x8000000000000) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.div" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (f64.const -inf))
+(assert_return (invoke "f64.div" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0000000000001p-1022))
+(assert_return (invoke "f64.div" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.div" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.div" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0p+0) (f64.const inf)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const -0x0.0p+0) (f64.const inf)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.min" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.min" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.min" (f64.const inf) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const inf) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.min" (f64.const inf) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.min" (f64.const inf) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.min" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const nan:0x8000000000000) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.min" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.min" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.min" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.min" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.max" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const 0x0.0p+0) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.max" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const -0x0.0p+0) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.max" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.max" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.max" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.max" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const inf) (f64.const 0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const inf) (f64.const -0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const inf) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const inf) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const nan:0x8000000000000) (f64.const inf)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.max" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.max" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.max" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.max" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.max" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.max" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0p+0) (f64.const inf)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x0.0p+0) (f64.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x0.0p+0) (f64.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x0.0p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x0.0p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x0.0p+0) (f64.const inf)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x0.0p+0) (f64.const nan:0x8000000000000)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x0.0p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0p+0)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x1.8000000000000p+0) (f64.const -0x0.0p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x1.8000000000000p+0) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x1.8000000000000p+0) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x1.8000000000000p+0) (f64.const inf)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x1.8000000000000p+0) (f64.const nan:0x8000000000000)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.copysign" (f64.const -0x1.8000000000000p+0) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1.8000000000000p+0))
+(assert_return (invoke "f64.copysign" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0p+0)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.copysign" (f64.const 0x1.4000000000000p+1) (f64.const -0x0.0p+0)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64.copysign" (f64.const 0x1.4000000000000p+1) (f64.const -0x1.8000000000000p+0)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64.copysign" (f64.const 0x1.4000000000000p+1) (f64.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.copysign" (f64.const 0x1.4000000000000p+1) (f64.const inf)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.copysign" (f64.const 0x1.4000000000000p+1) (f64.const nan:0x8000000000000)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.copysign" (f64.const 0x1.4000000000000p+1) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.copysign" (f64.const inf) (f64.const 0x0.0p+0)) (f64.const inf))
+(assert_return (invoke "f64.copysign" (f64.const inf) (f64.const -0x0.0p+0)) (f64.const -inf))
+(assert_return (invoke "f64.copysign" (f64.const inf) (f64.const -0x1.8000000000000p+0)) (f64.const -inf))
+(assert_return (invoke "f64.copysign" (f64.const inf) (f64.const 0x1.4000000000000p+1)) (f64.const inf))
+(assert_return (invoke "f64.copysign" (f64.const inf) (f64.const inf)) (f64.const inf))
+(assert_return (invoke "f64.copysign" (f64.const inf) (f64.const nan:0x8000000000000)) (f64.const inf))
+(assert_return (invoke "f64.copysign" (f64.const inf) (f64.const 0x0.0000000000001p-1022)) (f64.const inf))
+(assert_return (invoke "f64.copysign" (f64.const nan:0x8000000000000) (f64.const 0x0.0p+0)) (f64.const nan:0x8000000000000))
+(assert_return (invoke "f64.copysign" (f64.const nan:0x8000000000000) (f64.const -0x0.0p+0)) (f64.const -nan:0x8000000000000))
+(assert_return (invoke "f64.copysign" (f64.const nan:0x8000000000000) (f64.const -0x1.8000000000000p+0)) (f64.const -nan:0x8000000000000))
+(assert_return (invoke "f64.copysign" (f64.const nan:0x8000000000000) (f64.const 0x1.4000000000000p+1)) (f64.const nan:0x8000000000000))
+(assert_return (invoke "f64.copysign" (f64.const nan:0x8000000000000) (f64.const inf)) (f64.const nan:0x8000000000000))
+(assert_return (invoke "f64.copysign" (f64.const nan:0x8000000000000) (f64.const nan:0x8000000000000)) (f64.const nan:0x8000000000000))
+(assert_return (invoke "f64.copysign" (f64.const nan:0x8000000000000) (f64.const 0x0.0000000000001p-1022)) (f64.const nan:0x8000000000000))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0p+0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0000000000001p-1022) (f64.const -0x0.0p+0)) (f64.const -0x0.0000000000001p-1022))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0000000000001p-1022) (f64.const -0x1.8000000000000p+0)) (f64.const -0x0.0000000000001p-1022))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0000000000001p-1022) (f64.const 0x1.4000000000000p+1)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0000000000001p-1022) (f64.const inf)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0000000000001p-1022) (f64.const nan:0x8000000000000)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.copysign" (f64.const 0x0.0000000000001p-1022) (f64.const 0x0.0000000000001p-1022)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "i32.wrap_i64" (i64.const 0)) (i32.const 0))
+(assert_return (invoke "i32.wrap_i64" (i64.const 1)) (i32.const 1))
+(assert_return (invoke "i32.wrap_i64" (i64.const -1)) (i32.const -1))
+(assert_return (invoke "i32.wrap_i64" (i64.const 9223372036854775807)) (i32.const -1))
+(assert_return (invoke "i32.wrap_i64" (i64.const -9223372036854775808)) (i32.const 0))
+(assert_return (invoke "i32.wrap_i64" (i64.const 81985529216486895)) (i32.const -1985229329))
+(assert_return (invoke "i32.wrap_i64" (i64.const 63)) (i32.const 63))
+(assert_return (invoke "i32.wrap_i64" (i64.const -7)) (i32.const -7))
+(assert_return (invoke "i32.wrap_i64" (i64.const 2147483648)) (i32.const -2147483648))
+(assert_return (invoke "i32.wrap_i64" (i64.const 4294967295)) (i32.const -1))
+(assert_return (invoke "i32.trunc_f32_s" (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f32_s" (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f32_s" (f32.const 0x1p+0)) (i32.const 1))
+(assert_return (invoke "i32.trunc_f32_s" (f32.const -0x1.8000000000000p+0)) (i32.const -1))
+(assert_return (invoke "i32.trunc_f32_s" (f32.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_return (invoke "i32.trunc_f32_s" (f32.const -0x1.c000000000000p+1)) (i32.const -3))
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const -inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const nan:0x400000)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const -nan:0x400001)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const 0x1.fffffe0000000p+127)) "integer overflow")
+(assert_return (invoke "i32.trunc_f32_s" (f32.const 0x1p-149)) (i32.const 0))
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const 0x1p+31)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const -0x1.0000020000000p+31)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_s" (f32.const 0x1.2a05f20000000p+33)) "integer overflow")
+(assert_return (invoke "i32.trunc_f32_s" (f32.const 0x1.fffffe0000000p-2)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f32_u" (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f32_u" (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f32_u" (f32.const 0x1p+0)) (i32.const 1))
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const -0x1.8000000000000p+0)) "integer overflow")
+(assert_return (invoke "i32.trunc_f32_u" (f32.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const -0x1.c000000000000p+1)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const -inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const nan:0x400000)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const -nan:0x400001)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const 0x1.fffffe0000000p+127)) "integer overflow")
+(assert_return (invoke "i32.trunc_f32_u" (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f32_u" (f32.const 0x1p+31)) (i32.const -2147483648))
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const -0x1.0000020000000p+31)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f32_u" (f32.const 0x1.2a05f20000000p+33)) "integer overflow")
+(assert_return (invoke "i32.trunc_f32_u" (f32.const 0x1.fffffe0000000p-2)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_s" (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_s" (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_s" (f64.const 0x1p+0)) (i32.const 1))
+(assert_return (invoke "i32.trunc_f64_s" (f64.const -0x1.8000000000000p+0)) (i32.const -1))
+(assert_return (invoke "i32.trunc_f64_s" (f64.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_return (invoke "i32.trunc_f64_s" (f64.const -0x1.c000000000000p+1)) (i32.const -3))
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const -inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const nan:0x8000000000000)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const -nan:0x8000000000001)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const 0x1.fffffffffffffp+1023)) "integer overflow")
+(assert_return (invoke "i32.trunc_f64_s" (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const 0x1p+63)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const -0x1.0000000200000p+31)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_s" (f64.const 0x1.0000000080000p+32)) "integer overflow")
+(assert_return (invoke "i32.trunc_f64_s" (f64.const 0x1.56e1fc2f8f359p-997)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_s" (f64.const 0x1.fffffffffffffp-2)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_u" (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_u" (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_u" (f64.const 0x1p+0)) (i32.const 1))
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const -0x1.8000000000000p+0)) "integer overflow")
+(assert_return (invoke "i32.trunc_f64_u" (f64.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const -0x1.c000000000000p+1)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const -inf)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const nan:0x8000000000000)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const -nan:0x8000000000001)) "invalid conversion to integer")
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const 0x1.fffffffffffffp+1023)) "integer overflow")
+(assert_return (invoke "i32.trunc_f64_u" (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const 0x1p+63)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const -0x1.0000000200000p+31)) "integer overflow")
+(assert_trap (invoke "i32.trunc_f64_u" (f64.const 0x1.0000000080000p+32)) "integer overflow")
+(assert_return (invoke "i32.trunc_f64_u" (f64.const 0x1.56e1fc2f8f359p-997)) (i32.const 0))
+(assert_return (invoke "i32.trunc_f64_u" (f64.const 0x1.fffffffffffffp-2)) (i32.const 0))
+(assert_return (invoke "i64.extend_i32_s" (i32.const 0)) (i64.const 0))
+(assert_return (invoke "i64.extend_i32_s" (i32.const 1)) (i64.const 1))
+(assert_return (invoke "i64.extend_i32_s" (i32.const -1)) (i64.const -1))
+(assert_return (invoke "i64.extend_i32_s" (i32.const 2147483647)) (i64.const 2147483647))
+(assert_return (invoke "i64.extend_i32_s" (i32.const -2147483648)) (i64.const -2147483648))
+(assert_return (invoke "i64.extend_i32_s" (i32.const 305419896)) (i64.const 305419896))
+(assert_return (invoke "i64.extend_i32_s" (i32.const 31)) (i64.const 31))
+(assert_return (invoke "i64.extend_i32_s" (i32.const -7)) (i64.const -7))
+(assert_return (invoke "i64.extend_i32_s" (i32.const -2147483647)) (i64.const -2147483647))
+(assert_return (invoke "i64.extend_i32_s" (i32.const 2)) (i64.const 2))
+(assert_return (invoke "i64.extend_i32_s" (i32.const 65535)) (i64.const 65535))
+(assert_return (invoke "i64.extend_i32_u" (i32.const 0)) (i64.const 0))
+(assert_return (invoke "i64.extend_i32_u" (i32.const 1)) (i64.const 1))
+(assert_return (invoke "i64.extend_i32_u" (i32.const -1)) (i64.const 4294967295))
+(assert_return (invoke "i64.extend_i32_u" (i32.const 2147483647)) (i64.const 2147483647))
+(assert_return (invoke "i64.extend_i32_u" (i32.const -2147483648)) (i64.const 2147483648))
+(assert_return (invoke "i64.extend_i32_u" (i32.const 305419896)) (i64.const 305419896))
+(assert_return (invoke "i64.extend_i32_u" (i32.const 31)) (i64.const 31))
+(assert_return (invoke "i64.extend_i32_u" (i32.const -7)) (i64.const 4294967289))
+(assert_return (invoke "i64.extend_i32_u" (i32.const -2147483647)) (i64.const 2147483649))
+(assert_return (invoke "i64.extend_i32_u" (i32.const 2)) (i64.const 2))
+(assert_return (invoke "i64.extend_i32_u" (i32.const 65535)) (i64.const 65535))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const 0x1p+0)) (i64.const 1))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const -0x1.8000000000000p+0)) (i64.const -1))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const -0x1.c000000000000p+1)) (i64.const -3))
+(assert_trap (invoke "i64.trunc_f32_s" (f32.const inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f32_s" (f32.const -inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f32_s" (f32.const nan:0x400000)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f32_s" (f32.const -nan:0x400001)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f32_s" (f32.const 0x1.fffffe0000000p+127)) "integer overflow")
+(assert_return (invoke "i64.trunc_f32_s" (f32.const 0x1p-149)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const 0x1p+31)) (i64.const 2147483648))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const -0x1.0000020000000p+31)) (i64.const -2147483904))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const 0x1.2a05f20000000p+33)) (i64.const 10000000000))
+(assert_return (invoke "i64.trunc_f32_s" (f32.const 0x1.fffffe0000000p-2)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f32_u" (f32.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f32_u" (f32.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f32_u" (f32.const 0x1p+0)) (i64.const 1))
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const -0x1.8000000000000p+0)) "integer overflow")
+(assert_return (invoke "i64.trunc_f32_u" (f32.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const -0x1.c000000000000p+1)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const -inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const nan:0x400000)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const -nan:0x400001)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const 0x1.fffffe0000000p+127)) "integer overflow")
+(assert_return (invoke "i64.trunc_f32_u" (f32.const 0x1p-149)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f32_u" (f32.const 0x1p+31)) (i64.const 2147483648))
+(assert_trap (invoke "i64.trunc_f32_u" (f32.const -0x1.0000020000000p+31)) "integer overflow")
+(assert_return (invoke "i64.trunc_f32_u" (f32.const 0x1.2a05f20000000p+33)) (i64.const 10000000000))
+(assert_return (invoke "i64.trunc_f32_u" (f32.const 0x1.fffffe0000000p-2)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const 0x1p+0)) (i64.const 1))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const -0x1.8000000000000p+0)) (i64.const -1))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const -0x1.c000000000000p+1)) (i64.const -3))
+(assert_trap (invoke "i64.trunc_f64_s" (f64.const inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f64_s" (f64.const -inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f64_s" (f64.const nan:0x8000000000000)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f64_s" (f64.const -nan:0x8000000000001)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f64_s" (f64.const 0x1.fffffffffffffp+1023)) "integer overflow")
+(assert_return (invoke "i64.trunc_f64_s" (f64.const 0x0.0000000000001p-1022)) (i64.const 0))
+(assert_trap (invoke "i64.trunc_f64_s" (f64.const 0x1p+63)) "integer overflow")
+(assert_return (invoke "i64.trunc_f64_s" (f64.const -0x1.0000000200000p+31)) (i64.const -2147483649))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const 0x1.0000000080000p+32)) (i64.const 4294967296))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const 0x1.56e1fc2f8f359p-997)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_s" (f64.const 0x1.fffffffffffffp-2)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_u" (f64.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x1p+0)) (i64.const 1))
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const -0x1.8000000000000p+0)) "integer overflow")
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const -0x1.c000000000000p+1)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const -inf)) "integer overflow")
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const nan:0x8000000000000)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const -nan:0x8000000000001)) "invalid conversion to integer")
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const 0x1.fffffffffffffp+1023)) "integer overflow")
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x0.0000000000001p-1022)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x1p+63)) (i64.const -9223372036854775808))
+(assert_trap (invoke "i64.trunc_f64_u" (f64.const -0x1.0000000200000p+31)) "integer overflow")
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x1.0000000080000p+32)) (i64.const 4294967296))
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x1.56e1fc2f8f359p-997)) (i64.const 0))
+(assert_return (invoke "i64.trunc_f64_u" (f64.const 0x1.fffffffffffffp-2)) (i64.const 0))
+(assert_return (invoke "f32.convert_i32_s" (i32.const 0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.convert_i32_s" (i32.const 1)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.convert_i32_s" (i32.const -1)) (f32.const -0x1p+0))
+(assert_return (invoke "f32.convert_i32_s" (i32.const 2147483647)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.convert_i32_s" (i32.const -2147483648)) (f32.const -0x1p+31))
+(assert_return (invoke "f32.convert_i32_s" (i32.const 305419896)) (f32.const 0x1.2345680000000p+28))
+(assert_return (invoke "f32.convert_i32_s" (i32.const 31)) (f32.const 0x1.f000000000000p+4))
+(assert_return (invoke "f32.convert_i32_s" (i32.const -7)) (f32.const -0x1.c000000000000p+2))
+(assert_return (invoke "f32.convert_i32_s" (i32.const -2147483647)) (f32.const -0x1p+31))
+(assert_return (invoke "f32.convert_i32_s" (i32.const 2)) (f32.const 0x1p+1))
+(assert_return (invoke "f32.convert_i32_s" (i32.const 65535)) (f32.const 0x1.fffe000000000p+15))
+(assert_return (invoke "f32.convert_i32_u" (i32.const 0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.convert_i32_u" (i32.const 1)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.convert_i32_u" (i32.const -1)) (f32.const 0x1p+32))
+(assert_return (invoke "f32.convert_i32_u" (i32.const 2147483647)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.convert_i32_u" (i32.const -2147483648)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.convert_i32_u" (i32.const 305419896)) (f32.const 0x1.2345680000000p+28))
+(assert_return (invoke "f32.convert_i32_u" (i32.const 31)) (f32.const 0x1.f000000000000p+4))
+(assert_return (invoke "f32.convert_i32_u" (i32.const -7)) (f32.const 0x1p+32))
+(assert_return (invoke "f32.convert_i32_u" (i32.const -2147483647)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.convert_i32_u" (i32.const 2)) (f32.const 0x1p+1))
+(assert_return (invoke "f32.convert_i32_u" (i32.const 65535)) (f32.const 0x1.fffe000000000p+15))
+(assert_return (invoke "f32.convert_i64_s" (i64.const 0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.convert_i64_s" (i64.const 1)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.convert_i64_s" (i64.const -1)) (f32.const -0x1p+0))
+(assert_return (invoke "f32.convert_i64_s" (i64.const 9223372036854775807)) (f32.const 0x1p+63))
+(assert_return (invoke "f32.convert_i64_s" (i64.const -9223372036854775808)) (f32.const -0x1p+63))
+(assert_return (invoke "f32.convert_i64_s" (i64.const 81985529216486895)) (f32.const 0x1.2345680000000p+56))
+(assert_return (invoke "f32.convert_i64_s" (i64.const 63)) (f32.const 0x1.f800000000000p+5))
+(assert_return (invoke "f32.convert_i64_s" (i64.const -7)) (f32.const -0x1.c000000000000p+2))
+(assert_return (invoke "f32.convert_i64_s" (i64.const 2147483648)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.convert_i64_s" (i64.const 4294967295)) (f32.const 0x1p+32))
+(assert_return (invoke "f32.convert_i64_u" (i64.const 0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.convert_i64_u" (i64.const 1)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.convert_i64_u" (i64.const -1)) (f32.const 0x1p+64))
+(assert_return (invoke "f32.convert_i64_u" (i64.const 9223372036854775807)) (f32.const 0x1p+63))
+(assert_return (invoke "f32.convert_i64_u" (i64.const -9223372036854775808)) (f32.const 0x1p+63))
+(assert_return (invoke "f32.convert_i64_u" (i64.const 81985529216486895)) (f32.const 0x1.2345680000000p+56))
+(assert_return (invoke "f32.convert_i64_u" (i64.const 63)) (f32.const 0x1.f800000000000p+5))
+(assert_return (invoke "f32.convert_i64_u" (i64.const -7)) (f32.const 0x1p+64))
+(assert_return (invoke "f32.convert_i64_u" (i64.const 2147483648)) (f32.const 0x1p+31))
+(assert_return (invoke "f32.convert_i64_u" (i64.const 4294967295)) (f32.const 0x1p+32))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x0.0p+0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.demote_f64" (f64.const -0x0.0p+0)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x1p+0)) (f32.const 0x1p+0))
+(assert_return (invoke "f32.demote_f64" (f64.const -0x1.8000000000000p+0)) (f32.const -0x1.8000000000000p+0))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x1.4000000000000p+1)) (f32.const 0x1.4000000000000p+1))
+(assert_return (invoke "f32.demote_f64" (f64.const -0x1.c000000000000p+1)) (f32.const -0x1.c000000000000p+1))
+(assert_return (invoke "f32.demote_f64" (f64.const inf)) (f32.const inf))
+(assert_return (invoke "f32.demote_f64" (f64.const -inf)) (f32.const -inf))
+(assert_return (invoke "f32.demote_f64" (f64.const nan:0x8000000000000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.demote_f64" (f64.const -nan:0x8000000000001)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x1.fffffffffffffp+1023)) (f32.const inf))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x0.0000000000001p-1022)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x1p+63)) (f32.const 0x1p+63))
+(assert_return (invoke "f32.demote_f64" (f64.const -0x1.0000000200000p+31)) (f32.const -0x1p+31))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x1.0000000080000p+32)) (f32.const 0x1p+32))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x1.56e1fc2f8f359p-997)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.demote_f64" (f64.const 0x1.fffffffffffffp-2)) (f32.const 0x1p-1))
+(assert_return (invoke "f64.convert_i32_s" (i32.const 0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.convert_i32_s" (i32.const 1)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.convert_i32_s" (i32.const -1)) (f64.const -0x1p+0))
+(assert_return (invoke "f64.convert_i32_s" (i32.const 2147483647)) (f64.const 0x1.fffffffc00000p+30))
+(assert_return (invoke "f64.convert_i32_s" (i32.const -2147483648)) (f64.const -0x1p+31))
+(assert_return (invoke "f64.convert_i32_s" (i32.const 305419896)) (f64.const 0x1.2345678000000p+28))
+(assert_return (invoke "f64.convert_i32_s" (i32.const 31)) (f64.const 0x1.f000000000000p+4))
+(assert_return (invoke "f64.convert_i32_s" (i32.const -7)) (f64.const -0x1.c000000000000p+2))
+(assert_return (invoke "f64.convert_i32_s" (i32.const -2147483647)) (f64.const -0x1.fffffffc00000p+30))
+(assert_return (invoke "f64.convert_i32_s" (i32.const 2)) (f64.const 0x1p+1))
+(assert_return (invoke "f64.convert_i32_s" (i32.const 65535)) (f64.const 0x1.fffe000000000p+15))
+(assert_return (invoke "f64.convert_i32_u" (i32.const 0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.convert_i32_u" (i32.const 1)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.convert_i32_u" (i32.const -1)) (f64.const 0x1.fffffffe00000p+31))
+(assert_return (invoke "f64.convert_i32_u" (i32.const 2147483647)) (f64.const 0x1.fffffffc00000p+30))
+(assert_return (invoke "f64.convert_i32_u" (i32.const -2147483648)) (f64.const 0x1p+31))
+(assert_return (invoke "f64.convert_i32_u" (i32.const 305419896)) (f64.const 0x1.2345678000000p+28))
+(assert_return (invoke "f64.convert_i32_u" (i32.const 31)) (f64.const 0x1.f000000000000p+4))
+(assert_return (invoke "f64.convert_i32_u" (i32.const -7)) (f64.const 0x1.fffffff200000p+31))
+(assert_return (invoke "f64.convert_i32_u" (i32.const -2147483647)) (f64.const 0x1.0000000200000p+31))
+(assert_return (invoke "f64.convert_i32_u" (i32.const 2)) (f64.const 0x1p+1))
+(assert_return (invoke "f64.convert_i32_u" (i32.const 65535)) (f64.const 0x1.fffe000000000p+15))
+(assert_return (invoke "f64.convert_i64_s" (i64.const 0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.convert_i64_s" (i64.const 1)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.convert_i64_s" (i64.const -1)) (f64.const -0x1p+0))
+(assert_return (invoke "f64.convert_i64_s" (i64.const 9223372036854775807)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.convert_i64_s" (i64.const -9223372036854775808)) (f64.const -0x1p+63))
+(assert_return (invoke "f64.convert_i64_s" (i64.const 81985529216486895)) (f64.const 0x1.23456789abcdfp+56))
+(assert_return (invoke "f64.convert_i64_s" (i64.const 63)) (f64.const 0x1.f800000000000p+5))
+(assert_return (invoke "f64.convert_i64_s" (i64.const -7)) (f64.const -0x1.c000000000000p+2))
+(assert_return (invoke "f64.convert_i64_s" (i64.const 2147483648)) (f64.const 0x1p+31))
+(assert_return (invoke "f64.convert_i64_s" (i64.const 4294967295)) (f64.const 0x1.fffffffe00000p+31))
+(assert_return (invoke "f64.convert_i64_u" (i64.const 0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.convert_i64_u" (i64.const 1)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.convert_i64_u" (i64.const -1)) (f64.const 0x1p+64))
+(assert_return (invoke "f64.convert_i64_u" (i64.const 9223372036854775807)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.convert_i64_u" (i64.const -9223372036854775808)) (f64.const 0x1p+63))
+(assert_return (invoke "f64.convert_i64_u" (i64.const 81985529216486895)) (f64.const 0x1.23456789abcdfp+56))
+(assert_return (invoke "f64.convert_i64_u" (i64.const 63)) (f64.const 0x1.f800000000000p+5))
+(assert_return (invoke "f64.convert_i64_u" (i64.const -7)) (f64.const 0x1p+64))
+(assert_return (invoke "f64.convert_i64_u" (i64.const 2147483648)) (f64.const 0x1p+31))
+(assert_return (invoke "f64.convert_i64_u" (i64.const 4294967295)) (f64.const 0x1.fffffffe00000p+31))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x0.0p+0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.promote_f32" (f32.const -0x0.0p+0)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x1p+0)) (f64.const 0x1p+0))
+(assert_return (invoke "f64.promote_f32" (f32.const -0x1.8000000000000p+0)) (f64.const -0x1.8000000000000p+0))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x1.4000000000000p+1)) (f64.const 0x1.4000000000000p+1))
+(assert_return (invoke "f64.promote_f32" (f32.const -0x1.c000000000000p+1)) (f64.const -0x1.c000000000000p+1))
+(assert_return (invoke "f64.promote_f32" (f32.const inf)) (f64.const inf))
+(assert_return (invoke "f64.promote_f32" (f32.const -inf)) (f64.const -inf))
+(assert_return (invoke "f64.promote_f32" (f32.const nan:0x400000)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.promote_f32" (f32.const -nan:0x400001)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x1.fffffe0000000p+127)) (f64.const 0x1.fffffe0000000p+127))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x1p-149)) (f64.const 0x1p-149))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x1p+31)) (f64.const 0x1p+31))
+(assert_return (invoke "f64.promote_f32" (f32.const -0x1.0000020000000p+31)) (f64.const -0x1.0000020000000p+31))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x1.2a05f20000000p+33)) (f64.const 0x1.2a05f20000000p+33))
+(assert_return (invoke "f64.promote_f32" (f32.const 0x1.fffffe0000000p-2)) (f64.const 0x1.fffffe0000000p-2))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const -0x0.0p+0)) (i32.const -2147483648))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x1p+0)) (i32.const 1065353216))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const -0x1.8000000000000p+0)) (i32.const -1077936128))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x1.4000000000000p+1)) (i32.const 1075838976))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const -0x1.c000000000000p+1)) (i32.const -1067450368))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const inf)) (i32.const 2139095040))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const -inf)) (i32.const -8388608))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const nan:0x400000)) (i32.const 2143289344))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const -nan:0x400001)) (i32.const -4194303))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x1.fffffe0000000p+127)) (i32.const 2139095039))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x1p-149)) (i32.const 1))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x1p+31)) (i32.const 1325400064))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const -0x1.0000020000000p+31)) (i32.const -822083583))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x1.2a05f20000000p+33)) (i32.const 1343554297))
+(assert_return (invoke "i32.reinterpret_f32" (f32.const 0x1.fffffe0000000p-2)) (i32.const 1056964607))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const -0x0.0p+0)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x1p+0)) (i64.const 4607182418800017408))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const -0x1.8000000000000p+0)) (i64.const -4613937818241073152))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x1.4000000000000p+1)) (i64.const 4612811918334230528))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const -0x1.c000000000000p+1)) (i64.const -4608308318706860032))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const inf)) (i64.const 9218868437227405312))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const -inf)) (i64.const -4503599627370496))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const nan:0x8000000000000)) (i64.const 9221120237041090560))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const -nan:0x8000000000001)) (i64.const -2251799813685247))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x1.fffffffffffffp+1023)) (i64.const 9218868437227405311))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x0.0000000000001p-1022)) (i64.const 1))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x1p+63)) (i64.const 4890909195324358656))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const -0x1.0000000200000p+31)) (i64.const -4476578029604175872))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x1.0000000080000p+32)) (i64.const 4751297606876397568))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x1.56e1fc2f8f359p-997)) (i64.const 118622047889322841))
+(assert_return (invoke "i64.reinterpret_f64" (f64.const 0x1.fffffffffffffp-2)) (i64.const 4602678819172646911))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const 0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const 1)) (f32.const 0x1p-149))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const -1)) (f32.const -nan:0x7fffff))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const 2147483647)) (f32.const nan:0x7fffff))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const -2147483648)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const 305419896)) (f32.const 0x1.68acf00000000p-91))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const 31)) (f32.const 0x1.f000000000000p-145))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const -7)) (f32.const -nan:0x7ffff9))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const -2147483647)) (f32.const -0x1p-149))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const 2)) (f32.const 0x1p-148))
+(assert_return (invoke "f32.reinterpret_i32" (i32.const 65535)) (f32.const 0x1.fffe000000000p-134))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const 0)) (f64.const 0x0.0p+0))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const 1)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const -1)) (f64.const -nan:0xfffffffffffff))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const 9223372036854775807)) (f64.const nan:0xfffffffffffff))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const -9223372036854775808)) (f64.const -0x0.0p+0))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const 81985529216486895)) (f64.const 0x1.3456789abcdefp-1005))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const 63)) (f64.const 0x0.000000000003fp-1022))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const -7)) (f64.const -nan:0xffffffffffff9))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const 2147483648)) (f64.const 0x0.0000080000000p-1022))
+(assert_return (invoke "f64.reinterpret_i64" (i64.const 4294967295)) (f64.const 0x0.00000ffffffffp-1022))
+(assert_return (invoke "i32.extend8_s" (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.extend8_s" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.extend8_s" (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.extend8_s" (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.extend8_s" (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.extend8_s" (i32.const 305419896)) (i32.const 120))
+(assert_return (invoke "i32.extend8_s" (i32.const 31)) (i32.const 31))
+(assert_return (invoke "i32.extend8_s" (i32.const -7)) (i32.const -7))
+(assert_return (invoke "i32.extend8_s" (i32.const -2147483647)) (i32.const 1))
+(assert_return (invoke "i32.extend8_s" (i32.const 2)) (i32.const 2))
+(assert_return (invoke "i32.extend8_s" (i32.const 65535)) (i32.const -1))
+(assert_return (invoke "i32.extend16_s" (i32.const 0)) (i32.const 0))
+(assert_return (invoke "i32.extend16_s" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "i32.extend16_s" (i32.const -1)) (i32.const -1))
+(assert_return (invoke "i32.extend16_s" (i32.const 2147483647)) (i32.const -1))
+(assert_return (invoke "i32.extend16_s" (i32.const -2147483648)) (i32.const 0))
+(assert_return (invoke "i32.extend16_s" (i32.const 305419896)) (i32.const 22136))
+(assert_return (invoke "i32.extend16_s" (i32.const 31)) (i32.const 31))
+(assert_return (invoke "i32.extend16_s" (i32.const -7)) (i32.const -7))
+(assert_return (invoke "i32.extend16_s" (i32.const -2147483647)) (i32.const 1))
+(assert_return (invoke "i32.extend16_s" (i32.const 2)) (i32.const 2))
+(assert_return (invoke "i32.extend16_s" (i32.const 65535)) (i32.const -1))
+(assert_return (invoke "i64.extend8_s" (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.extend8_s" (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.extend8_s" (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.extend8_s" (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.extend8_s" (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.extend8_s" (i64.const 81985529216486895)) (i64.const -17))
+(assert_return (invoke "i64.extend8_s" (i64.const 63)) (i64.const 63))
+(assert_return (invoke "i64.extend8_s" (i64.const -7)) (i64.const -7))
+(assert_return (invoke "i64.extend8_s" (i64.const 2147483648)) (i64.const 0))
+(assert_return (invoke "i64.extend8_s" (i64.const 4294967295)) (i64.const -1))
+(assert_return (invoke "i64.extend16_s" (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.extend16_s" (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.extend16_s" (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.extend16_s" (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.extend16_s" (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.extend16_s" (i64.const 81985529216486895)) (i64.const -12817))
+(assert_return (invoke "i64.extend16_s" (i64.const 63)) (i64.const 63))
+(assert_return (invoke "i64.extend16_s" (i64.const -7)) (i64.const -7))
+(assert_return (invoke "i64.extend16_s" (i64.const 2147483648)) (i64.const 0))
+(assert_return (invoke "i64.extend16_s" (i64.const 4294967295)) (i64.const -1))
+(assert_return (invoke "i64.extend32_s" (i64.const 0)) (i64.const 0))
+(assert_return (invoke "i64.extend32_s" (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i64.extend32_s" (i64.const -1)) (i64.const -1))
+(assert_return (invoke "i64.extend32_s" (i64.const 9223372036854775807)) (i64.const -1))
+(assert_return (invoke "i64.extend32_s" (i64.const -9223372036854775808)) (i64.const 0))
+(assert_return (invoke "i64.extend32_s" (i64.const 81985529216486895)) (i64.const -1985229329))
+(assert_return (invoke "i64.extend32_s" (i64.const 63)) (i64.const 63))
+(assert_return (invoke "i64.extend32_s" (i64.const -7)) (i64.const -7))
+(assert_return (invoke "i64.extend32_s" (i64.const 2147483648)) (i64.const -2147483648))
+(assert_return (invoke "i64.extend32_s" (i64.const 4294967295)) (i64.const -1))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x1p+0)) (i32.const 1))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const -0x1.8000000000000p+0)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const -0x1.c000000000000p+1)) (i32.const -3))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const inf)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const -inf)) (i32.const -2147483648))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const -nan:0x400001)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x1.fffffe0000000p+127)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x1p+31)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const -0x1.0000020000000p+31)) (i32.const -2147483648))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x1.2a05f20000000p+33)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f32_s" (f32.const 0x1.fffffe0000000p-2)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x1p+0)) (i32.const 1))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const -0x1.c000000000000p+1)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const inf)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const -inf)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const nan:0x400000)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const -nan:0x400001)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x1.fffffe0000000p+127)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x1p-149)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x1p+31)) (i32.const -2147483648))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const -0x1.0000020000000p+31)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x1.2a05f20000000p+33)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f32_u" (f32.const 0x1.fffffe0000000p-2)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x1p+0)) (i32.const 1))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const -0x1.8000000000000p+0)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const -0x1.c000000000000p+1)) (i32.const -3))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const inf)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const -inf)) (i32.const -2147483648))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const -nan:0x8000000000001)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x1.fffffffffffffp+1023)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x1p+63)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const -0x1.0000000200000p+31)) (i32.const -2147483648))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x1.0000000080000p+32)) (i32.const 2147483647))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x1.56e1fc2f8f359p-997)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_s" (f64.const 0x1.fffffffffffffp-2)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const -0x0.0p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x1p+0)) (i32.const 1))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const -0x1.8000000000000p+0)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x1.4000000000000p+1)) (i32.const 2))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const -0x1.c000000000000p+1)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const inf)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const -inf)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const nan:0x8000000000000)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const -nan:0x8000000000001)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x1.fffffffffffffp+1023)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x0.0000000000001p-1022)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x1p+63)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const -0x1.0000000200000p+31)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x1.0000000080000p+32)) (i32.const -1))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x1.56e1fc2f8f359p-997)) (i32.const 0))
+(assert_return (invoke "i32.trunc_sat_f64_u" (f64.const 0x1.fffffffffffffp-2)) (i32.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x1p+0)) (i64.const 1))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const -0x1.8000000000000p+0)) (i64.const -1))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const -0x1.c000000000000p+1)) (i64.const -3))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const inf)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const -inf)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const nan:0x400000)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const -nan:0x400001)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x1.fffffe0000000p+127)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x1p-149)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x1p+31)) (i64.const 2147483648))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const -0x1.0000020000000p+31)) (i64.const -2147483904))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x1.2a05f20000000p+33)) (i64.const 10000000000))
+(assert_return (invoke "i64.trunc_sat_f32_s" (f32.const 0x1.fffffe0000000p-2)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x1p+0)) (i64.const 1))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const -0x1.8000000000000p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const -0x1.c000000000000p+1)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const inf)) (i64.const -1))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const -inf)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const nan:0x400000)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const -nan:0x400001)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x1.fffffe0000000p+127)) (i64.const -1))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x1p-149)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x1p+31)) (i64.const 2147483648))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const -0x1.0000020000000p+31)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x1.2a05f20000000p+33)) (i64.const 10000000000))
+(assert_return (invoke "i64.trunc_sat_f32_u" (f32.const 0x1.fffffe0000000p-2)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x1p+0)) (i64.const 1))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const -0x1.8000000000000p+0)) (i64.const -1))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const -0x1.c000000000000p+1)) (i64.const -3))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const inf)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const -inf)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const nan:0x8000000000000)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const -nan:0x8000000000001)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x1.fffffffffffffp+1023)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x0.0000000000001p-1022)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x1p+63)) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const -0x1.0000000200000p+31)) (i64.const -2147483649))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x1.0000000080000p+32)) (i64.const 4294967296))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x1.56e1fc2f8f359p-997)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_s" (f64.const 0x1.fffffffffffffp-2)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const -0x0.0p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x1p+0)) (i64.const 1))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const -0x1.8000000000000p+0)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x1.4000000000000p+1)) (i64.const 2))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const -0x1.c000000000000p+1)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const inf)) (i64.const -1))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const -inf)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const nan:0x8000000000000)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const -nan:0x8000000000001)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x1.fffffffffffffp+1023)) (i64.const -1))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x0.0000000000001p-1022)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x1p+63)) (i64.const -9223372036854775808))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const -0x1.0000000200000p+31)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x1.0000000080000p+32)) (i64.const 4294967296))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x1.56e1fc2f8f359p-997)) (i64.const 0))
+(assert_return (invoke "i64.trunc_sat_f64_u" (f64.const 0x1.fffffffffffffp-2)) (i64.const 0))
@@ -0,0 +1,2944 @@
+;; Every SIMD instruction without a memory operand, applied to a few vectors
+;; of interesting lanes. The module is in binary form, with one exported
+;; function per instruction and lane index, and the expected results come
+;; from running the same instructions in V8.
+
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01S\0f`\02{{\01{`\01\7f\01{`\01~\01{`\01}\01{`\01|\01{`\01{\01{`\03{{{\01{`\01{\01\7f`\02{\7f\01{`\01{\01~`\02{~\01{`\01{\01}`\02{}\01{`\01{\01|`\02{|\01{"
+  "\03\e6\01\e4\01\00\01\01\01\02\03\04\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\05\00\00\00\00\06\07\05\05\05\07\07\00\00\08\08\08\00\00\00\00\00\00\00\00\00\00\00\05\05\05\05\00\07\07\00\00\05\05\05\05\08\08\08\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\05\05\05\05\07\07\05\05\05\05\08\08\08\00\00\00\00\00\00\00\00\00\00\00\00\05\05\07\07\05\05\05\05\08\08\08\00\00\00\00\00\00\00\05\05\05\05\05\05\05\00\00\00\00\00\00\00\00\05\05\05\05\05\05\05\00\00\00\00\00\00\00\00\05\05\05\05\05\05\05\05\05\05\07\07\07\07\08\08\07\07\07\07\08\08\07\07\08\08\09\09\0a\0a\0b\0b\0c\0c\0d\0d\0e\0e\00\00"
+  "\07\be\1f\e4\01\0di8x16.swizzle\00\00\0bi8x16.splat\00\01\0bi16x8.splat\00\02\0bi32x4.splat\00\03\0bi64x2.splat\00\04\0bf32x4.splat\00\05\0bf64x2.splat\00\06\08i8x16.eq\00\07\08i8x16.ne\00\08\0ai8x16.lt_s\00\09\0ai8x16.lt_u\00\0a\0ai8x16.gt_s\00\0b\0ai8x16.gt_u\00\0c\0ai8x16.le_s\00\0d\0ai8x16.le_u\00\0e\0ai8x16.ge_s\00\0f\0ai8x16.ge_u\00\10\08i16x8.eq\00\11\08i16x8.ne\00\12\0ai16x8.lt_s\00\13\0ai16x8.lt_u\00\14\0ai16x8.gt_s\00\15\0ai16x8.gt_u\00\16\0ai16x8.le_s\00\17\0ai16x8.le_u\00\18\0ai16x8.ge_s\00\19\0ai16x8.ge_u\00\1a\08i32x4.eq\00\1b\08i32x4.ne\00\1c\0ai32x4.lt_s\00\1d\0ai32x4.lt_u\00\1e\0ai32x4.gt_s\00\1f\0ai32x4.gt_u\00 \0ai32x4.le_s\00!\0ai32x4.le_u\00\22\0ai32x4.ge_s\00#\0ai32x4.ge_u\00$\08i64x2.eq\00%\08i64x2.ne\00&\0ai64x2.lt_s\00'\0ai64x2.gt_s\00(\0ai64x2.le_s\00)\0ai64x2.ge_s\00*\08f32x4.eq\00+\08f32x4.ne\00,\08f32x4.lt\00-\08f32x4.gt\00.\08f32x4.le\00/\08f32x4.ge\000\08f64x2.eq\001\08f64x2.ne\002\08f64x2.lt\003\08f64x2.gt\004\08f64x2.le\005\08f64x2.ge\006\08v128.not\007\08v128.and\008\0cv128.and_not\009\07v128.or\00:\08v128.xor\00;\0ev128.bitselect\00<\0dv128.any_true\00=\09i8x16.abs\00>\09i8x16.neg\00?\0ci8x16.popcnt\00@\0ei8x16.all_true\00A\0di8x16.bitmask\00B\14i8x16.narrow_i16x8_s\00C\14i8x16.narrow_i16x8_u\00D\09i8x16.shl\00E\0bi8x16.shr_s\00F\0bi8x16.shr_u\00G\09i8x16.add\00H\0fi8x16.add_sat_s\00I\0fi8x16.add_sat_u\00J\09i8x16.sub\00K\0fi8x16.sub_sat_s\00L\0fi8x16.sub_sat_u\00M\0bi8x16.min_s\00N\0bi8x16.min_u\00O\0bi8x16.max_s\00P\0bi8x16.max_u\00Q\0ci8x16.avgr_u\00R\1di16x8.extadd_pairwise_i8x16_s\00S\1di16x8.extadd_pairwise_i8x16_u\00T\09i16x8.abs\00U\09i16x8.neg\00V\14i16x8.q15_mulr_sat_s\00W\0ei16x8.all_true\00X\0di16x8.bitmask\00Y\14i16x8.narrow_i32x4_s\00Z\14i16x8.narrow_i32x4_u\00[\18i16x8.extend_low_i8x16_s\00\5c\19i16x8.extend_high_i8x16_s\00]\18i16x8.extend_low_i8x16_u\00^\19i16x8.extend_high_i8x16_u\00_\09i16x8.shl\00`\0bi16x8.shr_s\00a\0bi16x8.shr_u\00b\09i16x8.add\00c\0fi16x8.add_sat_s\00d\0fi16x8.add_sat_u\00e\09i16x8.sub\00f\0fi16x8.sub_sat_s\00g\0fi16x8.sub_sat_u\00h\09i16x8.mul\00i\0bi16x8.min_s\00j\0bi16x8.min_u\00k\0bi16x8.max_s\00l\0bi16x8.max_u\00m\0ci16x8.avgr_u\00n\18i16x8.extmul_low_i8x16_s\00o\19i16x8.extmul_high_i8x16_s\00p\18i16x8.extmul_low_i8x16_u\00q\19i16x8.extmul_high_i8x16_u\00r\1di32x4.extadd_pairwise_i16x8_s\00s\1di32x4.extadd_pairwise_i16x8_u\00t\09i32x4.abs\00u\09i32x4.neg\00v\0ei32x4.all_true\00w\0di32x4.bitmask\00x\18i32x4.extend_low_i16x8_s\00y\19i32x4.extend_high_i16x8_s\00z\18i32x4.extend_low_i16x8_u\00{\19i32x4.extend_high_i16x8_u\00|\09i32x4.shl\00}\0bi32x4.shr_s\00~\0bi32x4.shr_u\00\7f\09i32x4.add\00\80\01\09i32x4.sub\00\81\01\09i32x4.mul\00\82\01\0bi32x4.min_s\00\83\01\0bi32x4.min_u\00\84\01\0bi32x4.max_s\00\85\01\0bi32x4.max_u\00\86\01\11i32x4.dot_i16x8_s\00\87\01\18i32x4.extmul_low_i16x8_s\00\88\01\19i32x4.extmul_high_i16x8_s\00\89\01\18i32x4.extmul_low_i16x8_u\00\8a\01\19i32x4.extmul_high_i16x8_u\00\8b\01\09i64x2.abs\00\8c\01\09i64x2.neg\00\8d\01\0ei64x2.all_true\00\8e\01\0di64x2.bitmask\00\8f\01\18i64x2.extend_low_i32x4_s\00\90\01\19i64x2.extend_high_i32x4_s\00\91\01\18i64x2.extend_low_i32x4_u\00\92\01\19i64x2.extend_high_i32x4_u\00\93\01\09i64x2.shl\00\94\01\0bi64x2.shr_s\00\95\01\0bi64x2.shr_u\00\96\01\09i64x2.add\00\97\01\09i64x2.sub\00\98\01\09i64x2.mul\00\99\01\18i64x2.extmul_low_i32x4_s\00\9a\01\19i64x2.extmul_high_i32x4_s\00\9b\01\18i64x2.extmul_low_i32x4_u\00\9c\01\19i64x2.extmul_high_i32x4_u\00\9d\01\0af32x4.ceil\00\9e\01\0bf32x4.floor\00\9f\01\0bf32x4.trunc\00\a0\01\0df32x4.nearest\00\a1\01\09f32x4.abs\00\a2\01\09f32x4.neg\00\a3\01\0af32x4.sqrt\00\a4\01\09f32x4.add\00\a5\01\09f32x4.sub\00\a6\01\09f32x4.mul\00\a7\01\09f32x4.div\00\a8\01\09f32x4.min\00\a9\01\09f32x4.max\00\aa\01\0af32x4.pmin\00\ab\01\0af32x4.pmax\00\ac\01\0af64x2.ceil\00\ad\01\0bf64x2.floor\00\ae\01\0bf64x2.trunc\00\af\01\0df64x2.nearest\00\b0\01\09f64x2.abs\00\b1\01\09f64x2.neg\00\b2\01\0af64x2.sqrt\00\b3\01\09f64x2.add\00\b4\01\09f64x2.sub\00\b5\01\09f64x2.mul\00\b6\01\09f64x2.div\00\b7\01\09f64x2.min\00\b8\01\09f64x2.max\00\b9\01\0af64x2.pmin\00\ba\01\0af64x2.pmax\00\bb\01\17i32x4.trunc_sat_f32x4_s\00\bc\01\17i32x4.trunc_sat_f32x4_u\00\bd\01\15f32x4.convert_i32x4_s\00\be\01\15f32x4.convert_i32x4_u\00\bf\01\1ci32x4.trunc_sat_f64x2_s_zero\00\c0\01\1ci32x4.trunc_sat_f64x2_u_zero\00\c1\01\19f64x2.convert_low_i32x4_s\00\c2\01\19f64x2.convert_low_i32x4_u\00\c3\01\17f32x4.demote_f64x2_zero\00\c4\01\17f64x2.promote_low_f32x4\00\c5\01\16i8x16.extract_lane_s 0\00\c6\01\17i8x16.extract_lane_s 15\00\c7\01\16i8x16.extract_lane_u 0\00\c8\01\17i8x16.extract_lane_u 15\00\c9\01\14i8x16.replace_lane 0\00\ca\01\15i8x16.replace_lane 15\00\cb\01\16i16x8.extract_lane_s 0\00\cc\01\16i16x8.extract_lane_s 7\00\cd\01\16i16x8.extract_lane_u 0\00\ce\01\16i16x8.extract_lane_u 7\00\cf\01\14i16x8.replace_lane 0\00\d0\01\14i16x8.replace_lane 7\00\d1\01\14i32x4.extract_lane 0\00\d2\01\14i32x4.extract_lane 3\00\d3\01\14i32x4.replace_lane 0\00\d4\01\14i32x4.replace_lane 3\00\d5\01\14i64x2.extract_lane 0\00\d6\01\14i64x2.extract_lane 1\00\d7\01\14i64x2.replace_lane 0\00\d8\01\14i64x2.replace_lane 1\00\d9\01\14f32x4.extract_lane 0\00\da\01\14f32x4.extract_lane 3\00\db\01\14f32x4.replace_lane 0\00\dc\01\14f32x4.replace_lane 3\00\dd\01\14f64x2.extract_lane 0\00\de\01\14f64x2.extract_lane 1\00\df\01\14f64x2.replace_lane 0\00\e0\01\14f64x2.replace_lane 1\00\e1\01\0fi8x16.shuffle 0\00\e2\01\0fi8x16.shuffle 1\00\e3\01"
+  "\0a\8e\10\e4\01\08\00 \00 \01\fd\0e\0b\06\00 \00\fd\0f\0b\06\00 \00\fd\10\0b\06\00 \00\fd\11\0b\06\00 \00\fd\12\0b\06\00 \00\fd\13\0b\06\00 \00\fd\14\0b\08\00 \00 \01\fd#\0b\08\00 \00 \01\fd$\0b\08\00 \00 \01\fd%\0b\08\00 \00 \01\fd&\0b\08\00 \00 \01\fd'\0b\08\00 \00 \01\fd(\0b\08\00 \00 \01\fd)\0b\08\00 \00 \01\fd*\0b\08\00 \00 \01\fd+\0b\08\00 \00 \01\fd,\0b\08\00 \00 \01\fd-\0b\08\00 \00 \01\fd.\0b\08\00 \00 \01\fd/\0b\08\00 \00 \01\fd0\0b\08\00 \00 \01\fd1\0b\08\00 \00 \01\fd2\0b\08\00 \00 \01\fd3\0b\08\00 \00 \01\fd4\0b\08\00 \00 \01\fd5\0b\08\00 \00 \01\fd6\0b\08\00 \00 \01\fd7\0b\08\00 \00 \01\fd8\0b\08\00 \00 \01\fd9\0b\08\00 \00 \01\fd:\0b\08\00 \00 \01\fd;\0b\08\00 \00 \01\fd<\0b\08\00 \00 \01\fd=\0b\08\00 \00 \01\fd>\0b\08\00 \00 \01\fd?\0b\08\00 \00 \01\fd@\0b\09\00 \00 \01\fd\d6\01\0b\09\00 \00 \01\fd\d7\01\0b\09\00 \00 \01\fd\d8\01\0b\09\00 \00 \01\fd\d9\01\0b\09\00 \00 \01\fd\da\01\0b\09\00 \00 \01\fd\db\01\0b\08\00 \00 \01\fdA\0b\08\00 \00 \01\fdB\0b\08\00 \00 \01\fdC\0b\08\00 \00 \01\fdD\0b\08\00 \00 \01\fdE\0b\08\00 \00 \01\fdF\0b\08\00 \00 \01\fdG\0b\08\00 \00 \01\fdH\0b\08\00 \00 \01\fdI\0b\08\00 \00 \01\fdJ\0b\08\00 \00 \01\fdK\0b\08\00 \00 \01\fdL\0b\06\00 \00\fdM\0b\08\00 \00 \01\fdN\0b\08\00 \00 \01\fdO\0b\08\00 \00 \01\fdP\0b\08\00 \00 \01\fdQ\0b\0a\00 \00 \01 \02\fdR\0b\06\00 \00\fdS\0b\06\00 \00\fd`\0b\06\00 \00\fda\0b\06\00 \00\fdb\0b\06\00 \00\fdc\0b\06\00 \00\fdd\0b\08\00 \00 \01\fde\0b\08\00 \00 \01\fdf\0b\08\00 \00 \01\fdk\0b\08\00 \00 \01\fdl\0b\08\00 \00 \01\fdm\0b\08\00 \00 \01\fdn\0b\08\00 \00 \01\fdo\0b\08\00 \00 \01\fdp\0b\08\00 \00 \01\fdq\0b\08\00 \00 \01\fdr\0b\08\00 \00 \01\fds\0b\08\00 \00 \01\fdv\0b\08\00 \00 \01\fdw\0b\08\00 \00 \01\fdx\0b\08\00 \00 \01\fdy\0b\08\00 \00 \01\fd{\0b\06\00 \00\fd|\0b\06\00 \00\fd}\0b\07\00 \00\fd\80\01\0b\07\00 \00\fd\81\01\0b\09\00 \00 \01\fd\82\01\0b\07\00 \00\fd\83\01\0b\07\00 \00\fd\84\01\0b\09\00 \00 \01\fd\85\01\0b\09\00 \00 \01\fd\86\01\0b\07\00 \00\fd\87\01\0b\07\00 \00\fd\88\01\0b\07\00 \00\fd\89\01\0b\07\00 \00\fd\8a\01\0b\09\00 \00 \01\fd\8b\01\0b\09\00 \00 \01\fd\8c\01\0b\09\00 \00 \01\fd\8d\01\0b\09\00 \00 \01\fd\8e\01\0b\09\00 \00 \01\fd\8f\01\0b\09\00 \00 \01\fd\90\01\0b\09\00 \00 \01\fd\91\01\0b\09\00 \00 \01\fd\92\01\0b\09\00 \00 \01\fd\93\01\0b\09\00 \00 \01\fd\95\01\0b\09\00 \00 \01\fd\96\01\0b\09\00 \00 \01\fd\97\01\0b\09\00 \00 \01\fd\98\01\0b\09\00 \00 \01\fd\99\01\0b\09\00 \00 \01\fd\9b\01\0b\09\00 \00 \01\fd\9c\01\0b\09\00 \00 \01\fd\9d\01\0b\09\00 \00 \01\fd\9e\01\0b\09\00 \00 \01\fd\9f\01\0b\06\00 \00\fd~\0b\06\00 \00\fd\7f\0b\07\00 \00\fd\a0\01\0b\07\00 \00\fd\a1\01\0b\07\00 \00\fd\a3\01\0b\07\00 \00\fd\a4\01\0b\07\00 \00\fd\a7\01\0b\07\00 \00\fd\a8\01\0b\07\00 \00\fd\a9\01\0b\07\00 \00\fd\aa\01\0b\09\00 \00 \01\fd\ab\01\0b\09\00 \00 \01\fd\ac\01\0b\09\00 \00 \01\fd\ad\01\0b\09\00 \00 \01\fd\ae\01\0b\09\00 \00 \01\fd\b1\01\0b\09\00 \00 \01\fd\b5\01\0b\09\00 \00 \01\fd\b6\01\0b\09\00 \00 \01\fd\b7\01\0b\09\00 \00 \01\fd\b8\01\0b\09\00 \00 \01\fd\b9\01\0b\09\00 \00 \01\fd\ba\01\0b\09\00 \00 \01\fd\bc\01\0b\09\00 \00 \01\fd\bd\01\0b\09\00 \00 \01\fd\be\01\0b\09\00 \00 \01\fd\bf\01\0b\07\00 \00\fd\c0\01\0b\07\00 \00\fd\c1\01\0b\07\00 \00\fd\c3\01\0b\07\00 \00\fd\c4\01\0b\07\00 \00\fd\c7\01\0b\07\00 \00\fd\c8\01\0b\07\00 \00\fd\c9\01\0b\07\00 \00\fd\ca\01\0b\09\00 \00 \01\fd\cb\01\0b\09\00 \00 \01\fd\cc\01\0b\09\00 \00 \01\fd\cd\01\0b\09\00 \00 \01\fd\ce\01\0b\09\00 \00 \01\fd\d1\01\0b\09\00 \00 \01\fd\d5\01\0b\09\00 \00 \01\fd\dc\01\0b\09\00 \00 \01\fd\dd\01\0b\09\00 \00 \01\fd\de\01\0b\09\00 \00 \01\fd\df\01\0b\06\00 \00\fdg\0b\06\00 \00\fdh\0b\06\00 \00\fdi\0b\06\00 \00\fdj\0b\07\00 \00\fd\e0\01\0b\07\00 \00\fd\e1\01\0b\07\00 \00\fd\e3\01\0b\09\00 \00 \01\fd\e4\01\0b\09\00 \00 \01\fd\e5\01\0b\09\00 \00 \01\fd\e6\01\0b\09\00 \00 \01\fd\e7\01\0b\09\00 \00 \01\fd\e8\01\0b\09\00 \00 \01\fd\e9\01\0b\09\00 \00 \01\fd\ea\01\0b\09\00 \00 \01\fd\eb\01\0b\06\00 \00\fdt\0b\06\00 \00\fdu\0b\06\00 \00\fdz\0b\07\00 \00\fd\94\01\0b\07\00 \00\fd\ec\01\0b\07\00 \00\fd\ed\01\0b\07\00 \00\fd\ef\01\0b\09\00 \00 \01\fd\f0\01\0b\09\00 \00 \01\fd\f1\01\0b\09\00 \00 \01\fd\f2\01\0b\09\00 \00 \01\fd\f3\01\0b\09\00 \00 \01\fd\f4\01\0b\09\00 \00 \01\fd\f5\01\0b\09\00 \00 \01\fd\f6\01\0b\09\00 \00 \01\fd\f7\01\0b\07\00 \00\fd\f8\01\0b\07\00 \00\fd\f9\01\0b\07\00 \00\fd\fa\01\0b\07\00 \00\fd\fb\01\0b\07\00 \00\fd\fc\01\0b\07\00 \00\fd\fd\01\0b\07\00 \00\fd\fe\01\0b\07\00 \00\fd\ff\01\0b\06\00 \00\fd^\0b\06\00 \00\fd_\0b\07\00 \00\fd\15\00\0b\07\00 \00\fd\15\0f\0b\07\00 \00\fd\16\00\0b\07\00 \00\fd\16\0f\0b\09\00 \00 \01\fd\17\00\0b\09\00 \00 \01\fd\17\0f\0b\07\00 \00\fd\18\00\0b\07\00 \00\fd\18\07\0b\07\00 \00\fd\19\00\0b\07\00 \00\fd\19\07\0b\09\00 \00 \01\fd\1a\00\0b\09\00 \00 \01\fd\1a\07\0b\07\00 \00\fd\1b\00\0b\07\00 \00\fd\1b\03\0b\09\00 \00 \01\fd\1c\00\0b\09\00 \00 \01\fd\1c\03\0b\07\00 \00\fd\1d\00\0b\07\00 \00\fd\1d\01\0b\09\00 \00 \01\fd\1e\00\0b\09\00 \00 \01\fd\1e\01\0b\07\00 \00\fd\1f\00\0b\07\00 \00\fd\1f\03\0b\09\00 \00 \01\fd \00\0b\09\00 \00 \01\fd \03\0b\07\00 \00\fd!\00\0b\07\00 \00\fd!\01\0b\09\00 \00 \01\fd\22\00\0b\09\00 \00 \01\fd\22\01\0b\18\00 \00 \01\fd\0d\00\02\04\06\08\0a\0c\0e\10\12\14\16\18\1a\1c\1e\0b\18\00 \00 \01\fd\0d\1f\00\11\03\10\10\05\1e\08\09\0a\0b\01\02\03\04\0b")
+
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xc0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0xf9 0xf9 0x0 0x0 0x0 0x0 0x0 0xf9 0x0 0x0 0x0 0x0 0x0 0x2 0xf9))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0xf9 0x0 0x0 0xf9 0xf9 0xf9 0x0 0xf9 0xf9 0x0 0x0 0xf9 0xf9 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x15 0x0 0x0 0xf9 0xf9 0x0 0x0 0x2 0xf9 0xf9 0xf9 0xf9 0xf9 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0x0 0x0 0x2 0xf9 0xf9 0xf9 0xf9 0xf9 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.splat" (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.splat" (i32.const 1)) (v128.const i8x16 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i8x16.splat" (i32.const -1)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.splat" (i32.const 2147483647)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.splat" (i32.const -2147483648)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.splat" (i32.const 305419896)) (v128.const i8x16 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78 0x78))
+(assert_return (invoke "i8x16.splat" (i32.const 31)) (v128.const i8x16 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f))
+(assert_return (invoke "i8x16.splat" (i32.const -7)) (v128.const i8x16 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9 0xf9))
+(assert_return (invoke "i8x16.splat" (i32.const -2147483647)) (v128.const i8x16 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i8x16.splat" (i32.const 2)) (v128.const i8x16 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2))
+(assert_return (invoke "i8x16.splat" (i32.const 65535)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i16x8.splat" (i32.const 0)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.splat" (i32.const 1)) (v128.const i16x8 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i16x8.splat" (i32.const -1)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.splat" (i32.const 2147483647)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.splat" (i32.const -2147483648)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.splat" (i32.const 305419896)) (v128.const i16x8 0x5678 0x5678 0x5678 0x5678 0x5678 0x5678 0x5678 0x5678))
+(assert_return (invoke "i16x8.splat" (i32.const 31)) (v128.const i16x8 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f 0x1f))
+(assert_return (invoke "i16x8.splat" (i32.const -7)) (v128.const i16x8 0xfff9 0xfff9 0xfff9 0xfff9 0xfff9 0xfff9 0xfff9 0xfff9))
+(assert_return (invoke "i16x8.splat" (i32.const -2147483647)) (v128.const i16x8 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i16x8.splat" (i32.const 2)) (v128.const i16x8 0x2 0x2 0x2 0x2 0x2 0x2 0x2 0x2))
+(assert_return (invoke "i16x8.splat" (i32.const 65535)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i32x4.splat" (i32.const 0)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.splat" (i32.const 1)) (v128.const i32x4 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i32x4.splat" (i32.const -1)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.splat" (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x7fffffff 0x7fffffff 0x7fffffff))
+(assert_return (invoke "i32x4.splat" (i32.const -2147483648)) (v128.const i32x4 0x80000000 0x80000000 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.splat" (i32.const 305419896)) (v128.const i32x4 0x12345678 0x12345678 0x12345678 0x12345678))
+(assert_return (invoke "i32x4.splat" (i32.const 31)) (v128.const i32x4 0x1f 0x1f 0x1f 0x1f))
+(assert_return (invoke "i32x4.splat" (i32.const -7)) (v128.const i32x4 0xfffffff9 0xfffffff9 0xfffffff9 0xfffffff9))
+(assert_return (invoke "i32x4.splat" (i32.const -2147483647)) (v128.const i32x4 0x80000001 0x80000001 0x80000001 0x80000001))
+(assert_return (invoke "i32x4.splat" (i32.const 2)) (v128.const i32x4 0x2 0x2 0x2 0x2))
+(assert_return (invoke "i32x4.splat" (i32.const 65535)) (v128.const i32x4 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i64x2.splat" (i64.const 0)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.splat" (i64.const 1)) (v128.const i64x2 0x1 0x1))
+(assert_return (invoke "i64x2.splat" (i64.const -1)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.splat" (i64.const 9223372036854775807)) (v128.const i64x2 0x7fffffffffffffff 0x7fffffffffffffff))
+(assert_return (invoke "i64x2.splat" (i64.const -9223372036854775808)) (v128.const i64x2 0x8000000000000000 0x8000000000000000))
+(assert_return (invoke "i64x2.splat" (i64.const 81985529216486895)) (v128.const i64x2 0x123456789abcdef 0x123456789abcdef))
+(assert_return (invoke "i64x2.splat" (i64.const 63)) (v128.const i64x2 0x3f 0x3f))
+(assert_return (invoke "i64x2.splat" (i64.const -7)) (v128.const i64x2 0xfffffffffffffff9 0xfffffffffffffff9))
+(assert_return (invoke "i64x2.splat" (i64.const 2147483648)) (v128.const i64x2 0x80000000 0x80000000))
+(assert_return (invoke "i64x2.splat" (i64.const 4294967295)) (v128.const i64x2 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.splat" (f32.const 0x0.0p+0)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.splat" (f32.const -0x0.0p+0)) (v128.const f32x4 -0x0.0p+0 -0x0.0p+0 -0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1p+0)) (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 -0x1.8000000000000p+0 -0x1.8000000000000p+0 -0x1.8000000000000p+0 -0x1.8000000000000p+0))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.4000000000000p+1)) (v128.const f32x4 0x1.4000000000000p+1 0x1.4000000000000p+1 0x1.4000000000000p+1 0x1.4000000000000p+1))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1.c000000000000p+1)) (v128.const f32x4 -0x1.c000000000000p+1 -0x1.c000000000000p+1 -0x1.c000000000000p+1 -0x1.c000000000000p+1))
+(assert_return (invoke "f32x4.splat" (f32.const inf)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.splat" (f32.const -inf)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.splat" (f32.const nan:0x400000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.splat" (f32.const -nan:0x400001)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.fffffe0000000p+127)) (v128.const f32x4 0x1.fffffe0000000p+127 0x1.fffffe0000000p+127 0x1.fffffe0000000p+127 0x1.fffffe0000000p+127))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1p-149)) (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1p+31)) (v128.const f32x4 0x1p+31 0x1p+31 0x1p+31 0x1p+31))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1.0000020000000p+31)) (v128.const f32x4 -0x1.0000020000000p+31 -0x1.0000020000000p+31 -0x1.0000020000000p+31 -0x1.0000020000000p+31))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.2a05f20000000p+33)) (v128.const f32x4 0x1.2a05f20000000p+33 0x1.2a05f20000000p+33 0x1.2a05f20000000p+33 0x1.2a05f20000000p+33))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.fffffe0000000p-2)) (v128.const f32x4 0x1.fffffe0000000p-2 0x1.fffffe0000000p-2 0x1.fffffe0000000p-2 0x1.fffffe0000000p-2))
+(assert_return (invoke "f64x2.splat" (f64.const 0x0.0p+0)) (v128.const f64x2 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f64x2.splat" (f64.const -0x0.0p+0)) (v128.const f64x2 -0x0.0p+0 -0x0.0p+0))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1p+0)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 -0x1.8000000000000p+0 -0x1.8000000000000p+0))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.4000000000000p+1)) (v128.const f64x2 0x1.4000000000000p+1 0x1.4000000000000p+1))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1.c000000000000p+1)) (v128.const f64x2 -0x1.c000000000000p+1 -0x1.c000000000000p+1))
+(assert_return (invoke "f64x2.splat" (f64.const inf)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.splat" (f64.const -inf)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.splat" (f64.const nan:0x8000000000000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.splat" (f64.const -nan:0x8000000000001)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.splat" (f64.const 0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1p+63)) (v128.const f64x2 0x1p+63 0x1p+63))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1.0000000200000p+31)) (v128.const f64x2 -0x1.0000000200000p+31 -0x1.0000000200000p+31))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.0000000080000p+32)) (v128.const f64x2 0x1.0000000080000p+32 0x1.0000000080000p+32))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.56e1fc2f8f359p-997)) (v128.const f64x2 0x1.56e1fc2f8f359p-997 0x1.56e1fc2f8f359p-997))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.fffffffffffffp-2)) (v128.const f64x2 0x1.fffffffffffffp-2 0x1.fffffffffffffp-2))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0x0 0xff 0xff 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0xff 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0x0 0x0 0xff 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0x0 0xff 0xff 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0xff 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0x0 0x0 0xff 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0xff 0x0 0x0 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0x0 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0xff 0xff 0x0 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0xff 0x0 0x0 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0x0 0x0 0xff 0x0 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0x0 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0xff 0xff 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0xff 0x0 0xff 0xff 0x0 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff 0x0 0xff 0xff 0xff 0xff 0xff 0x0 0x0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0xff 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0xff 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i8x16.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0xffff 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0x0 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0x0 0x0 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0x0 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0xffff 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0x0 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0x0 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0x0 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0xffff 0x0 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0x0 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0x0 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0x0 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0x0 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0x0 0x0 0xffff 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0xffff 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0x0 0xffff 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0x0 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0xffff 0x0 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.gt_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.le_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.ge_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0xffffffff))
+(assert_return (invoke "f32x4.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffffffff 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f32x4.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.eq" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ne" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.lt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.gt" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.le" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "f64x2.ge" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "v128.not" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3))
+(assert_return (invoke "v128.not" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x7fffffff 0x80000000 0x7fff 0xfffe8000))
+(assert_return (invoke "v128.not" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xc03fffff 0x7fffffff 0x807fffff 0x803fffff))
+(assert_return (invoke "v128.not" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0xffffffff 0x3ffbffff 0xfffffffe 0xffffffff))
+(assert_return (invoke "v128.not" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x2feafd06 0xbfbfffff 0xfffffffe 0x7fffff))
+(assert_return (invoke "v128.not" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.not" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x80017ffe 0xff0000ff 0x123edcb 0x80ff7f))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x7f800000 0x0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x80000000 0x40400000 0x0 0x0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x0 0x0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x7f800000 0x0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x10000000 0x0 0x0 0x7f800000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x7fc00000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x40400000 0x0 0x0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x10000000 0x0 0x0 0x7f800000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x40400000 0x1 0xff800000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x43e00000 0x0 0x0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x7fc00000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x40400000 0x1 0xff800000))
+(assert_return (invoke "v128.and" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x80000000 0x7fffffff 0x807f8000 0x17fff))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x3fbfffff 0xffff8000 0x17fff))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x80000000 0x3c1fffff 0xffff8000 0x17fff))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x3fc00000 0x80000000 0x0 0x7fc00000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x2fc00000 0x80000000 0x7f800000 0x400000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x0))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x501502f9 0x0 0x1 0xff800000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xc01502f9 0x40400000 0x1 0x80000000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xd01502f9 0x0 0x0 0x0))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x1 0xfff80000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x43e00000 0x1 0x80380000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x3a00000 0x0 0x780000))
+(assert_return (invoke "v128.and_not" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xbfc00000 0xffffffff 0xffff8000 0x7fc17fff))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x7fffffff 0xffff8001 0xff817fff))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8001 0xfff97fff))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xbfc00000 0xffffffff 0xffff8000 0x7fc17fff))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffd502f9 0xc0400000 0x7f800001 0xffc00000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x3fc00000 0xc3e00000 0x7f800001 0xfff80000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xd01502f9 0x7fffffff 0xffff8001 0xff817fff))
+(assert_return (invoke "v128.or" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffd502f9 0xc0400000 0x7f800001 0xffc00000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xd01502f9 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8001 0xfff97fff))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0xc3e00000 0x7f800001 0xfff80000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "v128.or" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xbfc00000 0xffffffff 0x807f8000 0x7fc17fff))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x501502f9 0x3fbfffff 0xffff8001 0xff817fff))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x80000000 0x3c1fffff 0xffff8001 0xfff97fff))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xbfc00000 0xffffffff 0x807f8000 0x7fc17fff))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xefd502f9 0xc0400000 0x7f800001 0x80400000))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x3fc00000 0xc3e00000 0x7f800001 0x80380000))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x501502f9 0x3fbfffff 0xffff8001 0xff817fff))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xefd502f9 0xc0400000 0x7f800001 0x80400000))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xd01502f9 0x3a00000 0x0 0x780000))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x3c1fffff 0xffff8001 0xfff97fff))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0xc3e00000 0x7f800001 0x80380000))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x3a00000 0x0 0x780000))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3)) (v128.const i32x4 0x83000000 0x78f9fafb 0xfff58000 0xf0172f3))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x3cc00000 0x87060504 0x7f8a0000 0x70c00d0c))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3)) (v128.const i32x4 0x80000000 0x78f9fafb 0xf4f58000 0xf0172f3))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0xd01502f9 0x47460504 0xb0a0001 0xf0800d0c))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xfcfdfeff 0xf8f9fafb 0xf4f5f6f7 0xf0f1f2f3)) (v128.const i32x4 0x3cc00000 0x80000000 0x74800000 0x7fc00000))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0xd31502f9 0x40400000 0xb000001 0xff800000))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 1))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i8x16 0x0 0x1 0x2 0x3 0x4 0x5 0x6 0x7 0x8 0x9 0xa 0xb 0xc 0xd 0xe 0xf))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0x1 0x1 0x1 0x7f 0x0 0x80 0x1 0x1 0x1 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x40 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0x40 0x7f))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x4 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0x2 0x15 0x30 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0x1))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i8x16 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i8x16.abs" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i8x16 0x1 0x80 0x2 0x7f 0x0 0x1 0x1 0x0 0x34 0x12 0x24 0x2 0x80 0x0 0x7f 0x1))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i8x16 0x0 0xff 0xfe 0xfd 0xfc 0xfb 0xfa 0xf9 0xf8 0xf7 0xf6 0xf5 0xf4 0xf3 0xf2 0xf1))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0x1 0x1 0x1 0x81 0x0 0x80 0x1 0x1 0x1 0x81 0xff 0x0))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x40 0xc1 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x81 0x0 0x0 0x40 0x81))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xfc 0x40 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0xfe 0xeb 0x30 0x0 0x0 0xc0 0xc0 0xff 0x0 0x0 0x0 0x0 0x0 0x80 0x1))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i8x16 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i8x16.neg" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i8x16 0xff 0x80 0x2 0x81 0x0 0x1 0x1 0x0 0xcc 0xee 0x24 0x2 0x80 0x0 0x81 0x1))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i8x16 0x0 0x1 0x1 0x2 0x1 0x2 0x2 0x3 0x1 0x2 0x2 0x3 0x2 0x3 0x3 0x4))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x1 0x8 0x8 0x8 0x7 0x0 0x1 0x8 0x8 0x8 0x7 0x1 0x0))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x2 0x6 0x0 0x0 0x0 0x1 0x0 0x0 0x1 0x7 0x0 0x0 0x2 0x7))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x2 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x6 0x1 0x3 0x3 0x0 0x0 0x1 0x1 0x1 0x0 0x0 0x0 0x0 0x0 0x1 0x8))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i8x16 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8 0x8))
+(assert_return (invoke "i8x16.popcnt" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i8x16 0x1 0x1 0x7 0x7 0x0 0x8 0x8 0x0 0x3 0x2 0x5 0x7 0x1 0x0 0x7 0x8))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 7800))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 17540))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 128))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 49161))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i8x16.bitmask" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 40038))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8 0x0 0x80 0xff 0x7f 0x80 0xff 0x7f 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8 0x0 0x7f 0x0 0x80 0x0 0x7f 0x0 0x7f))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8 0x7f 0x80 0x0 0x7f 0x1 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8 0x0 0x0 0x0 0x7f 0x1 0x0 0x0 0xf8))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0xff 0x0 0xff))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xfe 0xfe 0xfe 0xfe 0x0 0x0 0xfe 0xfe 0xfe 0xfe 0x2 0x0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x80 0x80 0x80 0x80 0x0 0x0 0x80 0x80 0x80 0x80 0x80 0x0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x80 0x80 0x80 0x80 0x0 0x0 0x80 0x80 0x80 0x80 0x80 0x0))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x80 0x7e 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xfe 0x0 0x0 0x80 0xfe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x80 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x80 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i8x16 0xf2 0x4 0x2a 0xa0 0x0 0x0 0x80 0x80 0x2 0x0 0x0 0x0 0x0 0x0 0x0 0xfe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i8x16 0x80 0x0 0x80 0x0 0x0 0x0 0x0 0x0 0x80 0x0 0x0 0x0 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i8x16 0x80 0x0 0x80 0x0 0x0 0x0 0x0 0x0 0x80 0x0 0x0 0x0 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xc0 0x86 0x2 0x0 0x0 0x0 0x0 0x0 0xf0 0xfe))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x80 0x0 0x0 0x0 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x80 0x0 0x0 0x0 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0xc0 0xff 0xff 0xff 0x3f 0x0 0xc0 0xff 0xff 0xff 0x3f 0x0 0x0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0xff 0xff 0xff 0xff 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0xe0 0x1f 0x0 0x0 0x0 0xc0 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0x3f))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0xff 0x0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i8x16 0xfc 0x1 0xa 0xe8 0x0 0x0 0x20 0x20 0x0 0x0 0x0 0x0 0x0 0x0 0xc0 0xff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i8x16 0xff 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xf0 0x21 0x0 0x0 0x0 0x0 0x0 0x0 0xfc 0xff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0x40 0x7f 0x7f 0x7f 0x3f 0x0 0x40 0x7f 0x7f 0x7f 0x3f 0x0 0x0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x1 0x1 0x1 0x1 0x0 0x0 0x1 0x1 0x1 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x1 0x1 0x1 0x1 0x0 0x0 0x1 0x1 0x1 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x60 0x1f 0x0 0x0 0x0 0x40 0x0 0x0 0x40 0x3f 0x0 0x0 0x60 0x3f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x1 0x0 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x1 0x0 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i8x16 0x7c 0x1 0xa 0x68 0x0 0x0 0x20 0x20 0x0 0x0 0x0 0x0 0x0 0x0 0x40 0x7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i8x16 0x1 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x1))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i8x16 0x1 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x1))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x70 0x21 0x0 0x0 0x0 0x0 0x0 0x0 0x7c 0x7f))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x1))
+(assert_return (invoke "i8x16.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x1))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xfe 0xfe 0xfe 0xfe 0x0 0x0 0xfe 0xfe 0xfe 0xfe 0x2 0x0))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0xbf 0xff 0xff 0xff 0xff 0x0 0x80 0x7f 0x7e 0xff 0x7f 0xc1 0x7f))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0x50 0xff 0xff 0x3f 0xbf 0x1 0x80 0xff 0xff 0xff 0x7f 0x81 0xff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xdf 0xc2 0x1 0x80 0xff 0xff 0xff 0x7f 0xf9 0xff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0xbf 0xff 0xff 0xff 0xff 0x0 0x80 0x7f 0x7e 0xff 0x7f 0xc1 0x7f))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x80 0x7e 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xfe 0x0 0x0 0x80 0xfe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0xd5 0xf 0x0 0x0 0x40 0xc0 0x1 0x0 0x80 0x7f 0x0 0x0 0x40 0x7e))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0xc3 0x1 0x0 0x80 0x7f 0x0 0x0 0xb8 0x7e))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x2 0x15 0x50 0xff 0xff 0x3f 0xbf 0x1 0x80 0xff 0xff 0xff 0x7f 0x81 0xff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x2 0xd5 0xf 0x0 0x0 0x40 0xc0 0x1 0x0 0x80 0x7f 0x0 0x0 0x40 0x7e))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf2 0x4 0x2a 0xa0 0x0 0x0 0x80 0x80 0x2 0x0 0x0 0x0 0x0 0x0 0x0 0xfe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x20 0x83 0x2 0x0 0x0 0x0 0x0 0x0 0x78 0xfe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xdf 0xc2 0x1 0x80 0xff 0xff 0xff 0x7f 0xf9 0xff))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0xc3 0x1 0x0 0x80 0x7f 0x0 0x0 0xb8 0x7e))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x20 0x83 0x2 0x0 0x0 0x0 0x0 0x0 0x78 0xfe))
+(assert_return (invoke "i8x16.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xc0 0x86 0x2 0x0 0x0 0x0 0x0 0x0 0xf0 0xfe))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xfe 0xfe 0xfe 0x7f 0x0 0x80 0xfe 0xfe 0xfe 0x7f 0x2 0x0))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0xbf 0xff 0xff 0xff 0xff 0x0 0x80 0x80 0x7e 0xff 0x7f 0xc1 0x7f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0x80 0xff 0xff 0x3f 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0x81 0xff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xdf 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0xf9 0xff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0xbf 0xff 0xff 0xff 0xff 0x0 0x80 0x80 0x7e 0xff 0x7f 0xc1 0x7f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x80 0x7e 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0x80 0x7f))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0xd5 0xf 0x0 0x0 0x40 0xc0 0x1 0x0 0x80 0x7f 0x0 0x0 0x80 0x7e))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0xc3 0x1 0x0 0x80 0x7f 0x0 0x0 0xb8 0x7e))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x2 0x15 0x80 0xff 0xff 0x3f 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0x81 0xff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x2 0xd5 0xf 0x0 0x0 0x40 0xc0 0x1 0x0 0x80 0x7f 0x0 0x0 0x80 0x7e))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf2 0x4 0x2a 0xa0 0x0 0x0 0x7f 0x7f 0x2 0x0 0x0 0x0 0x0 0x0 0x80 0xfe))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x20 0x7f 0x2 0x0 0x0 0x0 0x0 0x0 0x80 0xfe))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xdf 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0xf9 0xff))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0xc3 0x1 0x0 0x80 0x7f 0x0 0x0 0xb8 0x7e))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x20 0x7f 0x2 0x0 0x0 0x0 0x0 0x0 0x80 0xfe))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xc0 0x7f 0x2 0x0 0x0 0x0 0x0 0x0 0xf0 0xfe))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0xff 0xff 0xff 0xff 0xfe 0x0 0xff 0xff 0xff 0xff 0xfe 0x2 0x0))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0xbf 0xff 0xff 0xff 0xff 0x0 0x80 0xff 0xff 0xff 0x7f 0xc1 0x7f))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xff 0xff 0xff 0xff 0xbf 0x1 0x80 0xff 0xff 0xff 0x7f 0x81 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0xc2 0x1 0x80 0xff 0xff 0xff 0x7f 0xf9 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0xbf 0xff 0xff 0xff 0xff 0x0 0x80 0xff 0xff 0xff 0x7f 0xc1 0x7f))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xff 0x7e 0x0 0x0 0x0 0xff 0x0 0x0 0xff 0xfe 0x0 0x0 0xff 0xfe))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0xd5 0xff 0x0 0x0 0x40 0xc0 0x1 0x0 0x80 0x7f 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0xc3 0x1 0x0 0x80 0x7f 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x2 0x15 0xff 0xff 0xff 0xff 0xbf 0x1 0x80 0xff 0xff 0xff 0x7f 0x81 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x2 0xd5 0xff 0x0 0x0 0x40 0xc0 0x1 0x0 0x80 0x7f 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xff 0x4 0x2a 0xff 0x0 0x0 0x80 0x80 0x2 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0xff 0x83 0x2 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0xc2 0x1 0x80 0xff 0xff 0xff 0x7f 0xf9 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0xc3 0x1 0x0 0x80 0x7f 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0xff 0x83 0x2 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x86 0x2 0x0 0x0 0x0 0x0 0x0 0xff 0xff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x40 0x41 0xff 0xff 0xff 0xff 0x0 0x80 0x7f 0x80 0xff 0x7f 0x41 0x81))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0xfe 0xeb 0xb0 0xff 0xff 0xbf 0x3f 0xff 0x80 0xff 0xff 0xff 0x7f 0x81 0x1))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0x1f 0x3c 0xff 0x80 0xff 0xff 0xff 0x7f 0x9 0x1))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0xbf 0x1 0x1 0x1 0x1 0x0 0x80 0x81 0x80 0x1 0x81 0xbf 0x7f))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0xfe 0xab 0x6f 0x0 0x0 0xc0 0x40 0xff 0x0 0x80 0x7f 0x0 0x0 0x40 0x80))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x20 0x3d 0xff 0x0 0x80 0x7f 0x0 0x0 0xc8 0x80))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x2 0x15 0x50 0x1 0x1 0x41 0xc1 0x1 0x80 0x1 0x1 0x1 0x81 0x7f 0xff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x2 0x55 0x91 0x0 0x0 0x40 0xc0 0x1 0x0 0x80 0x81 0x0 0x0 0xc0 0x80))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x60 0xfd 0x0 0x0 0x0 0x0 0x0 0x0 0x88 0x0))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0x1 0x1 0xe1 0xc4 0x1 0x80 0x1 0x1 0x1 0x81 0xf7 0xff))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x40 0xc1 0x0 0x0 0xe0 0xc3 0x1 0x0 0x80 0x81 0x0 0x0 0x38 0x80))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0xfe 0xeb 0x30 0x0 0x0 0xa0 0x3 0x0 0x0 0x0 0x0 0x0 0x0 0x78 0x0))
+(assert_return (invoke "i8x16.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x40 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0x7f 0x80 0xff 0x7f 0x41 0x81))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0xfe 0xeb 0xb0 0xff 0xff 0xbf 0x3f 0xff 0x80 0xff 0xff 0xff 0x7f 0x7f 0x1))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0x1f 0x3c 0xff 0x80 0xff 0xff 0xff 0x7f 0x9 0x1))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0x7f 0x1 0x1 0x1 0x80 0x0 0x7f 0x81 0x7f 0x1 0x81 0xbf 0x7f))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0xfe 0xab 0x6f 0x0 0x0 0xc0 0x80 0xff 0x0 0x80 0x7f 0x0 0x0 0x40 0x7f))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x20 0x80 0xff 0x0 0x80 0x7f 0x0 0x0 0xc8 0x7f))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x2 0x15 0x50 0x1 0x1 0x41 0xc1 0x1 0x7f 0x1 0x1 0x1 0x81 0x80 0xff))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x2 0x55 0x91 0x0 0x0 0x40 0x7f 0x1 0x0 0x7f 0x81 0x0 0x0 0xc0 0x80))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x60 0xfd 0x0 0x0 0x0 0x0 0x0 0x0 0x88 0x0))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x7f 0x1 0x1 0xe1 0xc4 0x1 0x7f 0x1 0x1 0x1 0x81 0xf7 0xff))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x40 0xc1 0x0 0x0 0xe0 0x7f 0x1 0x0 0x7f 0x81 0x0 0x0 0x38 0x80))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7 0xfe 0xeb 0x30 0x0 0x0 0xa0 0x3 0x0 0x0 0x0 0x0 0x0 0x0 0x78 0x0))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x41 0xff 0xff 0xff 0x0 0x0 0x80 0x7f 0x80 0xff 0x7f 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0xff 0xff 0xbf 0x3f 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0x1f 0x3c 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0xbf 0x7f))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0xab 0x0 0x0 0x0 0x0 0x40 0x0 0x0 0x80 0x7f 0x0 0x0 0x40 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x3d 0x0 0x0 0x80 0x7f 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x2 0x15 0x50 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x7f 0xff))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x2 0x0 0x91 0x0 0x0 0x40 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0xf7 0xff))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x38 0x80))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xa0 0x3 0x0 0x0 0x0 0x0 0x0 0x0 0x78 0x0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x80 0xff 0xff 0xff 0x80 0x0 0x80 0x80 0xff 0xff 0x0 0xc0 0x0))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x0 0x0 0x80 0xff 0xff 0xff 0x40 0x0 0x80 0xff 0xff 0xff 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xe0 0x43 0x0 0x80 0xff 0xff 0xff 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0x80 0xff 0xff 0xff 0x80 0x0 0x80 0x80 0xff 0xff 0x0 0xc0 0x0))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x0 0xc0 0xd0 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x0 0x0 0x0 0xe0 0x80 0x0 0x0 0x80 0x0 0x0 0x0 0xc0 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x0 0x0 0x80 0xff 0xff 0xff 0x40 0x0 0x80 0xff 0xff 0xff 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x0 0xc0 0xd0 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x0 0x0 0xd0 0x0 0x0 0xe0 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xe0 0x43 0x0 0x80 0xff 0xff 0xff 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x0 0x0 0x0 0xe0 0x80 0x0 0x0 0x80 0x0 0x0 0x0 0xc0 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x0 0x0 0xd0 0x0 0x0 0xe0 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x3f 0x0 0x0 0x0 0x7f 0x0 0x0 0x80 0x7f 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0x0 0x0 0x40 0x40 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x3f 0x0 0x0 0x0 0x7f 0x0 0x0 0x80 0x7f 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x15 0x3f 0x0 0x0 0x0 0x40 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x43 0x0 0x0 0x0 0x0 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0x0 0x0 0x40 0x40 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x15 0x3f 0x0 0x0 0x0 0x40 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x0 0x0 0x0 0x0 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x43 0x0 0x0 0x0 0x0 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x3f 0x0 0x0 0x0 0x7f 0x0 0x0 0xff 0x7f 0x0 0x7f 0x1 0x7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x2 0x15 0xd0 0x0 0x0 0x40 0x7f 0x1 0x0 0x0 0x0 0x0 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x7f 0x1 0x0 0x0 0x0 0x0 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x3f 0x0 0x0 0x0 0x7f 0x0 0x0 0xff 0x7f 0x0 0x7f 0x1 0x7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x2 0x15 0x3f 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x3f 0x0 0x0 0x0 0x43 0x1 0x0 0x0 0x7f 0x0 0x0 0xf8 0x7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x2 0x15 0xd0 0x0 0x0 0x40 0x7f 0x1 0x0 0x0 0x0 0x0 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x2 0x15 0x3f 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x2 0x15 0x0 0x0 0x0 0x40 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xff 0x7f 0x1 0x0 0x0 0x0 0x0 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x0 0x3f 0x0 0x0 0x0 0x43 0x1 0x0 0x0 0x7f 0x0 0x0 0xf8 0x7f))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x0 0x2 0x15 0x0 0x0 0x0 0x40 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x80 0xff 0xff 0xff 0x80 0x0 0x80 0xff 0xff 0xff 0x7f 0xc0 0x7f))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0xff 0xff 0xff 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0x80 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0xf8 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0xc0 0x80 0xff 0xff 0xff 0x80 0x0 0x80 0xff 0xff 0xff 0x7f 0xc0 0x7f))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0xc0 0xd0 0x0 0x0 0x40 0x80 0x1 0x0 0x80 0x7f 0x0 0x0 0xc0 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0x80 0x1 0x0 0x80 0x7f 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0xff 0xff 0xff 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0x80 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0xf9 0x2 0xc0 0xd0 0x0 0x0 0x40 0x80 0x1 0x0 0x80 0x7f 0x0 0x0 0xc0 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x80 0xff 0xff 0xff 0x7f 0xf8 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0xe0 0x80 0x1 0x0 0x80 0x7f 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x60 0x60 0x80 0x80 0x80 0x80 0x0 0x40 0xc0 0xbf 0x80 0x40 0x61 0x40))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7d 0x1 0xb 0xa8 0x80 0x80 0xa0 0x60 0x1 0x40 0x80 0x80 0x80 0x40 0x41 0x80))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x40 0x80 0x80 0xf0 0x61 0x1 0x40 0x80 0x80 0x80 0x40 0x7d 0x80))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x60 0x60 0x80 0x80 0x80 0x80 0x0 0x40 0xc0 0xbf 0x80 0x40 0x61 0x40))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7d 0x1 0x6b 0x88 0x0 0x0 0x20 0x60 0x1 0x0 0x40 0x40 0x0 0x0 0xa0 0xbf))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x60 0x20 0x0 0x0 0x70 0x62 0x1 0x0 0x40 0x40 0x0 0x0 0xdc 0xbf))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x7d 0x1 0xb 0xa8 0x80 0x80 0xa0 0x60 0x1 0x40 0x80 0x80 0x80 0x40 0x41 0x80))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x7d 0x1 0x6b 0x88 0x0 0x0 0x20 0x60 0x1 0x0 0x40 0x40 0x0 0x0 0xa0 0xbf))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x7d 0x1 0xb 0x68 0x0 0x0 0x90 0x42 0x1 0x0 0x0 0x0 0x0 0x0 0xbc 0xff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i8x16 0x0 0x0 0x0 0x40 0x80 0x80 0xf0 0x61 0x1 0x40 0x80 0x80 0x80 0x40 0x7d 0x80))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i8x16 0x0 0x0 0x60 0x20 0x0 0x0 0x70 0x62 0x1 0x0 0x40 0x40 0x0 0x0 0xdc 0xbf))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i8x16 0x7d 0x1 0xb 0x68 0x0 0x0 0x90 0x42 0x1 0x0 0x0 0x0 0x0 0x0 0xbc 0xff))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0x1 0x5 0x9 0xd 0x11 0x15 0x19 0x1d))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xff80 0xfffe 0x7e 0xff80 0xfffe 0x7e 0x1))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x0 0xff80 0x0 0xffff 0x0 0x3f))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x0 0x0 0x0 0xffc4 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfffb 0xffe5 0x0 0x80 0x1 0x0 0x0 0xff7f))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0xfffe 0xfffe 0xfffe 0xfffe 0xfffe 0xfffe 0xfffe 0xfffe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0xff81 0x7d 0xffff 0xffff 0x46 0xffda 0xff80 0x7e))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0x1 0x5 0x9 0xd 0x11 0x15 0x19 0x1d))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x80 0x1fe 0x17e 0x80 0x1fe 0x17e 0x1))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xff 0x0 0x80 0x0 0xff 0x0 0x13f))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x0 0x0 0x0 0xc4 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfb 0xe5 0x0 0x80 0x1 0x0 0x0 0x17f))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0x1fe 0x1fe 0x1fe 0x1fe 0x1fe 0x1fe 0x1fe 0x1fe))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0x81 0x17d 0xff 0xff 0x46 0x1da 0x80 0x17e))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0x100 0x302 0x504 0x706 0x908 0xb0a 0xd0c 0xf0e))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0x1 0x7fff 0x8000 0x1 0x7fff 0x1))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x0 0x0 0x0 0x3ffc 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0x2feb 0x0 0x4040 0x1 0x0 0x0 0x80))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i16x8.abs" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0x7fff 0x7ffe 0x100 0xff 0x1234 0x124 0x80 0x81))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0xff00 0xfcfe 0xfafc 0xf8fa 0xf6f8 0xf4f6 0xf2f4 0xf0f2))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0x1 0x8001 0x8000 0x1 0x8001 0xffff))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xc040 0x0 0x8000 0x0 0x8080 0x0 0x8040))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x0 0x0 0x0 0x3ffc 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfd07 0x2feb 0x0 0xbfc0 0xffff 0x0 0x0 0x80))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0x1 0x1 0x1 0x1 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i16x8.neg" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0x7fff 0x8002 0x100 0xff01 0xedcc 0x124 0xff80 0x81))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x7fff 0x0 0x7ffe 0x7fff 0x0 0x7ffe 0x0))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xc040 0x0 0x8001 0x0 0xffff 0x0 0x1))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x2feb 0x0 0x403f 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43df 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xc040 0x0 0x8001 0x0 0xffff 0x0 0x1))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x1fc0 0x0 0x7fff 0x0 0x7f01 0x0 0x7f80))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xe822 0x0 0xbfc0 0x0 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0xbc20 0x0 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x2feb 0x0 0x403f 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xe822 0x0 0xbfc0 0x0 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x12 0x11f0 0x0 0x2040 0x0 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x2212 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x43df 0xffff 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0xbc20 0x0 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x2212 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.q15_mulr_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x23fe 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 1))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 54))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 8))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 8))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 130))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 165))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x8000 0x7fff 0x8000 0x7fff 0x8000 0x7fff 0x8000 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x8000 0x7fff 0x8000 0x7fff 0x7fff 0x8000 0x7fff 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x8000 0x7fff 0x8000 0x7fff 0x8000 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x8000 0x7fff 0x8000 0x7fff 0x0 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x7fff 0x8000 0x7fff 0x7fff 0x8000 0x7fff 0x8000 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x7fff 0x8000 0x7fff 0x7fff 0x7fff 0x8000 0x7fff 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x7fff 0x8000 0x7fff 0x7fff 0x8000 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x7fff 0x8000 0x7fff 0x7fff 0x0 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x8000 0x7fff 0x1 0x8000 0x8000 0x7fff 0x8000 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x8000 0x7fff 0x1 0x8000 0x7fff 0x8000 0x7fff 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x8000 0x7fff 0x1 0x8000 0x8000 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x8000 0x7fff 0x1 0x8000 0x0 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x7fff 0x1 0x8000 0x8000 0x7fff 0x8000 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x7fff 0x1 0x8000 0x7fff 0x8000 0x7fff 0x7fff))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x7fff 0x1 0x8000 0x8000 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x7fff 0x1 0x8000 0x0 0x7fff 0x1 0x8000))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0x0 0xffff 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0xffff 0x0 0xffff 0xffff 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0x0 0xffff 0x0 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0xffff 0x0 0xffff 0xffff))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xffff 0x1 0x0 0x0 0xffff 0x1 0x0))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0x0 0x1 0x2 0x3 0x4 0x5 0x6 0x7))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xff80 0xffff 0xffff 0xffff 0x7f))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0xffc0 0x3f 0x0 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x4 0xffc0))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfff9 0x2 0x15 0xffd0 0x0 0x0 0x40 0x40))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0x1 0xff80 0xfffe 0x7f 0x0 0xffff 0xffff 0x0))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0x8 0x9 0xa 0xb 0xc 0xd 0xe 0xf))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xff80 0xffff 0xffff 0xffff 0x7f 0x1 0x0))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0xff80 0x7f 0x0 0x0 0xffc0 0x7f))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0xff80 0xffff))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0x34 0x12 0xffdc 0xfffe 0xff80 0x0 0x7f 0xffff))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0x0 0x1 0x2 0x3 0x4 0x5 0x6 0x7))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x4 0xc0))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0x1 0x80 0xfe 0x7f 0x0 0xff 0xff 0x0))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i16x8 0x8 0x9 0xa 0xb 0xc 0xd 0xe 0xf))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i16x8 0xff 0xff 0xff 0xff 0xff 0xff 0xff 0xff))
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i16x8 0x34 0x12 0xdc 0xfe 0x80 0x0 0x7f 0xff))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i16x8 0x0 0x0 0xfffe 0xfffe 0x0 0xfffe 0xfffe 0x2))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x8000 0x8000 0x0 0x8000 0x8000 0x8000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x8000 0x8000 0x0 0x8000 0x8000 0x8000))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i16x8 0x0 0x7f80 0x0 0x0 0x0 0xff00 0x0 0xff80))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i16x8 0x5f2 0xa02a 0x0 0x8080 0x2 0x0 0x0 0xff00))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i16x8 0x8000 0x8000 0x0 0x0 0x8000 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i16x8 0x8000 0x8000 0x0 0x0 0x8000 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i16x8 0x0 0x0 0x0 0x87c0 0x2 0x0 0x0 0xfff0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x8000 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x8000 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i16x8 0x0 0xc000 0xffff 0x3fff 0xc000 0xffff 0x3fff 0x0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i16x8 0x0 0xffff 0xffff 0x0 0xffff 0xffff 0x0 0x0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i16x8 0x0 0x1fe0 0x0 0xc000 0x0 0x3fc0 0x0 0x3fe0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x0 0xffff 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i16x8 0x17c 0xe80a 0x0 0x2020 0x0 0x0 0x0 0xffc0))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i16x8 0x0 0xffff 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i16x8 0x0 0xffff 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i16x8 0x0 0x0 0x0 0x21f0 0x0 0x0 0x0 0xfffc))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i16x8 0x0 0x4000 0x7fff 0x3fff 0x4000 0x7fff 0x3fff 0x0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i16x8 0x0 0x1 0x1 0x0 0x1 0x1 0x0 0x0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i16x8 0x0 0x1 0x1 0x0 0x1 0x1 0x0 0x0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i16x8 0x0 0x1fe0 0x0 0x4000 0x0 0x3fc0 0x0 0x3fe0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i16x8 0x17c 0x680a 0x0 0x2020 0x0 0x0 0x0 0x7fc0))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i16x8 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x1 0x0 0x0 0x0 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i16x8 0x0 0x0 0x0 0x21f0 0x0 0x0 0x0 0x7ffc))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0xfffe 0xfffe 0x0 0xfffe 0xfffe 0x2))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xbfc0 0xffff 0xffff 0x8000 0x7f7f 0x7fff 0x7fc1))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0x5015 0xffff 0xc03f 0x8001 0xffff 0x7fff 0xff81))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0xc3df 0x8001 0xffff 0x7fff 0xfff9))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xbfc0 0xffff 0xffff 0x8000 0x7f7f 0x7fff 0x7fc1))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x7f80 0x0 0x0 0x0 0xff00 0x0 0xff80))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xfd5 0x0 0xc040 0x1 0x7f80 0x0 0x7f40))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0xc3e0 0x1 0x7f80 0x0 0x7fb8))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0x5015 0xffff 0xc03f 0x8001 0xffff 0x7fff 0xff81))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0xfd5 0x0 0xc040 0x1 0x7f80 0x0 0x7f40))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x5f2 0xa02a 0x0 0x8080 0x2 0x0 0x0 0xff00))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x8420 0x2 0x0 0x0 0xff78))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0xc3df 0x8001 0xffff 0x7fff 0xfff9))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0xc3e0 0x1 0x7f80 0x0 0x7fb8))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x8420 0x2 0x0 0x0 0xff78))
+(assert_return (invoke "i16x8.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x87c0 0x2 0x0 0x0 0xfff0))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xfffe 0x7fff 0x8000 0xfffe 0x7fff 0x2))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xbfc0 0xffff 0xffff 0x8000 0x7f7f 0x7fff 0x7fc1))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0x8000 0xffff 0x7fff 0x8001 0xffff 0x7fff 0xff81))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8001 0xffff 0x7fff 0xfff9))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xbfc0 0xffff 0xffff 0x8000 0x7f7f 0x7fff 0x7fc1))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x7f80 0x0 0x8000 0x0 0x7fff 0x0 0x7fff))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xfd5 0x0 0xc040 0x1 0x7f80 0x0 0x7f40))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0xc3e0 0x1 0x7f80 0x0 0x7fb8))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0x8000 0xffff 0x7fff 0x8001 0xffff 0x7fff 0xff81))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0xfd5 0x0 0xc040 0x1 0x7f80 0x0 0x7f40))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x5f2 0xa02a 0x0 0x7fff 0x2 0x0 0x0 0xff00))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x7fff 0x2 0x0 0x0 0xff78))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8001 0xffff 0x7fff 0xfff9))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0xc3e0 0x1 0x7f80 0x0 0x7fb8))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x7fff 0x2 0x0 0x0 0xff78))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x7fff 0x2 0x0 0x0 0xfff0))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xffff 0xffff 0xfffe 0xffff 0xffff 0xfffe 0x2))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xbfc0 0xffff 0xffff 0x8000 0xffff 0x7fff 0x7fc1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xffff 0xffff 0xc03f 0x8001 0xffff 0x7fff 0xff81))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0xc3df 0x8001 0xffff 0x7fff 0xfff9))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xbfc0 0xffff 0xffff 0x8000 0xffff 0x7fff 0x7fc1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x7f80 0x0 0xffff 0x0 0xff00 0x0 0xff80))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xffff 0x0 0xc040 0x1 0x7f80 0x0 0xffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0xc3e0 0x1 0x7f80 0x0 0xffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0xffff 0xffff 0xc03f 0x8001 0xffff 0x7fff 0xff81))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0xffff 0x0 0xc040 0x1 0x7f80 0x0 0xffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x5f2 0xffff 0x0 0x8080 0x2 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x8420 0x2 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0xc3df 0x8001 0xffff 0x7fff 0xfff9))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0xc3e0 0x1 0x7f80 0x0 0xffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x8420 0x2 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x87c0 0x2 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x4040 0xffff 0xffff 0x8000 0x807f 0x7fff 0x8041))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfd07 0xafeb 0xffff 0x3fbf 0x7fff 0xffff 0x7fff 0x81))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0x3c1f 0x7fff 0xffff 0x7fff 0x9))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0xbfc0 0x1 0x1 0x8000 0x7f81 0x8001 0x7fbf))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfd07 0x6fab 0x0 0x3fc0 0xffff 0x7f80 0x0 0x8040))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x3c20 0xffff 0x7f80 0x0 0x7fc8))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0x5015 0x1 0xc041 0x8001 0x1 0x8001 0xff7f))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0x9055 0x0 0xc040 0x1 0x8080 0x0 0x7fc0))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0xd015 0x0 0xfc60 0x0 0x0 0x0 0xff88))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0x1 0xc3e1 0x8001 0x1 0x8001 0xfff7))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xc040 0x0 0xc3e0 0x1 0x8080 0x0 0x8038))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfd07 0x2feb 0x0 0x3a0 0x0 0x0 0x0 0x78))
+(assert_return (invoke "i16x8.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0x807f 0x7fff 0x8041))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfd07 0xafeb 0xffff 0x3fbf 0x8000 0xffff 0x7fff 0x81))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0x3c1f 0x8000 0xffff 0x7fff 0x9))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x7fff 0x1 0x8000 0x7fff 0x7f81 0x8001 0x7fbf))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfd07 0x6fab 0x0 0x8000 0xffff 0x7f80 0x0 0x7fff))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0xffff 0x7f80 0x0 0x7fc8))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0x5015 0x1 0xc041 0x7fff 0x1 0x8001 0xff7f))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0x9055 0x0 0x7fff 0x1 0x8080 0x0 0x8000))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0xd015 0x0 0xfc60 0x0 0x0 0x0 0xff88))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x7fff 0x1 0xc3e1 0x7fff 0x1 0x8001 0xfff7))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xc040 0x0 0x7fff 0x1 0x8080 0x0 0x8038))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xfd07 0x2feb 0x0 0x3a0 0x0 0x0 0x0 0x78))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x4040 0xffff 0x0 0x8000 0x807f 0x7fff 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xffff 0x3fbf 0x7fff 0xffff 0x7fff 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0x3c1f 0x7fff 0xffff 0x7fff 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x1 0x0 0x0 0x0 0x7fbf))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x3fc0 0x0 0x7f80 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x3c20 0x0 0x7f80 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0x5015 0x0 0x0 0x0 0x0 0x0 0xff7f))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0x9055 0x0 0x0 0x1 0x0 0x0 0x7fc0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xfff7))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x1 0x0 0x0 0x8038))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x3a0 0x0 0x0 0x0 0x78))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x1 0x1 0x0 0x1 0x1 0x1))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x8000 0x0 0x8080 0x0 0x7fc0))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x8000 0x0 0xbfc0 0x8000 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0xbc20 0x8000 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x8000 0x0 0x8080 0x0 0x7fc0))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x1000 0x0 0x0 0x0 0x4000 0x0 0x1000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x3ac0 0x0 0x0 0x0 0x0 0x0 0x2000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x200))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0x0 0xbfc0 0x8000 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3ac0 0x0 0x0 0x0 0x0 0x0 0x2000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xd631 0x21b9 0x0 0x1000 0x1 0x0 0x0 0x4000))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0xf800 0x1 0x0 0x0 0x400))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xbc20 0x8000 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x200))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0xf800 0x1 0x0 0x0 0x400))
+(assert_return (invoke "i16x8.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x400 0x1 0x0 0x0 0x40))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x8000 0xffff 0x8000 0x8000 0xffff 0x0 0x1))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x8000 0xffff 0x4040 0x8000 0xffff 0x0 0xff80))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0x43e0 0x8000 0xffff 0x0 0xfff8))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x8000 0x8000 0xffff 0x0 0x1))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xd015 0x0 0x8000 0x0 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x8000 0x0 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x4040 0x8000 0xffff 0x0 0xff80))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0xd015 0x0 0x8000 0x0 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x43e0 0x8000 0xffff 0x0 0xfff8))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x8000 0x0 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x7fff 0x0 0x7f80 0x0 0x1))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x8000 0x0 0x4040 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x3fc0 0x0 0x7fff 0x0 0x7f80 0x0 0x1))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x4040 0x0 0x0 0x0 0x7fc0))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x0 0x0 0x0 0x7fc0))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0x0 0x4040 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x4040 0x0 0x0 0x0 0x7fc0))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x0 0x0 0x0 0x7fc0))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x7fff 0x0 0x7f80 0x7fff 0x7fc0))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x7fff 0x1 0x0 0x7fff 0x1))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x7fff 0x1 0x0 0x7fff 0x1))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x3fc0 0x0 0x7fff 0x0 0x7f80 0x7fff 0x7fc0))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0x3fc0 0x0 0x4040 0x1 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x43e0 0x1 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0xd015 0x0 0x7fff 0x1 0x0 0x7fff 0x1))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0x3fc0 0x0 0x4040 0x1 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x7fff 0x1 0x0 0x7fff 0x1))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x43e0 0x1 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x8000 0xffff 0x8000 0x8000 0xffff 0x7fff 0x7fc0))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0xffff 0x7fff 0x8000 0xffff 0x7fff 0xff80))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0xfff8))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x8000 0x8000 0xffff 0x7fff 0x7fc0))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x8000 0x1 0x7f80 0x0 0xff80))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x1 0x7f80 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x2f9 0xd015 0xffff 0x7fff 0x8000 0xffff 0x7fff 0xff80))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x8000 0x1 0x7f80 0x0 0xff80))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0xfff8))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x1 0x7f80 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x5fe0 0x8000 0x8000 0x4000 0xbfc0 0x4000 0x3fe1))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x17d 0xa80b 0x8000 0x6020 0x4001 0x8000 0x4000 0x7fc1))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x4000 0x8000 0x61f0 0x4001 0x8000 0x4000 0x7ffd))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x5fe0 0x8000 0x8000 0x4000 0xbfc0 0x4000 0x3fe1))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x17d 0x87eb 0x0 0x6020 0x1 0x3fc0 0x0 0xbfa0))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x1fe0 0x0 0x61f0 0x1 0x3fc0 0x0 0xbfdc))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x17d 0xa80b 0x8000 0x6020 0x4001 0x8000 0x4000 0x7fc1))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x17d 0x87eb 0x0 0x6020 0x1 0x3fc0 0x0 0xbfa0))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x17d 0x680b 0x0 0x4210 0x1 0x0 0x0 0xffbc))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x4000 0x8000 0x61f0 0x4001 0x8000 0x4000 0x7ffd))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x1fe0 0x0 0x61f0 0x1 0x3fc0 0x0 0xbfdc))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x17d 0x680b 0x0 0x4210 0x1 0x0 0x0 0xffbc))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x4000 0x1 0x1 0x1 0x3f01))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0xe080 0x0 0x0 0x0 0xc080))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x1800 0x0 0x0 0xffc0 0x1fc0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x20 0x213d))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0xe080 0x0 0x0 0x0 0xc080))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x1000 0xf81 0x0 0x0 0x0 0x4000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xfac0 0xf430 0x0 0x0 0x0 0xe000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xde80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x1800 0x0 0x0 0xffc0 0x1fc0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0xfac0 0xf430 0x0 0x0 0x0 0xe000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x31 0x4 0x1b9 0x900 0x0 0x0 0x1000 0x1000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xf800 0x10c0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x20 0x213d))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0xde80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xf800 0x10c0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x400 0x1189))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x4000 0x1 0x1 0x1 0x3f01 0x1 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x80 0xff81 0x0 0x0 0xffc0 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xff80 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xfff8 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x80 0xff81 0x0 0x0 0xffc0 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x4000 0x3f01 0x0 0x0 0x1000 0x3f01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x2000 0xff81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x200 0xff81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xff80 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x2000 0xff81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x4000 0x1))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x400 0x1))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xfff8 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x200 0xff81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x400 0x1))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x40 0x1))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x4000 0xfe01 0xfe01 0xfe01 0x3f01))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x1f80 0x0 0x0 0x0 0x3f80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x6800 0x0 0x0 0x3fc0 0x1fc0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xdf20 0x213d))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x1f80 0x0 0x0 0x0 0x3f80))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x9000 0xf81 0x0 0x0 0x0 0x4000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0xfc0 0x3330 0x0 0x0 0x0 0x2000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x2180))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x6800 0x0 0x0 0x3fc0 0x1fc0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0xfc0 0x3330 0x0 0x0 0x0 0x2000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0xf231 0x4 0x1b9 0xa900 0x0 0x0 0x1000 0x1000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x3800 0x10c0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xdf20 0x213d))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x0 0x2180))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x3800 0x10c0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xc400 0x1189))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x4000 0xfe01 0xfe01 0xfe01 0x3f01 0x1 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x7f80 0x7e81 0x0 0x0 0xc0 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xf8 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x7f80 0x7e81 0x0 0x0 0xc0 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x4000 0x3f01 0x0 0x0 0x9000 0x3f01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x6000 0x7e81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xba00 0x7e81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x80 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0x6000 0x7e81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x4000 0xfe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x7c00 0xfe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xf8 0x0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i16x8 0x0 0x0 0x0 0x0 0x0 0x0 0xba00 0x7e81))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0x7c00 0xfe01))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i16x8 0x1 0x0 0x0 0x0 0x0 0x0 0xf040 0xfe01))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x402 0xc0a 0x1412 0x1c1a))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffff8000 0x7ffe 0xffff7fff 0x8000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc0 0xffff8000 0x7f80 0x7fc0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0xffffc004 0x1 0x0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffffd30e 0x4040 0x1 0xffffff80))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xfffffffe 0xfffffffe 0xfffffffe 0xfffffffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0xffffffff 0xffffffff 0x1110 0xffffffff))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x402 0xc0a 0x1412 0x1c1a))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x8000 0x17ffe 0x17fff 0x8000))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc0 0x8000 0x7f80 0x7fc0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0xc004 0x1 0x0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd30e 0x4040 0x1 0xff80))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x1fffe 0x1fffe 0x1fffe 0x1fffe))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0xffff 0xffff 0x11110 0xffff))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0x8000 0x17fff))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0x3ffc0000 0x1 0x0))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x2feafd07 0x40400000 0x1 0x800000))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x7ffe8001 0xffff00 0x123edcc 0x80ff80))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0xfcfdff00 0xf8f9fafc 0xf4f5f6f8 0xf0f1f2f4))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x80000001 0x8000 0xfffe8001))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xc0400000 0x80000000 0x80800000 0x80400000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0x3ffc0000 0xffffffff 0x0))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x2feafd07 0xbfc00000 0xffffffff 0x800000))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x1 0x1 0x1 0x1))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x80017fff 0xff000100 0x123edcc 0x80ff80))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 1))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 5))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 2))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 2))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 9))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 12))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x100 0x302 0x504 0x706))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffff8000 0xffffffff 0x7fff))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x3fc0 0x0 0xffff8000))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0x0 0x0 0xffffc004))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x2f9 0xffffd015 0x0 0x4040))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0xffff8001 0x7ffe 0xffffff00 0xff))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x908 0xb0a 0xd0c 0xf0e))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffff8000 0xffffffff 0x7fff 0x1))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x1 0x0 0x0 0xffffff80))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x1234 0xfffffedc 0x80 0xffffff7f))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x100 0x302 0x504 0x706))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x8000 0xffff 0x7fff))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x3fc0 0x0 0x8000))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0x0 0x0 0xc004))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x2f9 0xd015 0x0 0x4040))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x8001 0x7ffe 0xff00 0xff))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x908 0xb0a 0xd0c 0xf0e))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffff 0xffff 0xffff 0xffff))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x1234 0xfedc 0x80 0xff7f))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i32x4 0x0 0xfffffffe 0xffff0000 0x2fffe))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i32x4 0x0 0x80000000 0x0 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i32x4 0x0 0x80000000 0x0 0x80000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i32x4 0x7f800000 0x0 0xff000000 0xff800000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i32x4 0xa02a05f2 0x80800000 0x2 0xff000000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i32x4 0x80000000 0x0 0x80000000 0x0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i32x4 0x80000000 0x0 0x80000000 0x0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i32x4 0x0 0x87c00000 0x2 0xfff00000))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i32x4 0x0 0x0 0x80000000 0x0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i32x4 0x0 0x0 0x80000000 0x0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i32x4 0xc0000000 0x3fffffff 0xffffc000 0xbfff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i32x4 0xffffffff 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i32x4 0x1fe00000 0xc0000000 0x3fc00000 0x3fe00000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i32x4 0xe80a817c 0x20200000 0x0 0xffc00000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i32x4 0xffffffff 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i32x4 0x0 0x21f00000 0x0 0xfffc0000))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i32x4 0x0 0x0 0x0 0xffffffff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i32x4 0x40000000 0x3fffffff 0x7fffc000 0xbfff))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i32x4 0x1 0x0 0x1 0x0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i32x4 0x1 0x0 0x1 0x0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i32x4 0x1fe00000 0x40000000 0x3fc00000 0x3fe00000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i32x4 0x0 0x1 0x0 0x0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i32x4 0x0 0x1 0x0 0x0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i32x4 0x680a817c 0x20200000 0x0 0x7fc00000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i32x4 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i32x4 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i32x4 0x0 0x21f00000 0x0 0x7ffc0000))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i32x4 0x0 0x0 0x0 0x1))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i32x4 0x0 0x0 0x0 0x1))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xfffffffe 0xffff0000 0x2fffe))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xbfc00000 0xffffffff 0x7f7f8000 0x7fc17fff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x501502f9 0xc03fffff 0xffff8001 0xff817fff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x80000000 0xc3dfffff 0xffff8001 0xfff97fff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xbfc00000 0xffffffff 0x7f7f8000 0x7fc17fff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x7f800000 0x0 0xff000000 0xff800000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xfd502f9 0xc0400000 0x7f800001 0x7f400000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x3fc00000 0xc3e00000 0x7f800001 0x7fb80000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x501502f9 0xc03fffff 0xffff8001 0xff817fff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xfd502f9 0xc0400000 0x7f800001 0x7f400000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xa02a05f2 0x80800000 0x2 0xff000000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xd01502f9 0x84200000 0x2 0xff780000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0xc3dfffff 0xffff8001 0xfff97fff))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0xc3e00000 0x7f800001 0x7fb80000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x84200000 0x2 0xff780000))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x87c00000 0x2 0xfff00000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x40400000 0xffffffff 0x807f8000 0x80417fff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xafeafd07 0x3fbfffff 0xffff7fff 0x817fff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x80000000 0x3c1fffff 0xffff7fff 0x97fff))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xbfc00000 0x1 0x7f808000 0x7fbe8001))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x6faafd07 0x3fc00000 0x7f7fffff 0x80400000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x3fc00000 0x3c200000 0x7f7fffff 0x7fc80000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x501502f9 0xc0400001 0x8001 0xff7e8001))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x905502f9 0xc0400000 0x80800001 0x7fc00000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xd01502f9 0xfc600000 0x0 0xff880000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0xc3e00001 0x8001 0xfff68001))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xc0400000 0xc3e00000 0x80800001 0x80380000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x2feafd07 0x3a00000 0x0 0x780000))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x1 0x40000000 0x3ffd0001))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x80000000 0x0 0x80400000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x80000000 0xbfc00000 0xffff8000 0x800000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xbc200000 0xffff8000 0x80000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x80000000 0x0 0x80400000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x81c00000 0x0 0x7f800000 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x7f800000 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0xbfc00000 0xffff8000 0x800000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x81c00000 0x0 0x7f800000 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x1ce2d631 0x0 0x1 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xbc200000 0xffff8000 0x80000))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x7f800000 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x1 0x0))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x80000000 0x80000000 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x80000000 0x40400000 0xffff8000 0xff800000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x80000000 0x43e00000 0xffff8000 0xfff80000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x80000000 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x80000000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x80000000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x40400000 0xffff8000 0xff800000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xd01502f9 0x80000000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x43e00000 0xffff8000 0xfff80000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x80000000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x7fffffff 0x7f800000 0x17fff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x80000000 0x40400000 0x1 0x17fff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0x17fff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x3fc00000 0x7fffffff 0x7f800000 0x17fff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x3fc00000 0x40400000 0x1 0x7fc00000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0x7fc00000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x40400000 0x1 0x17fff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x40400000 0x1 0x7fc00000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x43e00000 0x1 0x17fff))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x43e00000 0x1 0x7fc00000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x7fffffff 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x7fffffff 0x1 0x17fff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x7fffffff 0x1 0x17fff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x3fc00000 0x7fffffff 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x3fc00000 0x40400000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x3fc00000 0x43e00000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xd01502f9 0x7fffffff 0x1 0x17fff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x40400000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x7fffffff 0x1 0x17fff))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x43e00000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x80000000 0x80000000 0xffff8000 0x7fc00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x7fffffff 0xffff8000 0xff800000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0xfff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x80000000 0xffff8000 0x7fc00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x80000000 0x7f800000 0xff800000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0xfff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xd01502f9 0x7fffffff 0xffff8000 0xff800000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xd01502f9 0x80000000 0x7f800000 0xff800000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xd01502f9 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0xfff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0xfff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x40000000 0x3fff0002 0x40000001 0x3fff0002))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xe0200000 0xc0008000 0xffff8080 0x7fc0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x17f58000 0x201fbfc0 0xffff8000 0xffffff80))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x21efbc20 0xffff8000 0xfffffff8))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xe0200000 0xc0008000 0xffff8080 0x7fc0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xfe01000 0x40000000 0x3f804000 0x3fc01000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xf4113ac0 0xdfe00000 0x0 0xffc02000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0xde100000 0x0 0xfffc0200))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x17f58000 0x201fbfc0 0xffff8000 0xffffff80))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xf4113ac0 0xdfe00000 0x0 0xffc02000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x900f7ea 0x10201000 0x1 0x4000))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x1108f800 0x1 0x400))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x21efbc20 0xffff8000 0xfffffff8))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xde100000 0x0 0xfffc0200))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x1108f800 0x1 0x400))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x11ff0400 0x1 0x40))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x40000000 0x1 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xe0200000 0x0 0xc0008000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x17f58000 0x0 0x201fbfc0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x21efbc20))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xe0200000 0x0 0xc0008000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xfe01000 0x0 0x40000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0xf4113ac0 0x0 0xdfe00000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0xde100000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x17f58000 0x0 0x201fbfc0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xf4113ac0 0x0 0xdfe00000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x8d631 0x8f821b9 0x0 0x10201000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x1108f800))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x21efbc20))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0xde100000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x1108f800))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x11ff0400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x40000000 0x1 0x3fff0001 0x1))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffff8080 0x0 0x7fc0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffff8000 0x0 0x0 0xffffff80))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0xffff8000 0x0 0x0 0xfffffff8))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffff8080 0x0 0x7fc0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x3f804000 0x0 0x3fc01000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0xffc02000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0xfffc0200))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffff8000 0x0 0x0 0xffffff80))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0xffc02000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x1 0x0 0x0 0x4000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x1 0x0 0x0 0x400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffff8000 0x0 0x0 0xfffffff8))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0xfffc0200))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x1 0x0 0x0 0x400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x1 0x0 0x0 0x40))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x40000000 0xfffe0001 0x3fff0001))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x1fe00000 0x0 0x3fff8000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x680a8000 0x0 0x201fbfc0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x21efbc20))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x1fe00000 0x0 0x3fff8000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xfe01000 0x0 0x40000000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x33d13ac0 0x0 0x20200000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x21f00000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x680a8000 0x0 0x201fbfc0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x33d13ac0 0x0 0x20200000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x8d631 0xa92221b9 0x0 0x10201000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x1108f800))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x21efbc20))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x21f00000))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x1108f800))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x11ff0400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x40000000 0xfffe0001 0x3fff0001 0x1))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x7f7f8080 0x0 0x7fc0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x8000 0x0 0x0 0xff80))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x8000 0x0 0x0 0xfff8))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x7f7f8080 0x0 0x7fc0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x3f804000 0x0 0x3fc01000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x0 0x0 0x7f802000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x0 0x0 0x0 0x7fbc0200))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x8000 0x0 0x0 0xff80))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x7f802000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x1 0x0 0x0 0xff004000))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x1 0x0 0x0 0xff780400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x8000 0x0 0x0 0xfff8))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x0 0x0 0x7fbc0200))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x1 0x0 0x0 0xff780400))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i32x4 0x1 0x0 0x0 0xfff00040))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0x706050403020100 0xf0e0d0c0b0a0908))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x7fffffff80000000 0x17fffffff8000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7fffffffc0400000 0x7fc000007f800000))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0x3ffc000000000000 0x1))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x40400000d01502f9 0x7fffffffffffff))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0x1 0x1))
+(assert_return (invoke "i64x2.abs" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0xffff007ffe8001 0x80ff7f0123edcc))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0xf8f9fafbfcfdff00 0xf0f1f2f3f4f5f6f8))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x8000000080000000 0xfffe800000008000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7fffffffc0400000 0x803fffff80800000))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0x3ffc000000000000 0xffffffffffffffff))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xbfbfffff2feafd07 0x7fffffffffffff))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0x1 0x1))
+(assert_return (invoke "i64x2.neg" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0xff0000ff80017fff 0x80ff7f0123edcc))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 2))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0x3020100 0x7060504))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffff80000000 0x7fffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x3fc00000 0xffffffff80000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0x0 0xffffffffc0040000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffd01502f9 0x40400000))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0x7ffe8001 0xffff00))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0xb0a0908 0xf0e0d0c))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffff8000 0x17fff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f800000 0x7fc00000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0x1 0x0))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x1 0xffffffffff800000))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0xfffffffffedc1234 0xffffffffff7f0080))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0x3020100 0x7060504))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x80000000 0x7fffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x3fc00000 0x80000000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0x0 0xc0040000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xd01502f9 0x40400000))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0x7ffe8001 0xffff00))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0xb0a0908 0xf0e0d0c))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffff8000 0x17fff))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f800000 0x7fc00000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0x1 0x0))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x1 0xff800000))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0xffffffff 0xffffffff))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0xfedc1234 0xff7f0080))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i64x2 0x7fffffff80000000 0x17fffffff8000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i64x2 0xffffffff00000000 0x2ffffffff0000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i64x2 0x800000003fc00000 0x7fc000007f800000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i64x2 0x7f800000 0xff800000ff000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i64x2 0x40400000d01502f9 0xff80000000000001))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i64x2 0x80800001a02a05f2 0xff00000000000002))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i64x2 0x8000000000000000 0x8000000000000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i64x2 0x8000000000000000 0x8000000000000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i64x2 0x43e0000000000000 0xfff8000000000001))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i64x2 0x87c0000000000000 0xfff0000000000002))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i64x2 0x0 0x8000000000000000))
+(assert_return (invoke "i64x2.shl" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i64x2 0x0 0x8000000000000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i64x2 0x7fffffff80000000 0x17fffffff8000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i64x2 0x3fffffffc0000000 0xbfffffffc000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i64x2 0x800000003fc00000 0x7fc000007f800000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i64x2 0xc00000001fe00000 0x3fe000003fc00000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i64x2 0xffffffffffffffff 0x0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i64x2 0x40400000d01502f9 0xff80000000000001))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i64x2 0x20200000680a817c 0xffc0000000000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i64x2 0x43e0000000000000 0xfff8000000000001))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i64x2 0x21f0000000000000 0xfffc000000000000))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.shr_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i64x2 0x0 0xffffffffffffffff))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i64x2 0x7fffffff80000000 0x17fffffff8000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i64x2 0x3fffffffc0000000 0xbfffffffc000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i64x2 0x800000003fc00000 0x7fc000007f800000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i64x2 0x400000001fe00000 0x3fe000003fc00000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i64x2 0x1 0x0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i64x2 0x1 0x0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i64x2 0x40400000d01502f9 0xff80000000000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i64x2 0x20200000680a817c 0x7fc0000000000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i64x2 0x0 0x1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i64x2 0x0 0x1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i64x2 0x43e0000000000000 0xfff8000000000001))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i64x2 0x21f0000000000000 0x7ffc000000000000))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i64x2 0x0 0x1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i64x2 0x0 0x1))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffff00000000 0x2ffffffff0000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffffbfc00000 0x7fc180007f7f8000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xc0400000501502f9 0xff817fffffff8001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xc3dfffff80000000 0xfff97fffffff8001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffbfc00000 0x7fc180007f7f8000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f800000 0xff800000ff000000))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xc04000010fd502f9 0x7f4000007f800001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xc3e000003fc00000 0x7fb800007f800001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xc0400000501502f9 0xff817fffffff8001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xc04000010fd502f9 0x7f4000007f800001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x80800001a02a05f2 0xff00000000000002))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x84200000d01502f9 0xff78000000000002))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xc3dfffff80000000 0xfff97fffffff8001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xc3e000003fc00000 0x7fb800007f800001))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x84200000d01502f9 0xff78000000000002))
+(assert_return (invoke "i64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x87c0000000000000 0xfff0000000000002))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffffff40400000 0x80417fff807f8000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x3fbffffeafeafd07 0x817fffffff7fff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x3c1fffff80000000 0x97fffffff7fff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xbfc00000 0x7fbe80007f808000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x3fbfffff6faafd07 0x804000007f7fffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x3c2000003fc00000 0x7fc800007f7fffff))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xc0400001501502f9 0xff7e800000008001))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xc0400000905502f9 0x7fbfffff80800001))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xfc600000d01502f9 0xff88000000000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xc3e0000080000000 0xfff6800000008001))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xc3dfffffc0400000 0x8037ffff80800001))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x39fffff2feafd07 0x78000000000000))
+(assert_return (invoke "i64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x4000000000000000 0x8000000040000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xe020000000000000 0xffffc04000000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x17f57e8380000000 0x17fffffff8000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x17fffffff8000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xe020000000000000 0xffffc04000000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xfe0100000000000 0x3f80400000000000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xb3d13b7d81c00000 0x7fc000007f800000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x7fc000007f800000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x17f57e8380000000 0x17fffffff8000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xb3d13b7d81c00000 0x7fc000007f800000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xa5a2268e1ce2d631 0xff00000000000001))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xc4e0000000000000 0xff78000000000001))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x17fffffff8000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x7fc000007f800000))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xc4e0000000000000 0xff78000000000001))
+(assert_return (invoke "i64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0xfff0000000000001))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x4000000000000000 0x3fffffff00000001))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xe020000000000000 0xc000000080000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x17f57e8380000000 0x201fffffbfc00000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x21efffffbc200000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xe020000000000000 0xc000000080000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xfe0100000000000 0x4000000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xf4113b7d81c00000 0xdfe0000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0xde10000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x17f57e8380000000 0x201fffffbfc00000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xf4113b7d81c00000 0xdfe0000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x8f8209c1ce2d631 0x1020100000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x1108f80000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x21efffffbc200000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0xde10000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x1108f80000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x11ff040000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x40000000 0x23ffd0001))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xffffc04000000000 0xbf9f80400000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffffffffffff8000 0xffffff4000800000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffffffffffff8000 0xfffffff400080000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffc04000000000 0xbf9f80400000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x3f80400000000000 0x3fc0100000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x7f800000 0xffc0200000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x7f800000 0xfffc020000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffff8000 0xffffff4000800000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f800000 0xffc0200000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x1 0x400000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x1 0x40000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffffffff8000 0xfffffff400080000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f800000 0xfffc020000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x1 0x40000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x1 0x4000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x4000000000000000 0x3fffffff00000001))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x1fe0000000000000 0x3fffffff80000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x680a817c80000000 0x201fffffbfc00000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x21efffffbc200000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x1fe0000000000000 0x3fffffff80000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0xfe0100000000000 0x4000000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x33d13b7d81c00000 0x2020000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x21f0000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x680a817c80000000 0x201fffffbfc00000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x33d13b7d81c00000 0x2020000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xa922268e1ce2d631 0x1020100000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x1108f80000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x0 0x21efffffbc200000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x0 0x21f0000000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x0 0x1108f80000000000))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x0 0x11ff040000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffff000040000000 0x23ffd0001))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f7fc04000000000 0xbf9f80400000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xffff8000 0x17f3f00800000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0xffff8000 0x17ff300080000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x7f7fc04000000000 0xbf9f80400000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x3f80400000000000 0x3fc0100000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x7f800000 0x7f80200000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x7f800000 0x7fbc020000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffff8000 0x17f3f00800000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f800000 0x7f80200000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x1 0xff00400000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x1 0xff78040000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffff8000 0x17ff300080000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x7f800000 0x7fbc020000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x1 0xff78040000000000))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const i64x2 0x1 0xfff0004000000000))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1p+1 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 0x0.0p+0 -0x1p+1 0x1p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p+0 -inf))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.ceil" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 nan:arithmetic 0x1p+0 -0x1.b824680000000p+126 -0x1.fe01000000000p+127))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 0x0.0p+0 -0x1.8000000000000p+1 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x0.0p+0 -inf))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.floor" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 -0x1.b824680000000p+126 -0x1.fe01000000000p+127))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 0x0.0p+0 -0x1p+1 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x0.0p+0 -inf))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.trunc" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 -0x1.b824680000000p+126 -0x1.fe01000000000p+127))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x0))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x40000000 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0xc0000000 0x0 0x0))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd01502f9 0x40400000 0x0 0xff800000))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff))
+(assert_return (invoke "f32x4.nearest" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x7ffe8001 0x0 0xfedc1234 0xff7f0080))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x1.0402000000000p-121 0x1.0c0a080000000p-113 0x1.1412100000000p-105 0x1.1c1a180000000p-97))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 nan:0x7fffff nan:0x7f8000 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 0x0.0p+0 inf nan:0x400000))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 0x0.0p+0 0x1.0800000000000p+1 0x1p-149 0x0.0p+0))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 inf))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:0x7fffff nan:0x7fffff nan:0x7fffff nan:0x7fffff))
+(assert_return (invoke "f32x4.abs" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 nan:0x7e8001 0x1.fffe000000000p-126 0x1.b824680000000p+126 0x1.fe01000000000p+127))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x83020100 0x87060504 0x8b0a0908 0x8f0e0d0c))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0xffffffff 0x7fff8000 0x80017fff))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xbfc00000 0x0 0xff800000 0xffc00000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x80000000 0x40040000 0x80000001 0x80000000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x501502f9 0xc0400000 0x80000001 0x7f800000))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x7fffffff 0x7fffffff 0x7fffffff 0x7fffffff))
+(assert_return (invoke "f32x4.neg" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0xfffe8001 0x80ffff00 0x7edc1234 0x7f7f0080))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x1.6cdc920000000p-61 0x1.7274300000000p-57 0x1.77f6820000000p-53 0x1.7d64760000000p-49))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.bb671a0000000p-67))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.3988e20000000p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 0x0.0p+0 nan:arithmetic 0x1.6a09e60000000p-75 0x0.0p+0))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 nan:arithmetic 0x1.bb67ae0000000p+0 0x1.6a09e60000000p-75 nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sqrt" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 nan:arithmetic 0x1.6a09320000000p-63 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-132))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x1.8000000000000p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+1 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 inf nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.c000000000000p+8 inf nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x1.2a05f20000000p+33 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 inf nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+34 0x1.8000000000000p+2 0x1p-148 -inf))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.c300000000000p+8 0x1p-148 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.c000000000000p+8 inf nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.c300000000000p+8 0x1p-148 nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+9 0x1p-148 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.8000000000000p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.2a05f20000000p+33 nan:arithmetic nan:arithmetic inf))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x1.8000000000000p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.2a05f20000000p+33 -0x1.8000000000000p+1 inf nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x1.8000000000000p+0 -0x1.c000000000000p+8 inf nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x1.2a05f20000000p+33 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 -inf nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x1.2a05f20000000p+33 -0x1.bd00000000000p+8 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.8000000000000p+0 0x1.c000000000000p+8 -inf nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.2a05f20000000p+33 0x1.bd00000000000p+8 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.2000000000000p+1 0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.bf08ec0000000p+33 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.bf08ec0000000p+33 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.5af1d80000000p+66 0x1.2000000000000p+3 0x0.0p+0 inf))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x0.0p+0 0x1.5000000000000p+10 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x0.0p+0 0x1.5000000000000p+10 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.8800000000000p+17 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic 0x1p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -inf nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.49da7e0000000p-33 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 inf -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 inf nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.8d5d420000000p+32 -inf 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -inf 0x1.b6db6e0000000p-8 0x1p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x0.0p+0 -inf 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x0.0p+0 0x1.2aaaaa0000000p+7 0x1p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 nan:arithmetic 0x1p+0 0x1p+0 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 -0x0.0p+0 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x1.2a05f20000000p+33 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.2a05f20000000p+33 -0x0.0p+0 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x1.8000000000000p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.8000000000000p+1 inf nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.c000000000000p+8 inf nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.8000000000000p+1 inf nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.c000000000000p+8 inf nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 nan:arithmetic nan:arithmetic -inf))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 -0x0.0p+0 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x1.2a05f20000000p+33 -0x0.0p+0 0x1p-149 -inf))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.8000000000000p+1 inf nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.c000000000000p+8 inf nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x0.0p+0 0x1.8000000000000p+1 0x1p-149 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.8000000000000p+1 inf -inf))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 -inf))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.8000000000000p+0 0x1.c000000000000p+8 inf nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x1p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.0p+0 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 -0x1p+1 0x1p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.0800000000000p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.ceil" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 0x1p+0 -0x1.f0080fedc1234p+1016))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x1p+0 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 -0x1.8000000000000p+1 0x0.0p+0))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.floor" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 0x0.0p+0 -0x1.f0080fedc1234p+1016))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.0p+0 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 -0x1p+1 0x0.0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.trunc" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 0x0.0p+0 -0x1.f0080fedc1234p+1016))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0x0 0x0))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0x7fffffff80000000 0x0))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x8000000000000000 0x7fc000007f800000))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0xc000000000000000 0x0))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0x4040000000000000 0xff80000000000001))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0xffffffffffffffff 0xffffffffffffffff))
+(assert_return (invoke "f64x2.nearest" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0x0 0xff7f0080fedc1234))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x1.6050403020100p-911 0x1.e0d0c0b0a0908p-783))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:0xfffff80000000 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x0.000003fc00000p-1022 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 0x1.4000000000000p+1 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:0xfffffffffffff nan:0xfffffffffffff))
+(assert_return (invoke "f64x2.abs" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 0x1.fff007ffe8001p-1008 0x1.f0080fedc1234p+1016))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i64x2 0x8706050403020100 0x8f0e0d0c0b0a0908))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i64x2 0xffffffff80000000 0x80017fffffff8000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i64x2 0x3fc00000 0xffc000007f800000))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i64x2 0x4004000000000000 0x8000000000000001))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i64x2 0xc0400000d01502f9 0x7f80000000000001))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i64x2 0x7fffffffffffffff 0x7fffffffffffffff))
+(assert_return (invoke "f64x2.neg" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i64x2 0x80ffff007ffe8001 0x7f7f0080fedc1234))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x1.a8b78b1a971a0p-456 0x1.f029ac065fde6p-392))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x1.3988e1405dd17p-513))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x1.6a09ec0a73239p+510))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 nan:arithmetic 0x1p-537))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.6a09ef9a21b0ap+2 nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sqrt" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 0x1.6a044109203ecp-504 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.2ffffffff0000p-1022))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000007f800000p-1022 0x1.000007f800000p+1022))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1.e0000ff000000p+1020))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1.e0000ff000000p+1020))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+6 -0x1.0000000000001p+1018))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+64 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic -0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic 0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 -0x1.00000d01502f9p+5 0x1.100007f800000p+1021))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 -0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.100007f800000p+1021))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 -0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x1.80000bf380000p-5))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic -0x1.7fffffff80001p-9))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x1.80000bf380000p-5))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x0.0p+0 inf))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 -0x0.00007f80067a2p-1022 -inf))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 -0x1.fe00000000000p-982 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic -0x1.7fffffff80001p-9))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.00007f80067a2p-1022 -inf))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00001a02a1084p+10 inf))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1.00000d01502f9p+68 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x1.fe00000000000p-982 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+68 nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+126 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic inf))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 -0x0.0000001fdffe6p-1022 -0x1.000007f7fffffp+4))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -inf -0x1.fffff010007f2p-5))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1.00000d01502f9p-58 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -inf nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.ffffe5fd60f32p+57 nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 -0x0.000003fc00000p-1022 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 -0x0.000003fc00000p-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1.00000d01502f9p+5 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 nan:arithmetic))
+(assert_return (invoke "f64x2.min" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.max" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 -0x0.000003fc00000p-1022 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 nan:arithmetic))
+(assert_return (invoke "f64x2.pmin" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 nan:arithmetic 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 nan:arithmetic 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 0x1.00000d01502f9p+5 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "f64x2.pmax" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000)) (v128.const f64x2 0x1p+63 nan:arithmetic))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x1 0x0 0x7fffffff 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0xfffffffe 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x80000000 0x3 0x0 0x80000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x0 0x0 0x80000000 0x80000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x1 0x0 0xffffffff 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x0 0x3 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x1.8100800000000p+25 0x1.c181400000000p+26 0x1.6141200000000p+27 0x1.e1c1a20000000p+27))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 -0x1p+31 0x1p+31 -0x1p+15 0x1.7fff000000000p+16))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.fe00000000000p+29 -0x1p+31 0x1.fe00000000000p+30 0x1.ff00000000000p+30))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 0x0.0p+0 -0x1.ffe0000000000p+29 0x1p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 -0x1.7f57e80000000p+29 0x1.0100000000000p+30 0x1p+0 -0x1p+23))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 0x1.fffa000000000p+30 0x1.fffe000000000p+23 -0x1.23edcc0000000p+24 -0x1.01ff000000000p+23))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x1.8100800000000p+25 0x1.c181400000000p+26 0x1.6141200000000p+27 0x1.e1c1a20000000p+27))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 0x1p+31 0x1p+31 0x1.ffff000000000p+31 0x1.7fff000000000p+16))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 0x1.fe00000000000p+29 0x1p+31 0x1.fe00000000000p+30 0x1.ff00000000000p+30))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 0x0.0p+0 0x1.8008000000000p+31 0x1p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.a02a060000000p+31 0x1.0100000000000p+30 0x1p+0 0x1.ff00000000000p+31))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 0x1p+32 0x1p+32 0x1p+32 0x1p+32))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 0x1.fffa000000000p+30 0x1.fffe000000000p+23 0x1.fdb8240000000p+31 0x1.fefe000000000p+31))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0x7fffffff 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0xfffffffe 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x20 0x80000000 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x0 0x80000000 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x0 0xffffffff 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x20 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const i32x4 0x0 0x0 0x0 0x0))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x1.8100800000000p+25 0x1.c181410000000p+26))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 -0x1p+31 0x1.fffffffc00000p+30))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1.fe00000000000p+29 -0x1p+31))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 0x0.0p+0 -0x1.ffe0000000000p+29))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 -0x1.7f57e83800000p+29 0x1.0100000000000p+30))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 0x1.fffa000400000p+30 0x1.fffe000000000p+23))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x1.8100800000000p+25 0x1.c181410000000p+26))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 0x1p+31 0x1.fffffffc00000p+30))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1.fe00000000000p+29 0x1p+31))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 0x0.0p+0 0x1.8008000000000p+31))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 0x1.a02a05f200000p+31 0x1.0100000000000p+30))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 0x1.fffffffe00000p+31 0x1.fffffffe00000p+31))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 0x1.fffa000400000p+30 0x1.fffe000000000p+23))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f32x4 0x0.0p+0 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f32x4 nan:arithmetic 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f32x4 -0x0.0p+0 inf 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f32x4 -0x1.4000000000000p+1 0x0.0p+0 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f32x4 0x1.00000e0000000p+5 -inf 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f32x4 0x0.0p+0 -inf 0x0.0p+0 0x0.0p+0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (v128.const f64x2 0x1.0402000000000p-121 0x1.0c0a080000000p-113))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const f64x2 0x1.8000000000000p+0 -0x0.0p+0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (v128.const f64x2 0x0.0p+0 -0x1.0800000000000p+1))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const f64x2 -0x1.2a05f20000000p+33 0x1.8000000000000p+1))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (v128.const f64x2 nan:arithmetic 0x1.fffe000000000p-126))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const -7))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 15))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_s 15" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const -1))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 249))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 1))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 15))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 127))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 255))
+(assert_return (invoke "i8x16.extract_lane_u 15" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 255))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i8x16 0x1 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i8x16 0xff 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i8x16 0xff 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i8x16 0x1 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i8x16 0xff 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i8x16 0xff 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x7f))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i8x16 0x0 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i8x16 0x1 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i8x16 0xff 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i8x16 0xff 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i8x16 0x1 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i8x16 0xff 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i8x16 0xff 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x0))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0x1))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x80 0xff 0xff 0xff 0x7f 0x0 0x80 0xff 0xff 0xff 0x7f 0x1 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x0))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0x1))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0xc0 0x3f 0x0 0x0 0x0 0x80 0x0 0x0 0x80 0x7f 0x0 0x0 0xc0 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0x0))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0x1))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i8x16 0xf9 0x2 0x15 0xd0 0x0 0x0 0x40 0x40 0x1 0x0 0x0 0x0 0x0 0x0 0x80 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0x0))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0x1))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i8x16.replace_lane 15" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i8x16 0x0 0x0 0x0 0x0 0x0 0x0 0xe0 0x43 0x1 0x0 0x0 0x0 0x0 0x0 0xf8 0xff))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 256))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 761))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const -32767))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 3854))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 32704))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const -128))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i16x8.extract_lane_s 7" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const -129))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 256))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 761))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 32769))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 3854))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 1))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 32704))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const 65408))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const 65535))
+(assert_return (invoke "i16x8.extract_lane_u 7" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 65407))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i16x8 0x1 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i16x8 0xffff 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i16x8 0xffff 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i16x8 0x1 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i16x8 0xffff 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i16x8 0xffff 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x7fc0))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i16x8 0x0 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i16x8 0x1 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i16x8 0xffff 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i16x8 0xffff 0xd015 0x0 0x4040 0x1 0x0 0x0 0xff80))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i16x8 0x1 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i16x8 0xffff 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i16x8 0xffff 0x0 0x0 0x43e0 0x1 0x0 0x0 0xfff8))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x0))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0x1))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0xffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i16x8 0x0 0x8000 0xffff 0x7fff 0x8000 0xffff 0x7fff 0xffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x0))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0x1))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0xffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x3fc0 0x0 0x8000 0x0 0x7f80 0x0 0xffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i16x8 0x2f9 0xd015 0x0 0x4040 0x1 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0x0))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0x1))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xffff))
+(assert_return (invoke "i16x8.replace_lane 7" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i16x8 0x0 0x0 0x0 0x43e0 0x1 0x0 0x0 0xffff))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 50462976))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const -2147483648))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 1069547520))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const -803929351))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const 2147385345))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i32.const 252579084))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i32.const 98303))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i32.const 2143289344))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i32.const 0))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i32.const -8388608))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i32.const -1))
+(assert_return (invoke "i32x4.extract_lane 3" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i32.const -8454016))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i32x4 0x0 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i32x4 0x1 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i32x4 0xffffffff 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x7fffffff 0xffff8000 0x17fff))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i32x4 0x0 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i32x4 0x1 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x80000000 0x7f800000 0x7fc00000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i32x4 0x0 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i32x4 0x1 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x40400000 0x1 0xff800000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i32x4 0x1 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i32x4 0xffffffff 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i32x4 0x7fffffff 0x43e00000 0x1 0xfff80000))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 0)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x0))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 1)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x1))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const -1)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i32.const 2147483647)) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x7fffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 0)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x0))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 1)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x1))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const -1)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i32.const 2147483647)) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 0)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0x0))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 1)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0x1))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const -1)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i32.const 2147483647)) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0x7fffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 0)) (v128.const i32x4 0x0 0x43e00000 0x1 0x0))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 1)) (v128.const i32x4 0x0 0x43e00000 0x1 0x1))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const -1)) (v128.const i32x4 0x0 0x43e00000 0x1 0xffffffff))
+(assert_return (invoke "i32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i32.const 2147483647)) (v128.const i32x4 0x0 0x43e00000 0x1 0x7fffffff))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i64.const 506097522914230528))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i64.const 9223372034707292160))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i64.const -9223372035785228288))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i64.const -4610560118520545280))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i64.const 4629700420427907833))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i64.const -1))
+(assert_return (invoke "i64x2.extract_lane 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i64.const 72056496673685505))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (i64.const 1084818905618843912))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (i64.const 422212465033216))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (i64.const 9205357640484388864))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (i64.const 1))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (i64.const -36028797018963967))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (i64.const -1))
+(assert_return (invoke "i64x2.extract_lane 1" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (i64.const -36309717964025292))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const 0)) (v128.const i64x2 0x0 0x17fffffff8000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const 1)) (v128.const i64x2 0x1 0x17fffffff8000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const -1)) (v128.const i64x2 0xffffffffffffffff 0x17fffffff8000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const 9223372036854775807)) (v128.const i64x2 0x7fffffffffffffff 0x17fffffff8000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const 0)) (v128.const i64x2 0x0 0x7fc000007f800000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const 1)) (v128.const i64x2 0x1 0x7fc000007f800000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const -1)) (v128.const i64x2 0xffffffffffffffff 0x7fc000007f800000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const 9223372036854775807)) (v128.const i64x2 0x7fffffffffffffff 0x7fc000007f800000))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const 0)) (v128.const i64x2 0x0 0xff80000000000001))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const 1)) (v128.const i64x2 0x1 0xff80000000000001))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const -1)) (v128.const i64x2 0xffffffffffffffff 0xff80000000000001))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const 9223372036854775807)) (v128.const i64x2 0x7fffffffffffffff 0xff80000000000001))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const 0)) (v128.const i64x2 0x0 0xfff8000000000001))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const 1)) (v128.const i64x2 0x1 0xfff8000000000001))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const -1)) (v128.const i64x2 0xffffffffffffffff 0xfff8000000000001))
+(assert_return (invoke "i64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const 9223372036854775807)) (v128.const i64x2 0x7fffffffffffffff 0xfff8000000000001))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const 0)) (v128.const i64x2 0x7fffffff80000000 0x0))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const 1)) (v128.const i64x2 0x7fffffff80000000 0x1))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const -1)) (v128.const i64x2 0x7fffffff80000000 0xffffffffffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (i64.const 9223372036854775807)) (v128.const i64x2 0x7fffffff80000000 0x7fffffffffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const 0)) (v128.const i64x2 0x800000003fc00000 0x0))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const 1)) (v128.const i64x2 0x800000003fc00000 0x1))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const -1)) (v128.const i64x2 0x800000003fc00000 0xffffffffffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (i64.const 9223372036854775807)) (v128.const i64x2 0x800000003fc00000 0x7fffffffffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const 0)) (v128.const i64x2 0x40400000d01502f9 0x0))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const 1)) (v128.const i64x2 0x40400000d01502f9 0x1))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const -1)) (v128.const i64x2 0x40400000d01502f9 0xffffffffffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (i64.const 9223372036854775807)) (v128.const i64x2 0x40400000d01502f9 0x7fffffffffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const 0)) (v128.const i64x2 0x43e0000000000000 0x0))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const 1)) (v128.const i64x2 0x43e0000000000000 0x1))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const -1)) (v128.const i64x2 0x43e0000000000000 0xffffffffffffffff))
+(assert_return (invoke "i64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (i64.const 9223372036854775807)) (v128.const i64x2 0x43e0000000000000 0x7fffffffffffffff))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (f32.const 0x1.0402000000000p-121))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (f32.const -0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (f32.const 0x1.8000000000000p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (f32.const -0x1.2a05f20000000p+33))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (f32.const 0x1.1c1a180000000p-97))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (f32.const 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (f32.const 0x0.0p+0))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (f32.const -inf))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f32.const nan:arithmetic))
+(assert_return (invoke "f32x4.extract_lane 3" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (f32.const -0x1.fe01000000000p+127))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const 0x0.0p+0)) (v128.const f32x4 0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const -0x0.0p+0)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const 0x1p+0)) (v128.const f32x4 0x1p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 -0x1.8000000000000p+0 nan:arithmetic nan:arithmetic 0x1.7fff000000000p-133))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const 0x0.0p+0)) (v128.const f32x4 0x0.0p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const -0x0.0p+0)) (v128.const f32x4 -0x0.0p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const 0x1p+0)) (v128.const f32x4 0x1p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 -0x1.8000000000000p+0 -0x0.0p+0 inf nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const 0x0.0p+0)) (v128.const f32x4 0x0.0p+0 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const -0x0.0p+0)) (v128.const f32x4 -0x0.0p+0 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const 0x1p+0)) (v128.const f32x4 0x1p+0 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 -0x1.8000000000000p+0 0x1.8000000000000p+1 0x1p-149 -inf))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const 0x0.0p+0)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const -0x0.0p+0)) (v128.const f32x4 -0x0.0p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const 0x1p+0)) (v128.const f32x4 0x1p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 -0x1.8000000000000p+0 0x1.c000000000000p+8 0x1p-149 nan:arithmetic))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const 0x0.0p+0)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const -0x0.0p+0)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const 0x1p+0)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic 0x1p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 -0x0.0p+0 nan:arithmetic nan:arithmetic -0x1.8000000000000p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const 0x0.0p+0)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf 0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const -0x0.0p+0)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf -0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const 0x1p+0)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf 0x1p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 0x1.8000000000000p+0 -0x0.0p+0 inf -0x1.8000000000000p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const 0x0.0p+0)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const -0x0.0p+0)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const 0x1p+0)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 0x1p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 -0x1.2a05f20000000p+33 0x1.8000000000000p+1 0x1p-149 -0x1.8000000000000p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const 0x0.0p+0)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const -0x0.0p+0)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 -0x0.0p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const 0x1p+0)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 0x1p+0))
+(assert_return (invoke "f32x4.replace_lane 3" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f32.const -0x1.8000000000000p+0)) (v128.const f32x4 0x0.0p+0 0x1.c000000000000p+8 0x1p-149 -0x1.8000000000000p+0))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (f64.const 0x1.6050403020100p-911))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (f64.const -0x0.000003fc00000p-1022))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (f64.const -0x1.4000000000000p+1))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (f64.const 0x1.00000d01502f9p+5))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 0" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (f64.const 0x1.fff007ffe8001p-1008))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x3020100 0x7060504 0xb0a0908 0xf0e0d0c)) (f64.const 0x1.e0d0c0b0a0908p-783))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (f64.const 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (f64.const 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x0 0xc0040000 0x1 0x0)) (f64.const 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (f64.const -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0xffffffff 0xffffffff 0xffffffff 0xffffffff)) (f64.const nan:arithmetic))
+(assert_return (invoke "f64x2.extract_lane 1" (v128.const i32x4 0x7ffe8001 0xffff00 0xfedc1234 0xff7f0080)) (f64.const -0x1.f0080fedc1234p+1016))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const 0x0.0p+0)) (v128.const f64x2 0x0.0p+0 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const -0x0.0p+0)) (v128.const f64x2 -0x0.0p+0 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const 0x1p+0)) (v128.const f64x2 0x1p+0 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 -0x1.8000000000000p+0 0x0.17fffffff8000p-1022))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const 0x0.0p+0)) (v128.const f64x2 0x0.0p+0 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const -0x0.0p+0)) (v128.const f64x2 -0x0.0p+0 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const 0x1p+0)) (v128.const f64x2 0x1p+0 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 -0x1.8000000000000p+0 0x1.000007f800000p+1021))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const 0x0.0p+0)) (v128.const f64x2 0x0.0p+0 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const -0x0.0p+0)) (v128.const f64x2 -0x0.0p+0 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const 0x1p+0)) (v128.const f64x2 0x1p+0 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 -0x1.8000000000000p+0 -0x1.0000000000001p+1017))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const 0x0.0p+0)) (v128.const f64x2 0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const -0x0.0p+0)) (v128.const f64x2 -0x0.0p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const 0x1p+0)) (v128.const f64x2 0x1p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.replace_lane 0" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 -0x1.8000000000000p+0 nan:arithmetic))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const 0x0.0p+0)) (v128.const f64x2 nan:arithmetic 0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const -0x0.0p+0)) (v128.const f64x2 nan:arithmetic -0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const 0x1p+0)) (v128.const f64x2 nan:arithmetic 0x1p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 nan:arithmetic -0x1.8000000000000p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const 0x0.0p+0)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const -0x0.0p+0)) (v128.const f64x2 -0x0.000003fc00000p-1022 -0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const 0x1p+0)) (v128.const f64x2 -0x0.000003fc00000p-1022 0x1p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 -0x0.000003fc00000p-1022 -0x1.8000000000000p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const 0x0.0p+0)) (v128.const f64x2 0x1.00000d01502f9p+5 0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const -0x0.0p+0)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const 0x1p+0)) (v128.const f64x2 0x1.00000d01502f9p+5 0x1p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 0x1.00000d01502f9p+5 -0x1.8000000000000p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const 0x0.0p+0)) (v128.const f64x2 0x1p+63 0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const -0x0.0p+0)) (v128.const f64x2 0x1p+63 -0x0.0p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const 0x1p+0)) (v128.const f64x2 0x1p+63 0x1p+0))
+(assert_return (invoke "f64x2.replace_lane 1" (v128.const i32x4 0x0 0x43e00000 0x1 0xfff80000) (f64.const -0x1.8000000000000p+0)) (v128.const f64x2 0x1p+63 -0x1.8000000000000p+0))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xffff0000 0x1ffff00 0xffff0000 0x1ffff00))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xffff0000 0x1ffff00 0xc000 0xc0008000))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xffff0000 0x1ffff00 0x400015f9 0x80000001))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xc000 0xc0008000 0xffff0000 0x1ffff00))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xc000 0xc0008000 0xc000 0xc0008000))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xc000 0xc0008000 0x400015f9 0x80000001))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x400015f9 0x80000001 0xffff0000 0x1ffff00))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x400015f9 0x80000001 0xc000 0xc0008000))
+(assert_return (invoke "i8x16.shuffle 0" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x400015f9 0x80000001 0x400015f9 0x80000001))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x80000000 0x1ff0000 0xffff8000 0xff800000))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x8000007f 0xc0ff0000 0xffff8000 0xff800000))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x800200ff 0x80fff9f9 0xffff8000 0xff800000))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0x3f000000 0x1000000 0x7f800000 0x3fc000))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0x3f00007f 0xc0000000 0x7f800000 0x3fc000))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0x3f0200ff 0x8000f9f9 0x7f800000 0x3fc000))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x80000000 0x7fffffff 0xffff8000 0x17fff)) (v128.const i32x4 0xd000f900 0x1000000 0x1 0xd01502))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0x3fc00000 0x80000000 0x7f800000 0x7fc00000)) (v128.const i32x4 0xd000f97f 0xc0000000 0x1 0xd01502))
+(assert_return (invoke "i8x16.shuffle 1" (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000) (v128.const i32x4 0xd01502f9 0x40400000 0x1 0xff800000)) (v128.const i32x4 0xd002f9ff 0x8000f9f9 0x1 0xd01502))
@@ -0,0 +1,495 @@
+;; Modules that decode but don't validate, and valid modules that exercise
+;; the corners of the type checker. Modules are in binary form, each
+;; preceded by a comment with its text format; V8 agrees on which are valid.
+
+;; (func (result i32) (i64.const 0))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01\7f"
+    "\03\02\01\00"
+    "\0a\06\01\04\00B\00\0b")
+  "type mismatch")
+
+;; (func (i32.add))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\05\01\03\00j\0b")
+  "type mismatch")
+
+;; (func (i32.const 1))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\06\01\04\00A\01\0b")
+  "type mismatch")
+
+;; (func (result i32) (block (result i32)) )
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01\7f"
+    "\03\02\01\00"
+    "\0a\07\01\05\00\02\7f\0b\0b")
+  "type mismatch")
+
+;; (func (result i32) (if (result i32) (i32.const 0) (then (i32.const 1))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01\7f"
+    "\03\02\01\00"
+    "\0a\0b\01\09\00A\00\04\7fA\01\0b\0b")
+  "type mismatch")
+
+;; (func (result i32) (i32.const 0) (if (result i32) (then (i32.const 1)) (else (i64.const 1))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01\7f"
+    "\03\02\01\00"
+    "\0a\0e\01\0c\00A\00\04\7fA\01\05B\01\0b\0b")
+  "type mismatch")
+
+;; (func (result i32) (block (result f32) (br 0 (i32.const 1))) (drop) (i32.const 0))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01\7f"
+    "\03\02\01\00"
+    "\0a\0e\01\0c\00\02}A\01\0c\00\0b\1aA\00\0b")
+  "type mismatch")
+
+;; (func (block (result i32) (block (br_table 0 1 (i32.const 0) (i32.const 0)))) (drop))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\15\01\13\00\02\7f\02@A\00A\00\0e\01\00\01\0bA\00\0b\1a\0b")
+  "type mismatch")
+
+;; (func (result i32) (return (i64.const 1)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01\7f"
+    "\03\02\01\00"
+    "\0a\07\01\05\00B\01\0f\0b")
+  "type mismatch")
+
+;; (func (local.get 0) (drop))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\07\01\05\00 \00\1a\0b")
+  "unknown local")
+
+;; (func (br 1))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\06\01\04\00\0c\01\0b")
+  "unknown label")
+
+;; (func (call 1))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\06\01\04\00\10\01\0b")
+  "unknown function")
+
+;; (func (global.get 0) (drop))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\07\01\05\00#\00\1a\0b")
+  "unknown global")
+
+;; (global i32 (i32.const 0)) (func (global.set 0 (i32.const 1)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\06\06\01\7f\00A\00\0b"
+    "\0a\08\01\06\00A\01$\00\0b")
+  "global is immutable")
+
+;; (func (type 1))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\01"
+    "\0a\04\01\02\00\0b")
+  "unknown type")
+
+;; (func (block (type 1)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\07\01\05\00\02\01\0b\0b")
+  "unknown type")
+
+;; (func (drop (i32.load (i32.const 0))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\0a\01\08\00A\00(\02\00\1a\0b")
+  "unknown memory")
+
+;; (memory 1) (func (drop (i32.load align=8 (i32.const 0))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\05\03\01\00\01"
+    "\0a\0a\01\08\00A\00(\03\00\1a\0b")
+  "alignment must not be larger than natural")
+
+;; (memory 1) (func (drop (v128.load8_splat align=2 (i32.const 0))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\05\03\01\00\01"
+    "\0a\0b\01\09\00A\00\fd\07\01\00\1a\0b")
+  "alignment must not be larger than natural")
+
+;; (memory 1) (memory 1)
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\05\05\02\00\01\00\01")
+  "multiple memories")
+
+;; (memory 65537)
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\05\05\01\00\81\80\04")
+  "memory size must be at most 65536 pages (4GiB)")
+
+;; (memory 2 1)
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\05\04\01\01\02\01")
+  "size minimum must not be greater than maximum")
+
+;; (table 2 1 funcref)
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\04\05\01p\01\02\01")
+  "size minimum must not be greater than maximum")
+
+;; (func (call_indirect (i32.const 0)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\09\01\07\00A\00\11\00\00\0b")
+  "unknown table")
+
+;; (table 1 externref) (func (call_indirect (i32.const 0)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\04\04\01o\00\01"
+    "\0a\09\01\07\00A\00\11\00\00\0b")
+  "type mismatch")
+
+;; (table 1 funcref) (table 1 externref) (func (table.copy 0 1 (i32.const 0) (i32.const 0) (i32.const 0)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\04\07\02p\00\01o\00\01"
+    "\0a\0e\01\0c\00A\00A\00A\00\fc\0e\00\01\0b")
+  "type mismatch")
+
+;; (table 1 funcref) (elem 0 (i32.const 0) externref (ref.null extern))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\04\04\01p\00\01"
+    "\09\0b\01\06\00A\00\0bo\01\d0o\0b")
+  "type mismatch")
+
+;; (func (elem.drop 0))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\07\01\05\00\fc\0d\00\0b")
+  "unknown elem segment 0")
+
+;; (memory 1) (func (memory.init 1 (i32.const 0) (i32.const 0) (i32.const 0))) (data "")
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\05\03\01\00\01"
+    "\0c\01\01"
+    "\0a\0e\01\0c\00A\00A\00A\00\fc\08\01\00\0b"
+    "\0b\03\01\01\00")
+  "unknown data segment 1")
+
+;; (func (drop (ref.func 0)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\07\01\05\00\d2\00\1a\0b")
+  "undeclared function reference")
+
+;; (func (drop (ref.is_null (i32.const 0))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\08\01\06\00A\00\d1\1a\0b")
+  "type mismatch")
+
+;; (func (param funcref) (drop (select (local.get 0) (local.get 0) (i32.const 1))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\01p\00"
+    "\03\02\01\00"
+    "\0a\0c\01\0a\00 \00 \00A\01\1b\1a\0b")
+  "type mismatch")
+
+;; (func (drop (select (i32.const 0) (i64.const 0) (i32.const 1))))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\0a\0c\01\0a\00A\00B\00A\01\1b\1a\0b")
+  "type mismatch")
+
+;; (func (result i32) (i8x16.extract_lane_s 16 (v128.const i64x2 0 0)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01\7f"
+    "\03\02\01\00"
+    "\0a\19\01\17\00\fd\0c\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\fd\15\10\0b")
+  "invalid lane index")
+
+;; (func (result v128) (i8x16.shuffle 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 32 (v128.const i64x2 0 0) (v128.const i64x2 0 0)))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\00\01{"
+    "\03\02\01\00"
+    "\0a:\018\00\fd\0c\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\fd\0c\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\fd\0d\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00 \0b")
+  "invalid lane index")
+
+;; (global i32 (i32.const 0) (drop) (i32.const 1))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\06\09\01\7f\00A\00\1aA\01\0b")
+  "constant expression required")
+
+;; (global i32 (i64.const 0))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\06\06\01\7f\00B\00\0b")
+  "type mismatch")
+
+;; (global i32 (i32.const 0)) (global i32 (global.get 0))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\06\0b\02\7f\00A\00\0b\7f\00#\00\0b")
+  "unknown global 0")
+
+;; (func) (export "a" (func 0)) (export "a" (func 0))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\04\01`\00\00"
+    "\03\02\01\00"
+    "\07\09\02\01a\00\00\01a\00\00"
+    "\0a\04\01\02\00\0b")
+  "duplicate export name")
+
+;; (export "a" (func 0))
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\07\05\01\01a\00\00")
+  "unknown function 0")
+
+;; (func (param i32)) (start 0)
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\01\05\01`\01\7f\00"
+    "\03\02\01\00"
+    "\08\01\00"
+    "\0a\04\01\02\00\0b")
+  "start function")
+
+;; (start 0)
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\08\01\00")
+  "unknown function")
+
+;; (data (i32.const 0) "")
+(assert_invalid
+  (module binary
+    "\00asm"
+    "\01\00\00\00"
+    "\0b\06\01\00A\00\0b\00")
+  "unknown memory 0")
+
+;; (func (result i32) (unreachable) (i32.add))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\05\01`\00\01\7f"
+  "\03\02\01\00"
+  "\0a\06\01\04\00\00j\0b")
+
+;; (func (result i32) (unreachable) (select))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\05\01`\00\01\7f"
+  "\03\02\01\00"
+  "\0a\06\01\04\00\00\1b\0b")
+
+;; (func (block (br 0) (f32.add) (drop)))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\04\01`\00\00"
+  "\03\02\01\00"
+  "\0a\0b\01\09\00\02@\0c\00\92\1a\0b\0b")
+
+;; (func (block (result i32) (block (result f32) (unreachable) (br_table 0 1))) (drop))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\04\01`\00\00"
+  "\03\02\01\00"
+  "\0a\13\01\11\00\02\7f\02}\00\0e\01\00\01\0b\1aA\00\0b\1a\0b")
+
+;; (func (result i32) (loop (result i32) (i32.const 1)))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\05\01`\00\01\7f"
+  "\03\02\01\00"
+  "\0a\09\01\07\00\03\7fA\01\0b\0b")
+
+;; (func (param i32) (result i32) (local.get 0) (if (param i32) (result i32) (i32.const 1) (then (drop (i32.const 2)))))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\0b\02`\01\7f\01\7f`\01\7f\01\7f"
+  "\03\02\01\00"
+  "\0a\0e\01\0c\00 \00 \00\04\01\1aA\02\0b\0b")
+
+;; (func (drop (ref.func 0))) (elem declare func 0)
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\04\01`\00\00"
+  "\03\02\01\00"
+  "\09\05\01\03\00\01\00"
+  "\0a\07\01\05\00\d2\00\1a\0b")
+
+;; (global funcref (ref.func 0)) (func (drop (ref.func 0)))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\01\04\01`\00\00"
+  "\03\02\01\00"
+  "\06\06\01p\00\d2\00\0b"
+  "\0a\07\01\05\00\d2\00\1a\0b")
+
+;; (import "spectest" "global_i32" (global i32)) (global i32 (global.get 0))
+(module binary
+  "\00asm"
+  "\01\00\00\00"
+  "\02\18\01\08spectest\0aglobal_i32\03\7f\00"
+  "\06\06\01\7f\00#\00\0b")